@@ -0,0 +1,43 @@
+//! Compares the batch evaluation of the income tax tariff with the calculation of every income on
+//! its own.
+
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use net_income_germany::{batch, config, income_tax};
+
+fn bench_batch(c: &mut Criterion) {
+    let config = config::create(config::latest_year()).unwrap();
+    let incomes: Vec<u32> = (0..=300000).step_by(30).collect();
+
+    let mut group = c.benchmark_group("income_tax");
+    for splitting in [false, true] {
+        let suffix = match splitting {
+            true => "splitting",
+            false => "single",
+        };
+
+        group.bench_function(format!("scalar_{suffix}"), |b| {
+            b.iter(|| {
+                incomes
+                    .iter()
+                    .map(|income| {
+                        income_tax::calculate(
+                            &config.income_tax,
+                            black_box(*income),
+                            splitting,
+                            false,
+                        )
+                    })
+                    .collect::<Vec<u32>>()
+            })
+        });
+        group.bench_function(format!("batch_{suffix}"), |b| {
+            b.iter(|| batch::calculate(&config.income_tax, black_box(&incomes), splitting))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_batch);
+criterion_main!(benches);
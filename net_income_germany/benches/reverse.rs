@@ -0,0 +1,57 @@
+//! Compares the reverse calculation, which converts the configuration values only once, with the
+//! same estimation on the default pipeline, which converts them again for every estimated income.
+
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use net_income_germany::config::{self, Config};
+use net_income_germany::error::Error;
+use net_income_germany::pipeline::Pipeline;
+use net_income_germany::{TaxData, TaxResult, calculate, calculate_reverse};
+
+/// Reverse calculation with the same estimation as [`calculate_reverse`], but with a copy of the
+/// input data for every estimated gross income that is calculated with the given pipeline (which
+/// converts the configuration values for every calculation).
+fn calculate_reverse_with_pipeline(
+    pipeline: &Pipeline,
+    config: &Config,
+    tax_data: &TaxData,
+) -> Result<TaxResult, Error> {
+    let mut estimation = tax_data.income as f32 * 1.5;
+
+    loop {
+        let mut estimated_tax_data = tax_data.clone();
+        estimated_tax_data.income = estimation as u32;
+
+        let tax_result = pipeline.calculate(config, &estimated_tax_data)?;
+        let estimation_difference = tax_result.net_income - tax_data.income as i32;
+        estimation *= 1.0 - estimation_difference as f32 / estimation;
+
+        if estimation_difference == 0 {
+            return Ok(tax_result);
+        }
+    }
+}
+
+fn bench_reverse(c: &mut Criterion) {
+    let config = config::create(config::latest_year()).unwrap();
+    let pipeline = Pipeline::default();
+    let tax_data = TaxData::new(60000)
+        .with_expenses(1500)
+        .with_church_member(true);
+    let net_tax_data = TaxData::new(calculate(&config, &tax_data).unwrap().net_income as u32)
+        .with_expenses(1500)
+        .with_church_member(true);
+
+    let mut group = c.benchmark_group("calculate_reverse");
+    group.bench_function("converted_once", |b| {
+        b.iter(|| calculate_reverse(&config, black_box(&net_tax_data)))
+    });
+    group.bench_function("pipeline", |b| {
+        b.iter(|| calculate_reverse_with_pipeline(&pipeline, &config, black_box(&net_tax_data)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_reverse);
+criterion_main!(benches);
@@ -0,0 +1,84 @@
+//! Generates the configurations of the years from the configuration files (in the `config`
+//! directory) at compile time.
+//!
+//! Every file needs to be valid TOML, needs to contain the year of its file name and needs to contain
+//! exactly the keys of the configuration structs, so that a missing or misspelled key in a new year
+//! fails the build instead of the first calculation. All files are validated, but only the years
+//! with an enabled feature (e.g., `year-2025`) are included in the generated code, which is written
+//! to `configs.rs` (for the `config` module) and `sources.rs` (for the `provenance` module) in the
+//! output directory of the build.
+//!
+//! With the `proto` feature, the message types of the Protocol Buffers schema (in the `proto`
+//! directory) are generated with prost as well. The schema is compiled with protox, so that no
+//! installation of protoc is needed.
+
+#![allow(clippy::needless_return)]
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+#[path = "build/config_file.rs"]
+mod config_file;
+
+fn main() {
+    let directory = Path::new("config");
+    println!("cargo::rerun-if-changed={}", directory.display());
+    println!("cargo::rerun-if-changed=build/config_file.rs");
+
+    let out_dir = env::var("OUT_DIR").expect("cargo needs to set the output directory");
+
+    let files = fs::read_dir(directory)
+        .expect("the config directory needs to exist")
+        .map(|entry| {
+            entry
+                .expect("the config directory needs to be readable")
+                .path()
+        })
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|extension| extension == "toml")
+        });
+
+    let mut years = Vec::new();
+    for file in files {
+        let name = file.display().to_string();
+        let source = fs::read_to_string(&file).expect("the config file needs to be readable");
+        let generated =
+            config_file::generate(&source).unwrap_or_else(|err| panic!("{name}: {err}"));
+
+        assert_eq!(
+            file.file_stem().and_then(|stem| stem.to_str()),
+            Some(generated.year.to_string().as_str()),
+            "{name} needs to contain the year of its file name"
+        );
+
+        // cargo sets the variable of every enabled feature
+        if env::var_os(format!("CARGO_FEATURE_YEAR_{}", generated.year)).is_some() {
+            years.push(generated);
+        }
+    }
+    years.sort_by_key(|generated| generated.year);
+
+    let (config_code, sources_code) = config_file::generate_years(&years);
+    fs::write(Path::new(&out_dir).join("configs.rs"), config_code)
+        .expect("the generated configurations need to be writable");
+    fs::write(Path::new(&out_dir).join("sources.rs"), sources_code)
+        .expect("the generated legal sources need to be writable");
+
+    #[cfg(feature = "proto")]
+    generate_proto();
+}
+
+/// Generates the message types of the Protocol Buffers schema.
+#[cfg(feature = "proto")]
+fn generate_proto() {
+    let schema = "proto/net_income_germany.proto";
+    println!("cargo::rerun-if-changed={schema}");
+
+    let file_descriptors = protox::compile([schema], ["proto"])
+        .unwrap_or_else(|err| panic!("{schema} is not a valid schema: {err}"));
+    prost_build::Config::new()
+        .compile_fds(file_descriptors)
+        .expect("the message types need to be generated from the schema");
+}
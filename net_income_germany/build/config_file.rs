@@ -0,0 +1,528 @@
+//! Generator of the configurations of the years from the configuration files (in the `config`
+//! directory of the crate).
+//!
+//! Every section of the configuration is a table of the file and every value a key of the table,
+//! named like the fields of the configuration structs. The build script turns every file into the
+//! Rust expression of its `Config` struct, which is embedded by `config::create`, so that no TOML
+//! needs to be parsed at runtime. Missing, unknown and invalid keys are reported with their full
+//! path, so that errors in contributed files are easy to find.
+//!
+//! The `sources` table of a file is not part of the configuration, but contains the publications of
+//! the values of the year for `provenance::get`. Adding the file of a year (and its `year-<year>`
+//! feature) is therefore enough to include a new year.
+
+use std::str::FromStr;
+
+use toml_edit::{DocumentMut, Item, Table};
+
+/// Number of the progressive tax ranges of the income tax tariff (see `config::TAX_RANGE_COUNT`).
+const TAX_RANGE_COUNT: usize = 5;
+
+/// Number of the household sizes with an income limit of the housing benefit.
+const HOUSING_BENEFIT_LIMIT_COUNT: usize = 5;
+
+/// Reader for the keys of one table, which keeps track of the read keys to detect unknown keys and
+/// collects the fields of the generated struct expression.
+struct TableReader<'a> {
+    path: String,
+    table: &'a Table,
+    read_keys: Vec<&'static str>,
+    fields: Vec<String>,
+}
+
+impl<'a> TableReader<'a> {
+    fn new(path: String, table: &'a Table) -> Self {
+        return TableReader {
+            path,
+            table,
+            read_keys: Vec::new(),
+            fields: Vec::new(),
+        };
+    }
+
+    fn get_path(&self, key: &str) -> String {
+        return match self.path.is_empty() {
+            true => key.to_string(),
+            false => format!("{}.{key}", self.path),
+        };
+    }
+
+    fn get_item(&mut self, key: &'static str) -> Result<&'a Item, String> {
+        self.read_keys.push(key);
+        return self
+            .table
+            .get(key)
+            .ok_or_else(|| format!("Missing key `{}`.", self.get_path(key)));
+    }
+
+    fn get_table(&mut self, key: &'static str) -> Result<TableReader<'a>, String> {
+        let path = self.get_path(key);
+        return match self.get_item(key)?.as_table() {
+            Some(table) => Ok(TableReader::new(path, table)),
+            None => Err(format!("The key `{path}` needs to be a table.")),
+        };
+    }
+
+    fn get_u32(&mut self, key: &'static str) -> Result<u32, String> {
+        let path = self.get_path(key);
+        return self
+            .get_item(key)?
+            .as_integer()
+            .and_then(|value| u32::try_from(value).ok())
+            .ok_or_else(|| format!("The key `{path}` needs to be a positive integer."));
+    }
+
+    fn get_str(&mut self, key: &'static str) -> Result<&'a str, String> {
+        let path = self.get_path(key);
+        return self
+            .get_item(key)?
+            .as_str()
+            .ok_or_else(|| format!("The key `{path}` needs to be a string."));
+    }
+
+    fn get_f32(&mut self, key: &'static str) -> Result<f32, String> {
+        let path = self.get_path(key);
+        let item = self.get_item(key)?;
+        let value = item
+            .as_float()
+            .or_else(|| item.as_integer().map(|value| value as f64));
+
+        return match value {
+            Some(value) if value.is_finite() && value >= 0.0 => Ok(value as f32),
+            _ => Err(format!("The key `{path}` needs to be a positive number.")),
+        };
+    }
+
+    /// Adds the field with the given name and the given code of its value.
+    fn add_field(&mut self, key: &str, code: String) {
+        self.fields.push(format!("{key}: {code}"));
+    }
+
+    /// Reads the given key as positive integer and adds it as field.
+    fn read_u32(&mut self, key: &'static str) -> Result<(), String> {
+        let value = self.get_u32(key)?;
+        self.add_field(key, format!("{value}_u32"));
+        return Ok(());
+    }
+
+    /// Reads the given key as positive number and adds it as field.
+    fn read_f32(&mut self, key: &'static str) -> Result<(), String> {
+        // the debug format is the shortest representation that results in the same value
+        let value = self.get_f32(key)?;
+        self.add_field(key, format!("{value:?}_f32"));
+        return Ok(());
+    }
+
+    /// Reads the given key as string and adds it as field.
+    fn read_str(&mut self, key: &'static str) -> Result<(), String> {
+        // the debug format is the string literal with all necessary escapes
+        let value = self.get_str(key)?;
+        self.add_field(key, format!("{value:?}"));
+        return Ok(());
+    }
+
+    /// Reads the given key as table, which is converted into a field by the given function.
+    fn read_table(
+        &mut self,
+        key: &'static str,
+        parse: fn(TableReader) -> Result<String, String>,
+    ) -> Result<(), String> {
+        let code = parse(self.get_table(key)?)?;
+        self.add_field(key, code);
+        return Ok(());
+    }
+
+    /// Checks that all keys of the table were read and returns the expression of the struct with
+    /// the given name.
+    fn finish(self, name: &str) -> Result<String, String> {
+        if let Some((key, _)) = self
+            .table
+            .iter()
+            .find(|(key, _)| !self.read_keys.contains(key))
+        {
+            return Err(format!("Unknown key `{}`.", self.get_path(key)));
+        }
+
+        return Ok(format!("{name} {{ {} }}", self.fields.join(", ")));
+    }
+}
+
+/// The generated code of the configuration file of one year.
+pub struct GeneratedYear {
+    /// The year of the configuration.
+    pub year: u32,
+
+    /// The Rust expression of the `Config` struct of the year.
+    pub config: String,
+
+    /// The Rust expression of the `YearSources` struct (see `provenance`) of the year.
+    pub sources: String,
+}
+
+/// Generates the Rust expressions of the configuration and of the legal sources from the given
+/// content of a configuration file.
+pub fn generate(source: &str) -> Result<GeneratedYear, String> {
+    let document = DocumentMut::from_str(source).map_err(|err| err.to_string())?;
+    let mut root = TableReader::new(String::new(), document.as_table());
+
+    root.read_table("health_insurance", parse_health_insurance)?;
+    root.read_table("retirement_insurance", parse_retirement_insurance)?;
+    root.read_table("unemployment_insurance", parse_unemployment_insurance)?;
+    root.read_table("income_tax", parse_income_tax)?;
+    root.read_table("capital_income", parse_capital_income)?;
+    root.read_table("retiree_insurance", parse_retiree_insurance)?;
+    root.read_table("minijob", parse_minijob)?;
+    root.read_table("company_pension", parse_company_pension)?;
+    root.read_table("children", parse_children)?;
+    root.read_table("vat", parse_vat)?;
+    root.read_table("housing_benefit", parse_housing_benefit)?;
+    root.read_table("pension", parse_pension)?;
+    root.read_table("midijob", parse_midijob)?;
+    let sources = parse_sources(root.get_table("sources")?)?;
+
+    let year = root.get_u32("year")?;
+    root.add_field(
+        "origin",
+        format!("ConfigOrigin {{ year: {year}, ..Default::default() }}"),
+    );
+
+    return Ok(GeneratedYear {
+        year,
+        config: root.finish("Config")?,
+        sources,
+    });
+}
+
+/// Generates the code of the included years from the generated code of every year, which are given
+/// in ascending order.
+///
+/// Returns the code for `config` (the included years and the creation of their configurations) and
+/// the code for `provenance` (the legal sources of the included years).
+pub fn generate_years(years: &[GeneratedYear]) -> (String, String) {
+    let mut config_code = match years.last() {
+        Some(latest) => format!("const DEFAULT_YEAR: u32 = {};\n", latest.year),
+        None => concat!(
+            "compile_error!(\"At least one year needs to be included with its feature ",
+            "(e.g., `year-2025`).\");\n",
+            "const DEFAULT_YEAR: u32 = 0;\n"
+        )
+        .to_string(),
+    };
+
+    let supported_years: Vec<String> = years.iter().map(|year| year.year.to_string()).collect();
+    config_code.push_str(&format!(
+        "const SUPPORTED_YEARS: &[u32] = &[{}];\n",
+        supported_years.join(", ")
+    ));
+
+    let arms = |code: fn(&GeneratedYear) -> &str| -> String {
+        return years
+            .iter()
+            .map(|year| format!("        {} => Some({}),\n", year.year, code(year)))
+            .collect();
+    };
+    config_code.push_str(&format!(
+        "fn create_included(year: u32) -> Option<Config> {{\n    return match year {{\n{}        _ => None,\n    }};\n}}\n",
+        arms(|year| &year.config)
+    ));
+    let sources_code = format!(
+        "fn year_sources(year: u32) -> Option<YearSources> {{\n    return match year {{\n{}        _ => None,\n    }};\n}}\n",
+        arms(|year| &year.sources)
+    );
+
+    return (config_code, sources_code);
+}
+
+fn parse_sources(mut table: TableReader) -> Result<String, String> {
+    table.read_str("social_security_publication")?;
+    table.read_str("income_tax_publication")?;
+    table.read_str("valid_from")?;
+    table.read_str("pension_valid_from")?;
+    return table.finish("YearSources");
+}
+
+fn parse_health_insurance(mut table: TableReader) -> Result<String, String> {
+    table.read_f32("premium_general")?;
+    table.read_f32("premium_general_reduced")?;
+    table.read_f32("premium_additional")?;
+    table.read_f32("premium_nursing")?;
+    table.read_f32("premium_nursing_additional")?;
+    table.read_f32("min_income")?;
+    table.read_f32("max_income")?;
+    table.read_f32("premium_nursing_employee_surcharge")?;
+    table.read_f32("compulsory_insurance_limit")?;
+    return table.finish("HealthInsuranceConfig");
+}
+
+fn parse_retirement_insurance(mut table: TableReader) -> Result<String, String> {
+    table.read_f32("premium")?;
+    table.read_f32("max_income")?;
+    table.read_f32("max_income_east")?;
+    return table.finish("RetirementInsuranceConfig");
+}
+
+fn parse_unemployment_insurance(mut table: TableReader) -> Result<String, String> {
+    table.read_f32("premium")?;
+    table.read_f32("max_income")?;
+    table.read_f32("max_income_east")?;
+    return table.finish("UnemploymentInsuranceConfig");
+}
+
+fn parse_income_tax(mut table: TableReader) -> Result<String, String> {
+    let path = table.get_path("tax_ranges");
+    let tax_range_tables = table
+        .get_item("tax_ranges")?
+        .as_array_of_tables()
+        .ok_or_else(|| format!("The key `{path}` needs to be an array of tables."))?;
+    if tax_range_tables.len() != TAX_RANGE_COUNT {
+        return Err(format!(
+            "The key `{path}` needs to contain exactly {TAX_RANGE_COUNT} tax ranges."
+        ));
+    }
+
+    // the tax ranges start at zero and every tax range starts where the previous one ends, the last
+    // tax range has no upper limit
+    let mut tax_ranges = Vec::new();
+    let mut expected_lower_limit = 0;
+    for (index, tax_range_table) in tax_range_tables.iter().enumerate() {
+        let mut tax_range = TableReader::new(format!("{path}[{index}]"), tax_range_table);
+
+        let lower_limit = tax_range.get_u32("lower_limit")?;
+        if lower_limit != expected_lower_limit {
+            return Err(format!(
+                "The key `{}` needs to be {expected_lower_limit} (the upper limit of the previous tax range).",
+                tax_range.get_path("lower_limit")
+            ));
+        }
+        tax_range.add_field("lower_limit", format!("{lower_limit}_u32"));
+
+        let upper_limit = match index + 1 == tax_range_tables.len() {
+            true => u32::MAX,
+            false => tax_range.get_u32("upper_limit")?,
+        };
+        if upper_limit < lower_limit {
+            return Err(format!(
+                "The key `{}` needs to be at least the lower limit {lower_limit}.",
+                tax_range.get_path("upper_limit")
+            ));
+        }
+        match upper_limit {
+            u32::MAX => tax_range.add_field("upper_limit", "u32::MAX".to_string()),
+            _ => tax_range.add_field("upper_limit", format!("{upper_limit}_u32")),
+        }
+        expected_lower_limit = upper_limit;
+
+        tax_range.read_f32("rate_min")?;
+        tax_range.read_f32("rate_max")?;
+        tax_ranges.push(tax_range.finish("TaxRange")?);
+    }
+    table.add_field("tax_ranges", format!("[{}]", tax_ranges.join(", ")));
+
+    table.read_table("solidary_addition_config", parse_solidary_addition)?;
+    table.read_table("tax_class_six", parse_tax_class_six)?;
+    table.read_f32("church_tax_rate")?;
+    return table.finish("IncomeTaxConfig");
+}
+
+fn parse_solidary_addition(mut table: TableReader) -> Result<String, String> {
+    table.read_u32("exemption_level")?;
+    table.read_f32("rate")?;
+    table.read_f32("max_percentage")?;
+    return table.finish("SolidaryAdditionConfig");
+}
+
+fn parse_tax_class_six(mut table: TableReader) -> Result<String, String> {
+    table.read_u32("first_limit")?;
+    table.read_u32("second_limit")?;
+    table.read_u32("third_limit")?;
+    return table.finish("TaxClassSixConfig");
+}
+
+fn parse_capital_income(mut table: TableReader) -> Result<String, String> {
+    table.read_f32("flat_rate")?;
+    table.read_u32("saver_allowance")?;
+    table.read_f32("base_rate")?;
+    return table.finish("CapitalIncomeConfig");
+}
+
+fn parse_retiree_insurance(mut table: TableReader) -> Result<String, String> {
+    table.read_f32("company_pension_allowance")?;
+    return table.finish("RetireeInsuranceConfig");
+}
+
+fn parse_minijob_rates(mut table: TableReader) -> Result<String, String> {
+    table.read_f32("health_insurance")?;
+    table.read_f32("retirement_insurance")?;
+    table.read_f32("flat_tax")?;
+    table.read_f32("levies")?;
+    return table.finish("MinijobRatesConfig");
+}
+
+fn parse_minijob(mut table: TableReader) -> Result<String, String> {
+    table.read_f32("income_limit")?;
+    table.read_table("commercial", parse_minijob_rates)?;
+    table.read_table("household", parse_minijob_rates)?;
+    return table.finish("MinijobConfig");
+}
+
+fn parse_company_pension(mut table: TableReader) -> Result<String, String> {
+    table.read_f32("tax_free_share")?;
+    table.read_f32("social_security_free_share")?;
+    table.read_f32("flat_tax_rate")?;
+    table.read_u32("flat_tax_limit")?;
+    return table.finish("CompanyPensionConfig");
+}
+
+fn parse_children(mut table: TableReader) -> Result<String, String> {
+    table.read_u32("child_allowance")?;
+    table.read_u32("care_allowance")?;
+    table.read_u32("child_benefit")?;
+    return table.finish("ChildrenConfig");
+}
+
+fn parse_vat(mut table: TableReader) -> Result<String, String> {
+    table.read_f32("rate")?;
+    table.read_u32("small_business_previous_limit")?;
+    table.read_u32("small_business_current_limit")?;
+    return table.finish("VatConfig");
+}
+
+fn parse_housing_benefit(mut table: TableReader) -> Result<String, String> {
+    let path = table.get_path("income_limits");
+    let income_limits: Vec<String> = table
+        .get_item("income_limits")?
+        .as_array()
+        .map(|array| {
+            array
+                .iter()
+                .filter_map(|value| value.as_integer())
+                .filter_map(|value| u32::try_from(value).ok())
+                .map(|value| format!("{value}_u32"))
+                .collect()
+        })
+        .unwrap_or_default();
+    if income_limits.len() != HOUSING_BENEFIT_LIMIT_COUNT {
+        return Err(format!(
+            "The key `{path}` needs to be an array of {HOUSING_BENEFIT_LIMIT_COUNT} positive integers."
+        ));
+    }
+
+    table.add_field("income_limits", format!("[{}]", income_limits.join(", ")));
+    table.read_u32("additional_member_limit")?;
+    return table.finish("HousingBenefitConfig");
+}
+
+fn parse_pension(mut table: TableReader) -> Result<String, String> {
+    table.read_u32("average_income")?;
+    table.read_f32("pension_value")?;
+    table.read_u32("retirement_provision_limit")?;
+    table.read_f32("taxable_share")?;
+    return table.finish("PensionConfig");
+}
+
+fn parse_midijob(mut table: TableReader) -> Result<String, String> {
+    table.read_f32("lower_limit")?;
+    table.read_f32("upper_limit")?;
+    table.read_f32("factor")?;
+    return table.finish("MidijobConfig");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_embedded_files() {
+        for (year, source) in [
+            (2024, include_str!("../config/2024.toml")),
+            (2025, include_str!("../config/2025.toml")),
+        ] {
+            let generated = generate(source).unwrap_or_else(|err| panic!("{year}: {err}"));
+            assert_eq!(generated.year, year);
+            assert!(
+                generated
+                    .config
+                    .starts_with("Config { health_insurance: HealthInsuranceConfig {")
+            );
+            assert!(
+                generated
+                    .config
+                    .contains("upper_limit: u32::MAX, rate_min: 0.45_f32")
+            );
+            assert!(
+                generated
+                    .sources
+                    .contains(&format!("valid_from: \"{year}-01-01\""))
+            );
+        }
+    }
+
+    #[test]
+    fn test_generate_years() {
+        let years: Vec<GeneratedYear> = [
+            include_str!("../config/2024.toml"),
+            include_str!("../config/2025.toml"),
+        ]
+        .iter()
+        .map(|source| generate(source).unwrap())
+        .collect();
+
+        let (config_code, sources_code) = generate_years(&years);
+        assert!(config_code.contains("const DEFAULT_YEAR: u32 = 2025;"));
+        assert!(config_code.contains("const SUPPORTED_YEARS: &[u32] = &[2024, 2025];"));
+        assert!(config_code.contains("        2024 => Some(Config { "));
+        assert!(sources_code.contains("        2025 => Some(YearSources { "));
+
+        let (config_code, _) = generate_years(&[]);
+        assert!(config_code.starts_with("compile_error!("));
+    }
+
+    #[test]
+    fn test_errors_contain_the_path() {
+        let source = include_str!("../config/2025.toml");
+
+        let missing = source.replace("levies = 0.0262\n", "");
+        assert_eq!(
+            generate(&missing).err().unwrap(),
+            "Missing key `minijob.household.levies`."
+        );
+
+        let unknown = source.replace("factor = 0.6683", "factor = 0.6683\nfactr = 0.6683");
+        assert_eq!(
+            generate(&unknown).err().unwrap(),
+            "Unknown key `midijob.factr`."
+        );
+
+        let invalid = source.replace("lower_limit = 12096", "lower_limit = -1");
+        assert_eq!(
+            generate(&invalid).err().unwrap(),
+            "The key `income_tax.tax_ranges[1].lower_limit` needs to be a positive integer."
+        );
+
+        let range = "[[income_tax.tax_ranges]]\nlower_limit = 0\nupper_limit = 12096\n";
+        let additional = source.replace(
+            range,
+            &format!("{range}rate_min = 0.0\nrate_max = 0.0\n\n{range}"),
+        );
+        assert_eq!(
+            generate(&additional).err().unwrap(),
+            "The key `income_tax.tax_ranges` needs to contain exactly 5 tax ranges."
+        );
+
+        let gap = source.replace("lower_limit = 17444", "lower_limit = 17445");
+        assert_eq!(
+            generate(&gap).err().unwrap(),
+            "The key `income_tax.tax_ranges[2].lower_limit` needs to be 17444 (the upper limit of the \
+             previous tax range)."
+        );
+
+        let inverted = source.replace(
+            "lower_limit = 68480\nupper_limit = 277825",
+            "lower_limit = 68480\nupper_limit = 68479",
+        );
+        assert_eq!(
+            generate(&inverted).err().unwrap(),
+            "The key `income_tax.tax_ranges[3].upper_limit` needs to be at least the lower limit 68480."
+        );
+    }
+}
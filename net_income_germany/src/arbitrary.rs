@@ -0,0 +1,444 @@
+//! Random input data with realistic value ranges for property-based tests.
+//!
+//! Code that is built on this crate can be tested with many random inputs without writing own
+//! generators. There is one feature per source of the random values, which all use the same value
+//! ranges (e.g., incomes up to [`MAX_INCOME`]):
+//!
+//! - `arbitrary`: the values are drawn from a given random number generator with the `Arbitrary`
+//!   trait of this module, so that they can be used with any test framework.
+//! - `proptest`: the tax data and the configuration overrides implement
+//!   `proptest::arbitrary::Arbitrary`, so that they can be generated with `any::<TaxData>()`.
+//! - `quickcheck`: the tax data and the configuration overrides implement `quickcheck::Arbitrary`,
+//!   so that they can be arguments of quickcheck properties.
+//!
+//! ```
+//! # #[cfg(feature = "arbitrary")] {
+//! use net_income_germany::arbitrary::Arbitrary;
+//! use rand::SeedableRng;
+//!
+//! let config = net_income_germany::config::create(net_income_germany::config::latest_year()).unwrap();
+//! let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+//!
+//! for _ in 0..100 {
+//!     let tax_data = net_income_germany::TaxData::arbitrary(&mut rng);
+//!     assert!(net_income_germany::calculate(&config, &tax_data).is_ok());
+//! }
+//! # }
+//! ```
+//!
+//! With proptest, the values are generated by the strategies of their types:
+//!
+//! ```
+//! # #[cfg(feature = "proptest")] {
+//! use proptest::prelude::*;
+//!
+//! let config = net_income_germany::config::create(net_income_germany::config::latest_year()).unwrap();
+//! proptest!(|(tax_data in any::<net_income_germany::TaxData>())| {
+//!     prop_assert!(net_income_germany::calculate(&config, &tax_data).is_ok());
+//! });
+//! # }
+//! ```
+
+use crate::scenario::ConfigOverrides;
+use crate::what_if::{Levy, LevyToggle};
+use crate::{Assessment, TaxData};
+
+/// Maximum yearly gross income of the generated tax data.
+pub const MAX_INCOME: u32 = 500000;
+
+/// Maximum yearly expenses of the generated tax data.
+pub const MAX_EXPENSES: u32 = 20000;
+
+/// Maximum fixed monthly retirement insurance rate of the generated tax data.
+pub const MAX_FIXED_RETIREMENT: u32 = 1500;
+
+/// Maximum additional premium \[0,1\] of the health insurance of the generated overrides.
+const MAX_HEALTH_PREMIUM_ADDITIONAL: f32 = 0.045;
+
+/// Maximum additional premium \[0,1\] of the nursing insurance of the generated overrides.
+const MAX_NURSING_PREMIUM_ADDITIONAL: f32 = 0.01;
+
+/// Maximum factor of the generated levy toggles.
+const MAX_LEVY_FACTOR: f32 = 2.0;
+
+/// Maximum number of levy toggles of the generated overrides.
+const MAX_LEVY_TOGGLES: usize = 2;
+
+/// The church tax rates of the generated overrides: the one of Bayern and Baden-Württemberg, the one
+/// of all other federal states or the one of the configuration.
+const CHURCH_TAX_RATES: [Option<f32>; 3] = [Some(0.08), Some(0.09), None];
+
+/// All levies that can be toggled.
+const LEVIES: [Levy; 6] = [
+    Levy::SolidaritySurcharge,
+    Levy::UnemploymentInsurance,
+    Levy::NursingSurcharge,
+    Levy::HealthInsurance,
+    Levy::NursingInsurance,
+    Levy::ChurchTax,
+];
+
+/// All types of the income tax assessment.
+const ASSESSMENTS: [Assessment; 3] = [
+    Assessment::Single,
+    Assessment::Joint,
+    Assessment::WidowedSplitting,
+];
+
+/// Types that can be created from random values with realistic value ranges.
+#[cfg(feature = "arbitrary")]
+pub trait Arbitrary: Sized {
+    /// Creates a random value with the given random number generator.
+    fn arbitrary<R: rand::Rng + ?Sized>(rng: &mut R) -> Self;
+}
+
+#[cfg(feature = "arbitrary")]
+impl Arbitrary for Assessment {
+    fn arbitrary<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+        return ASSESSMENTS[rng.gen_range(0..ASSESSMENTS.len())];
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl Arbitrary for TaxData {
+    fn arbitrary<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+        let self_employed = rng.gen_bool(0.3);
+
+        // fixed retirement insurance rates only apply to self-employed persons
+        let fixed_retirement = match self_employed && rng.gen_bool(0.5) {
+            true => Some(rng.gen_range(0..=MAX_FIXED_RETIREMENT)),
+            false => None,
+        };
+
+        return TaxData::new(rng.gen_range(0..=MAX_INCOME))
+            .with_expenses(rng.gen_range(0..=MAX_EXPENSES))
+            .with_fixed_retirement(fixed_retirement)
+            .with_self_employed(self_employed)
+            .with_assessment(Assessment::arbitrary(rng))
+            .with_church_member(rng.gen_bool(0.5));
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl Arbitrary for Levy {
+    fn arbitrary<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+        return LEVIES[rng.gen_range(0..LEVIES.len())];
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl Arbitrary for LevyToggle {
+    fn arbitrary<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+        return LevyToggle {
+            levy: Levy::arbitrary(rng),
+            factor: rng.gen_range(0.0..=MAX_LEVY_FACTOR),
+        };
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl Arbitrary for ConfigOverrides {
+    fn arbitrary<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+        // the additional premiums of the insurance companies and the nursing surcharge for childless
+        // persons stay within a few percent
+        let health_premium_additional = match rng.gen_bool(0.5) {
+            true => Some(rng.gen_range(0.0..=MAX_HEALTH_PREMIUM_ADDITIONAL)),
+            false => None,
+        };
+        let nursing_premium_additional = match rng.gen_bool(0.5) {
+            true => Some(rng.gen_range(0.0..=MAX_NURSING_PREMIUM_ADDITIONAL)),
+            false => None,
+        };
+        let levy_toggles = (0..rng.gen_range(0..=MAX_LEVY_TOGGLES))
+            .map(|_| LevyToggle::arbitrary(rng))
+            .collect();
+
+        return ConfigOverrides {
+            health_premium_additional,
+            nursing_premium_additional,
+            levy_toggles,
+            church_tax_rate: CHURCH_TAX_RATES[rng.gen_range(0..CHURCH_TAX_RATES.len())],
+        };
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl proptest::arbitrary::Arbitrary for Assessment {
+    type Parameters = ();
+    type Strategy = proptest::sample::Select<Assessment>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        return proptest::sample::select(&ASSESSMENTS[..]);
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl proptest::arbitrary::Arbitrary for TaxData {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<TaxData>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use proptest::prelude::*;
+
+        // fixed retirement insurance rates only apply to self-employed persons
+        let employment = prop_oneof![
+            7 => Just((false, None)),
+            3 => proptest::option::of(0..=MAX_FIXED_RETIREMENT)
+                .prop_map(|fixed_retirement| (true, fixed_retirement)),
+        ];
+
+        return (
+            0..=MAX_INCOME,
+            0..=MAX_EXPENSES,
+            employment,
+            any::<Assessment>(),
+            any::<bool>(),
+        )
+            .prop_map(
+                |(
+                    income,
+                    expenses,
+                    (self_employed, fixed_retirement),
+                    assessment,
+                    church_member,
+                )| {
+                    TaxData::new(income)
+                        .with_expenses(expenses)
+                        .with_fixed_retirement(fixed_retirement)
+                        .with_self_employed(self_employed)
+                        .with_assessment(assessment)
+                        .with_church_member(church_member)
+                },
+            )
+            .boxed();
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl proptest::arbitrary::Arbitrary for Levy {
+    type Parameters = ();
+    type Strategy = proptest::sample::Select<Levy>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        return proptest::sample::select(&LEVIES[..]);
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl proptest::arbitrary::Arbitrary for LevyToggle {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<LevyToggle>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use proptest::prelude::*;
+
+        return (any::<Levy>(), 0.0..=MAX_LEVY_FACTOR)
+            .prop_map(|(levy, factor)| LevyToggle { levy, factor })
+            .boxed();
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl proptest::arbitrary::Arbitrary for ConfigOverrides {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<ConfigOverrides>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use proptest::prelude::*;
+
+        // the additional premiums of the insurance companies and the nursing surcharge for childless
+        // persons stay within a few percent
+        return (
+            proptest::option::of(0.0..=MAX_HEALTH_PREMIUM_ADDITIONAL),
+            proptest::option::of(0.0..=MAX_NURSING_PREMIUM_ADDITIONAL),
+            proptest::collection::vec(any::<LevyToggle>(), 0..=MAX_LEVY_TOGGLES),
+            proptest::sample::select(&CHURCH_TAX_RATES[..]),
+        )
+            .prop_map(
+                |(
+                    health_premium_additional,
+                    nursing_premium_additional,
+                    levy_toggles,
+                    church_tax_rate,
+                )| ConfigOverrides {
+                    health_premium_additional,
+                    nursing_premium_additional,
+                    levy_toggles,
+                    church_tax_rate,
+                },
+            )
+            .boxed();
+    }
+}
+
+/// Returns a random value in the range \[0,max\] from the given quickcheck generator.
+#[cfg(feature = "quickcheck")]
+fn quickcheck_range(g: &mut quickcheck::Gen, max: u32) -> u32 {
+    return <u32 as quickcheck::Arbitrary>::arbitrary(g) % (max + 1);
+}
+
+/// Returns a random rate in the range \[0,max\] from the given quickcheck generator.
+#[cfg(feature = "quickcheck")]
+fn quickcheck_rate(g: &mut quickcheck::Gen, max: f32) -> f32 {
+    return quickcheck_range(g, 10000) as f32 / 10000.0 * max;
+}
+
+#[cfg(feature = "quickcheck")]
+impl quickcheck::Arbitrary for Assessment {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        return ASSESSMENTS[quickcheck_range(g, ASSESSMENTS.len() as u32 - 1) as usize];
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl quickcheck::Arbitrary for TaxData {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        let self_employed = quickcheck_range(g, 9) < 3;
+
+        // fixed retirement insurance rates only apply to self-employed persons
+        let fixed_retirement = match self_employed && <bool as quickcheck::Arbitrary>::arbitrary(g)
+        {
+            true => Some(quickcheck_range(g, MAX_FIXED_RETIREMENT)),
+            false => None,
+        };
+
+        return TaxData::new(quickcheck_range(g, MAX_INCOME))
+            .with_expenses(quickcheck_range(g, MAX_EXPENSES))
+            .with_fixed_retirement(fixed_retirement)
+            .with_self_employed(self_employed)
+            .with_assessment(<Assessment as quickcheck::Arbitrary>::arbitrary(g))
+            .with_church_member(<bool as quickcheck::Arbitrary>::arbitrary(g));
+    }
+
+    /// Shrinks the income and the expenses, so that failures are reported with small values.
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let tax_data = self.clone();
+        let incomes = quickcheck::Arbitrary::shrink(&self.income).map(move |income| TaxData {
+            income,
+            ..tax_data.clone()
+        });
+
+        let tax_data = self.clone();
+        let expenses = quickcheck::Arbitrary::shrink(&self.expenses).map(move |expenses| TaxData {
+            expenses,
+            ..tax_data.clone()
+        });
+
+        return Box::new(incomes.chain(expenses));
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl quickcheck::Arbitrary for Levy {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        return LEVIES[quickcheck_range(g, LEVIES.len() as u32 - 1) as usize];
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl quickcheck::Arbitrary for LevyToggle {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        return LevyToggle {
+            levy: <Levy as quickcheck::Arbitrary>::arbitrary(g),
+            factor: quickcheck_rate(g, MAX_LEVY_FACTOR),
+        };
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl quickcheck::Arbitrary for ConfigOverrides {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        // the additional premiums of the insurance companies and the nursing surcharge for childless
+        // persons stay within a few percent
+        let health_premium_additional = match <bool as quickcheck::Arbitrary>::arbitrary(g) {
+            true => Some(quickcheck_rate(g, MAX_HEALTH_PREMIUM_ADDITIONAL)),
+            false => None,
+        };
+        let nursing_premium_additional = match <bool as quickcheck::Arbitrary>::arbitrary(g) {
+            true => Some(quickcheck_rate(g, MAX_NURSING_PREMIUM_ADDITIONAL)),
+            false => None,
+        };
+        let levy_toggles = (0..quickcheck_range(g, MAX_LEVY_TOGGLES as u32))
+            .map(|_| <LevyToggle as quickcheck::Arbitrary>::arbitrary(g))
+            .collect();
+        let church_tax_rate =
+            CHURCH_TAX_RATES[quickcheck_range(g, CHURCH_TAX_RATES.len() as u32 - 1) as usize];
+
+        return ConfigOverrides {
+            health_premium_additional,
+            nursing_premium_additional,
+            levy_toggles,
+            church_tax_rate,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculate;
+    use crate::config::{create as create_config, latest_year};
+
+    /// Returns whether the generated values are within their ranges and the calculation succeeds.
+    fn check_values(tax_data: &TaxData, overrides: &ConfigOverrides) -> bool {
+        let mut config = create_config(latest_year()).unwrap();
+        overrides.apply(&mut config);
+
+        return tax_data.income <= MAX_INCOME
+            && tax_data.expenses <= MAX_EXPENSES
+            && (tax_data.fixed_retirement.is_none() || tax_data.self_employed)
+            && overrides.levy_toggles.len() <= MAX_LEVY_TOGGLES
+            && calculate(&config, tax_data).is_ok();
+    }
+
+    #[test]
+    #[cfg(feature = "arbitrary")]
+    fn test_value_ranges_and_calculation() {
+        use rand::SeedableRng;
+        use rand::rngs::StdRng;
+
+        let mut rng = StdRng::seed_from_u64(1);
+
+        for _ in 0..500 {
+            let tax_data = TaxData::arbitrary(&mut rng);
+            let overrides = ConfigOverrides::arbitrary(&mut rng);
+            assert!(check_values(&tax_data, &overrides));
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    proptest::proptest! {
+        #[test]
+        fn test_proptest_value_ranges_and_calculation(
+            tax_data in proptest::arbitrary::any::<TaxData>(),
+            overrides in proptest::arbitrary::any::<ConfigOverrides>(),
+        ) {
+            proptest::prop_assert!(check_values(&tax_data, &overrides));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "quickcheck")]
+    fn test_quickcheck_value_ranges_and_calculation() {
+        fn property(tax_data: TaxData, overrides: ConfigOverrides) -> bool {
+            return check_values(&tax_data, &overrides);
+        }
+
+        quickcheck::QuickCheck::new()
+            .tests(500)
+            .quickcheck(property as fn(TaxData, ConfigOverrides) -> bool);
+    }
+
+    #[test]
+    #[cfg(feature = "quickcheck")]
+    fn test_quickcheck_shrinks_within_ranges() {
+        use quickcheck::Arbitrary;
+
+        let tax_data = TaxData::new(80000).with_expenses(1000);
+        assert!(
+            tax_data
+                .shrink()
+                .all(|shrunk| shrunk.income <= 80000 && shrunk.expenses <= 1000)
+        );
+    }
+}
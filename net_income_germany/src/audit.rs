@@ -0,0 +1,98 @@
+//! Cross-check of a configuration against official reference values.
+//!
+//! The reference values are the income taxes of the tariff (§ 32a EStG) for selected taxable
+//! incomes, as calculated by the income tax calculator of the Federal Ministry of Finance
+//! (BMF-Steuerrechner, see [`crate::test_vectors::INCOME_TAX`]). The check recalculates them with
+//! the given configuration, so that custom configurations or the configurations of new years can be
+//! validated:
+//!
+//! ```
+//! let config = net_income_germany::config::create(net_income_germany::config::latest_year()).unwrap();
+//!
+//! let deviations = net_income_germany::audit::check(&config).unwrap();
+//! assert!(deviations.is_empty());
+//! ```
+
+use crate::config::Config;
+use crate::error::Error;
+use crate::income_tax::calculate_tariff;
+use crate::test_vectors;
+
+/// Maximum difference (in euros) between a reference value and the calculated value, which is
+/// caused by the rounding of the calculation (and doubled by the tax splitting).
+pub const TOLERANCE: u32 = 2;
+
+/// Deviation of a calculated value from its reference value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Deviation {
+    /// The taxable income of the reference value.
+    pub taxable_income: u32,
+
+    /// Whether tax splitting applies for the reference value.
+    pub splitting: bool,
+
+    /// The income taxes according to the reference.
+    pub expected: u32,
+
+    /// The income taxes calculated with the configuration.
+    pub calculated: u32,
+}
+
+/// Recalculates all reference values of the year of the given configuration and returns those that
+/// deviate by more than [`TOLERANCE`] from the calculated values.
+///
+/// Returns an error if there are no reference values for the year of the configuration.
+pub fn check(config: &Config) -> Result<Vec<Deviation>, Error> {
+    let year = config.origin().year;
+    let references: Vec<_> = test_vectors::income_taxes(year).collect();
+    if references.is_empty() {
+        return Err(Error::NoReferenceValues);
+    }
+
+    return Ok(references
+        .into_iter()
+        .map(|reference| Deviation {
+            taxable_income: reference.taxable_income,
+            splitting: reference.splitting,
+            expected: reference.income_tax,
+            calculated: calculate_tariff(
+                &config.income_tax,
+                reference.taxable_income,
+                reference.splitting,
+            ),
+        })
+        .filter(|deviation| deviation.calculated.abs_diff(deviation.expected) > TOLERANCE)
+        .collect());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::create as create_config;
+
+    #[test]
+    fn test_configurations_match_references() {
+        for year in crate::config::supported_years() {
+            assert_eq!(check(&create_config(*year).unwrap()).unwrap(), vec![]);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "year-2025")]
+    fn test_deviations_of_custom_configuration() {
+        let mut config = create_config(2025).unwrap();
+        config.income_tax.tax_ranges[0].upper_limit = 13000;
+        config.income_tax.tax_ranges[1].lower_limit = 13000;
+
+        let deviations = check(&config).unwrap();
+        assert!(!deviations.is_empty());
+        assert!(
+            deviations
+                .iter()
+                .all(|deviation| deviation.calculated < deviation.expected)
+        );
+
+        config.origin.year = 2023;
+        assert_eq!(check(&config).err(), Some(Error::NoReferenceValues));
+    }
+}
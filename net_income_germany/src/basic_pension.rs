@@ -0,0 +1,282 @@
+//! Basic pension (Basisrente or Rürup-Rente) with the deferred taxation of its payouts.
+//!
+//! The contributions to a basic pension are retirement provision expenses, which are deductible
+//! from the taxable income together with the contributions to the statutory retirement insurance up
+//! to the maximum retirement provision expenses (§ 10 Abs. 3 EStG). The payouts are taxed like the
+//! statutory pension with the taxable share of the year in which they start (§ 22 Nr. 1 Satz 3
+//! Buchstabe a Doppelbuchstabe aa EStG, see [`pension::get_taxable_share`]).
+//!
+//! The payouts are no income on which the health insurance of compulsorily insured retirees applies
+//! (see [`crate::retiree`]), but voluntarily insured retirees pay contributions on them like on any
+//! other income.
+//!
+//! This allows to compare the income taxes that are saved in the contribution years with the taxes
+//! and contributions that are paid on the payouts in the retirement:
+//!
+//! ```
+//! use net_income_germany::basic_pension::{self, BasicPensionData};
+//!
+//! let config = net_income_germany::config::create(net_income_germany::config::latest_year()).unwrap();
+//! let tax_data = net_income_germany::TaxData::new(80000);
+//! let data = BasicPensionData {
+//!     contribution: 6000,
+//!     contribution_years: 20,
+//!     payout: 7200,
+//!     payout_years: 20,
+//!     statutory_pension: 24000,
+//!     start_year: 2045,
+//!     voluntarily_insured: false,
+//! };
+//!
+//! let result = basic_pension::compare(&config, &tax_data, &data).unwrap();
+//! println!("Saved taxes: {}, paid taxes: {}", result.total_tax_saving, result.total_payout_burden);
+//! ```
+
+use crate::config::Config;
+use crate::error::Error;
+use crate::numeric::{Number, Numeric};
+use crate::pension;
+use crate::retiree::{self, RetireeData};
+use crate::{Assessment, TaxData, income_tax};
+
+/// Input data struct for the comparison of the contribution years and the payout years.
+#[derive(Clone)]
+pub struct BasicPensionData {
+    /// The yearly contribution to the basic pension.
+    pub contribution: u32,
+
+    /// The number of years in which the contribution is paid.
+    pub contribution_years: u32,
+
+    /// The expected yearly payout of the basic pension.
+    pub payout: u32,
+
+    /// The expected number of years in which the payout is received.
+    pub payout_years: u32,
+
+    /// The yearly statutory pension that is received in addition to the payout.
+    pub statutory_pension: u32,
+
+    /// The year in which the payout and the statutory pension start.
+    pub start_year: u32,
+
+    /// Whether the retiree is voluntarily insured in the health insurance, so that contributions
+    /// apply on the payout.
+    pub voluntarily_insured: bool,
+}
+
+/// Result struct of one year of the contribution phase.
+pub struct ContributionResult {
+    /// The part of the contribution that is deductible from the taxable income.
+    pub deductible_contribution: u32,
+
+    /// The income taxes that are saved by deducting the contribution.
+    pub tax_saving: u32,
+}
+
+/// Result struct of one year of the payout phase.
+pub struct PayoutResult {
+    /// The taxable share \[0,1\] of the payout.
+    pub taxable_share: f32,
+
+    /// The contributions to the health and nursing care insurance that apply because of the payout.
+    pub health_insurance: u32,
+
+    /// The income taxes (including the solidarity surcharge and the church tax for members of a
+    /// church) that apply because of the payout.
+    pub income_taxes: u32,
+
+    /// The payout after the contributions and the income taxes.
+    pub net_payout: i64,
+}
+
+/// Result struct of the comparison of the contribution years and the payout years.
+pub struct BasicPensionResult {
+    /// The result of every contribution year.
+    pub contribution: ContributionResult,
+
+    /// The result of every payout year.
+    pub payout: PayoutResult,
+
+    /// The sum of the saved income taxes of all contribution years.
+    pub total_tax_saving: u64,
+
+    /// The sum of the contributions and the income taxes on the payouts of all payout years.
+    pub total_payout_burden: u64,
+}
+
+/// Calculates the deductible part of the given yearly contribution and the income taxes that are
+/// saved with it for the given tax data.
+pub fn calculate_contribution(
+    config: &Config,
+    tax_data: &TaxData,
+    contribution: u32,
+) -> Result<ContributionResult, Error> {
+    let (deductible_contribution, tax_saving) =
+        pension::calculate_provision_deduction(config, tax_data, contribution)?;
+
+    return Ok(ContributionResult {
+        deductible_contribution,
+        tax_saving,
+    });
+}
+
+/// Returns the contributions of the retiree and the income taxes for the given yearly pensions.
+fn calculate_retirement_burden(
+    config: &Config,
+    statutory_pension: u32,
+    payout: u32,
+    taxable_share: f32,
+    voluntarily_insured: bool,
+    assessment: Assessment,
+    church_member: bool,
+) -> (u32, u32) {
+    let health_insurance = retiree::calculate(
+        config,
+        &RetireeData {
+            statutory_pension,
+            company_pension: 0,
+            other_income: payout,
+            voluntarily_insured,
+        },
+    )
+    .get_total();
+
+    let taxable_income = (Number::from_u32(statutory_pension + payout)
+        * Number::from_f32(taxable_share))
+    .to_u32()
+    .saturating_sub(pension::PENSION_ALLOWANCE)
+    .saturating_sub(health_insurance);
+
+    return (
+        health_insurance,
+        income_tax::calculate(
+            &config.income_tax,
+            taxable_income,
+            assessment.is_splitting(),
+            church_member,
+        ),
+    );
+}
+
+/// Calculates the contributions and the income taxes that apply because of the given yearly payout
+/// in addition to the statutory pension. Apart from the taxable share, the values of the
+/// configuration are used (i.e., the results are in today's money).
+pub fn calculate_payout(
+    config: &Config,
+    data: &BasicPensionData,
+    assessment: Assessment,
+    church_member: bool,
+) -> PayoutResult {
+    let taxable_share = pension::get_taxable_share(config, data.start_year);
+    let (pension_health_insurance, pension_income_taxes) = calculate_retirement_burden(
+        config,
+        data.statutory_pension,
+        0,
+        taxable_share,
+        data.voluntarily_insured,
+        assessment,
+        church_member,
+    );
+    let (total_health_insurance, total_income_taxes) = calculate_retirement_burden(
+        config,
+        data.statutory_pension,
+        data.payout,
+        taxable_share,
+        data.voluntarily_insured,
+        assessment,
+        church_member,
+    );
+
+    let health_insurance = total_health_insurance - pension_health_insurance;
+    let income_taxes = total_income_taxes - pension_income_taxes;
+
+    return PayoutResult {
+        taxable_share,
+        health_insurance,
+        income_taxes,
+        net_payout: data.payout as i64 - health_insurance as i64 - income_taxes as i64,
+    };
+}
+
+/// Compares the income taxes that are saved in the contribution years (with the given tax data of a
+/// contribution year) with the contributions and the income taxes that are paid on the payouts.
+pub fn compare(
+    config: &Config,
+    tax_data: &TaxData,
+    data: &BasicPensionData,
+) -> Result<BasicPensionResult, Error> {
+    let contribution = calculate_contribution(config, tax_data, data.contribution)?;
+    let payout = calculate_payout(config, data, tax_data.assessment, tax_data.church_member);
+
+    return Ok(BasicPensionResult {
+        total_tax_saving: contribution.tax_saving as u64 * data.contribution_years as u64,
+        total_payout_burden: (payout.health_insurance + payout.income_taxes) as u64
+            * data.payout_years as u64,
+        contribution,
+        payout,
+    });
+}
+
+#[cfg(all(test, feature = "year-2025"))]
+mod tests {
+    use super::*;
+    use crate::config::create as create_config;
+
+    fn create_data(voluntarily_insured: bool) -> BasicPensionData {
+        return BasicPensionData {
+            contribution: 6000,
+            contribution_years: 20,
+            payout: 7200,
+            payout_years: 20,
+            statutory_pension: 24000,
+            start_year: 2045,
+            voluntarily_insured,
+        };
+    }
+
+    #[test]
+    fn test_contribution() {
+        let config = create_config(2025).unwrap();
+
+        let result = calculate_contribution(&config, &TaxData::new(80000), 6000).unwrap();
+        assert_eq!(result.deductible_contribution, 6000);
+        assert!(result.tax_saving > 2000);
+
+        // the regular contributions on the maximum income are 17966
+        let result = calculate_contribution(&config, &TaxData::new(100000), 20000).unwrap();
+        assert_eq!(result.deductible_contribution, 29344 - 17966);
+    }
+
+    #[test]
+    fn test_payout() {
+        let config = create_config(2025).unwrap();
+
+        let result = calculate_payout(&config, &create_data(false), Assessment::Single, false);
+        assert_eq!(result.taxable_share, 0.935);
+        assert_eq!(result.health_insurance, 0);
+        assert!(result.income_taxes > 0);
+        assert_eq!(result.net_payout, 7200 - result.income_taxes as i64);
+
+        // voluntarily insured retirees pay contributions on the payout
+        let voluntary = calculate_payout(&config, &create_data(true), Assessment::Single, false);
+        assert!(voluntary.health_insurance > 0);
+        assert!(voluntary.net_payout < result.net_payout);
+    }
+
+    #[test]
+    fn test_compare() {
+        let config = create_config(2025).unwrap();
+
+        let result = compare(&config, &TaxData::new(80000), &create_data(false)).unwrap();
+        assert_eq!(
+            result.total_tax_saving,
+            result.contribution.tax_saving as u64 * 20
+        );
+        assert_eq!(
+            result.total_payout_burden,
+            result.payout.income_taxes as u64 * 20
+        );
+        assert!(result.total_tax_saving > result.total_payout_burden);
+    }
+}
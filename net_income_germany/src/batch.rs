@@ -0,0 +1,146 @@
+//! Batch evaluation of the income tax tariff for many taxable incomes at once.
+//!
+//! Simulations often need the taxes of thousands of incomes for the same configuration. Instead of
+//! calling [`crate::income_tax::calculate`] for every income, the batch evaluation converts the
+//! parameters of every tax range and of the solidarity surcharge only once and then iterates over
+//! the incomes in chunks, one tax range at a time. The inner loops only contain the arithmetic of
+//! the tariff on contiguous arrays, which the compiler can vectorize. The results are identical to
+//! the ones of the single calculation:
+//!
+//! ```
+//! let config = net_income_germany::config::create(net_income_germany::config::latest_year()).unwrap();
+//!
+//! let incomes: Vec<u32> = (0..=200000).step_by(100).collect();
+//! let taxes = net_income_germany::batch::calculate(&config.income_tax, &incomes, false);
+//!
+//! assert_eq!(
+//!     taxes[500],
+//!     net_income_germany::income_tax::calculate(&config.income_tax, incomes[500], false, false)
+//! );
+//! ```
+
+use crate::config::IncomeTaxConfig;
+use crate::income_tax::{SolidarityAdditionParameters, TariffParameters};
+use crate::numeric::{Number, Numeric};
+
+/// Number of incomes that are processed together for every tax range.
+const CHUNK_SIZE: usize = 256;
+
+/// Calculates the income taxes (including the solidarity surcharge, but without the church tax) for
+/// all the given taxable incomes, in the same order as the incomes.
+///
+/// If `splitting` is set, the tax splitting for married couples is applied to every income (see
+/// [`crate::income_tax::calculate`]).
+pub fn calculate(config: &IncomeTaxConfig, taxable_incomes: &[u32], splitting: bool) -> Vec<u32> {
+    let mut taxes = vec![0; taxable_incomes.len()];
+    calculate_into(config, taxable_incomes, splitting, &mut taxes);
+
+    return taxes;
+}
+
+/// Calculates the income taxes like [`calculate`], but writes them into the given output slice.
+///
+/// Only as many incomes are calculated as fit into the output slice, so that the caller can reuse
+/// the same output buffer for several batches without any allocations.
+pub fn calculate_into(
+    config: &IncomeTaxConfig,
+    taxable_incomes: &[u32],
+    splitting: bool,
+    taxes: &mut [u32],
+) {
+    let tariff = TariffParameters::new(config);
+    let solidarity_addition =
+        SolidarityAdditionParameters::new(&config.solidary_addition_config, splitting);
+    let two = Number::from_u32(2);
+    let zero = Number::from_u32(0);
+
+    let mut incomes = [0; CHUNK_SIZE];
+    let mut tax_sums = [zero; CHUNK_SIZE];
+
+    for (taxable_incomes, taxes) in taxable_incomes
+        .chunks(CHUNK_SIZE)
+        .zip(taxes.chunks_mut(CHUNK_SIZE))
+    {
+        let length = taxable_incomes.len().min(taxes.len());
+        let incomes = &mut incomes[..length];
+        let tax_sums = &mut tax_sums[..length];
+
+        // for married couples the taxes are calculated based on half of the combined income
+        for (income, taxable_income) in incomes.iter_mut().zip(taxable_incomes) {
+            *income = match splitting {
+                true => taxable_income / 2,
+                false => *taxable_income,
+            };
+        }
+        tax_sums.fill(zero);
+
+        for range in &tariff.ranges {
+            for (tax_sum, income) in tax_sums.iter_mut().zip(incomes.iter()) {
+                // incomes below the lower limit are not taxed in this tax range
+                let taxed_income = income.saturating_sub(range.lower_limit).min(range.range);
+                if taxed_income == 0 {
+                    continue;
+                }
+
+                let taxed_income = Number::from_u32(taxed_income);
+                let effective_rate_diff = taxed_income / range.income_range * range.rate_diff;
+                let effective_rate = range.rate_min + effective_rate_diff / two;
+
+                *tax_sum += taxed_income * effective_rate;
+            }
+        }
+
+        for (tax, tax_sum) in taxes.iter_mut().zip(tax_sums.iter()) {
+            // the tax value needs to be doubled again after calculating with half for married couples
+            let tariff = match splitting {
+                true => tax_sum.to_u32() * 2,
+                false => tax_sum.to_u32(),
+            };
+            *tax = tariff + solidarity_addition.calculate(tariff);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::create as create_config;
+    use crate::income_tax;
+
+    #[test]
+    fn test_batch_matches_single_calculation() {
+        for year in crate::config::supported_years() {
+            let config = create_config(*year).unwrap();
+            let incomes: Vec<u32> = (0..=600000).step_by(37).collect();
+
+            for splitting in [false, true] {
+                let taxes = calculate(&config.income_tax, &incomes, splitting);
+
+                assert_eq!(taxes.len(), incomes.len());
+                for (income, tax) in incomes.iter().zip(taxes) {
+                    assert_eq!(
+                        tax,
+                        income_tax::calculate(&config.income_tax, *income, splitting, false)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "year-2025")]
+    fn test_calculate_into_smaller_output() {
+        let config = create_config(2025).unwrap();
+        let incomes = [20000, 40000, 60000];
+
+        let mut taxes = [0; 2];
+        calculate_into(&config.income_tax, &incomes, false, &mut taxes);
+        assert_eq!(
+            taxes,
+            [
+                income_tax::calculate(&config.income_tax, 20000, false, false),
+                income_tax::calculate(&config.income_tax, 40000, false, false)
+            ]
+        );
+    }
+}
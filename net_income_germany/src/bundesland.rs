@@ -0,0 +1,301 @@
+//! Federal states of Germany (Bundesländer) with their location-dependent rules.
+//!
+//! Some rules of the tax and social security calculation depend on the federal state:
+//! - the rate of the church tax (Kirchensteuer) is 8% in Bayern and Baden-Württemberg and 9% in all
+//!   other federal states
+//! - employees in Sachsen pay an additional 0.5% of the nursing care insurance premium instead of the
+//!   employer, because Sachsen kept the Buß- und Bettag as public holiday (§ 58 Abs. 3 SGB XI)
+//! - the eastern federal states have their own maximum incomes of the retirement and unemployment
+//!   insurance (until 2024)
+//!
+//! The rules for the social security calculation and the rate of the church tax are applied to the
+//! configuration:
+//!
+//! ```
+//! use net_income_germany::bundesland::Bundesland;
+//!
+//! let mut config = net_income_germany::config::create(net_income_germany::config::latest_year()).unwrap();
+//! let state: Bundesland = "SN".parse().unwrap();
+//! state.adapt_config(&mut config);
+//!
+//! assert_eq!(config.health_insurance.premium_nursing_employee_surcharge, 0.005);
+//! ```
+
+use crate::config::{self, Config};
+use crate::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Share of the nursing care insurance premium \[0,1\] that employees in Sachsen pay instead of the
+/// employer.
+const SAXONY_NURSING_SURCHARGE: f32 = 0.005;
+
+/// Federal states of Germany (Bundesländer).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Bundesland {
+    BadenWuerttemberg,
+    Bayern,
+    Berlin,
+    Brandenburg,
+    Bremen,
+    Hamburg,
+    Hessen,
+    MecklenburgVorpommern,
+    Niedersachsen,
+    NordrheinWestfalen,
+    RheinlandPfalz,
+    Saarland,
+    Sachsen,
+    SachsenAnhalt,
+    SchleswigHolstein,
+    Thueringen,
+}
+
+impl Bundesland {
+    /// All federal states in alphabetical order.
+    pub const ALL: [Bundesland; 16] = [
+        Bundesland::BadenWuerttemberg,
+        Bundesland::Bayern,
+        Bundesland::Berlin,
+        Bundesland::Brandenburg,
+        Bundesland::Bremen,
+        Bundesland::Hamburg,
+        Bundesland::Hessen,
+        Bundesland::MecklenburgVorpommern,
+        Bundesland::Niedersachsen,
+        Bundesland::NordrheinWestfalen,
+        Bundesland::RheinlandPfalz,
+        Bundesland::Saarland,
+        Bundesland::Sachsen,
+        Bundesland::SachsenAnhalt,
+        Bundesland::SchleswigHolstein,
+        Bundesland::Thueringen,
+    ];
+
+    /// Returns the German name of the federal state.
+    pub fn name(&self) -> &'static str {
+        return match self {
+            Bundesland::BadenWuerttemberg => "Baden-Württemberg",
+            Bundesland::Bayern => "Bayern",
+            Bundesland::Berlin => "Berlin",
+            Bundesland::Brandenburg => "Brandenburg",
+            Bundesland::Bremen => "Bremen",
+            Bundesland::Hamburg => "Hamburg",
+            Bundesland::Hessen => "Hessen",
+            Bundesland::MecklenburgVorpommern => "Mecklenburg-Vorpommern",
+            Bundesland::Niedersachsen => "Niedersachsen",
+            Bundesland::NordrheinWestfalen => "Nordrhein-Westfalen",
+            Bundesland::RheinlandPfalz => "Rheinland-Pfalz",
+            Bundesland::Saarland => "Saarland",
+            Bundesland::Sachsen => "Sachsen",
+            Bundesland::SachsenAnhalt => "Sachsen-Anhalt",
+            Bundesland::SchleswigHolstein => "Schleswig-Holstein",
+            Bundesland::Thueringen => "Thüringen",
+        };
+    }
+
+    /// Returns the official two-letter abbreviation of the federal state (ISO 3166-2:DE).
+    pub fn abbreviation(&self) -> &'static str {
+        return match self {
+            Bundesland::BadenWuerttemberg => "BW",
+            Bundesland::Bayern => "BY",
+            Bundesland::Berlin => "BE",
+            Bundesland::Brandenburg => "BB",
+            Bundesland::Bremen => "HB",
+            Bundesland::Hamburg => "HH",
+            Bundesland::Hessen => "HE",
+            Bundesland::MecklenburgVorpommern => "MV",
+            Bundesland::Niedersachsen => "NI",
+            Bundesland::NordrheinWestfalen => "NW",
+            Bundesland::RheinlandPfalz => "RP",
+            Bundesland::Saarland => "SL",
+            Bundesland::Sachsen => "SN",
+            Bundesland::SachsenAnhalt => "ST",
+            Bundesland::SchleswigHolstein => "SH",
+            Bundesland::Thueringen => "TH",
+        };
+    }
+
+    /// Returns the rate \[0,1\] of the church tax on the income taxes.
+    pub fn get_church_tax_rate(&self) -> f32 {
+        return match self {
+            Bundesland::Bayern | Bundesland::BadenWuerttemberg => 0.08,
+            _ => 0.09,
+        };
+    }
+
+    /// Returns whether the employees pay a larger share of the nursing care insurance premium than
+    /// the employers, which is the case in Sachsen.
+    pub fn has_nursing_split(&self) -> bool {
+        return *self == Bundesland::Sachsen;
+    }
+
+    /// Returns whether the federal state is one of the eastern federal states with their own maximum
+    /// incomes of the retirement and unemployment insurance. Berlin is treated as western federal
+    /// state.
+    pub fn is_east(&self) -> bool {
+        return matches!(
+            self,
+            Bundesland::Brandenburg
+                | Bundesland::MecklenburgVorpommern
+                | Bundesland::Sachsen
+                | Bundesland::SachsenAnhalt
+                | Bundesland::Thueringen
+        );
+    }
+
+    /// Applies the rules of the federal state for the social security calculation and the rate of
+    /// the church tax to the given configuration.
+    ///
+    /// The values that depend on the federal state are reset to the ones of the configuration of the
+    /// year (see [`config::cached`]) first, so that another federal state can be applied on a
+    /// configuration that was already adapted. Without a configuration of the year, the values of
+    /// other federal states are kept.
+    pub fn adapt_config(&self, config: &mut Config) {
+        let base = config::cached(config.origin.year).ok();
+
+        let church_tax_rate = self.get_church_tax_rate();
+        if config.income_tax.church_tax_rate != church_tax_rate {
+            config.income_tax.church_tax_rate = church_tax_rate;
+            config.record_modification("income_tax.church_tax_rate");
+        }
+
+        let nursing_surcharge = match self.has_nursing_split() {
+            true => Some(SAXONY_NURSING_SURCHARGE),
+            false => base.map(|base| base.health_insurance.premium_nursing_employee_surcharge),
+        };
+        if let Some(nursing_surcharge) = nursing_surcharge
+            && config.health_insurance.premium_nursing_employee_surcharge != nursing_surcharge
+        {
+            config.health_insurance.premium_nursing_employee_surcharge = nursing_surcharge;
+            config.record_modification("health_insurance.premium_nursing_employee_surcharge");
+        }
+
+        let (retirement_max_income, unemployment_max_income) = match self.is_east() {
+            true => (
+                Some(config.retirement_insurance.max_income_east),
+                Some(config.unemployment_insurance.max_income_east),
+            ),
+            false => (
+                base.map(|base| base.retirement_insurance.max_income),
+                base.map(|base| base.unemployment_insurance.max_income),
+            ),
+        };
+        if let Some(max_income) = retirement_max_income
+            && config.retirement_insurance.max_income != max_income
+        {
+            config.retirement_insurance.max_income = max_income;
+            config.record_modification("retirement_insurance.max_income");
+        }
+        if let Some(max_income) = unemployment_max_income
+            && config.unemployment_insurance.max_income != max_income
+        {
+            config.unemployment_insurance.max_income = max_income;
+            config.record_modification("unemployment_insurance.max_income");
+        }
+    }
+}
+
+impl fmt::Display for Bundesland {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "{}", self.name());
+    }
+}
+
+impl FromStr for Bundesland {
+    type Err = Error;
+
+    /// Parses the federal state from its name or its abbreviation (ignoring the case).
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim().to_lowercase();
+
+        return Bundesland::ALL
+            .into_iter()
+            .find(|state| {
+                state.name().to_lowercase() == value || state.abbreviation().to_lowercase() == value
+            })
+            .ok_or(Error::UnknownFederalState);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::create as create_config;
+
+    #[test]
+    fn test_conversions() {
+        for state in Bundesland::ALL {
+            assert_eq!(state.name().parse::<Bundesland>().unwrap(), state);
+            assert_eq!(state.abbreviation().parse::<Bundesland>().unwrap(), state);
+        }
+
+        assert_eq!(
+            "baden-württemberg".parse::<Bundesland>().unwrap(),
+            Bundesland::BadenWuerttemberg
+        );
+        assert_eq!(Bundesland::Thueringen.to_string(), "Thüringen");
+        assert_eq!(
+            "Atlantis".parse::<Bundesland>().err(),
+            Some(Error::UnknownFederalState)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "year-2024")]
+    fn test_adapt_config() {
+        let mut config = create_config(2024).unwrap();
+        Bundesland::Bayern.adapt_config(&mut config);
+        assert_eq!(
+            config.health_insurance.premium_nursing_employee_surcharge,
+            0.0
+        );
+        assert_eq!(config.retirement_insurance.max_income, 7550.0);
+        assert_eq!(config.income_tax.church_tax_rate, 0.08);
+
+        Bundesland::Sachsen.adapt_config(&mut config);
+        assert_eq!(
+            config.health_insurance.premium_nursing_employee_surcharge,
+            0.005
+        );
+        assert_eq!(config.retirement_insurance.max_income, 7450.0);
+        assert_eq!(config.unemployment_insurance.max_income, 7450.0);
+        assert_eq!(config.income_tax.church_tax_rate, 0.09);
+        assert_eq!(config.origin().modified_fields.len(), 4);
+    }
+
+    #[test]
+    #[cfg(feature = "year-2024")]
+    fn test_adapt_config_in_sequence() {
+        let mut config = create_config(2024).unwrap();
+        Bundesland::Sachsen.adapt_config(&mut config);
+        Bundesland::Bayern.adapt_config(&mut config);
+
+        // the values of Sachsen are reset to the ones of the year
+        assert_eq!(
+            config.health_insurance.premium_nursing_employee_surcharge,
+            0.0
+        );
+        assert_eq!(config.retirement_insurance.max_income, 7550.0);
+        assert_eq!(config.unemployment_insurance.max_income, 7550.0);
+        assert_eq!(config.income_tax.church_tax_rate, 0.08);
+    }
+
+    #[test]
+    #[cfg(feature = "year-2025")]
+    fn test_nursing_split_of_employees() {
+        let tax_data = crate::TaxData::new(50000);
+
+        let config = create_config(2025).unwrap();
+        let mut saxony_config = create_config(2025).unwrap();
+        Bundesland::Sachsen.adapt_config(&mut saxony_config);
+
+        let regular = crate::calculate(&config, &tax_data).unwrap();
+        let saxony = crate::calculate(&saxony_config, &tax_data).unwrap();
+        assert_eq!(
+            saxony.social_security_taxes - regular.social_security_taxes,
+            250
+        );
+    }
+}
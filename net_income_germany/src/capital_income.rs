@@ -0,0 +1,252 @@
+//! Flat tax on capital income (Abgeltungsteuer).
+//!
+//! Capital income (interest, dividends and realized gains) is taxed with a flat rate plus the
+//! solidarity surcharge, independent of the personal income tax rate. Only the capital income above
+//! the saver's allowance (Sparer-Pauschbetrag) is taxed.
+//!
+//! Distributions and gains of investment funds are partially exempt from taxes depending on the type
+//! of the fund (Teilfreistellung), so the capital income can also be given as separate items with an
+//! optional fund type.
+//!
+//! On request, the capital income is taxed with the personal income tax rate instead of the flat
+//! rate, if this leads to lower taxes (Günstigerprüfung, § 32d Abs. 6 EStG). This is beneficial for
+//! years with a low other income.
+
+use crate::config::Config;
+use crate::error::Error;
+use crate::income_tax;
+use crate::investment_fund::FundType;
+use crate::numeric::{Number, Numeric};
+use crate::{Assessment, TaxData};
+
+/// One item of capital income (e.g., the distributions or the realized gains of one fund).
+#[derive(Clone)]
+pub struct CapitalIncomeItem {
+    /// The capital income of the item.
+    pub amount: u32,
+
+    /// The type of the fund for fund income with a partial exemption (`None` for other capital
+    /// income like interest or dividends of single shares).
+    pub fund_type: Option<FundType>,
+}
+
+impl CapitalIncomeItem {
+    /// Returns the part of the capital income that remains taxable after the partial exemption.
+    pub fn get_taxable_amount(&self) -> u32 {
+        return match self.fund_type {
+            Some(fund_type) => fund_type.get_taxable_income(self.amount),
+            None => self.amount,
+        };
+    }
+}
+
+/// Result struct of the capital income tax calculation.
+pub struct CapitalIncomeResult {
+    /// The capital income that remains taxable after deducting the saver's allowance.
+    pub taxable_capital_income: u32,
+
+    /// The flat tax on the taxable capital income.
+    pub flat_tax: u32,
+
+    /// The solidarity surcharge on the flat tax.
+    pub solidarity_surcharge: u32,
+}
+
+impl CapitalIncomeResult {
+    /// Returns the sum of the flat tax and the solidarity surcharge.
+    pub fn get_taxes(&self) -> u32 {
+        return self.flat_tax + self.solidarity_surcharge;
+    }
+}
+
+/// Calculates the flat tax and the solidarity surcharge on the given yearly capital income.
+///
+/// The saver's allowance is doubled for married couples. The solidarity surcharge on the flat tax
+/// applies without an exemption level.
+pub fn calculate(config: &Config, capital_income: u32, married: bool) -> CapitalIncomeResult {
+    let saver_allowance = match married {
+        true => config.capital_income.saver_allowance * 2,
+        false => config.capital_income.saver_allowance,
+    };
+
+    let taxable_capital_income = capital_income.saturating_sub(saver_allowance);
+    let flat_tax = (Number::from_u32(taxable_capital_income)
+        * Number::from_f32(config.capital_income.flat_rate))
+    .to_u32();
+    let solidarity_surcharge = (Number::from_u32(flat_tax)
+        * Number::from_f32(config.income_tax.solidary_addition_config.rate))
+    .to_u32();
+
+    return CapitalIncomeResult {
+        taxable_capital_income,
+        flat_tax,
+        solidarity_surcharge,
+    };
+}
+
+/// Calculates the flat tax and the solidarity surcharge on the given capital income items, applying
+/// the partial exemption of every item before deducting the saver's allowance.
+pub fn calculate_items(
+    config: &Config,
+    items: &[CapitalIncomeItem],
+    married: bool,
+) -> CapitalIncomeResult {
+    let capital_income = items.iter().fold(0u32, |sum, item| {
+        sum.saturating_add(item.get_taxable_amount())
+    });
+
+    return calculate(config, capital_income, married);
+}
+
+/// Result struct of the comparison of the flat tax and the personal income tax rate.
+pub struct FavorableAssessmentResult {
+    /// The flat tax and the solidarity surcharge (and the church tax for members of a church) on the
+    /// capital income.
+    pub flat_taxes: u32,
+
+    /// The additional income taxes (including the solidarity surcharge and the church tax for
+    /// members of a church) if the capital income is taxed with the personal income tax rate.
+    pub personal_rate_taxes: u32,
+
+    /// Whether the personal income tax rate applies, because it leads to lower taxes.
+    pub personal_rate_applied: bool,
+}
+
+impl FavorableAssessmentResult {
+    /// Returns the taxes on the capital income of the applied option.
+    pub fn get_taxes(&self) -> u32 {
+        return match self.personal_rate_applied {
+            true => self.personal_rate_taxes,
+            false => self.flat_taxes,
+        };
+    }
+}
+
+/// Calculates the taxes on the given yearly capital income with the flat tax and with the personal
+/// income tax rate (on top of the other income of the given tax data) and applies the lower one.
+pub fn calculate_favorable_assessment(
+    config: &Config,
+    tax_data: &TaxData,
+    capital_income: u32,
+) -> Result<FavorableAssessmentResult, Error> {
+    // the saver's allowance is only doubled for the joint assessment of a married couple
+    let flat_tax_result = calculate(
+        config,
+        capital_income,
+        tax_data.assessment == Assessment::Joint,
+    );
+    let (_, taxable_income) = crate::calculate_taxable_income(config, tax_data)?;
+
+    // the saver's allowance also applies for the personal income tax rate
+    let income_taxes = income_tax::calculate(
+        &config.income_tax,
+        taxable_income,
+        tax_data.assessment.is_splitting(),
+        tax_data.church_member,
+    );
+    let income_taxes_with_capital_income = income_tax::calculate(
+        &config.income_tax,
+        taxable_income.saturating_add(flat_tax_result.taxable_capital_income),
+        tax_data.assessment.is_splitting(),
+        tax_data.church_member,
+    );
+
+    // the church tax on the flat tax is approximated without the reduction of the flat rate that
+    // applies for members of a church (§ 32d Abs. 1 Satz 3 EStG)
+    let flat_taxes = match tax_data.church_member {
+        true => {
+            flat_tax_result.get_taxes()
+                + income_tax::calculate_church_tax(&config.income_tax, flat_tax_result.flat_tax)
+        }
+        false => flat_tax_result.get_taxes(),
+    };
+    let personal_rate_taxes = income_taxes_with_capital_income - income_taxes;
+
+    return Ok(FavorableAssessmentResult {
+        flat_taxes,
+        personal_rate_taxes,
+        personal_rate_applied: personal_rate_taxes < flat_taxes,
+    });
+}
+
+#[cfg(all(test, feature = "year-2025"))]
+mod tests {
+    use super::*;
+    use crate::config::create as create_config;
+
+    #[test]
+    fn test_flat_tax_above_allowance() {
+        let config = create_config(2025).unwrap();
+
+        let result = calculate(&config, 11000, false);
+        assert_eq!(result.taxable_capital_income, 10000);
+        assert_eq!(result.flat_tax, 2500);
+        assert_eq!(result.solidarity_surcharge, 137);
+        assert_eq!(result.get_taxes(), 2637);
+    }
+
+    #[test]
+    fn test_no_tax_within_allowance() {
+        let config = create_config(2025).unwrap();
+
+        assert_eq!(calculate(&config, 1000, false).get_taxes(), 0);
+        assert_eq!(calculate(&config, 2000, true).get_taxes(), 0);
+        assert!(calculate(&config, 2000, false).get_taxes() > 0);
+    }
+
+    #[test]
+    fn test_partial_exemption_per_item() {
+        let config = create_config(2025).unwrap();
+        let items = vec![
+            CapitalIncomeItem {
+                amount: 10000,
+                fund_type: Some(FundType::Equity),
+            },
+            CapitalIncomeItem {
+                amount: 2000,
+                fund_type: Some(FundType::Mixed),
+            },
+            CapitalIncomeItem {
+                amount: 1000,
+                fund_type: None,
+            },
+            CapitalIncomeItem {
+                amount: 1000,
+                fund_type: Some(FundType::ForeignRealEstate),
+            },
+        ];
+
+        // 7000 + 1700 + 1000 + 200 - 1000 saver's allowance
+        let result = calculate_items(&config, &items, false);
+        assert_eq!(result.taxable_capital_income, 8900);
+        assert_eq!(result.flat_tax, 2225);
+    }
+
+    #[test]
+    fn test_personal_rate_for_low_income() {
+        let config = create_config(2025).unwrap();
+
+        // without other income, the capital income stays below the basic allowance
+        let result = calculate_favorable_assessment(&config, &TaxData::new(0), 10000).unwrap();
+        assert_eq!(result.flat_taxes, 2373);
+        assert_eq!(result.personal_rate_taxes, 0);
+        assert!(result.personal_rate_applied);
+        assert_eq!(result.get_taxes(), 0);
+    }
+
+    #[test]
+    fn test_flat_tax_for_high_income() {
+        let config = create_config(2025).unwrap();
+
+        let result = calculate_favorable_assessment(&config, &TaxData::new(100000), 10000).unwrap();
+        assert!(result.personal_rate_taxes > result.flat_taxes);
+        assert!(!result.personal_rate_applied);
+        assert_eq!(result.get_taxes(), 2373);
+
+        // members of a church additionally pay 9% church tax on the flat tax of 2250
+        let tax_data = TaxData::new(100000).with_church_member(true);
+        let result = calculate_favorable_assessment(&config, &tax_data, 10000).unwrap();
+        assert!(!result.personal_rate_applied);
+        assert_eq!(result.get_taxes(), 2373 + 202);
+    }
+}
@@ -0,0 +1,138 @@
+//! Utilization of the maximum incomes of the social security insurances (Beitragsbemessungsgrenzen).
+//!
+//! The contributions to every insurance are only calculated on the income up to the maximum income
+//! of the insurance. For an income above the maximum income, the additional income is free of
+//! contributions, which is why the marginal social security burden of high earners drops to zero:
+//!
+//! ```
+//! let config = net_income_germany::config::create(net_income_germany::config::latest_year()).unwrap();
+//! let tax_data = net_income_germany::TaxData::new(90000);
+//!
+//! for utilization in net_income_germany::ceiling::calculate(&config, &tax_data).unwrap() {
+//!     println!(
+//!         "{:?}: capped {}, saved {}",
+//!         utilization.insurance, utilization.is_capped, utilization.saved_contributions
+//!     );
+//! }
+//! ```
+
+use crate::config::Config;
+use crate::error::Error;
+use crate::numeric::{Number, Numeric};
+use crate::observer::{CalculationObserver, Insurance};
+use crate::{TaxData, calculate_with_observer};
+
+/// Utilization of the maximum income of one insurance.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CeilingUtilization {
+    /// The insurance.
+    pub insurance: Insurance,
+
+    /// The yearly income on which the contributions were actually calculated (zero for a fixed
+    /// retirement insurance rate or an insurance that does not apply).
+    pub assessed_income: u32,
+
+    /// The yearly maximum income of the insurance.
+    pub max_income: u32,
+
+    /// Whether the income exceeds the maximum income, so that the contributions were capped.
+    pub is_capped: bool,
+
+    /// The contributions that would have been paid additionally on the income above the maximum
+    /// income.
+    pub saved_contributions: u32,
+}
+
+/// Observer that records the assessed income and the premium of every insurance.
+#[derive(Default)]
+struct CeilingObserver {
+    insurances: Vec<(Insurance, f32, f32)>,
+}
+
+impl CalculationObserver for CeilingObserver {
+    fn on_insurance(&mut self, insurance: Insurance, assessed_income: f32, premium: f32, _: f32) {
+        self.insurances.push((insurance, assessed_income, premium));
+    }
+}
+
+/// Returns the yearly maximum income of the given insurance.
+fn get_max_income(config: &Config, insurance: Insurance) -> u32 {
+    let max_income = match insurance {
+        Insurance::Health | Insurance::Nursing => config.health_insurance.max_income,
+        Insurance::Retirement => config.retirement_insurance.max_income,
+        Insurance::Unemployment => config.unemployment_insurance.max_income,
+    };
+
+    return (Number::from_f32(max_income) * Number::from_u32(12)).to_u32();
+}
+
+/// Calculates the utilization of the maximum incomes of all insurances for the given tax data.
+pub fn calculate(config: &Config, tax_data: &TaxData) -> Result<Vec<CeilingUtilization>, Error> {
+    let mut observer = CeilingObserver::default();
+    calculate_with_observer(config, tax_data, &mut observer)?;
+
+    return Ok(observer
+        .insurances
+        .into_iter()
+        .map(|(insurance, assessed_income, premium)| {
+            let max_income = get_max_income(config, insurance);
+            let is_capped = premium > 0.0 && tax_data.income > max_income;
+            let saved_contributions = match is_capped {
+                true => (Number::from_u32(tax_data.income - max_income)
+                    * Number::from_f32(premium))
+                .to_u32(),
+                false => 0,
+            };
+
+            CeilingUtilization {
+                insurance,
+                assessed_income: assessed_income as u32,
+                max_income,
+                is_capped,
+                saved_contributions,
+            }
+        })
+        .collect());
+}
+
+#[cfg(all(test, feature = "year-2025"))]
+mod tests {
+    use super::*;
+    use crate::config::create as create_config;
+
+    #[test]
+    fn test_below_and_above_max_incomes() {
+        let config = create_config(2025).unwrap();
+
+        let utilizations = calculate(&config, &TaxData::new(50000)).unwrap();
+        assert_eq!(utilizations.len(), 4);
+        assert!(
+            utilizations
+                .iter()
+                .all(|utilization| !utilization.is_capped)
+        );
+        assert_eq!(utilizations[0].assessed_income, 50000);
+
+        // the health insurance is capped at 66150 and the retirement insurance at 96600
+        let utilizations = calculate(&config, &TaxData::new(80000)).unwrap();
+        assert!(utilizations[0].is_capped);
+        assert_eq!(utilizations[0].assessed_income, 66150);
+        assert!(utilizations[0].saved_contributions > 0);
+        assert!(!utilizations[2].is_capped);
+        assert_eq!(utilizations[2].max_income, 96600);
+        assert_eq!(utilizations[2].saved_contributions, 0);
+    }
+
+    #[test]
+    fn test_fixed_retirement_is_not_capped() {
+        let config = create_config(2025).unwrap();
+        let mut tax_data = TaxData::new(120000);
+        tax_data.fixed_retirement = Some(500);
+
+        let utilizations = calculate(&config, &tax_data).unwrap();
+        assert_eq!(utilizations[2].insurance, Insurance::Retirement);
+        assert!(!utilizations[2].is_capped);
+        assert_eq!(utilizations[2].assessed_income, 0);
+        assert!(utilizations[3].is_capped);
+    }
+}
@@ -0,0 +1,115 @@
+//! Membership fees of the chamber of industry and commerce (IHK-Beitrag) for trade businesses.
+//!
+//! Every trade business (Gewerbetreibender) is a member of the chamber and pays a yearly basic fee
+//! and an assessment on the profit (Umlage). The fees are defined by each chamber, but the
+//! allowances are statutory (§ 3 Abs. 3 IHKG): natural persons that are not registered in the
+//! commercial register are exempt with a profit up to 5200 and the assessment only applies to the
+//! profit above 15340.
+//!
+//! The fees are operating expenses and are therefore added to the expenses of the tax calculation.
+
+use crate::config::Config;
+use crate::error::Error;
+use crate::numeric::{Number, Numeric};
+use crate::{TaxData, TaxResult};
+
+/// Maximum profit of a business without any chamber fees.
+const EXEMPTION_LIMIT: u32 = 5200;
+
+/// Allowance on the profit for the assessment of natural persons.
+const ASSESSMENT_ALLOWANCE: u32 = 15340;
+
+/// Fees of a chamber of industry and commerce.
+#[derive(Debug, Clone)]
+pub struct ChamberFees {
+    /// The yearly basic fee (Grundbeitrag).
+    pub basic_fee: u32,
+
+    /// The rate \[0,1\] of the assessment on the profit (Hebesatz der Umlage).
+    pub assessment_rate: f32,
+}
+
+/// Result struct of the tax calculation with chamber fees.
+pub struct ChamberResult {
+    /// The yearly fees for the chamber.
+    pub fees: u32,
+
+    /// The result of the tax calculation with the fees as additional expenses.
+    pub tax_result: TaxResult,
+}
+
+impl ChamberFees {
+    /// Calculates the yearly fees for the given profit of a business that is not registered in the
+    /// commercial register.
+    pub fn calculate_fees(&self, profit: u32) -> u32 {
+        if profit <= EXEMPTION_LIMIT {
+            return 0;
+        }
+
+        let assessment = Number::from_u32(profit.saturating_sub(ASSESSMENT_ALLOWANCE))
+            * Number::from_f32(self.assessment_rate);
+
+        return self.basic_fee + assessment.to_u32();
+    }
+}
+
+/// Calculates social security taxes and income taxes of a trade business with the chamber fees as
+/// additional expenses.
+///
+/// The fees are calculated on the income minus the expenses of the tax data.
+pub fn calculate(
+    config: &Config,
+    tax_data: &TaxData,
+    fees: &ChamberFees,
+) -> Result<ChamberResult, Error> {
+    let fees = fees.calculate_fees(tax_data.income.saturating_sub(tax_data.expenses));
+
+    let mut chamber_tax_data = tax_data.clone();
+    chamber_tax_data.expenses = tax_data.expenses.saturating_add(fees);
+
+    return Ok(ChamberResult {
+        fees,
+        tax_result: crate::calculate(config, &chamber_tax_data)?,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "year-2025")]
+    use crate::config::create as create_config;
+
+    fn create_fees() -> ChamberFees {
+        return ChamberFees {
+            basic_fee: 60,
+            assessment_rate: 0.002,
+        };
+    }
+
+    #[test]
+    fn test_fees() {
+        let fees = create_fees();
+
+        assert_eq!(fees.calculate_fees(5000), 0);
+        assert_eq!(fees.calculate_fees(10000), 60);
+        assert_eq!(fees.calculate_fees(65340), 160);
+    }
+
+    #[test]
+    #[cfg(feature = "year-2025")]
+    fn test_fees_as_expenses() {
+        let config = create_config(2025).unwrap();
+        let mut tax_data = TaxData::new(70340)
+            .with_expenses(5000)
+            .with_fixed_retirement(Some(0))
+            .with_self_employed(true);
+
+        let result = calculate(&config, &tax_data, &create_fees()).unwrap();
+
+        tax_data.expenses = 5160;
+        let expected = crate::calculate(&config, &tax_data).unwrap();
+
+        assert_eq!(result.fees, 160);
+        assert_eq!(result.tax_result.net_income, expected.net_income);
+    }
+}
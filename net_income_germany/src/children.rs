@@ -0,0 +1,191 @@
+//! Allowances and benefits for children (Kinderfreibeträge and Kindergeld).
+//!
+//! For every child, the parents receive the monthly child benefit. In the income tax assessment, the
+//! finance office checks whether the child allowances lead to a higher tax reduction than the child
+//! benefit (Günstigerprüfung, § 31 EStG). In that case, the allowances are deducted from the taxable
+//! income and the child benefit is added to the income taxes instead. The allowances are always
+//! considered for the solidarity surcharge.
+//!
+//! Each parent is entitled to half of the allowances of a child, so that jointly assessed parents
+//! receive the full allowances. Separated or unmarried parents receive half of the allowances each,
+//! unless the half of the other parent was transferred (e.g., because the other parent does not pay
+//! child support). The child benefit is credited in the same share as the allowances.
+
+use crate::config::Config;
+use crate::error::Error;
+use crate::{TaxData, income_tax};
+
+/// Share of the allowances of a child that a parent is entitled to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AllowanceShare {
+    /// The full allowances (for jointly assessed parents or after a transfer of the other half).
+    Full,
+    /// Half of the allowances (for separated or unmarried parents).
+    Half,
+}
+
+impl AllowanceShare {
+    /// Returns the part of the given amount (for both parents together) for this share.
+    fn apply(&self, amount: u32) -> u32 {
+        return match self {
+            AllowanceShare::Full => amount,
+            AllowanceShare::Half => amount / 2,
+        };
+    }
+}
+
+/// Input data struct for one child.
+#[derive(Clone)]
+pub struct Child {
+    /// The share of the allowances of the child.
+    pub allowance_share: AllowanceShare,
+
+    /// Whether the child benefit is paid to this parent (or the jointly assessed parents).
+    pub receives_child_benefit: bool,
+}
+
+/// Result struct of the calculation with allowances and benefits for children.
+pub struct ChildrenResult {
+    /// The yearly child benefit that is paid to this parent.
+    pub child_benefit: u32,
+
+    /// The child benefit that is credited against the tax reduction of the allowances.
+    pub credited_child_benefit: u32,
+
+    /// The sum of the allowances this parent is entitled to.
+    pub allowances: u32,
+
+    /// Whether the allowances are more beneficial than the child benefit and are therefore applied.
+    pub allowances_applied: bool,
+
+    /// The income taxes (including the solidarity surcharge) after the check of the allowances.
+    pub income_taxes: u32,
+
+    /// The net income including the child benefit.
+    pub net_income: i32,
+}
+
+/// Calculates the income taxes and the net income of a parent with the given children.
+pub fn calculate(
+    config: &Config,
+    tax_data: &TaxData,
+    children: &[Child],
+) -> Result<ChildrenResult, Error> {
+    let regular = crate::calculate(config, tax_data)?;
+    let (_, taxable_income) = crate::calculate_taxable_income(config, tax_data)?;
+
+    let yearly_child_benefit = config.children.child_benefit * 12;
+    let mut child_benefit = 0;
+    let mut credited_child_benefit = 0;
+    let mut allowances = 0;
+
+    for child in children {
+        if child.receives_child_benefit {
+            child_benefit += yearly_child_benefit;
+        }
+
+        credited_child_benefit += child.allowance_share.apply(yearly_child_benefit);
+        allowances += child
+            .allowance_share
+            .apply(config.children.child_allowance + config.children.care_allowance);
+    }
+
+    // the solidarity surcharge is always calculated with the allowances
+    let taxable_income_with_allowances = taxable_income.saturating_sub(allowances);
+    let tariff_with_allowances = income_tax::calculate_tariff(
+        &config.income_tax,
+        taxable_income_with_allowances,
+        tax_data.assessment.is_splitting(),
+    );
+    let solidarity_surcharge = income_tax::calculate_solidarity_addition(
+        tariff_with_allowances,
+        tax_data.assessment.is_splitting(),
+        &config.income_tax.solidary_addition_config,
+    );
+
+    let tariff = income_tax::calculate_tariff(
+        &config.income_tax,
+        taxable_income,
+        tax_data.assessment.is_splitting(),
+    );
+    let allowances_applied = tariff_with_allowances + credited_child_benefit < tariff;
+    let income_taxes = match allowances_applied {
+        true => tariff_with_allowances + credited_child_benefit,
+        false => tariff,
+    } + solidarity_surcharge;
+
+    return Ok(ChildrenResult {
+        child_benefit,
+        credited_child_benefit,
+        allowances,
+        allowances_applied,
+        income_taxes,
+        net_income: (regular.net_income as i64 + regular.income_taxes as i64 - income_taxes as i64
+            + child_benefit as i64) as i32,
+    });
+}
+
+#[cfg(all(test, feature = "year-2025"))]
+mod tests {
+    use super::*;
+    use crate::Assessment;
+    use crate::config::create as create_config;
+
+    fn create_child(allowance_share: AllowanceShare) -> Child {
+        return Child {
+            allowance_share,
+            receives_child_benefit: true,
+        };
+    }
+
+    #[test]
+    fn test_child_benefit_for_low_income() {
+        let config = create_config(2025).unwrap();
+        let tax_data = TaxData::new(30000);
+
+        let result = calculate(&config, &tax_data, &[create_child(AllowanceShare::Half)]).unwrap();
+        let regular = crate::calculate(&config, &tax_data).unwrap();
+
+        assert!(!result.allowances_applied);
+        assert_eq!(result.child_benefit, 3060);
+        assert_eq!(result.credited_child_benefit, 1530);
+        assert_eq!(result.allowances, 4800);
+        assert_eq!(result.income_taxes, regular.income_taxes);
+        assert_eq!(result.net_income, regular.net_income + 3060);
+    }
+
+    #[test]
+    fn test_allowances_for_high_income() {
+        let config = create_config(2025).unwrap();
+        let tax_data = TaxData::new(150000).with_assessment(Assessment::Joint);
+        let children = [
+            create_child(AllowanceShare::Full),
+            create_child(AllowanceShare::Full),
+        ];
+
+        let result = calculate(&config, &tax_data, &children).unwrap();
+        let regular = crate::calculate(&config, &tax_data).unwrap();
+
+        assert!(result.allowances_applied);
+        assert_eq!(result.allowances, 2 * 9600);
+        assert!(result.income_taxes < regular.income_taxes + result.credited_child_benefit);
+        assert!(result.net_income > regular.net_income + result.child_benefit as i32);
+    }
+
+    #[test]
+    fn test_half_allowances_of_separated_parents() {
+        let config = create_config(2025).unwrap();
+        let tax_data = TaxData::new(120000);
+
+        // the parent without the child benefit still gets half of the allowances
+        let mut child = create_child(AllowanceShare::Half);
+        child.receives_child_benefit = false;
+        let half = calculate(&config, &tax_data, &[child]).unwrap();
+        let full = calculate(&config, &tax_data, &[create_child(AllowanceShare::Full)]).unwrap();
+
+        assert_eq!(half.child_benefit, 0);
+        assert!(half.allowances_applied);
+        assert!(full.allowances_applied);
+        assert!(full.income_taxes < half.income_taxes);
+    }
+}
@@ -0,0 +1,216 @@
+//! Contributions to occupational pension schemes (betriebliche Altersversorgung).
+//!
+//! Contributions to a Direktversicherung, Pensionskasse or Pensionsfonds can be paid by the
+//! employer in addition to the salary or by the employee through salary conversion
+//! (Entgeltumwandlung). Both together are tax-free up to 8% and free of social security up to 4% of
+//! the maximum income of the retirement insurance (§ 3 Nr. 63 EStG). Contributions above those limits
+//! are taxed like the salary.
+//!
+//! Contributions to old contracts (concluded before 2005) can instead be taxed by the employer with a
+//! flat rate (§ 40b EStG a.F.). Those contributions reduce the tax-free limit, but are neither taxable
+//! nor subject to social security for the employee.
+
+use crate::config::Config;
+use crate::error::Error;
+use crate::numeric::{Number, Numeric};
+use crate::pipeline::{DeductionStep, Pipeline, Stage};
+use crate::{TaxData, TaxResult};
+
+/// Input data struct with the yearly contributions to occupational pension schemes.
+#[derive(Clone, Default)]
+pub struct CompanyPensionData {
+    /// The contributions that are paid by the employer in addition to the salary.
+    pub employer_contribution: u32,
+
+    /// The contributions that are paid from the salary of the employee (Entgeltumwandlung).
+    pub salary_conversion: u32,
+
+    /// The contributions to old contracts that are taxed with the flat rate by the employer.
+    pub flat_taxed_contribution: u32,
+}
+
+/// Result struct of the calculation with contributions to occupational pension schemes.
+pub struct CompanyPensionResult {
+    /// The tax result of the employee. The gross income is the salary without the contributions of
+    /// the employer and the net income is the salary after the salary conversion, social security
+    /// taxes and income taxes.
+    pub tax_result: TaxResult,
+
+    /// The sum of all contributions to the occupational pension schemes.
+    pub contributions: u32,
+
+    /// The flat tax on the contributions to old contracts that is paid by the employer.
+    pub employer_flat_tax: u32,
+}
+
+/// Calculates the social security taxes, income taxes and the net income of an employee with the
+/// given contributions to occupational pension schemes. The income of the tax data is the salary of
+/// the employee (before the salary conversion).
+pub fn calculate(
+    config: &Config,
+    tax_data: &TaxData,
+    data: &CompanyPensionData,
+) -> Result<CompanyPensionResult, Error> {
+    if tax_data.self_employed {
+        return Err(Error::CompanyPensionRequiresEmployee);
+    }
+    if data.salary_conversion > tax_data.income {
+        return Err(Error::SalaryConversionTooLarge);
+    }
+    if data.flat_taxed_contribution > config.company_pension.flat_tax_limit {
+        return Err(Error::FlatTaxLimitExceeded);
+    }
+
+    let max_income =
+        Number::from_f32(config.retirement_insurance.max_income) * Number::from_u32(12);
+    let tax_free_limit = (max_income * Number::from_f32(config.company_pension.tax_free_share))
+        .to_u32()
+        .saturating_sub(data.flat_taxed_contribution);
+    let social_security_free_limit =
+        (max_income * Number::from_f32(config.company_pension.social_security_free_share)).to_u32();
+
+    // the contributions of the employer use up the limits first, then the salary conversion
+    let (employer_tax_free, conversion_tax_free) = split_limit(tax_free_limit, data);
+    let (employer_social_security_free, conversion_social_security_free) =
+        split_limit(social_security_free_limit, data);
+
+    let social_security_income = tax_data.income - conversion_social_security_free
+        + (data.employer_contribution - employer_social_security_free);
+    let taxable_income =
+        tax_data.income - conversion_tax_free + (data.employer_contribution - employer_tax_free);
+
+    // calculate the social security on the income that is subject to social security and exempt
+    // the additional tax-free part before the taxable income is calculated
+    let mut social_security_tax_data = tax_data.clone();
+    social_security_tax_data.income = social_security_income;
+
+    let mut pipeline = Pipeline::default();
+    pipeline.insert_before(
+        Stage::TaxableIncome,
+        Box::new(DeductionStep(
+            social_security_income.saturating_sub(taxable_income),
+        )),
+    );
+    let result = pipeline.calculate(config, &social_security_tax_data)?;
+
+    let employer_flat_tax = (Number::from_u32(data.flat_taxed_contribution)
+        * Number::from_f32(config.company_pension.flat_tax_rate))
+    .to_u32();
+
+    return Ok(CompanyPensionResult {
+        tax_result: TaxResult {
+            gross_income: tax_data.income as i32,
+            net_income: (tax_data.income as i64
+                - data.salary_conversion as i64
+                - tax_data.expenses as i64
+                - result.social_security_taxes as i64
+                - result.income_taxes as i64
+                - result.church_tax as i64) as i32,
+            social_security_taxes: result.social_security_taxes,
+            income_taxes: result.income_taxes,
+            church_tax: result.church_tax,
+        },
+        contributions: data.employer_contribution
+            + data.salary_conversion
+            + data.flat_taxed_contribution,
+        employer_flat_tax,
+    });
+}
+
+/// Splits the given limit on the contributions of the employer and the salary conversion.
+///
+/// Returns the parts of the employer contributions and the salary conversion within the limit.
+fn split_limit(limit: u32, data: &CompanyPensionData) -> (u32, u32) {
+    let employer = data.employer_contribution.min(limit);
+    let conversion = data.salary_conversion.min(limit - employer);
+
+    return (employer, conversion);
+}
+
+#[cfg(all(test, feature = "year-2025"))]
+mod tests {
+    use super::*;
+    use crate::config::create as create_config;
+
+    #[test]
+    fn test_salary_conversion_within_limits() {
+        let config = create_config(2025).unwrap();
+        let data = CompanyPensionData {
+            salary_conversion: 3000,
+            ..Default::default()
+        };
+
+        let result = calculate(&config, &TaxData::new(60000), &data).unwrap();
+        let expected = crate::calculate(&config, &TaxData::new(57000)).unwrap();
+
+        assert_eq!(result.tax_result.gross_income, 60000);
+        assert_eq!(result.tax_result.net_income, expected.net_income);
+        assert_eq!(
+            result.tax_result.social_security_taxes,
+            expected.social_security_taxes
+        );
+        assert_eq!(result.contributions, 3000);
+    }
+
+    #[test]
+    fn test_employer_contribution_above_social_security_limit() {
+        let config = create_config(2025).unwrap();
+        let data = CompanyPensionData {
+            employer_contribution: 6000,
+            ..Default::default()
+        };
+
+        // 6000 are tax-free, but only 3864 are free of social security
+        let result = calculate(&config, &TaxData::new(60000), &data).unwrap();
+        let expected = crate::calculate(&config, &TaxData::new(62136)).unwrap();
+
+        assert_eq!(
+            result.tax_result.social_security_taxes,
+            expected.social_security_taxes
+        );
+        assert_eq!(
+            result.tax_result.net_income,
+            60000
+                - result.tax_result.social_security_taxes as i32
+                - result.tax_result.income_taxes as i32
+        );
+    }
+
+    #[test]
+    fn test_flat_taxed_contribution() {
+        let config = create_config(2025).unwrap();
+        let data = CompanyPensionData {
+            flat_taxed_contribution: 1752,
+            ..Default::default()
+        };
+
+        let result = calculate(&config, &TaxData::new(60000), &data).unwrap();
+        let expected = crate::calculate(&config, &TaxData::new(60000)).unwrap();
+
+        assert_eq!(result.employer_flat_tax, 350);
+        assert_eq!(result.tax_result.net_income, expected.net_income);
+    }
+
+    #[test]
+    fn test_errors() {
+        let config = create_config(2025).unwrap();
+        let data = CompanyPensionData {
+            flat_taxed_contribution: 2000,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            calculate(&config, &TaxData::new(60000), &data).err(),
+            Some(Error::FlatTaxLimitExceeded)
+        );
+
+        let data = CompanyPensionData {
+            salary_conversion: 2000,
+            ..Default::default()
+        };
+        assert_eq!(
+            calculate(&config, &TaxData::new(1000), &data).err(),
+            Some(Error::SalaryConversionTooLarge)
+        );
+    }
+}
@@ -1,12 +1,22 @@
 //! Tax and social security configurations (e.g, the rates to apply on the income) per year.
 //!
 //! There are configurations available based on the German laws for the years 2024 and 2025.
+//!
+//! The values of every year are defined in a TOML file in the `config` directory of the crate (e.g.,
+//! `config/2025.toml`), with one table per configuration section. The files are validated and
+//! converted into the configuration structs when building the crate, so that no file needs to be
+//! parsed at runtime. A new year only needs its file and its feature (e.g., `year-2026`) in the
+//! manifest of the crate.
+
+use std::sync::{Arc, OnceLock, RwLock};
 
-// values for the social security (health and retirement) can be found on the website of the health ministry:
-// https://www.bundesgesundheitsministerium.de/beitraege
+use crate::error::Error;
+use crate::numeric::{Number, Numeric};
+use crate::provenance::{self, LegalSource};
 
 /// Configuration for the state-operated health insurance used as part of the social security calculations.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct HealthInsuranceConfig {
     /// General premium value for the health insurance \[0,1\] (Beitragssatz)
     pub premium_general: f32,
@@ -22,28 +32,59 @@ pub struct HealthInsuranceConfig {
     pub min_income: f32,
     /// Maximum monthly income that is used for the health insurance calculation (Beitragsbemessungsgrenze)
     pub max_income: f32,
+    /// Share of the nursing insurance premium \[0,1\] that is paid by employees in addition to the half of the premium,
+    /// instead of the employer (only in Sachsen, see [`crate::bundesland::Bundesland::adapt_config`])
+    pub premium_nursing_employee_surcharge: f32,
+    /// Maximum monthly income up to which employees are compulsorily insured in the statutory health insurance
+    /// (Versicherungspflichtgrenze or Jahresarbeitsentgeltgrenze, JAEG)
+    pub compulsory_insurance_limit: f32,
+}
+
+impl HealthInsuranceConfig {
+    /// Returns whether the given yearly income of an employee is above the compulsory insurance limit, so that the
+    /// employee can choose between a voluntary statutory and a private health insurance.
+    pub fn is_above_jaeg(&self, income: u32) -> bool {
+        return income as f32 > self.compulsory_insurance_limit * 12.0;
+    }
+}
+
+/// Additional premium of the health insurance that applies from the given month of the year on, until the next period
+/// starts (see [`Config::set_additional_premium_periods`]).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdditionalPremiumPeriod {
+    /// The first month \[1,12\] of the period.
+    pub from_month: u32,
+    /// Additional premium value \[0,1\] of the period (Zusatzbeitrag)
+    pub premium: f32,
 }
 
 /// Configuration for the state-operated retirement insurance used as part of the social security calculations.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct RetirementInsuranceConfig {
     /// Premium value for the retirement insurance (Beitragssatz)
     pub premium: f32,
     /// Maximum monthly income that is used for the retirement insurance calculation (Beitragsbemessungsgrenze)
     pub max_income: f32,
+    /// Maximum monthly income for the eastern federal states (Beitragsbemessungsgrenze Ost)
+    pub max_income_east: f32,
 }
 
 /// Configuration for the state-operated unemployment insurance used as part of the social security calculations.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct UnemploymentInsuranceConfig {
     /// Premium value for the unemployment insurance (Beitragssatz)
     pub premium: f32,
     /// Maximum monthly income that is used for the unemployment insurance calculation (Beitragsbemessungsgrenze)
     pub max_income: f32,
+    /// Maximum monthly income for the eastern federal states (Beitragsbemessungsgrenze Ost)
+    pub max_income_east: f32,
 }
 
 /// The income tax is calculated in multiple, progressive income ranges. This defines one range.
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct TaxRange {
     /// The gross income from which this rax range applies.
     pub lower_limit: u32,
@@ -56,7 +97,8 @@ pub struct TaxRange {
 }
 
 /// Configuration for the additional solidarity tax that applies on large incomes.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct SolidaryAdditionConfig {
     /// The income tax value up to which the solidarity tax does not apply.
     pub exemption_level: u32,
@@ -66,153 +108,613 @@ pub struct SolidaryAdditionConfig {
     pub max_percentage: f32,
 }
 
+/// Configuration for the withholding of the income tax in tax class VI (e.g., for a second job).
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct TaxClassSixConfig {
+    /// The income up to which the minimum rate of 14% or the doubled tariff difference applies.
+    pub first_limit: u32,
+    /// The income above which the income is taxed with a flat rate of 42%.
+    pub second_limit: u32,
+    /// The income above which the income is taxed with a flat rate of 45%.
+    pub third_limit: u32,
+}
+
+/// Number of the progressive tax ranges of the income tax tariff (§ 32a EStG).
+pub const TAX_RANGE_COUNT: usize = 5;
+
 /// Configuration for the income tax calculations.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct IncomeTaxConfig {
-    /// All the progressive tax ranges of the income tax.
-    pub tax_ranges: Vec<TaxRange>,
+    /// All the progressive tax ranges of the income tax. A fixed number of tax ranges is used, so
+    /// that the calculations do not need any heap allocations.
+    pub tax_ranges: [TaxRange; TAX_RANGE_COUNT],
 
     /// Configuration for the additional solidarity tax that applies on large incomes.
     pub solidary_addition_config: SolidaryAdditionConfig,
+
+    /// Configuration for the withholding of the income tax in tax class VI.
+    pub tax_class_six: TaxClassSixConfig,
+
+    /// The rate \[0,1\] of the church tax on the income taxes (Kirchensteuersatz), which is 9% in most
+    /// federal states (see [`crate::bundesland::Bundesland::adapt_config`])
+    pub church_tax_rate: f32,
+}
+
+/// Configuration for the flat tax on capital income (Abgeltungsteuer).
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct CapitalIncomeConfig {
+    /// The flat tax rate \[0,1\] on capital income (Abgeltungsteuersatz).
+    pub flat_rate: f32,
+    /// The yearly allowance for capital income of a single person (Sparer-Pauschbetrag), which is doubled for married couples.
+    pub saver_allowance: u32,
+    /// The base rate \[0,1\] for the minimum taxation of accumulating investment funds (Basiszins für die Vorabpauschale).
+    pub base_rate: f32,
+}
+
+/// Configuration for the health insurance of retirees (Krankenversicherung der Rentner).
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct RetireeInsuranceConfig {
+    /// The monthly allowance for company pensions (Freibetrag für Versorgungsbezüge), which is also the exemption limit for the nursing care insurance.
+    pub company_pension_allowance: f32,
+}
+
+/// Flat-rate contributions and taxes of the employer for one type of Minijob.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct MinijobRatesConfig {
+    /// The flat-rate premium \[0,1\] of the employer for the health insurance (Pauschalbeitrag zur Krankenversicherung).
+    pub health_insurance: f32,
+    /// The flat-rate premium \[0,1\] of the employer for the retirement insurance (Pauschalbeitrag zur Rentenversicherung).
+    pub retirement_insurance: f32,
+    /// The flat-rate income tax \[0,1\] including solidarity surcharge and church tax (einheitliche Pauschsteuer).
+    pub flat_tax: f32,
+    /// The sum of the levies \[0,1\] of the employer (Umlagen U1 and U2 and Insolvenzgeldumlage or accident insurance).
+    pub levies: f32,
+}
+
+/// Configuration for marginal employments (Minijobs).
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct MinijobConfig {
+    /// The maximum monthly income of a Minijob (Minijob-Grenze).
+    pub income_limit: f32,
+    /// The rates for Minijobs in commercial businesses (gewerbliche Minijobs).
+    pub commercial: MinijobRatesConfig,
+    /// The rates for Minijobs in private households (Minijobs in Privathaushalten).
+    pub household: MinijobRatesConfig,
+}
+
+/// Configuration for the contributions to occupational pension schemes (betriebliche Altersversorgung).
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct CompanyPensionConfig {
+    /// The share \[0,1\] of the maximum income of the retirement insurance up to which contributions are tax-free (§ 3 Nr. 63 EStG).
+    pub tax_free_share: f32,
+    /// The share \[0,1\] of the maximum income of the retirement insurance up to which contributions are free of social security (§ 1 Abs. 1 Nr. 9 SvEV).
+    pub social_security_free_share: f32,
+    /// The flat tax rate \[0,1\] on contributions to old contracts (Pauschalsteuer nach § 40b EStG a.F.).
+    pub flat_tax_rate: f32,
+    /// The yearly limit for contributions that can be taxed with the flat tax rate.
+    pub flat_tax_limit: u32,
+}
+
+/// Configuration for the allowances and benefits for children.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct ChildrenConfig {
+    /// The yearly allowance per child for both parents together (Kinderfreibetrag).
+    pub child_allowance: u32,
+    /// The yearly allowance per child for care, education and training for both parents together (Freibetrag für Betreuung, Erziehung oder Ausbildung).
+    pub care_allowance: u32,
+    /// The monthly child benefit per child (Kindergeld).
+    pub child_benefit: u32,
+}
+
+/// Configuration for the value added tax (Umsatzsteuer).
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct VatConfig {
+    /// The regular rate of the value added tax \[0,1\] (Regelsteuersatz).
+    pub rate: f32,
+    /// The maximum revenue of the previous year for the small business regulation (Kleinunternehmerregelung, § 19 UStG).
+    pub small_business_previous_limit: u32,
+    /// The maximum revenue of the current year for the small business regulation.
+    pub small_business_current_limit: u32,
+}
+
+/// Configuration for the rough income limits of the housing benefit (Wohngeld).
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct HousingBenefitConfig {
+    /// The rough maximum monthly net income of households with one to five members for the housing benefit (Höchsteinkommen)
+    pub income_limits: [u32; 5],
+    /// The increase of the rough maximum monthly net income for every further household member
+    pub additional_member_limit: u32,
+}
+
+/// Configuration for the statutory pension (gesetzliche Rente).
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct PensionConfig {
+    /// The provisional average yearly income of all insured persons, which earns one pension point (vorläufiges Durchschnittsentgelt)
+    pub average_income: u32,
+    /// The monthly pension for one pension point (aktueller Rentenwert)
+    pub pension_value: f32,
+    /// The maximum yearly retirement provision expenses that are deductible from the taxable income (Höchstbetrag für Altersvorsorgeaufwendungen)
+    pub retirement_provision_limit: u32,
+    /// The taxable share \[0,1\] of pensions that start in the year (Besteuerungsanteil)
+    pub taxable_share: f32,
+}
+
+/// Configuration for employments in the transition area above Minijobs (Übergangsbereich or Midijob).
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct MidijobConfig {
+    /// The monthly income above which the transition area begins (equal to the Minijob-Grenze).
+    pub lower_limit: f32,
+    /// The maximum monthly income of the transition area.
+    pub upper_limit: f32,
+    /// The factor for the reduced contribution base at the lower limit (Faktor F).
+    pub factor: f32,
+}
+
+/// Origin of a configuration: the year it was created for, the preset (e.g., of a health insurance company) that was
+/// applied and the fields that were modified afterwards (e.g., by [`crate::scenario::ConfigOverrides`]), so that the
+/// assumptions behind a result can be audited.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[non_exhaustive]
+pub struct ConfigOrigin {
+    /// The year for which the configuration was created.
+    pub year: u32,
+    /// The name of the applied preset (e.g., "Techniker Krankenkasse"), if any.
+    pub preset: Option<String>,
+    /// The paths of all modified fields (e.g., "health_insurance.premium_additional"), in the order of their first
+    /// modification.
+    pub modified_fields: Vec<&'static str>,
 }
 
 /// Main configuration struct that contains all the needed tax and social security configurations.
-#[derive(Debug)]
+///
+/// The configuration (like all its sections) can only be created with [`create`] outside of this crate, so that new
+/// values can be added in minor releases. All values can still be changed after the creation.
+///
+/// The configuration is `Send` and `Sync`, so that one instance can be shared between threads (e.g., the request
+/// handlers of a web server) with [`create_shared`] or [`Config::into_shared`]. A shared configuration is immutable:
+/// changes need an own copy (e.g., with [`Arc::make_mut`]), which leaves the configuration of all other threads
+/// untouched.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct Config {
     pub health_insurance: HealthInsuranceConfig,
     pub retirement_insurance: RetirementInsuranceConfig,
     pub unemployment_insurance: UnemploymentInsuranceConfig,
     pub income_tax: IncomeTaxConfig,
+    pub capital_income: CapitalIncomeConfig,
+    pub retiree_insurance: RetireeInsuranceConfig,
+    pub minijob: MinijobConfig,
+    pub company_pension: CompanyPensionConfig,
+    pub children: ChildrenConfig,
+    pub vat: VatConfig,
+    pub housing_benefit: HousingBenefitConfig,
+    pub pension: PensionConfig,
+    pub midijob: MidijobConfig,
+    pub origin: ConfigOrigin,
 }
 
+impl Config {
+    /// Returns the origin of the configuration.
+    pub fn origin(&self) -> &ConfigOrigin {
+        return &self.origin;
+    }
+
+    /// Returns the legal sources of all sections of the configuration (see [`crate::provenance`]).
+    pub fn provenance(&self) -> Vec<LegalSource> {
+        return provenance::get(self.origin.year).unwrap_or_default();
+    }
+
+    /// Records that the preset with the given name was applied on the configuration.
+    pub fn set_preset(&mut self, name: &str) {
+        self.origin.preset = Some(name.to_string());
+    }
+
+    /// Records that the field with the given path was modified.
+    pub fn record_modification(&mut self, field: &'static str) {
+        if !self.origin.modified_fields.contains(&field) {
+            self.origin.modified_fields.push(field);
+        }
+    }
+
+    /// Sets the additional premium of the health insurance to the average of the given periods of the year, weighted by
+    /// the number of months of each period, for insurance companies that changed the additional premium during the
+    /// year.
+    ///
+    /// The periods need to be ordered by their first month and the first period needs to start in January.
+    pub fn set_additional_premium_periods(
+        &mut self,
+        periods: &[AdditionalPremiumPeriod],
+    ) -> Result<(), Error> {
+        if periods.first().map(|period| period.from_month) != Some(1) {
+            return Err(Error::PremiumPeriodsNotFromJanuary);
+        }
+
+        if periods
+            .windows(2)
+            .any(|pair| pair[0].from_month >= pair[1].from_month)
+            || periods.iter().any(|period| period.from_month > 12)
+        {
+            return Err(Error::PremiumPeriodsNotAscending);
+        }
+
+        let mut weighted_premium = Number::from_u32(0);
+        for (index, period) in periods.iter().enumerate() {
+            let to_month = periods.get(index + 1).map_or(13, |next| next.from_month);
+            weighted_premium +=
+                Number::from_f32(period.premium) * Number::from_u32(to_month - period.from_month);
+        }
+
+        self.health_insurance.premium_additional =
+            (weighted_premium / Number::from_u32(12)).to_f32();
+        self.record_modification("health_insurance.premium_additional");
+        return Ok(());
+    }
+
+    /// Converts the configuration into an immutable configuration that can be shared between threads.
+    pub fn into_shared(self) -> Arc<Config> {
+        return Arc::new(self);
+    }
+}
+
+// the configuration needs to be shareable between threads (see [`create_shared`])
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Config>();
+};
+
+// the years for which a configuration is included (each one with its feature) in ascending order
+// (`SUPPORTED_YEARS`), the latest one of them, which is used for the default configuration
+// (`DEFAULT_YEAR`), and the creation of their configurations (`create_included`) are generated from
+// the files in the `config` directory by the build script
+include!(concat!(env!("OUT_DIR"), "/configs.rs"));
+
 impl Default for Config {
     /// Create configuration for the current year by default.
     fn default() -> Self {
-        return create(2025).unwrap();
+        return create(DEFAULT_YEAR).unwrap();
     }
 }
 
+/// Returns the years for which a configuration is available, in ascending order.
+///
+/// Only the years with an enabled feature (e.g., `year-2025`) are contained.
+pub fn supported_years() -> &'static [u32] {
+    return SUPPORTED_YEARS;
+}
+
+/// Returns the latest year for which a configuration is available.
+pub fn latest_year() -> u32 {
+    return DEFAULT_YEAR;
+}
+
 /// Creates the configuration for the given year.
 ///
-/// This function supports the years 2024 and 2025 and returns an error for every other year.
-pub fn create(year: u32) -> Result<Config, &'static str> {
-    match year {
-        2025 => Ok(Config {
-            retirement_insurance: RetirementInsuranceConfig {
-                premium: 0.186,
-                max_income: 8050.0,
-            },
-            health_insurance: HealthInsuranceConfig {
-                premium_general: 0.146,
-                premium_general_reduced: 0.14,
-                premium_additional: 0.0245,
-                premium_nursing: 0.036,
-                premium_nursing_additional: 0.006,
-                min_income: 1248.32,
-                max_income: 5512.5,
-            },
-            unemployment_insurance: UnemploymentInsuranceConfig {
-                premium: 0.026,
-                max_income: 8050.0,
-            },
-            income_tax: IncomeTaxConfig {
-                tax_ranges: vec![
-                    TaxRange {
-                        lower_limit: 0,
-                        upper_limit: 12096,
-                        rate_min: 0.00,
-                        rate_max: 0.00,
-                    },
-                    TaxRange {
-                        lower_limit: 12096,
-                        upper_limit: 17444,
-                        rate_min: 0.14,
-                        rate_max: 0.2397,
-                    },
-                    TaxRange {
-                        lower_limit: 17444,
-                        upper_limit: 68480,
-                        rate_min: 0.2397,
-                        rate_max: 0.42,
-                    },
-                    TaxRange {
-                        lower_limit: 68480,
-                        upper_limit: 277825,
-                        rate_min: 0.42,
-                        rate_max: 0.42,
-                    },
-                    TaxRange {
-                        lower_limit: 277825,
-                        upper_limit: u32::MAX,
-                        rate_min: 0.45,
-                        rate_max: 0.45,
-                    },
-                ],
-                solidary_addition_config: SolidaryAdditionConfig {
-                    exemption_level: 19950,
-                    rate: 0.055,
-                    max_percentage: 0.119,
-                },
-            },
-        }),
-        2024 => Ok(Config {
-            retirement_insurance: RetirementInsuranceConfig {
-                premium: 0.186,
-                max_income: 7550.0,
-            },
-            health_insurance: HealthInsuranceConfig {
-                premium_general: 0.146,
-                premium_general_reduced: 0.14,
-                premium_additional: 0.012,
-                premium_nursing: 0.034,
-                premium_nursing_additional: 0.006,
-                min_income: 1178.33,
-                max_income: 5175.0,
-            },
-            unemployment_insurance: UnemploymentInsuranceConfig {
-                premium: 0.026,
-                max_income: 7550.0,
+/// This function supports the years 2024 and 2025 (see [`supported_years`]) and returns an error for
+/// every other year. Every year is only included with its feature (e.g., `year-2025`), which are all
+/// enabled by default.
+pub fn create(year: u32) -> Result<Config, Error> {
+    return create_included(year).ok_or(Error::unsupported_year(year));
+}
+
+/// Creates the configuration for the given year like [`create`], but wrapped into an [`Arc`], so that
+/// it can be shared between threads without cloning it.
+pub fn create_shared(year: u32) -> Result<Arc<Config>, Error> {
+    return Ok(create(year)?.into_shared());
+}
+
+/// Returns the configuration for the given year like [`create`], but creates it only once and
+/// returns the same instance for every following call.
+///
+/// This avoids repeated allocations of identical configurations in hot paths (e.g., for batch
+/// calculations). Changes of single values still need a configuration from [`create`].
+pub fn get(year: u32) -> Result<&'static Config, Error> {
+    // one configuration per included year, in the order of the years
+    static CONFIGS: [OnceLock<Config>; SUPPORTED_YEARS.len()] =
+        [const { OnceLock::new() }; SUPPORTED_YEARS.len()];
+
+    let index = SUPPORTED_YEARS
+        .iter()
+        .position(|supported_year| *supported_year == year)
+        .ok_or(Error::unsupported_year(year))?;
+
+    return Ok(CONFIGS[index].get_or_init(|| create(year).unwrap()));
+}
+
+/// Configurations of the custom years that were registered with [`register`].
+///
+/// The configurations are only written on registration, so that concurrent lookups of [`cached`]
+/// do not block each other.
+static CUSTOM_CONFIGS: RwLock<Vec<&'static Config>> = RwLock::new(Vec::new());
+
+/// Returns the configuration for the given year like [`get`], but additionally considers the
+/// configurations of custom years that were registered with [`register`].
+///
+/// High-throughput services can use this as the single entry point to their configurations, which
+/// are created only once (or registered only once) and then shared by all calculations.
+/// The configurations of the included years are returned without any lock (see [`get`]), the
+/// registered configurations only need a lock that is shared by all concurrent lookups.
+pub fn cached(year: u32) -> Result<&'static Config, Error> {
+    if SUPPORTED_YEARS.contains(&year) {
+        return get(year);
+    }
+
+    let custom_configs = CUSTOM_CONFIGS
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    return custom_configs
+        .iter()
+        .find(|config| config.origin.year == year)
+        .copied()
+        .ok_or(Error::unsupported_year(year));
+}
+
+/// Registers the given configuration for its year (see [`ConfigOrigin::year`]), so that it is
+/// returned by every following call of [`cached`] for this year.
+///
+/// This allows the use of configurations for years that are not included in this crate (e.g., a
+/// configuration of the next year, that was created from the current one). The registered
+/// configurations are kept until the end of the program. Returns an error if there is already a
+/// configuration for the year.
+pub fn register(config: Config) -> Result<&'static Config, Error> {
+    let mut custom_configs = CUSTOM_CONFIGS
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    let year = config.origin.year;
+    if SUPPORTED_YEARS.contains(&year)
+        || custom_configs
+            .iter()
+            .any(|custom_config| custom_config.origin.year == year)
+    {
+        return Err(Error::ConfigurationAlreadyAvailable);
+    }
+
+    let config: &'static Config = Box::leak(Box::new(config));
+    custom_configs.push(config);
+
+    return Ok(config);
+}
+
+/// Configuration of the current year, as created by [`create_current`].
+#[cfg(feature = "clock")]
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct CurrentConfig {
+    /// The configuration of the current year (or of the latest supported year as fallback).
+    pub config: Config,
+
+    /// The current calendar year according to the system clock.
+    pub current_year: u32,
+
+    /// The warning in case that there is no configuration for the current year, so that the
+    /// configuration of the latest supported year is used instead.
+    pub warning: Option<Error>,
+}
+
+/// Creates the configuration for the current calendar year, as determined from the system clock.
+///
+/// If the current year is not supported (yet), the configuration of the latest supported year is
+/// created instead and the returned [`CurrentConfig`] contains a warning about the fallback.
+#[cfg(feature = "clock")]
+pub fn create_current() -> Result<CurrentConfig, Error> {
+    let seconds = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs());
+    let current_year = year_from_days(seconds / 86400);
+
+    let (year, warning) = match SUPPORTED_YEARS.contains(&current_year) {
+        true => (current_year, None),
+        false => (latest_year(), Some(Error::unsupported_year(current_year))),
+    };
+
+    return Ok(CurrentConfig {
+        config: create(year)?,
+        current_year,
+        warning,
+    });
+}
+
+/// Returns the calendar year of the given number of days since the 1st of January 1970.
+#[cfg(feature = "clock")]
+fn year_from_days(days: u64) -> u32 {
+    // shift the epoch to the 1st of March 0000, so that the leap day is the last day of a year
+    let days = days + 719468;
+    let era = days / 146097;
+    let day_of_era = days % 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+
+    // the months January and February belong to the following calendar year
+    let year = era * 400 + year_of_era + u64::from(day_of_year >= 306);
+    return u32::try_from(year).unwrap_or(u32::MAX);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(all(feature = "year-2024", feature = "year-2025"))]
+    fn test_supported_years() {
+        assert_eq!(supported_years(), &[2024, 2025]);
+        assert_eq!(latest_year(), 2025);
+
+        for year in supported_years() {
+            assert!(create(*year).is_ok());
+        }
+    }
+
+    #[cfg(feature = "clock")]
+    #[test]
+    fn test_year_from_days() {
+        assert_eq!(year_from_days(0), 1970);
+        assert_eq!(year_from_days(19722), 2023); // 2023-12-31
+        assert_eq!(year_from_days(19723), 2024); // 2024-01-01
+        assert_eq!(year_from_days(19782), 2024); // 2024-02-29
+        assert_eq!(year_from_days(20088), 2024); // 2024-12-31
+        assert_eq!(year_from_days(20089), 2025); // 2025-01-01
+    }
+
+    #[cfg(feature = "clock")]
+    #[test]
+    fn test_create_current() {
+        let current = create_current().unwrap();
+
+        assert_eq!(
+            current.warning.is_none(),
+            SUPPORTED_YEARS.contains(&current.current_year)
+        );
+        assert!(SUPPORTED_YEARS.contains(&current.config.origin().year));
+    }
+
+    #[test]
+    #[cfg(feature = "year-2025")]
+    fn test_shared_configuration() {
+        let config = create_shared(2025).unwrap();
+        let income = 60000;
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let config = Arc::clone(&config);
+                std::thread::spawn(move || {
+                    return crate::calculate(&config, &crate::TaxData::new(income))
+                        .unwrap()
+                        .net_income;
+                })
+            })
+            .collect();
+        let expected = crate::calculate(&config, &crate::TaxData::new(income))
+            .unwrap()
+            .net_income;
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), expected);
+        }
+
+        // changing a shared configuration creates an own copy
+        let mut changed = Arc::clone(&config);
+        Arc::make_mut(&mut changed)
+            .health_insurance
+            .premium_additional = 0.0;
+        assert!(!Arc::ptr_eq(&config, &changed));
+        assert!(config.health_insurance.premium_additional > 0.0);
+        assert!(create_shared(2000).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "year-2025")]
+    fn test_cached_custom_year() {
+        assert!(std::ptr::eq(cached(2025).unwrap(), get(2025).unwrap()));
+        assert!(cached(2099).is_err());
+
+        let mut config = create(2025).unwrap();
+        config.origin.year = 2099;
+        config.health_insurance.premium_additional = 0.03;
+        let registered = register(config.clone()).unwrap();
+
+        assert!(std::ptr::eq(cached(2099).unwrap(), registered));
+        assert_eq!(
+            cached(2099).unwrap().health_insurance.premium_additional,
+            0.03
+        );
+        assert_eq!(
+            register(config).err(),
+            Some(Error::ConfigurationAlreadyAvailable)
+        );
+        assert_eq!(
+            register(create(2025).unwrap()).err(),
+            Some(Error::ConfigurationAlreadyAvailable)
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "year-2024", feature = "year-2025"))]
+    fn test_get_returns_same_instance() {
+        let config = get(2025).unwrap();
+
+        assert!(std::ptr::eq(config, get(2025).unwrap()));
+        assert!(!std::ptr::eq(config, get(2024).unwrap()));
+        assert_eq!(
+            config.income_tax.tax_ranges.len(),
+            create(2025).unwrap().income_tax.tax_ranges.len()
+        );
+        assert!(get(2000).is_err());
+    }
+    #[test]
+    fn test_origin() {
+        let mut config = create(latest_year()).unwrap();
+        assert_eq!(config.origin().year, latest_year());
+        assert_eq!(config.provenance().len(), 13);
+        assert!(config.origin().modified_fields.is_empty());
+
+        config.set_preset("Techniker Krankenkasse");
+        config.record_modification("health_insurance.premium_additional");
+        config.record_modification("health_insurance.premium_additional");
+        assert_eq!(
+            config.origin().preset.as_deref(),
+            Some("Techniker Krankenkasse")
+        );
+        assert_eq!(
+            config.origin().modified_fields,
+            vec!["health_insurance.premium_additional"]
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "year-2024", feature = "year-2025"))]
+    fn test_is_above_jaeg() {
+        let config = create(2025).unwrap();
+
+        assert!(!config.health_insurance.is_above_jaeg(73800));
+        assert!(config.health_insurance.is_above_jaeg(73801));
+        assert!(create(2024).unwrap().health_insurance.is_above_jaeg(70000));
+    }
+
+    #[test]
+    #[cfg(feature = "year-2025")]
+    fn test_set_additional_premium_periods() {
+        let mut config = create(2025).unwrap();
+
+        let periods = [
+            AdditionalPremiumPeriod {
+                from_month: 1,
+                premium: 0.025,
             },
-            income_tax: IncomeTaxConfig {
-                tax_ranges: vec![
-                    TaxRange {
-                        lower_limit: 0,
-                        upper_limit: 11784,
-                        rate_min: 0.00,
-                        rate_max: 0.00,
-                    },
-                    TaxRange {
-                        lower_limit: 11784,
-                        upper_limit: 17005,
-                        rate_min: 0.14,
-                        rate_max: 0.2397,
-                    },
-                    TaxRange {
-                        lower_limit: 17005,
-                        upper_limit: 66760,
-                        rate_min: 0.2397,
-                        rate_max: 0.42,
-                    },
-                    TaxRange {
-                        lower_limit: 66760,
-                        upper_limit: 277825,
-                        rate_min: 0.42,
-                        rate_max: 0.42,
-                    },
-                    TaxRange {
-                        lower_limit: 277825,
-                        upper_limit: u32::MAX,
-                        rate_min: 0.45,
-                        rate_max: 0.45,
-                    },
-                ],
-                solidary_addition_config: SolidaryAdditionConfig {
-                    exemption_level: 18130,
-                    rate: 0.055,
-                    max_percentage: 0.119,
-                },
+            AdditionalPremiumPeriod {
+                from_month: 7,
+                premium: 0.031,
             },
-        }),
-        _ => Err("No configuration available for given year."),
+        ];
+        config.set_additional_premium_periods(&periods).unwrap();
+        approx::assert_abs_diff_eq!(
+            config.health_insurance.premium_additional,
+            0.028,
+            epsilon = 0.00001
+        );
+        assert_eq!(
+            config.origin().modified_fields,
+            vec!["health_insurance.premium_additional"]
+        );
+
+        assert_eq!(
+            config.set_additional_premium_periods(&periods[1..]),
+            Err(Error::PremiumPeriodsNotFromJanuary)
+        );
+        assert_eq!(
+            config.set_additional_premium_periods(&[periods[0], periods[0]]),
+            Err(Error::PremiumPeriodsNotAscending)
+        );
     }
 }
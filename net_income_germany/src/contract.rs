@@ -0,0 +1,150 @@
+//! Certification of properties that every configuration is expected to fulfill.
+//!
+//! The checks of [`crate::verify`] are combined to a contract that a configuration either fulfills
+//! for all incomes of a sweep or that is violated at specific incomes. The contract consists of the
+//! following properties (see [`Property`]):
+//!
+//! - the net income never decreases when the gross income increases,
+//! - the marginal burden (the share of an additional gross income spent on taxes) is at most 100%,
+//! - the taxes are never negative (i.e., the net income never exceeds the gross income).
+//!
+//! ```
+//! let config = net_income_germany::config::create(net_income_germany::config::latest_year()).unwrap();
+//! let tax_data = net_income_germany::TaxData::new(0);
+//!
+//! let violations =
+//!     net_income_germany::contract::certify(&config, &tax_data, 0, 300000, 500).unwrap();
+//! assert!(violations.is_empty());
+//! ```
+
+use crate::config::Config;
+use crate::error::Error;
+use crate::{TaxData, calculate};
+
+/// Property of the contract.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Property {
+    /// The net income is not lower than the net income of any smaller gross income of the sweep.
+    NetIncomeNeverDecreases,
+
+    /// The marginal burden between two consecutive gross incomes of the sweep is at most
+    /// [`MAX_MARGINAL_BURDEN`].
+    MarginalBurdenLimited,
+
+    /// The sum of the social security taxes and the income taxes is not negative.
+    NonNegativeTaxes,
+}
+
+/// The largest allowed marginal burden \[0,1\].
+pub const MAX_MARGINAL_BURDEN: f32 = 1.0;
+
+/// Violation of a property of the contract.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Violation {
+    /// The violated property.
+    pub property: Property,
+
+    /// The gross income at which the property is violated.
+    pub income: u32,
+}
+
+/// Sweeps all gross incomes in the given range (with the given step size between two incomes),
+/// using the given tax data for all other input values, and checks every property of the contract.
+///
+/// Returns all violations, ordered by the income. The configuration fulfills the contract for the
+/// given sweep if there are none.
+pub fn certify(
+    config: &Config,
+    tax_data: &TaxData,
+    from: u32,
+    to: u32,
+    step: u32,
+) -> Result<Vec<Violation>, Error> {
+    if step == 0 {
+        return Err(Error::InvalidStepSize);
+    }
+
+    let mut violations = Vec::new();
+    let mut tax_data = tax_data.clone();
+    let mut previous_net_income: Option<i32> = None;
+    let mut max_net_income: Option<i32> = None;
+
+    for income in (from..=to).step_by(step as usize) {
+        tax_data.income = income;
+        let result = calculate(config, &tax_data)?;
+
+        if max_net_income.is_some_and(|max| result.net_income < max) {
+            violations.push(Violation {
+                property: Property::NetIncomeNeverDecreases,
+                income,
+            });
+        }
+
+        if let Some(previous) = previous_net_income {
+            let marginal_burden = 1.0 - (result.net_income - previous) as f32 / step as f32;
+            if marginal_burden > MAX_MARGINAL_BURDEN {
+                violations.push(Violation {
+                    property: Property::MarginalBurdenLimited,
+                    income,
+                });
+            }
+        }
+
+        if result.net_income > result.gross_income {
+            violations.push(Violation {
+                property: Property::NonNegativeTaxes,
+                income,
+            });
+        }
+
+        previous_net_income = Some(result.net_income);
+        max_net_income =
+            Some(max_net_income.map_or(result.net_income, |max| max.max(result.net_income)));
+    }
+
+    return Ok(violations);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::create as create_config;
+
+    #[test]
+    fn test_default_configurations_fulfill_contract() {
+        for year in crate::config::supported_years() {
+            let config = create_config(*year).unwrap();
+
+            for tax_data in [TaxData::new(0), TaxData::new(0).with_self_employed(true)] {
+                assert_eq!(certify(&config, &tax_data, 0, 300000, 250).unwrap(), vec![]);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "year-2025")]
+    fn test_violations_of_invalid_configuration() {
+        let mut config = create_config(2025).unwrap();
+
+        // a rate above 100% lets the net income decrease within the tax range
+        config.income_tax.tax_ranges[3].rate_min = 1.5;
+        config.income_tax.tax_ranges[3].rate_max = 1.5;
+
+        let violations = certify(&config, &TaxData::new(0), 100000, 120000, 1000).unwrap();
+        assert!(violations.contains(&Violation {
+            property: Property::NetIncomeNeverDecreases,
+            income: 110000,
+        }));
+        assert!(violations.contains(&Violation {
+            property: Property::MarginalBurdenLimited,
+            income: 110000,
+        }));
+
+        assert_eq!(
+            certify(&config, &TaxData::new(0), 0, 1000, 0).err(),
+            Some(Error::InvalidStepSize)
+        );
+    }
+}
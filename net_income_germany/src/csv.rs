@@ -0,0 +1,145 @@
+//! Serialization of results into CSV records with stable column names.
+//!
+//! All results implementing [`CsvRecord`] provide a header with the column names and a record with
+//! the values in the same order, so that they can be written into a CSV file line by line:
+//!
+//! ```
+//! use net_income_germany::TaxResult;
+//! use net_income_germany::csv::CsvRecord;
+//!
+//! let config = net_income_germany::config::create(net_income_germany::config::latest_year()).unwrap();
+//! println!("income,{}", TaxResult::csv_header().join(","));
+//!
+//! for income in (20000..=100000).step_by(20000) {
+//!     let tax_data = net_income_germany::TaxData::new(income);
+//!
+//!     let result = net_income_germany::calculate(&config, &tax_data).unwrap();
+//!     println!("{},{}", income, result.to_csv_line());
+//! }
+//! ```
+
+use crate::TaxResult;
+use crate::capital_income::CapitalIncomeResult;
+use crate::social_security::SocialSecurityResult;
+
+/// Results that can be serialized into a CSV record.
+pub trait CsvRecord {
+    /// Returns the column names of the records. The names are stable between versions, new
+    /// columns are only appended.
+    fn csv_header() -> &'static [&'static str];
+
+    /// Returns the values of the record in the order of the column names.
+    fn to_csv_record(&self) -> Vec<String>;
+
+    /// Returns the values of the record as one comma-separated line (without a line break).
+    fn to_csv_line(&self) -> String {
+        return self.to_csv_record().join(",");
+    }
+}
+
+impl CsvRecord for TaxResult {
+    fn csv_header() -> &'static [&'static str] {
+        return &[
+            "gross_income",
+            "net_income",
+            "social_security_taxes",
+            "income_taxes",
+            "church_tax",
+        ];
+    }
+
+    fn to_csv_record(&self) -> Vec<String> {
+        return vec![
+            self.gross_income.to_string(),
+            self.net_income.to_string(),
+            self.social_security_taxes.to_string(),
+            self.income_taxes.to_string(),
+            self.church_tax.to_string(),
+        ];
+    }
+}
+
+impl CsvRecord for SocialSecurityResult {
+    fn csv_header() -> &'static [&'static str] {
+        return &[
+            "health_insurance",
+            "nursing_insurance",
+            "retirement_insurance",
+            "unemployment_insurance",
+            "total",
+        ];
+    }
+
+    fn to_csv_record(&self) -> Vec<String> {
+        return vec![
+            self.health_insurance.to_string(),
+            self.nursing_insurance.to_string(),
+            self.retirement_insurance.to_string(),
+            self.unemployment_insurance.to_string(),
+            self.total.to_string(),
+        ];
+    }
+}
+
+impl CsvRecord for CapitalIncomeResult {
+    fn csv_header() -> &'static [&'static str] {
+        return &["taxable_capital_income", "flat_tax", "solidarity_surcharge"];
+    }
+
+    fn to_csv_record(&self) -> Vec<String> {
+        return vec![
+            self.taxable_capital_income.to_string(),
+            self.flat_tax.to_string(),
+            self.solidarity_surcharge.to_string(),
+        ];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tax_result_record() {
+        let result = TaxResult {
+            gross_income: 50000,
+            net_income: -1200,
+            social_security_taxes: 10000,
+            income_taxes: 8000,
+            church_tax: 720,
+        };
+
+        assert_eq!(
+            TaxResult::csv_header().join(","),
+            "gross_income,net_income,social_security_taxes,income_taxes,church_tax"
+        );
+        assert_eq!(result.to_csv_line(), "50000,-1200,10000,8000,720");
+        assert_eq!(result.to_csv_record().len(), TaxResult::csv_header().len());
+    }
+
+    #[test]
+    fn test_breakdown_records_match_headers() {
+        let social_security = SocialSecurityResult {
+            health_insurance: 1,
+            nursing_insurance: 2,
+            retirement_insurance: 3,
+            unemployment_insurance: 4,
+            total: 10,
+        };
+        assert_eq!(social_security.to_csv_line(), "1,2,3,4,10");
+        assert_eq!(
+            social_security.to_csv_record().len(),
+            SocialSecurityResult::csv_header().len()
+        );
+
+        let capital_income = CapitalIncomeResult {
+            taxable_capital_income: 1000,
+            flat_tax: 250,
+            solidarity_surcharge: 13,
+        };
+        assert_eq!(
+            capital_income.to_csv_record().len(),
+            CapitalIncomeResult::csv_header().len()
+        );
+    }
+}
@@ -0,0 +1,107 @@
+//! Export of the monthly payroll figures in the CSV conventions of the DATEV payroll import.
+//!
+//! The export follows the conventions of DATEV Lohn und Gehalt for imported figures: the fields are
+//! separated by semicolons, amounts are written with a decimal comma and two decimal places, dates
+//! use the format TT.MM.JJJJ and lines end with CRLF. Every line contains the figures of one payslip
+//! (see [`crate::payslip`]) for one employee and one payroll period:
+//!
+//! ```
+//! use net_income_germany::payroll::PayrollData;
+//!
+//! let config = net_income_germany::config::create(net_income_germany::config::latest_year()).unwrap();
+//! let data = PayrollData {
+//!     monthly_salary: 4500,
+//!     bonus: None,
+//!     assessment: net_income_germany::Assessment::Single,
+//!     church_member: false,
+//! };
+//!
+//! let payslips = net_income_germany::payslip::create(&config, &data).unwrap();
+//! let csv = net_income_germany::datev::export(&payslips, 2025, 1001);
+//! assert!(csv.starts_with("Personalnummer;Abrechnungszeitraum;Steuerbrutto;"));
+//! ```
+
+use crate::payslip::Payslip;
+
+/// The column names of the export.
+pub const COLUMNS: [&str; 12] = [
+    "Personalnummer",
+    "Abrechnungszeitraum",
+    "Steuerbrutto",
+    "SV-Brutto",
+    "Lohnsteuer",
+    "Kirchensteuer",
+    "Solidaritätszuschlag",
+    "KV-Beitrag AN",
+    "PV-Beitrag AN",
+    "RV-Beitrag AN",
+    "AV-Beitrag AN",
+    "Nettoverdienst",
+];
+
+/// Line ending of the export.
+const LINE_ENDING: &str = "\r\n";
+
+/// Formats the given amount with a decimal comma and two decimal places.
+fn format_amount(amount: i64) -> String {
+    return format!("{amount},00");
+}
+
+/// Exports the given payslips of the given year for the employee with the given personnel number
+/// (Personalnummer).
+pub fn export(payslips: &[Payslip], year: u32, personnel_number: u32) -> String {
+    let mut csv = COLUMNS.join(";") + LINE_ENDING;
+
+    for payslip in payslips {
+        let fields = [
+            personnel_number.to_string(),
+            format!("01.{:02}.{year}", payslip.month),
+            format_amount(payslip.tax_gross_income as i64),
+            format_amount(payslip.social_security_gross_income as i64),
+            format_amount(payslip.wage_tax as i64),
+            format_amount(payslip.church_tax as i64),
+            format_amount(payslip.solidarity_surcharge as i64),
+            format_amount(payslip.health_insurance as i64),
+            format_amount(payslip.nursing_insurance as i64),
+            format_amount(payslip.retirement_insurance as i64),
+            format_amount(payslip.unemployment_insurance as i64),
+            format_amount(payslip.net_income as i64),
+        ];
+
+        csv.push_str(&fields.join(";"));
+        csv.push_str(LINE_ENDING);
+    }
+
+    return csv;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export() {
+        let payslip = Payslip {
+            month: 3,
+            tax_gross_income: 4500,
+            social_security_gross_income: 4500,
+            wage_tax: 702,
+            church_tax: 56,
+            solidarity_surcharge: 0,
+            health_insurance: 385,
+            nursing_insurance: 81,
+            retirement_insurance: 419,
+            unemployment_insurance: 58,
+            net_income: 2799,
+        };
+
+        let csv = export(&[payslip], 2025, 42);
+        let lines: Vec<&str> = csv.split_terminator(LINE_ENDING).collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].split(';').count(), COLUMNS.len());
+        assert_eq!(
+            lines[1],
+            "42;01.03.2025;4500,00;4500,00;702,00;56,00;0,00;385,00;81,00;419,00;58,00;2799,00"
+        );
+    }
+}
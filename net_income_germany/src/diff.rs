@@ -0,0 +1,149 @@
+//! Comparison of two tax results (e.g., of two job offers or two years).
+//!
+//! The differences are calculated per field of the tax results as absolute values and as
+//! percentage changes relative to the first (base) result:
+//!
+//! ```
+//! let config = net_income_germany::config::create(net_income_germany::config::latest_year()).unwrap();
+//! let mut tax_data = net_income_germany::TaxData::new(60000);
+//!
+//! let current = net_income_germany::calculate(&config, &tax_data).unwrap();
+//! tax_data.income = 66000;
+//! let offer = net_income_germany::calculate(&config, &tax_data).unwrap();
+//!
+//! let diff = current.diff(&offer);
+//! assert_eq!(diff.gross_income.value, 6000);
+//! println!("Additional net income: {}", diff.net_income.value);
+//! ```
+
+use crate::TaxResult;
+
+/// Difference of one value between two tax results.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ValueDiff {
+    /// The absolute difference (other value minus base value).
+    pub value: i64,
+
+    /// The difference relative to the base value \[0,1\] (e.g., 0.1 for an increase of 10%), or
+    /// `None` if the base value is zero.
+    pub percentage: Option<f32>,
+}
+
+impl ValueDiff {
+    /// Creates the difference between the given base value and the other value.
+    pub fn new(base: i64, other: i64) -> Self {
+        let value = other - base;
+
+        return ValueDiff {
+            value,
+            percentage: match base {
+                0 => None,
+                _ => Some(value as f32 / base.abs() as f32),
+            },
+        };
+    }
+}
+
+/// Differences between two tax results per field and in summary.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TaxResultDiff {
+    /// The difference of the gross incomes.
+    pub gross_income: ValueDiff,
+
+    /// The difference of the net incomes.
+    pub net_income: ValueDiff,
+
+    /// The difference of the social security taxes.
+    pub social_security_taxes: ValueDiff,
+
+    /// The difference of the income taxes.
+    pub income_taxes: ValueDiff,
+
+    /// The difference of the church taxes.
+    pub church_tax: ValueDiff,
+
+    /// The difference of the sum of social security taxes, income taxes and church taxes.
+    pub total_taxes: ValueDiff,
+
+    /// The difference of the tax ratios (see [`TaxResult::get_tax_ratio`]).
+    pub tax_ratio: f32,
+}
+
+impl TaxResultDiff {
+    /// Returns the share \[0,1\] of the additional gross income that remains as additional net income
+    /// (or `None` if the gross incomes are equal).
+    pub fn get_net_ratio(&self) -> Option<f32> {
+        return match self.gross_income.value {
+            0 => None,
+            gross_income => Some(self.net_income.value as f32 / gross_income as f32),
+        };
+    }
+}
+
+/// Calculates the differences between the base and the other tax result.
+pub(crate) fn calculate(base: &TaxResult, other: &TaxResult) -> TaxResultDiff {
+    let total_taxes = |result: &TaxResult| -> i64 {
+        return result.social_security_taxes as i64 + result.get_total_income_taxes() as i64;
+    };
+
+    return TaxResultDiff {
+        gross_income: ValueDiff::new(base.gross_income as i64, other.gross_income as i64),
+        net_income: ValueDiff::new(base.net_income as i64, other.net_income as i64),
+        social_security_taxes: ValueDiff::new(
+            base.social_security_taxes as i64,
+            other.social_security_taxes as i64,
+        ),
+        income_taxes: ValueDiff::new(base.income_taxes as i64, other.income_taxes as i64),
+        church_tax: ValueDiff::new(base.church_tax as i64, other.church_tax as i64),
+        total_taxes: ValueDiff::new(total_taxes(base), total_taxes(other)),
+        tax_ratio: other.get_tax_ratio() - base.get_tax_ratio(),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::abs_diff_eq;
+
+    fn create_result(
+        gross_income: i32,
+        social_security_taxes: u32,
+        income_taxes: u32,
+    ) -> TaxResult {
+        return TaxResult {
+            gross_income,
+            net_income: gross_income - social_security_taxes as i32 - income_taxes as i32,
+            social_security_taxes,
+            income_taxes,
+            church_tax: 0,
+        };
+    }
+
+    #[test]
+    fn test_diff_per_field() {
+        let base = create_result(50000, 10000, 8000);
+        let other = create_result(60000, 12000, 11000);
+
+        let diff = base.diff(&other);
+        assert_eq!(diff.gross_income.value, 10000);
+        assert!(abs_diff_eq!(diff.gross_income.percentage.unwrap(), 0.2));
+        assert_eq!(diff.net_income.value, 5000);
+        assert_eq!(diff.social_security_taxes.value, 2000);
+        assert_eq!(diff.income_taxes.value, 3000);
+        assert_eq!(diff.total_taxes.value, 5000);
+        assert!(abs_diff_eq!(diff.get_net_ratio().unwrap(), 0.5));
+        assert!(diff.tax_ratio > 0.0);
+
+        // the reversed comparison has the negated values
+        assert_eq!(other.diff(&base).net_income.value, -5000);
+    }
+
+    #[test]
+    fn test_diff_from_zero() {
+        let base = create_result(0, 0, 0);
+        let diff = base.diff(&base);
+
+        assert_eq!(diff.income_taxes.percentage, None);
+        assert_eq!(diff.get_net_ratio(), None);
+    }
+}
@@ -0,0 +1,191 @@
+//! Planning of an early retirement with bridge years before the pension starts.
+//!
+//! A person that stops working before the pension starts lives off the capital in the bridge years
+//! and needs to be voluntarily insured in the health insurance on the capital income (see
+//! [`crate::privatier`]). The pension can start up to 60 months before the regular retirement age,
+//! but is permanently reduced by the deductions for each month of the early retirement (see
+//! [`crate::pension`]).
+
+use crate::Assessment;
+use crate::config::Config;
+use crate::error::Error;
+use crate::pension;
+use crate::privatier::{self, PrivatierData};
+
+/// Input data struct for the planning of an early retirement.
+#[derive(Clone)]
+pub struct EarlyRetirementData {
+    /// The age at which the person stops working.
+    pub stop_working_age: u32,
+
+    /// The age at which the pension starts.
+    pub pension_age: u32,
+
+    /// The regular retirement age (Regelaltersgrenze).
+    pub regular_retirement_age: u32,
+
+    /// The expected monthly pension at the regular retirement age (without deductions).
+    pub monthly_pension: u32,
+
+    /// The yearly withdrawal from the investments in the bridge years.
+    pub withdrawal: u32,
+
+    /// The share \[0,1\] of the withdrawal that is capital income.
+    pub gain_ratio: f32,
+
+    /// The type of the income tax assessment.
+    pub assessment: Assessment,
+
+    /// Whether the person is a member of a church and pays the church tax on the pension.
+    pub church_member: bool,
+}
+
+/// Result of one year of the early retirement.
+pub struct RetirementYear {
+    /// The age in this year.
+    pub age: u32,
+
+    /// Whether the pension is paid in this year (otherwise it is a bridge year).
+    pub pension_paid: bool,
+
+    /// The yearly gross income, which is either the withdrawal or the pension.
+    pub income: u32,
+
+    /// The contributions to the health and nursing care insurance.
+    pub health_insurance: u32,
+
+    /// The income taxes or the taxes on the capital income.
+    pub taxes: u32,
+
+    /// The net income of the year.
+    pub net_income: i64,
+}
+
+/// Calculates the net income of every year from the stop of working until the regular retirement
+/// age (inclusive), with the bridge years before the pension starts and the reduced pension after.
+///
+/// All values are calculated with the given configuration and without any increase of the pension.
+pub fn calculate(
+    config: &Config,
+    data: &EarlyRetirementData,
+) -> Result<Vec<RetirementYear>, Error> {
+    if data.stop_working_age > data.pension_age {
+        return Err(Error::PensionBeforeEndOfWork);
+    }
+    if data.pension_age > data.regular_retirement_age {
+        return Err(Error::PensionAfterRegularAge);
+    }
+
+    let early_months = (data.regular_retirement_age - data.pension_age) * 12;
+    if early_months > pension::MAX_EARLY_MONTHS {
+        return Err(Error::RetirementTooEarly);
+    }
+
+    let monthly_pension = data.monthly_pension
+        - pension::calculate_pension_deduction(data.monthly_pension, early_months);
+    let pension = pension::calculate_net_pension(
+        config,
+        monthly_pension * 12,
+        data.assessment,
+        data.church_member,
+    );
+
+    let bridge_year = privatier::calculate(
+        config,
+        &PrivatierData {
+            withdrawal: data.withdrawal,
+            gain_ratio: data.gain_ratio,
+            married: data.assessment == Assessment::Joint,
+        },
+    )?;
+
+    let years = (data.stop_working_age..=data.regular_retirement_age)
+        .map(|age| match age < data.pension_age {
+            true => RetirementYear {
+                age,
+                pension_paid: false,
+                income: bridge_year.withdrawal,
+                health_insurance: bridge_year.health_insurance,
+                taxes: bridge_year.capital_income_taxes,
+                net_income: bridge_year.net_income,
+            },
+            false => RetirementYear {
+                age,
+                pension_paid: true,
+                income: pension.pension,
+                health_insurance: pension.health_insurance,
+                taxes: pension.income_taxes,
+                net_income: pension.net_income,
+            },
+        })
+        .collect();
+
+    return Ok(years);
+}
+
+#[cfg(all(test, feature = "year-2025"))]
+mod tests {
+    use super::*;
+    use crate::config::create as create_config;
+
+    fn create_data(pension_age: u32) -> EarlyRetirementData {
+        return EarlyRetirementData {
+            stop_working_age: 60,
+            pension_age,
+            regular_retirement_age: 67,
+            monthly_pension: 2000,
+            withdrawal: 30000,
+            gain_ratio: 0.5,
+            assessment: Assessment::Single,
+            church_member: false,
+        };
+    }
+
+    #[test]
+    fn test_bridge_years_and_reduced_pension() {
+        let config = create_config(2025).unwrap();
+
+        let years = calculate(&config, &create_data(63)).unwrap();
+        assert_eq!(years.len(), 8);
+        assert_eq!(years[0].age, 60);
+        assert!(!years[2].pension_paid);
+        assert!(years[2].health_insurance > 0);
+        assert!(years[3].pension_paid);
+
+        // the pension is reduced by 14.4% for 48 months
+        assert_eq!(years[3].income, 1712 * 12);
+        assert_eq!(years[7].net_income, years[3].net_income);
+
+        let regular = calculate(&config, &create_data(67)).unwrap();
+        assert!(!regular[6].pension_paid);
+        assert_eq!(regular[7].income, 24000);
+    }
+
+    #[test]
+    fn test_invalid_ages() {
+        let config = create_config(2025).unwrap();
+
+        assert_eq!(
+            calculate(&config, &create_data(59)).err(),
+            Some(Error::PensionBeforeEndOfWork)
+        );
+        assert_eq!(
+            calculate(&config, &create_data(68)).err(),
+            Some(Error::PensionAfterRegularAge)
+        );
+
+        let mut data = create_data(62);
+        data.stop_working_age = 63;
+        assert_eq!(
+            calculate(&config, &data).err(),
+            Some(Error::PensionBeforeEndOfWork)
+        );
+
+        let mut data = create_data(61);
+        data.stop_working_age = 55;
+        assert_eq!(
+            calculate(&config, &data).err(),
+            Some(Error::RetirementTooEarly)
+        );
+    }
+}
@@ -0,0 +1,202 @@
+//! Mapping of the calculated values to the fields of the income tax return (as entered in ELSTER).
+//!
+//! The values of the payslips of a year are assigned to the fields of the Anlage N and the Anlage
+//! Vorsorgeaufwand, which correspond to the lines of the annual wage tax certificate
+//! (Lohnsteuerbescheinigung). The taxes on capital income are assigned to the fields of the Anlage
+//! KAP. The fields are labeled with the texts of the forms, as the line numbers change between the
+//! years:
+//!
+//! ```
+//! use net_income_germany::elster;
+//! use net_income_germany::payroll::PayrollData;
+//!
+//! let config = net_income_germany::config::create(net_income_germany::config::latest_year()).unwrap();
+//! let data = PayrollData {
+//!     monthly_salary: 4500,
+//!     bonus: None,
+//!     assessment: net_income_germany::Assessment::Single,
+//!     church_member: true,
+//! };
+//!
+//! let payslips = net_income_germany::payslip::create(&config, &data).unwrap();
+//! let fields = elster::map_payslips(&payslips);
+//! println!("{}", elster::to_json(&fields));
+//! ```
+
+use crate::capital_income::CapitalIncomeResult;
+use crate::payslip::Payslip;
+
+/// Forms of the income tax return.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaxForm {
+    /// Income from employment (Anlage N).
+    AnlageN,
+
+    /// Contributions to the social security (Anlage Vorsorgeaufwand).
+    AnlageVorsorgeaufwand,
+
+    /// Capital income (Anlage KAP).
+    AnlageKap,
+}
+
+impl TaxForm {
+    /// Returns the name of the form.
+    pub fn name(&self) -> &'static str {
+        return match self {
+            TaxForm::AnlageN => "Anlage N",
+            TaxForm::AnlageVorsorgeaufwand => "Anlage Vorsorgeaufwand",
+            TaxForm::AnlageKap => "Anlage KAP",
+        };
+    }
+}
+
+/// One field of a form of the income tax return with its value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormField {
+    /// The form that contains the field.
+    pub form: TaxForm,
+
+    /// The label of the field as written on the form.
+    pub label: &'static str,
+
+    /// The value of the field.
+    pub value: i64,
+}
+
+impl FormField {
+    fn new(form: TaxForm, label: &'static str, value: i64) -> Self {
+        return FormField { form, label, value };
+    }
+}
+
+/// Maps the sums of the given payslips of one year to the fields of the Anlage N and the Anlage
+/// Vorsorgeaufwand.
+pub fn map_payslips(payslips: &[Payslip]) -> Vec<FormField> {
+    let sum = |value: fn(&Payslip) -> u32| payslips.iter().map(|p| value(p) as i64).sum();
+
+    return vec![
+        FormField::new(
+            TaxForm::AnlageN,
+            "Bruttoarbeitslohn",
+            sum(|p| p.tax_gross_income),
+        ),
+        FormField::new(TaxForm::AnlageN, "Lohnsteuer", sum(|p| p.wage_tax)),
+        FormField::new(
+            TaxForm::AnlageN,
+            "Solidaritätszuschlag",
+            sum(|p| p.solidarity_surcharge),
+        ),
+        FormField::new(
+            TaxForm::AnlageN,
+            "Kirchensteuer des Arbeitnehmers",
+            sum(|p| p.church_tax),
+        ),
+        FormField::new(
+            TaxForm::AnlageVorsorgeaufwand,
+            "Arbeitnehmeranteil zur gesetzlichen Rentenversicherung",
+            sum(|p| p.retirement_insurance),
+        ),
+        FormField::new(
+            TaxForm::AnlageVorsorgeaufwand,
+            "Arbeitnehmerbeiträge zur gesetzlichen Krankenversicherung",
+            sum(|p| p.health_insurance),
+        ),
+        FormField::new(
+            TaxForm::AnlageVorsorgeaufwand,
+            "Arbeitnehmerbeiträge zur sozialen Pflegeversicherung",
+            sum(|p| p.nursing_insurance),
+        ),
+        FormField::new(
+            TaxForm::AnlageVorsorgeaufwand,
+            "Arbeitnehmerbeiträge zur Arbeitslosenversicherung",
+            sum(|p| p.unemployment_insurance),
+        ),
+    ];
+}
+
+/// Maps the given yearly capital income and the taxes on it to the fields of the Anlage KAP.
+pub fn map_capital_income(capital_income: u32, result: &CapitalIncomeResult) -> Vec<FormField> {
+    return vec![
+        FormField::new(TaxForm::AnlageKap, "Kapitalerträge", capital_income as i64),
+        FormField::new(
+            TaxForm::AnlageKap,
+            "In Anspruch genommener Sparer-Pauschbetrag",
+            capital_income.saturating_sub(result.taxable_capital_income) as i64,
+        ),
+        FormField::new(
+            TaxForm::AnlageKap,
+            "Kapitalertragsteuer",
+            result.flat_tax as i64,
+        ),
+        FormField::new(
+            TaxForm::AnlageKap,
+            "Solidaritätszuschlag",
+            result.solidarity_surcharge as i64,
+        ),
+    ];
+}
+
+/// Exports the given fields as JSON array of objects with the form, the label and the value.
+pub fn to_json(fields: &[FormField]) -> String {
+    let objects: Vec<String> = fields
+        .iter()
+        .map(|field| {
+            format!(
+                "  {{\"form\": \"{}\", \"field\": \"{}\", \"value\": {}}}",
+                field.form.name(),
+                field.label,
+                field.value
+            )
+        })
+        .collect();
+
+    return format!("[\n{}\n]", objects.join(",\n"));
+}
+
+#[cfg(all(test, feature = "year-2025"))]
+mod tests {
+    use super::*;
+    use crate::Assessment;
+    use crate::config::create as create_config;
+    use crate::payroll::PayrollData;
+
+    #[test]
+    fn test_map_payslips() {
+        let config = create_config(2025).unwrap();
+        let data = PayrollData {
+            monthly_salary: 4500,
+            bonus: Some((5000, 12)),
+            assessment: Assessment::Single,
+            church_member: true,
+        };
+
+        let payslips = crate::payslip::create(&config, &data).unwrap();
+        let fields = map_payslips(&payslips);
+        assert_eq!(fields.len(), 8);
+        assert_eq!(fields[0].label, "Bruttoarbeitslohn");
+        assert_eq!(fields[0].value, 59000);
+
+        let annual_income_taxes = crate::calculate(&config, &crate::TaxData::new(59000))
+            .unwrap()
+            .income_taxes;
+        assert_eq!(
+            fields[1].value + fields[2].value,
+            annual_income_taxes as i64
+        );
+    }
+
+    #[test]
+    fn test_capital_income_to_json() {
+        let config = create_config(2025).unwrap();
+        let result = crate::capital_income::calculate(&config, 2000, false);
+
+        let json = to_json(&map_capital_income(2000, &result));
+        assert!(json.starts_with(
+            "[\n  {\"form\": \"Anlage KAP\", \"field\": \"Kapitalerträge\", \"value\": 2000},\n"
+        ));
+        assert!(json.contains(
+            "\"field\": \"In Anspruch genommener Sparer-Pauschbetrag\", \"value\": 1000}"
+        ));
+        assert!(json.ends_with("}\n]"));
+    }
+}
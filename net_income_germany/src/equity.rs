@@ -0,0 +1,214 @@
+//! Deferred taxation of employee shares in qualified startups (§ 19a EStG).
+//!
+//! The non-cash benefit of shares that an employee receives from a qualified startup does not need
+//! to be taxed in the year of the grant. Instead, the taxation happens when the shares are sold (or
+//! transferred as part of an exit) or at the latest when the deferral limit is reached. If more
+//! than three years are between the grant and the taxation, the Fünftelregelung (§ 34 EStG) can be
+//! applied on the benefit.
+//!
+//! The social security contributions on the benefit are due in the year of the grant in any case.
+//! Any gain on the shares above the benefit is capital income and not part of this calculation.
+
+use crate::config::Config;
+use crate::error::Error;
+use crate::{TaxData, calculate_taxable_income, income_tax};
+
+/// Maximum number of years that the taxation of the benefit can be deferred for shares that were
+/// granted from 2024 on (extended by the Zukunftsfinanzierungsgesetz).
+pub const DEFERRAL_LIMIT_YEARS: u32 = 15;
+
+/// Maximum number of years that the taxation of the benefit can be deferred for shares that were
+/// granted before 2024.
+pub const DEFERRAL_LIMIT_YEARS_BEFORE_2024: u32 = 12;
+
+/// Returns the maximum number of years that the taxation of the benefit of shares, which were
+/// granted in the given year, can be deferred.
+pub fn get_deferral_limit_years(grant_year: u32) -> u32 {
+    return match grant_year < 2024 {
+        true => DEFERRAL_LIMIT_YEARS_BEFORE_2024,
+        false => DEFERRAL_LIMIT_YEARS,
+    };
+}
+
+/// Minimum number of years between grant and taxation for the Fünftelregelung to be applicable.
+const FIFTH_RULE_MIN_YEARS: u32 = 3;
+
+/// Input data of the shares for the calculation of the deferred taxation.
+#[derive(Clone)]
+pub struct EquityData {
+    /// The non-cash benefit at the time of the grant (market value minus the price paid by the employee).
+    pub benefit: u32,
+
+    /// The number of years between the grant and the taxation event (sale, exit or deferral limit).
+    pub years_until_taxation: u32,
+}
+
+/// Result struct of the comparison between immediate and deferred taxation of the shares.
+pub struct EquityResult {
+    /// The social security taxes on the benefit that are due in the year of the grant.
+    pub social_security_taxes: u32,
+
+    /// The income taxes on the benefit, if it is taxed in the year of the grant.
+    pub income_taxes_at_grant: u32,
+
+    /// The income taxes on the benefit, if the taxation is deferred to the year of the taxation event.
+    pub income_taxes_deferred: u32,
+
+    /// The income taxes on the benefit, if the taxation is deferred and the Fünftelregelung is
+    /// applied. This is only available, if more than three years are between grant and taxation.
+    pub income_taxes_deferred_fifth_rule: Option<u32>,
+}
+
+/// Calculates the income taxes on the benefit of the shares for the taxation in the year of the grant
+/// and for the deferred taxation in the year of the taxation event.
+///
+/// The tax data of both years contain the regular income (without the benefit of the shares), which
+/// allows to compare scenarios like an exit in a year with a lower regular income.
+pub fn calculate(
+    grant_config: &Config,
+    grant_tax_data: &TaxData,
+    taxation_config: &Config,
+    taxation_tax_data: &TaxData,
+    equity_data: &EquityData,
+) -> Result<EquityResult, Error> {
+    if equity_data.years_until_taxation > get_deferral_limit_years(grant_config.origin.year) {
+        return Err(Error::DeferralLimitExceeded);
+    }
+
+    // the benefit is part of the income in the year of the grant for the social security taxes
+    let (social_security_without, _) = calculate_taxable_income(grant_config, grant_tax_data)?;
+    let mut grant_tax_data_with_benefit = grant_tax_data.clone();
+    grant_tax_data_with_benefit.income = grant_tax_data.income.saturating_add(equity_data.benefit);
+    let (social_security_with, taxable_income_with) =
+        calculate_taxable_income(grant_config, &grant_tax_data_with_benefit)?;
+
+    // for the taxation at grant, compare the taxes with and without the benefit (but with the
+    // social security taxes of the benefit deducted in both cases, as they are due anyway)
+    let taxable_income_without = taxable_income_with.saturating_sub(equity_data.benefit);
+    let income_taxes_at_grant = income_tax::calculate(
+        &grant_config.income_tax,
+        taxable_income_with,
+        grant_tax_data.assessment.is_splitting(),
+        grant_tax_data.church_member,
+    ) - income_tax::calculate(
+        &grant_config.income_tax,
+        taxable_income_without,
+        grant_tax_data.assessment.is_splitting(),
+        grant_tax_data.church_member,
+    );
+
+    // for the deferred taxation, the benefit is added to the taxable income of the taxation year
+    let (_, taxable_income) = calculate_taxable_income(taxation_config, taxation_tax_data)?;
+    let income_taxes_regular = income_tax::calculate(
+        &taxation_config.income_tax,
+        taxable_income,
+        taxation_tax_data.assessment.is_splitting(),
+        taxation_tax_data.church_member,
+    );
+    let income_taxes_deferred = income_tax::calculate(
+        &taxation_config.income_tax,
+        taxable_income.saturating_add(equity_data.benefit),
+        taxation_tax_data.assessment.is_splitting(),
+        taxation_tax_data.church_member,
+    ) - income_taxes_regular;
+
+    let income_taxes_deferred_fifth_rule =
+        match equity_data.years_until_taxation > FIFTH_RULE_MIN_YEARS {
+            true => Some(
+                income_tax::calculate_with_fifth_rule(
+                    &taxation_config.income_tax,
+                    taxable_income,
+                    equity_data.benefit,
+                    taxation_tax_data.assessment.is_splitting(),
+                    taxation_tax_data.church_member,
+                ) - income_taxes_regular,
+            ),
+            false => None,
+        };
+
+    return Ok(EquityResult {
+        social_security_taxes: social_security_with - social_security_without,
+        income_taxes_at_grant,
+        income_taxes_deferred,
+        income_taxes_deferred_fifth_rule,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "year-2025")]
+    use crate::config::create as create_config;
+    #[cfg(feature = "year-2025")]
+    #[test]
+    #[cfg(feature = "year-2025")]
+    fn test_deferred_taxation_in_year_with_lower_income() {
+        let config = create_config(2025).unwrap();
+        let equity_data = EquityData {
+            benefit: 50000,
+            years_until_taxation: 5,
+        };
+
+        let result = calculate(
+            &config,
+            &TaxData::new(90000),
+            &config,
+            &TaxData::new(20000),
+            &equity_data,
+        )
+        .unwrap();
+
+        // the benefit is above the health insurance income limit, so it contributes only to the
+        // retirement and unemployment insurance
+        assert!(result.social_security_taxes > 0);
+
+        // taxation in a year with lower income is cheaper, especially with the Fünftelregelung
+        assert!(result.income_taxes_deferred < result.income_taxes_at_grant);
+        assert!(result.income_taxes_deferred_fifth_rule.unwrap() < result.income_taxes_deferred);
+    }
+
+    #[test]
+    #[cfg(feature = "year-2025")]
+    fn test_fifth_rule_only_after_three_years() {
+        let config = create_config(2025).unwrap();
+        let tax_data = TaxData::new(60000);
+        let mut equity_data = EquityData {
+            benefit: 20000,
+            years_until_taxation: FIFTH_RULE_MIN_YEARS,
+        };
+
+        let result = calculate(&config, &tax_data, &config, &tax_data, &equity_data).unwrap();
+        assert!(result.income_taxes_deferred_fifth_rule.is_none());
+
+        // with the same tax data for grant and taxation year, the deferral is not cheaper, as the
+        // social security taxes on the benefit can only be deducted in the year of the grant
+        assert!(result.income_taxes_at_grant <= result.income_taxes_deferred);
+
+        equity_data.years_until_taxation = FIFTH_RULE_MIN_YEARS + 1;
+        let result = calculate(&config, &tax_data, &config, &tax_data, &equity_data).unwrap();
+        assert!(result.income_taxes_deferred_fifth_rule.is_some());
+    }
+
+    #[test]
+    #[cfg(feature = "year-2025")]
+    fn test_error_beyond_deferral_limit() {
+        let config = create_config(2025).unwrap();
+        let tax_data = TaxData::new(60000);
+        let equity_data = EquityData {
+            benefit: 20000,
+            years_until_taxation: get_deferral_limit_years(config.origin.year) + 1,
+        };
+
+        assert_eq!(
+            calculate(&config, &tax_data, &config, &tax_data, &equity_data).err(),
+            Some(Error::DeferralLimitExceeded)
+        );
+    }
+
+    #[test]
+    fn test_deferral_limit_of_grant_year() {
+        assert_eq!(get_deferral_limit_years(2023), 12);
+        assert_eq!(get_deferral_limit_years(2024), 15);
+        assert_eq!(get_deferral_limit_years(2025), 15);
+    }
+}
@@ -0,0 +1,635 @@
+//! Structured errors with machine-readable codes and localized messages.
+//!
+//! All calculations of the crate return an [`Error`] with one variant per error condition, so that
+//! frontends can show the message in the language of the user and can react on the variant (or on
+//! its code) without matching the English texts:
+//!
+//! ```
+//! use net_income_germany::error::{Error, Language};
+//!
+//! let error = net_income_germany::config::create(1990).unwrap_err();
+//! assert_eq!(error.code(), "unsupported_year");
+//! println!("{}", error.message(Language::German));
+//!
+//! let error = "Atlantis"
+//!     .parse::<net_income_germany::bundesland::Bundesland>()
+//!     .unwrap_err();
+//! assert_eq!(error, Error::UnknownFederalState);
+//! assert_eq!(error.code(), "unknown_federal_state");
+//! ```
+
+use std::fmt;
+
+/// Languages of the error messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Language {
+    /// English messages.
+    #[default]
+    English,
+
+    /// German messages.
+    German,
+}
+
+/// Errors of the calculations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// There is no configuration for the given year.
+    UnsupportedYear {
+        /// The requested year.
+        year: u32,
+
+        /// The years for which a configuration is available.
+        supported_years: &'static [u32],
+
+        /// The supported year that is nearest to the requested year.
+        nearest_year: u32,
+    },
+
+    /// The input values are too large for the calculation.
+    InputTooLarge {
+        /// The largest value that the calculation can handle (e.g., for the income minus the
+        /// expenses).
+        limit: u32,
+    },
+
+    /// The given name or abbreviation is no federal state.
+    UnknownFederalState,
+
+    /// The amount is not given as euros with up to two decimal places.
+    InvalidAmount,
+
+    /// The amount is too large to be parsed.
+    AmountTooLarge,
+
+    /// Occupational pension schemes are only available for employees.
+    CompanyPensionRequiresEmployee,
+
+    /// The salary conversion exceeds the salary.
+    SalaryConversionTooLarge,
+
+    /// The flat-taxed contributions exceed the yearly limit for the flat tax.
+    FlatTaxLimitExceeded,
+
+    /// The first period of the additional premium does not start in January.
+    PremiumPeriodsNotFromJanuary,
+
+    /// The periods of the additional premium do not start in ascending months.
+    PremiumPeriodsNotAscending,
+
+    /// The pension starts before the person stops working.
+    PensionBeforeEndOfWork,
+
+    /// The pension starts after the regular retirement age.
+    PensionAfterRegularAge,
+
+    /// The retirement is more than 60 months before the regular retirement age.
+    RetirementTooEarly,
+
+    /// The taxation of the shares is deferred beyond the deferral limit.
+    DeferralLimitExceeded,
+
+    /// The number of months of the employment is not between one and eleven.
+    InvalidEmploymentMonths,
+
+    /// The value added tax of the expenses exceeds the expenses.
+    InvalidValueAddedTax,
+
+    /// The revenue exceeds the limits of the small business regulation.
+    SmallBusinessLimitExceeded,
+
+    /// The share of the income is not between zero and one.
+    InvalidIncomeShare,
+
+    /// The monthly income exceeds the income limit of a Minijob.
+    MinijobLimitExceeded,
+
+    /// The comparison of the parental allowance is only available for employees.
+    ParentalAllowanceRequiresEmployee,
+
+    /// The lower bound of an interval exceeds its upper bound.
+    InvalidInterval,
+
+    /// The maternity benefit of the employer is only paid to employees.
+    MaternityBenefitRequiresEmployee,
+
+    /// The number of months with benefits is not between zero and twelve.
+    InvalidBenefitMonths,
+
+    /// The target hours are not between zero and the current hours.
+    InvalidTargetHours,
+
+    /// The month of the bonus is not between one and twelve.
+    InvalidBonusMonth,
+
+    /// The gain ratio is not between zero and one.
+    InvalidGainRatio,
+
+    /// No withdrawal leads to the given net income.
+    NoWithdrawalFound,
+
+    /// There are no reference values for the given year.
+    NoReferenceValues,
+
+    /// No income reaches the given goal.
+    GoalNotReached,
+
+    /// A second job in tax class VI requires an employed person.
+    SecondJobRequiresEmployee,
+
+    /// The statutory sick pay is only paid to employees.
+    SickPayRequiresEmployee,
+
+    /// The number of months with sick pay is not between zero and twelve.
+    InvalidSickPayMonths,
+
+    /// There is no additional premium of the health insurance company for the given year.
+    UnsupportedInsurerYear,
+
+    /// The start-up grant is only paid to self-employed persons.
+    StartUpGrantRequiresSelfEmployed,
+
+    /// The deferred amount is larger than the income of the deposit year.
+    DeferredAmountTooLarge,
+
+    /// The trade tax multiplier is below the minimum multiplier.
+    TradeTaxMultiplierTooLow,
+
+    /// The reverse calculation is requested for a net income that is not positive.
+    NetIncomeNotPositive,
+
+    /// The step size is zero.
+    InvalidStepSize,
+
+    /// There is already a configuration for the given year.
+    ConfigurationAlreadyAvailable,
+
+    /// The given value is no assessment.
+    UnknownAssessment,
+
+    /// The given value is no deduction category.
+    UnknownDeductionCategory,
+
+    /// The request does not contain any tax data.
+    MissingTaxData,
+
+    /// The retirement year is before the year of the configuration.
+    RetirementYearInPast,
+
+    /// A private health insurance is requested for an employee with an income below the compulsory
+    /// insurance limit (Versicherungspflichtgrenze).
+    PrivateHealthInsuranceBelowLimit,
+
+    /// The reverse calculation does not find a gross income that results in the given net income.
+    NetIncomeNotReachable,
+
+    /// The request does not specify the type of the income tax assessment.
+    MissingAssessment,
+
+    /// The requested range contains more incomes than the given limit.
+    RangeTooLarge {
+        /// The largest number of incomes of a range.
+        limit: u32,
+    },
+}
+
+impl Error {
+    /// Creates the error for the given unsupported year with the supported years as suggestions.
+    pub(crate) fn unsupported_year(year: u32) -> Self {
+        let supported_years = crate::config::supported_years();
+        let nearest_year = supported_years
+            .iter()
+            .copied()
+            .min_by_key(|supported_year| supported_year.abs_diff(year))
+            .unwrap_or(year);
+
+        return Error::UnsupportedYear {
+            year,
+            supported_years,
+            nearest_year,
+        };
+    }
+
+    /// Creates the error for input values that are too large for the signed output.
+    pub(crate) fn input_too_large() -> Self {
+        return Error::InputTooLarge {
+            limit: i32::MAX as u32,
+        };
+    }
+
+    /// Creates the error for input values that are too large for the unsigned intermediate values.
+    pub(crate) fn input_too_large_unsigned() -> Self {
+        return Error::InputTooLarge { limit: u32::MAX };
+    }
+
+    /// Returns the machine-readable code of the error (e.g., "unsupported_year").
+    pub fn code(&self) -> &'static str {
+        return match self {
+            Error::UnsupportedYear { .. } => "unsupported_year",
+            Error::InputTooLarge { .. } => "input_too_large",
+            Error::UnknownFederalState => "unknown_federal_state",
+            Error::InvalidAmount => "invalid_amount",
+            Error::AmountTooLarge => "amount_too_large",
+            Error::CompanyPensionRequiresEmployee => "company_pension_requires_employee",
+            Error::SalaryConversionTooLarge => "salary_conversion_too_large",
+            Error::FlatTaxLimitExceeded => "flat_tax_limit_exceeded",
+            Error::PremiumPeriodsNotFromJanuary => "invalid_premium_periods",
+            Error::PremiumPeriodsNotAscending => "invalid_premium_periods",
+            Error::PensionBeforeEndOfWork => "invalid_pension_start",
+            Error::PensionAfterRegularAge => "invalid_pension_start",
+            Error::RetirementTooEarly => "invalid_pension_start",
+            Error::DeferralLimitExceeded => "deferral_limit_exceeded",
+            Error::InvalidEmploymentMonths => "invalid_number_of_months",
+            Error::InvalidValueAddedTax => "invalid_value_added_tax",
+            Error::SmallBusinessLimitExceeded => "small_business_limit_exceeded",
+            Error::InvalidIncomeShare => "invalid_share",
+            Error::MinijobLimitExceeded => "minijob_limit_exceeded",
+            Error::ParentalAllowanceRequiresEmployee => "parental_allowance_requires_employee",
+            Error::InvalidInterval => "invalid_interval",
+            Error::MaternityBenefitRequiresEmployee => "maternity_benefit_requires_employee",
+            Error::InvalidBenefitMonths => "invalid_number_of_months",
+            Error::InvalidTargetHours => "invalid_target_hours",
+            Error::InvalidBonusMonth => "invalid_month",
+            Error::InvalidGainRatio => "invalid_gain_ratio",
+            Error::NoWithdrawalFound => "no_withdrawal_found",
+            Error::NoReferenceValues => "no_reference_values",
+            Error::GoalNotReached => "goal_not_reached",
+            Error::SecondJobRequiresEmployee => "second_job_requires_employee",
+            Error::SickPayRequiresEmployee => "sick_pay_requires_employee",
+            Error::InvalidSickPayMonths => "invalid_number_of_months",
+            Error::UnsupportedInsurerYear => "unsupported_insurer_year",
+            Error::StartUpGrantRequiresSelfEmployed => "start_up_grant_requires_self_employed",
+            Error::DeferredAmountTooLarge => "deferred_amount_too_large",
+            Error::TradeTaxMultiplierTooLow => "trade_tax_multiplier_too_low",
+            Error::NetIncomeNotPositive => "net_income_not_positive",
+            Error::InvalidStepSize => "invalid_step_size",
+            Error::ConfigurationAlreadyAvailable => "configuration_already_available",
+            Error::UnknownAssessment => "unknown_assessment",
+            Error::UnknownDeductionCategory => "unknown_deduction_category",
+            Error::MissingTaxData => "missing_tax_data",
+            Error::RetirementYearInPast => "retirement_year_in_past",
+            Error::PrivateHealthInsuranceBelowLimit => "private_health_insurance_below_limit",
+            Error::NetIncomeNotReachable => "net_income_not_reachable",
+            Error::MissingAssessment => "missing_assessment",
+            Error::RangeTooLarge { .. } => "range_too_large",
+        };
+    }
+
+    /// Returns the human-readable message of the error in the given language.
+    pub fn message(&self, language: Language) -> String {
+        return match self {
+            Error::UnsupportedYear {
+                year,
+                supported_years,
+                nearest_year,
+            } => match language {
+                Language::English => format!(
+                    "No configuration available for the year {year}. Supported years are {}, the nearest one is {nearest_year}.",
+                    join_years(supported_years)
+                ),
+                Language::German => format!(
+                    "Für das Jahr {year} ist keine Konfiguration verfügbar. Unterstützt werden die Jahre {}, das nächstgelegene ist {nearest_year}.",
+                    join_years(supported_years)
+                ),
+            },
+            Error::InputTooLarge { limit } => match language {
+                Language::English => {
+                    format!("Input values are too large for the calculation, the limit is {limit}.")
+                }
+                Language::German => {
+                    format!(
+                        "Die Eingabewerte sind zu groß für die Berechnung, die Grenze ist {limit}."
+                    )
+                }
+            },
+            Error::UnknownFederalState => translate(
+                language,
+                "Unknown federal state.",
+                "Unbekanntes Bundesland.",
+            ),
+            Error::InvalidAmount => translate(
+                language,
+                "The amount needs to be given as euros with up to two decimal places.",
+                "Der Betrag muss in Euro mit höchstens zwei Nachkommastellen angegeben werden.",
+            ),
+            Error::AmountTooLarge => translate(
+                language,
+                "The amount is too large to be parsed.",
+                "Der Betrag ist zu groß, um eingelesen zu werden.",
+            ),
+            Error::CompanyPensionRequiresEmployee => translate(
+                language,
+                "Occupational pension schemes are only available for employees.",
+                "Die betriebliche Altersversorgung ist nur für Arbeitnehmer verfügbar.",
+            ),
+            Error::SalaryConversionTooLarge => translate(
+                language,
+                "The salary conversion cannot exceed the salary.",
+                "Die Entgeltumwandlung kann das Gehalt nicht übersteigen.",
+            ),
+            Error::FlatTaxLimitExceeded => translate(
+                language,
+                "The flat-taxed contributions exceed the yearly limit for the flat tax.",
+                "Die pauschal versteuerten Beiträge übersteigen die jährliche Grenze der Pauschalbesteuerung.",
+            ),
+            Error::PremiumPeriodsNotFromJanuary => translate(
+                language,
+                "The first period of the additional premium needs to start in January.",
+                "Der erste Zeitraum des Zusatzbeitrags muss im Januar beginnen.",
+            ),
+            Error::PremiumPeriodsNotAscending => translate(
+                language,
+                "The periods of the additional premium need to start in ascending months of the year.",
+                "Die Zeiträume des Zusatzbeitrags müssen in aufsteigenden Monaten des Jahres beginnen.",
+            ),
+            Error::PensionBeforeEndOfWork => translate(
+                language,
+                "The pension cannot start before the person stops working.",
+                "Die Rente kann nicht vor dem Ende der Erwerbstätigkeit beginnen.",
+            ),
+            Error::PensionAfterRegularAge => translate(
+                language,
+                "The pension needs to start at the latest at the regular retirement age.",
+                "Die Rente muss spätestens mit der Regelaltersgrenze beginnen.",
+            ),
+            Error::RetirementTooEarly => translate(
+                language,
+                "The retirement can be at most 60 months before the regular retirement age.",
+                "Der Ruhestand kann höchstens 60 Monate vor der Regelaltersgrenze beginnen.",
+            ),
+            Error::DeferralLimitExceeded => translate(
+                language,
+                "The taxation of the shares cannot be deferred beyond the deferral limit.",
+                "Die Besteuerung der Anteile kann nicht über die Frist hinaus aufgeschoben werden.",
+            ),
+            Error::InvalidEmploymentMonths => translate(
+                language,
+                "The number of months of the employment needs to be between one and eleven.",
+                "Die Anzahl der Monate der Anstellung muss zwischen eins und elf liegen.",
+            ),
+            Error::InvalidValueAddedTax => translate(
+                language,
+                "The value added tax of the expenses cannot exceed the expenses.",
+                "Die Umsatzsteuer der Ausgaben kann die Ausgaben nicht übersteigen.",
+            ),
+            Error::SmallBusinessLimitExceeded => translate(
+                language,
+                "The revenue exceeds the limits of the small business regulation.",
+                "Der Umsatz übersteigt die Grenzen der Kleinunternehmerregelung.",
+            ),
+            Error::InvalidIncomeShare => translate(
+                language,
+                "The share of the income needs to be between zero and one.",
+                "Der Anteil des Einkommens muss zwischen null und eins liegen.",
+            ),
+            Error::MinijobLimitExceeded => translate(
+                language,
+                "The monthly income exceeds the income limit of a Minijob.",
+                "Das monatliche Einkommen übersteigt die Verdienstgrenze eines Minijobs.",
+            ),
+            Error::ParentalAllowanceRequiresEmployee => translate(
+                language,
+                "The comparison of the parental allowance is only available for employees.",
+                "Der Vergleich des Elterngelds ist nur für Arbeitnehmer verfügbar.",
+            ),
+            Error::InvalidInterval => translate(
+                language,
+                "The lower bound of an interval cannot exceed its upper bound.",
+                "Die untere Grenze eines Intervalls kann die obere Grenze nicht übersteigen.",
+            ),
+            Error::MaternityBenefitRequiresEmployee => translate(
+                language,
+                "The maternity benefit of the employer is only paid to employees.",
+                "Der Arbeitgeberzuschuss zum Mutterschaftsgeld wird nur an Arbeitnehmer gezahlt.",
+            ),
+            Error::InvalidBenefitMonths => translate(
+                language,
+                "The number of months with benefits needs to be between zero and twelve.",
+                "Die Anzahl der Monate mit Leistungen muss zwischen null und zwölf liegen.",
+            ),
+            Error::InvalidTargetHours => translate(
+                language,
+                "The target hours need to be between zero and the current hours.",
+                "Die angestrebten Stunden müssen zwischen null und den aktuellen Stunden liegen.",
+            ),
+            Error::InvalidBonusMonth => translate(
+                language,
+                "The month of the bonus needs to be between one and twelve.",
+                "Der Monat der Sonderzahlung muss zwischen eins und zwölf liegen.",
+            ),
+            Error::InvalidGainRatio => translate(
+                language,
+                "The gain ratio needs to be between zero and one.",
+                "Der Gewinnanteil muss zwischen null und eins liegen.",
+            ),
+            Error::NoWithdrawalFound => translate(
+                language,
+                "No withdrawal was found that leads to the given net income.",
+                "Es wurde keine Entnahme gefunden, die zum angegebenen Nettoeinkommen führt.",
+            ),
+            Error::NoReferenceValues => translate(
+                language,
+                "No reference values are available for the given year.",
+                "Für das angegebene Jahr sind keine Referenzwerte verfügbar.",
+            ),
+            Error::GoalNotReached => translate(
+                language,
+                "No income was found that reaches the given goal.",
+                "Es wurde kein Einkommen gefunden, bei dem das angegebene Ziel erreicht wird.",
+            ),
+            Error::SecondJobRequiresEmployee => translate(
+                language,
+                "A second job in tax class VI requires an employed person.",
+                "Ein Nebenjob in Steuerklasse VI setzt eine angestellte Person voraus.",
+            ),
+            Error::SickPayRequiresEmployee => translate(
+                language,
+                "The statutory sick pay is only paid to employees.",
+                "Das Krankengeld wird nur an Arbeitnehmer gezahlt.",
+            ),
+            Error::InvalidSickPayMonths => translate(
+                language,
+                "The number of months with sick pay needs to be between zero and twelve.",
+                "Die Anzahl der Monate mit Krankengeld muss zwischen null und zwölf liegen.",
+            ),
+            Error::UnsupportedInsurerYear => translate(
+                language,
+                "No additional premium of the health insurance company is available for the given year.",
+                "Für das angegebene Jahr ist kein Zusatzbeitrag der Krankenkasse verfügbar.",
+            ),
+            Error::StartUpGrantRequiresSelfEmployed => translate(
+                language,
+                "The start-up grant is only paid to self-employed persons.",
+                "Der Gründungszuschuss wird nur an Selbstständige gezahlt.",
+            ),
+            Error::DeferredAmountTooLarge => translate(
+                language,
+                "The deferred amount cannot be larger than the income of the deposit year.",
+                "Der angesparte Betrag kann nicht größer als das Einkommen des Ansparjahres sein.",
+            ),
+            Error::TradeTaxMultiplierTooLow => translate(
+                language,
+                "The trade tax multiplier is below the minimum multiplier.",
+                "Der Gewerbesteuerhebesatz liegt unter dem Mindesthebesatz.",
+            ),
+            Error::NetIncomeNotPositive => translate(
+                language,
+                "The reverse calculation requires a positive net income.",
+                "Die Rückrechnung erfordert ein positives Nettoeinkommen.",
+            ),
+            Error::InvalidStepSize => translate(
+                language,
+                "The step size needs to be larger than zero.",
+                "Die Schrittweite muss größer als null sein.",
+            ),
+            Error::ConfigurationAlreadyAvailable => translate(
+                language,
+                "A configuration is already available for the given year.",
+                "Für das angegebene Jahr ist bereits eine Konfiguration vorhanden.",
+            ),
+            Error::UnknownAssessment => translate(
+                language,
+                "Unknown assessment.",
+                "Unbekannte Veranlagungsart.",
+            ),
+            Error::UnknownDeductionCategory => translate(
+                language,
+                "Unknown deduction category.",
+                "Unbekannte Abzugskategorie.",
+            ),
+            Error::MissingTaxData => translate(
+                language,
+                "The request does not contain any tax data.",
+                "Die Anfrage enthält keine Steuerdaten.",
+            ),
+            Error::RetirementYearInPast => translate(
+                language,
+                "The retirement year needs to be the year of the configuration or later.",
+                "Das Jahr des Renteneintritts muss das Jahr der Konfiguration oder später sein.",
+            ),
+            Error::PrivateHealthInsuranceBelowLimit => translate(
+                language,
+                "Employees with an income below the compulsory insurance limit cannot choose a private health insurance.",
+                "Arbeitnehmer mit einem Einkommen unterhalb der Versicherungspflichtgrenze können keine private Krankenversicherung wählen.",
+            ),
+            Error::NetIncomeNotReachable => translate(
+                language,
+                "No gross income results in the given net income.",
+                "Kein Bruttoeinkommen ergibt das angegebene Nettoeinkommen.",
+            ),
+            Error::MissingAssessment => translate(
+                language,
+                "The request does not specify the assessment.",
+                "Die Anfrage gibt keine Veranlagungsart an.",
+            ),
+            Error::RangeTooLarge { limit } => match language {
+                Language::English => format!("The range can contain at most {limit} incomes."),
+                Language::German => {
+                    format!("Der Bereich kann höchstens {limit} Einkommen enthalten.")
+                }
+            },
+        };
+    }
+}
+
+/// Returns the message in the given language.
+fn translate(language: Language, english: &str, german: &str) -> String {
+    return match language {
+        Language::English => english.to_string(),
+        Language::German => german.to_string(),
+    };
+}
+
+/// Joins the given years into a comma-separated list.
+fn join_years(years: &[u32]) -> String {
+    return years
+        .iter()
+        .map(|year| year.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "{}", self.message(Language::English));
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codes_and_messages() {
+        let error = Error::UnsupportedYear {
+            year: 2019,
+            supported_years: &[2024, 2025],
+            nearest_year: 2024,
+        };
+        assert_eq!(error.code(), "unsupported_year");
+        assert_eq!(
+            error.to_string(),
+            "No configuration available for the year 2019. Supported years are 2024, 2025, the nearest one is 2024."
+        );
+        assert_eq!(
+            error.message(Language::German),
+            "Für das Jahr 2019 ist keine Konfiguration verfügbar. Unterstützt werden die Jahre 2024, 2025, das nächstgelegene ist 2024."
+        );
+
+        let error = Error::InvalidBonusMonth;
+        assert_eq!(error.code(), "invalid_month");
+        assert_eq!(
+            error.to_string(),
+            "The month of the bonus needs to be between one and twelve."
+        );
+        assert_eq!(
+            error.message(Language::German),
+            "Der Monat der Sonderzahlung muss zwischen eins und zwölf liegen."
+        );
+
+        // related conditions share their code
+        assert_eq!(
+            Error::InvalidSickPayMonths.code(),
+            "invalid_number_of_months"
+        );
+        assert_eq!(
+            Error::InvalidBenefitMonths.code(),
+            "invalid_number_of_months"
+        );
+    }
+
+    #[test]
+    fn test_input_too_large() {
+        assert_eq!(
+            Error::input_too_large().to_string(),
+            "Input values are too large for the calculation, the limit is 2147483647."
+        );
+        assert_eq!(
+            Error::input_too_large_unsigned(),
+            Error::InputTooLarge { limit: u32::MAX }
+        );
+        assert_eq!(Error::input_too_large_unsigned().code(), "input_too_large");
+    }
+
+    #[test]
+    #[cfg(all(feature = "year-2024", feature = "year-2025"))]
+    fn test_nearest_supported_year() {
+        for (year, nearest_year) in [(2019, 2024), (2026, 2025), (2030, 2025)] {
+            match Error::unsupported_year(year) {
+                Error::UnsupportedYear {
+                    supported_years,
+                    nearest_year: nearest,
+                    ..
+                } => {
+                    assert_eq!(supported_years, &[2024, 2025]);
+                    assert_eq!(nearest, nearest_year);
+                }
+                _ => panic!("unexpected error"),
+            }
+        }
+    }
+}
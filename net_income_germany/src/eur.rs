@@ -0,0 +1,197 @@
+//! Annual summary for self-employed persons in the structure of the Einnahmen-Überschuss-Rechnung.
+//!
+//! Self-employed persons without an obligation to keep accounts determine their profit as the
+//! surplus of the business revenue over the business expenses (§ 4 Abs. 3 EStG), grouped by the
+//! categories of the form Anlage EÜR. The summary contains these groups together with the resulting
+//! profit, the social security contributions, the income taxes and the remaining net income, so that
+//! it can be compared with the own bookkeeping.
+
+use crate::config::Config;
+use crate::error::Error;
+use crate::loss::{self, ProfitData};
+use crate::{Assessment, TaxResult};
+
+/// Categories of the business expenses (Betriebsausgaben) as grouped in the Anlage EÜR.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ExpenseCategory {
+    /// Goods, raw materials and external services (Waren, Rohstoffe und Fremdleistungen).
+    GoodsAndServices,
+
+    /// Wages and salaries of employees (Personalkosten).
+    Personnel,
+
+    /// Depreciation of fixed assets (Absetzung für Abnutzung, AfA).
+    Depreciation,
+
+    /// Rent and costs of business rooms (Raumkosten).
+    Rooms,
+
+    /// Costs of vehicles (Kfz-Kosten).
+    Vehicles,
+
+    /// Travel expenses (Reisekosten).
+    Travel,
+
+    /// All other business expenses (sonstige unbeschränkt abziehbare Betriebsausgaben).
+    Other,
+}
+
+impl ExpenseCategory {
+    /// All categories in the order of the Anlage EÜR.
+    pub const ALL: [ExpenseCategory; 7] = [
+        ExpenseCategory::GoodsAndServices,
+        ExpenseCategory::Personnel,
+        ExpenseCategory::Depreciation,
+        ExpenseCategory::Rooms,
+        ExpenseCategory::Vehicles,
+        ExpenseCategory::Travel,
+        ExpenseCategory::Other,
+    ];
+
+    /// Returns the German name of the category.
+    pub fn name(&self) -> &'static str {
+        return match self {
+            ExpenseCategory::GoodsAndServices => "Waren, Rohstoffe und Fremdleistungen",
+            ExpenseCategory::Personnel => "Personalkosten",
+            ExpenseCategory::Depreciation => "Absetzung für Abnutzung",
+            ExpenseCategory::Rooms => "Raumkosten",
+            ExpenseCategory::Vehicles => "Kfz-Kosten",
+            ExpenseCategory::Travel => "Reisekosten",
+            ExpenseCategory::Other => "Sonstige Betriebsausgaben",
+        };
+    }
+}
+
+/// Input data struct for the annual summary of a self-employed person.
+#[derive(Clone)]
+pub struct EurData {
+    /// The business revenue of the year (Betriebseinnahmen, without value added tax).
+    pub revenue: u32,
+
+    /// The business expenses of the year per category (without value added tax). A category can
+    /// occur several times.
+    pub expenses: Vec<(ExpenseCategory, u32)>,
+
+    /// Optional value of a fixed monthly retirement insurance rate.
+    pub fixed_retirement: Option<u32>,
+
+    /// The type of the income tax assessment.
+    pub assessment: Assessment,
+}
+
+/// Annual summary in the structure of the Einnahmen-Überschuss-Rechnung.
+pub struct EurSummary {
+    /// The business revenue of the year.
+    pub revenue: u32,
+
+    /// The sum of the business expenses per category, in the order of [`ExpenseCategory::ALL`] and
+    /// without the categories without expenses.
+    pub expenses: Vec<(ExpenseCategory, u64)>,
+
+    /// The profit (Gewinn), negative for a loss.
+    pub profit: i64,
+
+    /// The social security contributions and income taxes on the profit. The net income is the
+    /// profit after the contributions and taxes.
+    pub tax_result: TaxResult,
+}
+
+impl EurSummary {
+    /// Returns the sum of all business expenses.
+    pub fn get_total_expenses(&self) -> u64 {
+        return self.expenses.iter().map(|(_, amount)| amount).sum();
+    }
+}
+
+/// Creates the annual summary of a self-employed person from the given revenue and expenses.
+pub fn calculate(config: &Config, data: &EurData) -> Result<EurSummary, Error> {
+    let expenses: Vec<(ExpenseCategory, u64)> = ExpenseCategory::ALL
+        .into_iter()
+        .map(|category| {
+            let amount = data
+                .expenses
+                .iter()
+                .filter(|(expense_category, _)| *expense_category == category)
+                .map(|(_, amount)| *amount as u64)
+                .sum();
+            (category, amount)
+        })
+        .filter(|(_, amount)| *amount > 0)
+        .collect();
+
+    let total_expenses: u64 = expenses.iter().map(|(_, amount)| amount).sum();
+    let profit = data.revenue as i64 - total_expenses as i64;
+
+    let result = loss::calculate(
+        config,
+        &ProfitData {
+            profit,
+            fixed_retirement: data.fixed_retirement,
+            self_employed: true,
+            assessment: data.assessment,
+        },
+    )?;
+
+    return Ok(EurSummary {
+        revenue: data.revenue,
+        expenses,
+        profit,
+        tax_result: result.tax_result,
+    });
+}
+
+#[cfg(all(test, feature = "year-2025"))]
+mod tests {
+    use super::*;
+    use crate::config::create as create_config;
+
+    #[test]
+    fn test_summary() {
+        let config = create_config(2025).unwrap();
+        let data = EurData {
+            revenue: 90000,
+            expenses: vec![
+                (ExpenseCategory::Travel, 1200),
+                (ExpenseCategory::Rooms, 6000),
+                (ExpenseCategory::Travel, 800),
+                (ExpenseCategory::Other, 0),
+            ],
+            fixed_retirement: None,
+            assessment: Assessment::Single,
+        };
+
+        let summary = calculate(&config, &data).unwrap();
+        assert_eq!(
+            summary.expenses,
+            vec![
+                (ExpenseCategory::Rooms, 6000),
+                (ExpenseCategory::Travel, 2000)
+            ]
+        );
+        assert_eq!(summary.get_total_expenses(), 8000);
+        assert_eq!(summary.profit, 82000);
+        assert_eq!(
+            summary.tax_result.net_income as i64,
+            82000
+                - summary.tax_result.social_security_taxes as i64
+                - summary.tax_result.income_taxes as i64
+        );
+    }
+
+    #[test]
+    fn test_summary_with_loss() {
+        let config = create_config(2025).unwrap();
+        let data = EurData {
+            revenue: 10000,
+            expenses: vec![(ExpenseCategory::Depreciation, 15000)],
+            fixed_retirement: None,
+            assessment: Assessment::Single,
+        };
+
+        let summary = calculate(&config, &data).unwrap();
+        assert_eq!(summary.profit, -5000);
+        assert_eq!(summary.tax_result.income_taxes, 0);
+        assert!(summary.tax_result.social_security_taxes > 0);
+    }
+}
@@ -0,0 +1,107 @@
+//! Flat-rate operating expenses for specific professions (Betriebsausgabenpauschale).
+//!
+//! Instead of itemizing the operating expenses, some self-employed professions can deduct a flat
+//! rate of their income, limited by a yearly maximum (H 18.2 EStH). Childminders can deduct a fixed
+//! amount per child and month of full-time care instead.
+
+use crate::config::Config;
+use crate::error::Error;
+use crate::numeric::{Number, Numeric};
+use crate::{TaxData, TaxResult};
+
+/// Share \[0,1\] of the income for a full-time writer or journalist.
+const WRITER_RATE: f32 = 0.3;
+
+/// Yearly maximum of the flat rate for a full-time writer or journalist.
+const WRITER_LIMIT: u32 = 3600;
+
+/// Share \[0,1\] of the income for scientific, artistic or writing side activities (e.g., lecturers).
+const SIDE_ACTIVITY_RATE: f32 = 0.25;
+
+/// Yearly maximum of the flat rate for scientific, artistic or writing side activities.
+const SIDE_ACTIVITY_LIMIT: u32 = 900;
+
+/// Monthly amount per child in full-time care of a childminder.
+const CHILDMINDER_AMOUNT: u32 = 400;
+
+/// Professions with flat-rate operating expenses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlatRateExpenses {
+    /// Full-time writers and journalists (hauptberufliche schriftstellerische oder journalistische
+    /// Tätigkeit).
+    Writer,
+    /// Scientific, artistic or writing side activities, including lecturing and examining
+    /// (Nebentätigkeit).
+    SideActivity,
+    /// Childminders (Kindertagespflege) with the given sum of months of full-time care over all
+    /// children (e.g., 24 for two children that are cared for the whole year).
+    Childminder(u32),
+}
+
+impl FlatRateExpenses {
+    /// Returns the flat-rate operating expenses for the given yearly income.
+    pub fn get_expenses(&self, income: u32) -> u32 {
+        let (rate, limit) = match self {
+            FlatRateExpenses::Writer => (WRITER_RATE, WRITER_LIMIT),
+            FlatRateExpenses::SideActivity => (SIDE_ACTIVITY_RATE, SIDE_ACTIVITY_LIMIT),
+            FlatRateExpenses::Childminder(care_months) => {
+                // the fixed amounts cannot lead to a loss
+                return care_months.saturating_mul(CHILDMINDER_AMOUNT).min(income);
+            }
+        };
+
+        return (Number::from_u32(income) * Number::from_f32(rate))
+            .to_u32()
+            .min(limit);
+    }
+}
+
+/// Calculates social security taxes and income taxes with the flat-rate operating expenses of the
+/// given profession instead of the itemized expenses of the tax data.
+///
+/// The income of the tax data is the income of the profession before any operating expenses.
+pub fn calculate(
+    config: &Config,
+    tax_data: &TaxData,
+    flat_rate: FlatRateExpenses,
+) -> Result<TaxResult, Error> {
+    let mut flat_rate_tax_data = tax_data.clone();
+    flat_rate_tax_data.expenses = flat_rate.get_expenses(tax_data.income);
+
+    return crate::calculate(config, &flat_rate_tax_data);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "year-2025")]
+    use crate::config::create as create_config;
+
+    #[test]
+    fn test_flat_rates_with_limits() {
+        assert_eq!(FlatRateExpenses::Writer.get_expenses(10000), 3000);
+        assert_eq!(FlatRateExpenses::Writer.get_expenses(50000), 3600);
+        assert_eq!(FlatRateExpenses::SideActivity.get_expenses(2000), 500);
+        assert_eq!(FlatRateExpenses::SideActivity.get_expenses(8000), 900);
+        assert_eq!(FlatRateExpenses::Childminder(24).get_expenses(30000), 9600);
+        assert_eq!(FlatRateExpenses::Childminder(24).get_expenses(5000), 5000);
+    }
+
+    #[test]
+    #[cfg(feature = "year-2025")]
+    fn test_calculation_with_flat_rate() {
+        let config = create_config(2025).unwrap();
+        let mut tax_data = TaxData::new(40000)
+            .with_expenses(1000)
+            .with_fixed_retirement(Some(0))
+            .with_self_employed(true);
+
+        let result = calculate(&config, &tax_data, FlatRateExpenses::Writer).unwrap();
+
+        tax_data.expenses = 3600;
+        let expected = crate::calculate(&config, &tax_data).unwrap();
+
+        assert_eq!(result.net_income, expected.net_income);
+        assert_eq!(result.income_taxes, expected.income_taxes);
+    }
+}
@@ -0,0 +1,203 @@
+//! Years with a switch from an employment to a self-employment (e.g., the founding year).
+//!
+//! The social security contributions are calculated separately for both periods, with the maximum
+//! incomes (and the minimum income of the self-employed health insurance) applied proportionally to
+//! the months of each period. The income taxes are calculated once for the whole year on the sum of
+//! both incomes.
+
+use crate::config::Config;
+use crate::error::Error;
+use crate::social_security::{self, SocialSecurityData};
+use crate::{Assessment, TaxResult, income_tax};
+
+/// Input data struct for a year with a switch from an employment to a self-employment.
+#[derive(Clone)]
+pub struct FoundingYearData {
+    /// The number of months \[1,11\] of the employment at the beginning of the year.
+    pub employed_months: u32,
+
+    /// The gross salary of the employment period.
+    pub salary: u32,
+
+    /// The income of the self-employment period.
+    pub self_employed_income: u32,
+
+    /// The tax-deductible expenses of the whole year.
+    pub expenses: u32,
+
+    /// Optional value of a fixed monthly retirement insurance rate in the self-employment period.
+    pub fixed_retirement: Option<u32>,
+
+    /// The type of the income tax assessment.
+    pub assessment: Assessment,
+
+    /// Whether the person is a member of a church and pays the church tax.
+    pub church_member: bool,
+}
+
+/// Result struct of the calculation for a year with a switch to a self-employment.
+pub struct FoundingYearResult {
+    /// The social security taxes of the employment period.
+    pub employed_social_security_taxes: u32,
+
+    /// The social security taxes of the self-employment period.
+    pub self_employed_social_security_taxes: u32,
+
+    /// The combined result of the whole year.
+    pub tax_result: TaxResult,
+}
+
+/// Calculates the social security taxes of both periods and the income taxes of the whole year.
+pub fn calculate(config: &Config, data: &FoundingYearData) -> Result<FoundingYearResult, Error> {
+    if data.employed_months == 0 || data.employed_months >= 12 {
+        return Err(Error::InvalidEmploymentMonths);
+    }
+
+    let employed_social_security_taxes = calculate_period(
+        config,
+        data.salary,
+        data.employed_months,
+        &SocialSecurityData {
+            income: 0,
+            fixed_retirement: None,
+            self_employed: false,
+        },
+    )?;
+    let self_employed_social_security_taxes = calculate_period(
+        config,
+        data.self_employed_income,
+        12 - data.employed_months,
+        &SocialSecurityData {
+            income: 0,
+            fixed_retirement: data.fixed_retirement,
+            self_employed: true,
+        },
+    )?;
+
+    let income = data.salary as i64 + data.self_employed_income as i64;
+    if income - data.expenses as i64 > i32::MAX as i64 {
+        return Err(Error::input_too_large());
+    }
+
+    let social_security_taxes =
+        employed_social_security_taxes + self_employed_social_security_taxes;
+    let taxable_income =
+        (income - data.expenses as i64 - social_security_taxes as i64).max(0) as u32;
+    let (income_taxes, church_tax) = income_tax::calculate_separate_church_tax(
+        &config.income_tax,
+        taxable_income,
+        data.assessment.is_splitting(),
+        data.church_member,
+    );
+
+    return Ok(FoundingYearResult {
+        employed_social_security_taxes,
+        self_employed_social_security_taxes,
+        tax_result: TaxResult {
+            gross_income: income as i32,
+            net_income: (income
+                - data.expenses as i64
+                - social_security_taxes as i64
+                - income_taxes as i64
+                - church_tax as i64) as i32,
+            social_security_taxes,
+            income_taxes,
+            church_tax,
+        },
+    });
+}
+
+/// Calculates the social security taxes of a period with the given income and number of months.
+fn calculate_period(
+    config: &Config,
+    income: u32,
+    months: u32,
+    data: &SocialSecurityData,
+) -> Result<u32, Error> {
+    return Ok(social_security::calculate_for_months(
+        &config.health_insurance,
+        &config.retirement_insurance,
+        &config.unemployment_insurance,
+        &SocialSecurityData {
+            income,
+            ..data.clone()
+        },
+        months,
+    )?
+    .total);
+}
+
+#[cfg(all(test, feature = "year-2025"))]
+mod tests {
+    use super::*;
+    use crate::config::create as create_config;
+
+    fn create_data(salary: u32, self_employed_income: u32) -> FoundingYearData {
+        return FoundingYearData {
+            employed_months: 6,
+            salary,
+            self_employed_income,
+            expenses: 0,
+            fixed_retirement: Some(0),
+            assessment: Assessment::Single,
+            church_member: false,
+        };
+    }
+
+    #[test]
+    fn test_proportional_ceilings() {
+        let config = create_config(2025).unwrap();
+
+        // the salary above the half-year ceilings is free of social security
+        let result = calculate(&config, &create_data(60000, 0)).unwrap();
+        let full_year = crate::calculate(&config, &crate::TaxData::new(120000)).unwrap();
+        assert!(
+            result
+                .employed_social_security_taxes
+                .abs_diff(full_year.social_security_taxes / 2)
+                <= 1
+        );
+
+        // the self-employed person pays at least the contributions on the minimum income
+        assert!(result.self_employed_social_security_taxes > 0);
+    }
+
+    #[test]
+    fn test_combined_income_taxes() {
+        let config = create_config(2025).unwrap();
+
+        let result = calculate(&config, &create_data(30000, 30000)).unwrap();
+        assert_eq!(result.tax_result.gross_income, 60000);
+        assert_eq!(
+            result.tax_result.social_security_taxes,
+            result.employed_social_security_taxes + result.self_employed_social_security_taxes
+        );
+        assert_eq!(
+            result.tax_result.net_income,
+            60000
+                - result.tax_result.social_security_taxes as i32
+                - result.tax_result.income_taxes as i32
+        );
+
+        // the church tax is part of the net income, but not of the income taxes
+        let mut data = create_data(30000, 30000);
+        data.church_member = true;
+        let church_result = calculate(&config, &data).unwrap();
+        assert!(church_result.tax_result.church_tax > 0);
+        assert_eq!(
+            church_result.tax_result.income_taxes,
+            result.tax_result.income_taxes
+        );
+        assert_eq!(
+            church_result.tax_result.net_income,
+            result.tax_result.net_income - church_result.tax_result.church_tax as i32
+        );
+
+        let mut data = create_data(30000, 30000);
+        data.employed_months = 12;
+        assert_eq!(
+            calculate(&config, &data).err(),
+            Some(Error::InvalidEmploymentMonths)
+        );
+    }
+}
@@ -0,0 +1,167 @@
+//! Net income of freelancers from the invoiced revenue (e.g., day rate times days).
+//!
+//! With the regular taxation, the value added tax is charged on the invoices and the value added
+//! tax of the operating expenses is deducted (Vorsteuerabzug). The difference is paid to the
+//! finance office and is neither income nor expense for the profit. Small businesses
+//! (Kleinunternehmer, § 19 UStG) do not charge any value added tax, but cannot deduct the value added
+//! tax of their expenses either.
+//!
+//! The resulting profit is then used for the calculation of the social security taxes and the income
+//! taxes of a self-employed person.
+
+use crate::config::Config;
+use crate::error::Error;
+use crate::loss::{self, ProfitData};
+use crate::numeric::{Number, Numeric};
+use crate::{Assessment, TaxResult};
+
+/// Taxation of the revenue with value added tax.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VatScheme {
+    /// Regular taxation with value added tax on the invoices.
+    Regular,
+    /// Small business regulation without value added tax, with the revenue of the previous year.
+    SmallBusiness(u32),
+}
+
+/// Input data struct for the calculation of a freelancer.
+#[derive(Clone)]
+pub struct FreelancerData {
+    /// The invoiced rate per day (without value added tax).
+    pub day_rate: u32,
+
+    /// The number of invoiced days of the year.
+    pub days: u32,
+
+    /// The paid operating expenses of the year (including value added tax).
+    pub expenses: u32,
+
+    /// The value added tax that is contained in the operating expenses.
+    pub input_vat: u32,
+
+    /// The taxation of the revenue with value added tax.
+    pub vat_scheme: VatScheme,
+
+    /// Optional value of a fixed monthly retirement insurance rate.
+    pub fixed_retirement: Option<u32>,
+
+    /// The type of the income tax assessment.
+    pub assessment: Assessment,
+}
+
+/// Result struct of the calculation of a freelancer.
+pub struct FreelancerResult {
+    /// The revenue of the year (without value added tax).
+    pub revenue: u32,
+
+    /// The value added tax that is charged on the invoices.
+    pub output_vat: u32,
+
+    /// The value added tax that is paid to the finance office (after deducting the input value
+    /// added tax, negative for a refund).
+    pub vat_payment: i64,
+
+    /// The profit of the year (revenue minus operating expenses without value added tax).
+    pub profit: i64,
+
+    /// The result of the tax calculation for the profit.
+    pub tax_result: TaxResult,
+}
+
+/// Calculates the profit and from that the net income of a freelancer.
+pub fn calculate(config: &Config, data: &FreelancerData) -> Result<FreelancerResult, Error> {
+    if data.input_vat > data.expenses {
+        return Err(Error::InvalidValueAddedTax);
+    }
+
+    let revenue = data
+        .day_rate
+        .checked_mul(data.days)
+        .ok_or(Error::input_too_large_unsigned())?;
+
+    let (output_vat, operating_expenses) = match data.vat_scheme {
+        VatScheme::Regular => (
+            (Number::from_u32(revenue) * Number::from_f32(config.vat.rate)).to_u32(),
+            data.expenses - data.input_vat,
+        ),
+        VatScheme::SmallBusiness(previous_revenue) => {
+            if previous_revenue > config.vat.small_business_previous_limit
+                || revenue > config.vat.small_business_current_limit
+            {
+                return Err(Error::SmallBusinessLimitExceeded);
+            }
+            (0, data.expenses)
+        }
+    };
+    let vat_payment = match data.vat_scheme {
+        VatScheme::Regular => output_vat as i64 - data.input_vat as i64,
+        VatScheme::SmallBusiness(_) => 0,
+    };
+
+    let profit = revenue as i64 - operating_expenses as i64;
+    let result = loss::calculate(
+        config,
+        &ProfitData {
+            profit,
+            fixed_retirement: data.fixed_retirement,
+            self_employed: true,
+            assessment: data.assessment,
+        },
+    )?;
+
+    return Ok(FreelancerResult {
+        revenue,
+        output_vat,
+        vat_payment,
+        profit,
+        tax_result: result.tax_result,
+    });
+}
+
+#[cfg(all(test, feature = "year-2025"))]
+mod tests {
+    use super::*;
+    use crate::config::create as create_config;
+
+    fn create_data(vat_scheme: VatScheme) -> FreelancerData {
+        return FreelancerData {
+            day_rate: 800,
+            days: 100,
+            expenses: 11900,
+            input_vat: 1900,
+            vat_scheme,
+            fixed_retirement: Some(0),
+            assessment: Assessment::Single,
+        };
+    }
+
+    #[test]
+    fn test_regular_taxation() {
+        let config = create_config(2025).unwrap();
+
+        let result = calculate(&config, &create_data(VatScheme::Regular)).unwrap();
+        assert_eq!(result.revenue, 80000);
+
+        // the floating point numbers may truncate the value added tax of the f32 rate to 15199
+        assert!(result.output_vat.abs_diff(15200) <= 1);
+        assert_eq!(result.vat_payment, result.output_vat as i64 - 1900);
+        assert_eq!(result.profit, 70000);
+        assert_eq!(result.tax_result.gross_income, 70000);
+    }
+
+    #[test]
+    fn test_small_business() {
+        let config = create_config(2025).unwrap();
+
+        let result = calculate(&config, &create_data(VatScheme::SmallBusiness(20000))).unwrap();
+        assert_eq!(result.output_vat, 0);
+        assert_eq!(result.vat_payment, 0);
+        assert_eq!(result.profit, 68100);
+
+        // the revenue of the previous year exceeds the limit
+        assert_eq!(
+            calculate(&config, &create_data(VatScheme::SmallBusiness(30000))).err(),
+            Some(Error::SmallBusinessLimitExceeded)
+        );
+    }
+}
@@ -0,0 +1,115 @@
+//! Search for the gross income at which a goal on the tax result is reached (goal seek).
+//!
+//! The search bisects the range of gross incomes, so that it needs only a few calculations even for
+//! large ranges. It expects the goal to be monotonic in the gross income: once reached, it stays
+//! reached for all larger incomes (e.g., a net income above a limit or a net ratio below a limit):
+//!
+//! ```
+//! let config = net_income_germany::config::create(net_income_germany::config::latest_year()).unwrap();
+//! let tax_data = net_income_germany::TaxData::new(0);
+//!
+//! // the smallest gross income at which the taxes exceed 20000
+//! let result = net_income_germany::goal_seek::seek(&config, &tax_data, 1000000, |result| {
+//!     result.social_security_taxes + result.income_taxes > 20000
+//! })
+//! .unwrap();
+//! println!("Gross income: {}", result.gross_income);
+//! ```
+
+use crate::config::Config;
+use crate::error::Error;
+use crate::{TaxData, TaxResult, calculate};
+
+/// The largest gross income that is considered by [`find_net_ratio`].
+pub const MAX_INCOME: u32 = 10000000;
+
+/// Returns the result of the smallest gross income between the income of the given tax data and
+/// the given maximum income for which the given goal is reached.
+///
+/// Returns an error if the goal is not reached at the maximum income. For goals that are not
+/// monotonic in the gross income, any of the incomes at which the goal changes might be found.
+pub fn seek(
+    config: &Config,
+    tax_data: &TaxData,
+    max_income: u32,
+    is_reached: impl Fn(&TaxResult) -> bool,
+) -> Result<TaxResult, Error> {
+    let mut tax_data = tax_data.clone();
+    let mut lower = tax_data.income;
+    let mut upper = max_income.max(lower);
+
+    tax_data.income = upper;
+    let mut result = calculate(config, &tax_data)?;
+    if !is_reached(&result) {
+        return Err(Error::GoalNotReached);
+    }
+
+    while lower < upper {
+        tax_data.income = lower + (upper - lower) / 2;
+
+        let candidate = calculate(config, &tax_data)?;
+        if is_reached(&candidate) {
+            upper = tax_data.income;
+            result = candidate;
+        } else {
+            lower = tax_data.income + 1;
+        }
+    }
+
+    return Ok(result);
+}
+
+/// Returns the result of the smallest gross income (up to [`MAX_INCOME`]) at which the net ratio
+/// (the share \[0,1\] of the gross income that remains as net income) falls to the given net ratio.
+pub fn find_net_ratio(
+    config: &Config,
+    tax_data: &TaxData,
+    net_ratio: f32,
+) -> Result<TaxResult, Error> {
+    return seek(config, tax_data, MAX_INCOME, |result| {
+        1.0 - result.get_tax_ratio() <= net_ratio
+    });
+}
+
+#[cfg(all(test, feature = "year-2025"))]
+mod tests {
+    use super::*;
+    use crate::config::create as create_config;
+
+    #[test]
+    fn test_seek_smallest_income() {
+        let config = create_config(2025).unwrap();
+        let tax_data = TaxData::new(10000);
+
+        let result = seek(&config, &tax_data, 200000, |result| {
+            result.net_income >= 40000
+        })
+        .unwrap();
+        assert!(result.net_income >= 40000);
+
+        let mut below = tax_data.clone();
+        below.income = result.gross_income as u32 - 1;
+        assert!(calculate(&config, &below).unwrap().net_income < 40000);
+
+        assert_eq!(
+            seek(&config, &tax_data, 20000, |result| result.net_income
+                >= 40000)
+            .err(),
+            Some(Error::GoalNotReached)
+        );
+    }
+
+    #[test]
+    fn test_find_net_ratio() {
+        let config = create_config(2025).unwrap();
+
+        let result = find_net_ratio(&config, &TaxData::new(0), 0.65).unwrap();
+        assert!(1.0 - result.get_tax_ratio() <= 0.65);
+        assert!((45000..50000).contains(&result.gross_income));
+
+        assert_eq!(
+            find_net_ratio(&config, &TaxData::new(0), 0.1).err(),
+            Some(Error::GoalNotReached)
+        );
+    }
+}
@@ -0,0 +1,354 @@
+//! Private health insurance and fixed health insurance contributions.
+//!
+//! Instead of the percentage of the income for the statutory health and nursing care insurance, a
+//! privately insured person pays a fixed monthly premium (private Kranken- und Pflegeversicherung).
+//! Employers pay half of the premium of an employee as subsidy, but at most half of the maximum
+//! contribution of the statutory insurances (Arbeitgeberzuschuss, § 257 SGB V and § 61 SGB XI).
+//! Self-employed persons pay the full premium.
+//!
+//! Employees with an income above the compulsory insurance limit (Versicherungspflichtgrenze, see
+//! [`HealthInsuranceConfig::is_above_jaeg`]) can choose a private insurance or can stay voluntarily
+//! insured in the statutory insurance. Voluntarily insured employees pay the full contributions to
+//! the insurance themselves, on all their income up to the maximum income (including other income
+//! like rents or capital income, § 240 SGB V). The employer subsidy is only calculated on the wage,
+//! as half of the contributions that the employer would pay for a compulsorily insured employee
+//! (§ 257 Abs. 1 SGB V and § 61 Abs. 1 SGB XI).
+//!
+//! The contributions to the health insurance are deducted from the taxable income like the
+//! statutory contributions.
+
+use crate::config::{Config, HealthInsuranceConfig};
+use crate::error::Error;
+use crate::numeric::{Number, Numeric};
+use crate::observer::{CalculationObserver, Insurance, InsuranceFilter};
+use crate::pipeline::{CalculationState, Pipeline, SocialSecurityStep, Stage, TaxStep};
+use crate::social_security::{self, SocialSecurityData};
+use crate::{TaxData, TaxResult};
+
+/// Type of the health and nursing care insurance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HealthInsurance {
+    /// Statutory insurance with contributions as percentage of the income.
+    Statutory,
+    /// Private insurance with the given monthly premium. For employees, the employer subsidy is
+    /// deducted from the premium. Employees need an income above the compulsory insurance limit.
+    Private(u32),
+    /// Fixed monthly contribution that is paid by the insured person (after any subsidy).
+    Fixed(u32),
+    /// Voluntary statutory insurance with the given yearly other income (e.g., rents or capital
+    /// income) that is subject to contributions in addition to the income.
+    Voluntary(u32),
+}
+
+/// Yearly contributions of an employee that is voluntarily insured in the statutory insurance.
+pub struct VoluntaryContributions {
+    /// The contributions to the health and nursing care insurance on the wage and the other income.
+    pub contributions: u32,
+
+    /// The subsidy of the employer on the contributions for the wage.
+    pub employer_subsidy: u32,
+}
+
+impl VoluntaryContributions {
+    /// Returns the contributions that are paid by the employee after the employer subsidy.
+    pub fn get_own_contributions(&self) -> u32 {
+        return self.contributions.saturating_sub(self.employer_subsidy);
+    }
+}
+
+/// Calculates the social security taxes with the given health insurance and adds them to the
+/// deductions. Replaces the [`SocialSecurityStep`] of the calculation pipeline.
+pub struct HealthInsuranceStep(pub HealthInsurance);
+
+impl TaxStep for HealthInsuranceStep {
+    fn apply(
+        &self,
+        state: &mut CalculationState,
+        observer: &mut dyn CalculationObserver,
+    ) -> Result<(), Error> {
+        // the yearly health and nursing care insurance contributions that are actually paid (a
+        // private premium or a fixed contribution includes the nursing care insurance)
+        let (health_insurance, nursing_insurance) = match self.0 {
+            HealthInsurance::Statutory => return SocialSecurityStep.apply(state, observer),
+            HealthInsurance::Private(premium) => match state.tax_data.self_employed {
+                true => (premium * 12, 0),
+                // employees below the compulsory insurance limit need to stay in the statutory
+                // insurance
+                false
+                    if !state
+                        .config
+                        .health_insurance
+                        .is_above_jaeg(state.tax_data.income) =>
+                {
+                    return Err(Error::PrivateHealthInsuranceBelowLimit);
+                }
+                false => (
+                    (premium * 12).saturating_sub(calculate_employer_subsidy(
+                        &state.config.health_insurance,
+                        premium,
+                    )),
+                    0,
+                ),
+            },
+            HealthInsurance::Fixed(amount) => (amount * 12, 0),
+            HealthInsurance::Voluntary(other_income) => {
+                let income = state.tax_data.income.saturating_add(other_income);
+                match state.tax_data.self_employed {
+                    true => {
+                        let result = social_security::calculate(
+                            &state.config.health_insurance,
+                            &state.config.retirement_insurance,
+                            &state.config.unemployment_insurance,
+                            &SocialSecurityData {
+                                income,
+                                ..state.tax_data.into()
+                            },
+                        )?;
+                        (result.health_insurance, result.nursing_insurance)
+                    }
+                    false => (
+                        calculate_voluntary_contributions(
+                            &state.config.health_insurance,
+                            state.tax_data.income,
+                            other_income,
+                        )
+                        .get_own_contributions(),
+                        0,
+                    ),
+                }
+            }
+        };
+        observer.on_insurance(Insurance::Health, 0.0, 0.0, health_insurance as f32);
+        observer.on_insurance(Insurance::Nursing, 0.0, 0.0, nursing_insurance as f32);
+
+        // only the retirement and unemployment insurance of the statutory calculation apply
+        let result = social_security::calculate_observed(
+            &state.config.health_insurance,
+            &state.config.retirement_insurance,
+            &state.config.unemployment_insurance,
+            &state.tax_data.into(),
+            &mut InsuranceFilter {
+                observer,
+                insurances: &[Insurance::Retirement, Insurance::Unemployment],
+            },
+        )?;
+
+        state.social_security_taxes = health_insurance
+            + nursing_insurance
+            + result.retirement_insurance
+            + result.unemployment_insurance;
+        state.deductions = state.deductions.saturating_add(state.social_security_taxes);
+
+        return Ok(());
+    }
+
+    fn stage(&self) -> Option<Stage> {
+        return Some(Stage::SocialSecurity);
+    }
+}
+
+/// Calculates the yearly employer subsidy for the given monthly premium of a private health
+/// insurance of an employee.
+pub fn calculate_employer_subsidy(config: &HealthInsuranceConfig, premium: u32) -> u32 {
+    let max_subsidy = Number::from_f32(config.max_income)
+        * (Number::from_f32(config.premium_general)
+            + Number::from_f32(config.premium_additional)
+            + Number::from_f32(config.premium_nursing))
+        / Number::from_u32(2);
+
+    return (Numeric::min(Number::from_u32(premium) / Number::from_u32(2), max_subsidy)
+        * Number::from_u32(12))
+    .to_u32();
+}
+
+/// Calculates the yearly contributions of an employee with the given yearly wage and other income,
+/// who is voluntarily insured in the statutory health insurance.
+pub fn calculate_voluntary_contributions(
+    config: &HealthInsuranceConfig,
+    wage: u32,
+    other_income: u32,
+) -> VoluntaryContributions {
+    let max_income = Number::from_f32(config.max_income) * Number::from_u32(12);
+    let assessed_income = Numeric::min(
+        Number::from_u32(wage.saturating_add(other_income)),
+        max_income,
+    );
+    let assessed_wage = Numeric::min(Number::from_u32(wage), max_income);
+
+    let premium = Number::from_f32(config.premium_general)
+        + Number::from_f32(config.premium_additional)
+        + Number::from_f32(config.premium_nursing)
+        + Number::from_f32(config.premium_nursing_additional);
+
+    // the additional nursing premium for childless persons is not subsidized
+    let employer_premium = (Number::from_f32(config.premium_general)
+        + Number::from_f32(config.premium_additional)
+        + Number::from_f32(config.premium_nursing))
+        / Number::from_u32(2)
+        - Number::from_f32(config.premium_nursing_employee_surcharge);
+
+    return VoluntaryContributions {
+        contributions: (assessed_income * premium).to_u32(),
+        employer_subsidy: (assessed_wage * employer_premium).to_u32(),
+    };
+}
+
+/// Calculates social security taxes and income taxes with the given health insurance.
+pub fn calculate(
+    config: &Config,
+    tax_data: &TaxData,
+    health_insurance: HealthInsurance,
+) -> Result<TaxResult, Error> {
+    if tax_data.expenses < tax_data.income && tax_data.income - tax_data.expenses > i32::MAX as u32
+    {
+        return Err(Error::input_too_large());
+    }
+
+    let mut pipeline = Pipeline::default();
+    pipeline.replace(
+        Stage::SocialSecurity,
+        Box::new(HealthInsuranceStep(health_insurance)),
+    );
+
+    return pipeline.calculate(config, tax_data);
+}
+
+#[cfg(all(test, feature = "year-2025"))]
+mod tests {
+    use super::*;
+    use crate::config::create as create_config;
+
+    #[test]
+    fn test_statutory_health_insurance() {
+        let config = create_config(2025).unwrap();
+        let tax_data = TaxData::new(60000);
+
+        let result = calculate(&config, &tax_data, HealthInsurance::Statutory).unwrap();
+        let expected = crate::calculate(&config, &tax_data).unwrap();
+
+        assert_eq!(result.net_income, expected.net_income);
+    }
+
+    #[test]
+    fn test_private_health_insurance() {
+        let config = create_config(2025).unwrap();
+
+        // the employer pays half of the premium below the maximum subsidy
+        let employee =
+            calculate(&config, &TaxData::new(80000), HealthInsurance::Private(600)).unwrap();
+        let fixed = calculate(&config, &TaxData::new(80000), HealthInsurance::Fixed(300)).unwrap();
+        assert_eq!(employee.net_income, fixed.net_income);
+
+        // the self-employed person pays the full premium
+        let self_employed = calculate(
+            &config,
+            &TaxData::new(80000).with_self_employed(true),
+            HealthInsurance::Private(600),
+        )
+        .unwrap();
+        let fixed = calculate(
+            &config,
+            &TaxData::new(80000).with_self_employed(true),
+            HealthInsurance::Fixed(600),
+        )
+        .unwrap();
+        assert_eq!(self_employed.net_income, fixed.net_income);
+    }
+
+    #[test]
+    fn test_voluntary_health_insurance() {
+        let config = create_config(2025).unwrap();
+
+        // without other income, the own contributions equal the ones of a compulsorily insured
+        // employee
+        let tax_data = TaxData::new(80000);
+        let voluntary = calculate(&config, &tax_data, HealthInsurance::Voluntary(0)).unwrap();
+        let statutory = calculate(&config, &tax_data, HealthInsurance::Statutory).unwrap();
+        assert!(
+            voluntary
+                .social_security_taxes
+                .abs_diff(statutory.social_security_taxes)
+                <= 1
+        );
+
+        // the other income is subject to contributions up to the maximum income, but not subsidized
+        let contributions =
+            calculate_voluntary_contributions(&config.health_insurance, 50000, 10000);
+        let without_other_income =
+            calculate_voluntary_contributions(&config.health_insurance, 50000, 0);
+        assert!(contributions.contributions > without_other_income.contributions);
+        assert_eq!(
+            contributions.employer_subsidy,
+            without_other_income.employer_subsidy
+        );
+
+        let above_maximum =
+            calculate_voluntary_contributions(&config.health_insurance, 80000, 10000);
+        assert_eq!(
+            above_maximum.contributions,
+            calculate_voluntary_contributions(&config.health_insurance, 80000, 0).contributions
+        );
+    }
+
+    #[test]
+    fn test_maximum_employer_subsidy() {
+        let config = create_config(2025).unwrap();
+
+        assert_eq!(
+            calculate_employer_subsidy(&config.health_insurance, 600),
+            3600
+        );
+        assert!(calculate_employer_subsidy(&config.health_insurance, 3000) < 18000);
+    }
+
+    #[test]
+    fn test_applied_premium_is_observed() {
+        struct Payments(Vec<(Insurance, f32)>);
+
+        impl CalculationObserver for Payments {
+            fn on_insurance(&mut self, insurance: Insurance, _: f32, _: f32, payment: f32) {
+                self.0.push((insurance, payment));
+            }
+        }
+
+        let config = create_config(2025).unwrap();
+        let mut pipeline = Pipeline::default();
+        pipeline.replace(
+            Stage::SocialSecurity,
+            Box::new(HealthInsuranceStep(HealthInsurance::Fixed(300))),
+        );
+
+        let mut payments = Payments(Vec::new());
+        pipeline
+            .calculate_with_observer(&config, &TaxData::new(80000), &mut payments)
+            .unwrap();
+
+        let statutory = crate::calculate(&config, &TaxData::new(80000)).unwrap();
+        assert_eq!(payments.0.len(), 4);
+        assert_eq!(payments.0[0], (Insurance::Health, 3600.0));
+        assert_eq!(payments.0[1], (Insurance::Nursing, 0.0));
+        assert_eq!(payments.0[2].0, Insurance::Retirement);
+        assert_eq!(payments.0[3].0, Insurance::Unemployment);
+        assert!(payments.0[2].1 + payments.0[3].1 < statutory.social_security_taxes as f32);
+    }
+
+    #[test]
+    fn test_private_health_insurance_below_limit() {
+        let config = create_config(2025).unwrap();
+
+        assert_eq!(
+            calculate(&config, &TaxData::new(60000), HealthInsurance::Private(600)).err(),
+            Some(Error::PrivateHealthInsuranceBelowLimit)
+        );
+
+        // self-employed persons can choose a private insurance with any income
+        assert!(
+            calculate(
+                &config,
+                &TaxData::new(30000).with_self_employed(true),
+                HealthInsurance::Private(600)
+            )
+            .is_ok()
+        );
+    }
+}
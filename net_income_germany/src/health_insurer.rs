@@ -0,0 +1,158 @@
+//! Statutory health insurance companies (Krankenkassen) with their additional premiums.
+//!
+//! Every health insurance company defines its own additional premium (Zusatzbeitrag) per year,
+//! while the default configuration applies the one of Techniker Krankenkasse. This module contains
+//! the largest health insurance companies, so that they can be selected by a short key (e.g., "tk")
+//! or by name and applied as preset on the configuration:
+//!
+//! ```
+//! let mut config = net_income_germany::config::create(net_income_germany::config::latest_year()).unwrap();
+//!
+//! let insurer = net_income_germany::health_insurer::find("barmer").unwrap();
+//! insurer.apply(&mut config).unwrap();
+//!
+//! assert_eq!(config.origin().preset.as_deref(), Some("Barmer"));
+//! ```
+
+use crate::config::Config;
+use crate::error::Error;
+
+/// Statutory health insurance company with its additional premiums.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HealthInsurer {
+    /// The short key of the health insurance company (e.g., "tk").
+    pub key: &'static str,
+
+    /// The name of the health insurance company.
+    pub name: &'static str,
+
+    /// The additional premiums \[0,1\] per year (Zusatzbeitrag).
+    pub premiums_additional: &'static [(u32, f32)],
+}
+
+/// The largest statutory health insurance companies with their additional premiums.
+pub const HEALTH_INSURERS: [HealthInsurer; 9] = [
+    HealthInsurer {
+        key: "tk",
+        name: "Techniker Krankenkasse",
+        premiums_additional: &[(2024, 0.012), (2025, 0.0245)],
+    },
+    HealthInsurer {
+        key: "barmer",
+        name: "Barmer",
+        premiums_additional: &[(2024, 0.015), (2025, 0.0329)],
+    },
+    HealthInsurer {
+        key: "dak",
+        name: "DAK-Gesundheit",
+        premiums_additional: &[(2024, 0.017), (2025, 0.028)],
+    },
+    HealthInsurer {
+        key: "aok-bayern",
+        name: "AOK Bayern",
+        premiums_additional: &[(2024, 0.0158), (2025, 0.0269)],
+    },
+    HealthInsurer {
+        key: "aok-bw",
+        name: "AOK Baden-Württemberg",
+        premiums_additional: &[(2024, 0.016), (2025, 0.026)],
+    },
+    HealthInsurer {
+        key: "aok-plus",
+        name: "AOK PLUS",
+        premiums_additional: &[(2024, 0.015), (2025, 0.031)],
+    },
+    HealthInsurer {
+        key: "ikk-classic",
+        name: "IKK classic",
+        premiums_additional: &[(2024, 0.017), (2025, 0.034)],
+    },
+    HealthInsurer {
+        key: "kkh",
+        name: "KKH Kaufmännische Krankenkasse",
+        premiums_additional: &[(2024, 0.0198), (2025, 0.0378)],
+    },
+    HealthInsurer {
+        key: "hkk",
+        name: "hkk Krankenkasse",
+        premiums_additional: &[(2024, 0.0098), (2025, 0.0219)],
+    },
+];
+
+/// Returns the health insurance company with the given key or name (ignoring the case), if it is
+/// contained in the list of known health insurance companies.
+pub fn find(key_or_name: &str) -> Option<&'static HealthInsurer> {
+    let key_or_name = key_or_name.trim().to_lowercase();
+
+    return HEALTH_INSURERS
+        .iter()
+        .find(|insurer| insurer.key == key_or_name || insurer.name.to_lowercase() == key_or_name);
+}
+
+impl HealthInsurer {
+    /// Returns the additional premium \[0,1\] of the health insurance company in the given year, if
+    /// it is known.
+    pub fn get_premium_additional(&self, year: u32) -> Option<f32> {
+        return self
+            .premiums_additional
+            .iter()
+            .find(|(premium_year, _)| *premium_year == year)
+            .map(|(_, premium)| *premium);
+    }
+
+    /// Applies the additional premium of the health insurance company in the year of the given
+    /// configuration and records the health insurance company as preset of the configuration.
+    pub fn apply(&self, config: &mut Config) -> Result<(), Error> {
+        config.health_insurance.premium_additional = self
+            .get_premium_additional(config.origin().year)
+            .ok_or(Error::UnsupportedInsurerYear)?;
+
+        config.record_modification("health_insurance.premium_additional");
+        config.set_preset(self.name);
+
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "year-2025")]
+    use crate::config::create as create_config;
+
+    #[test]
+    fn test_find_health_insurer() {
+        assert_eq!(find("tk").unwrap().name, "Techniker Krankenkasse");
+        assert_eq!(find(" AOK Bayern").unwrap().key, "aok-bayern");
+        assert!(find("unknown").is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "year-2025")]
+    fn test_apply_health_insurer() {
+        // the default configuration applies the additional premium of Techniker Krankenkasse
+        for year in crate::config::supported_years() {
+            let mut config = create_config(*year).unwrap();
+            let default_premium = config.health_insurance.premium_additional;
+
+            find("tk").unwrap().apply(&mut config).unwrap();
+            assert_eq!(config.health_insurance.premium_additional, default_premium);
+        }
+
+        let mut config = create_config(2025).unwrap();
+        find("aok-bayern").unwrap().apply(&mut config).unwrap();
+        assert_eq!(config.health_insurance.premium_additional, 0.0269);
+        assert_eq!(config.origin().preset.as_deref(), Some("AOK Bayern"));
+        assert_eq!(
+            config.origin().modified_fields,
+            vec!["health_insurance.premium_additional"]
+        );
+
+        let mut config = create_config(2025).unwrap();
+        config.origin.year = 2023;
+        assert_eq!(
+            find("tk").unwrap().apply(&mut config).err(),
+            Some(Error::UnsupportedInsurerYear)
+        );
+    }
+}
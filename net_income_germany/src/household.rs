@@ -0,0 +1,431 @@
+//! Households of jointly assessed couples with two incomes.
+//!
+//! The social security taxes are calculated separately for each spouse, while the income taxes are
+//! calculated on the sum of both taxable incomes with the splitting tariff. Because of the splitting
+//! tariff, the income taxes depend on the split of the income between the spouses only through the
+//! deducted social security taxes. Those are limited by the maximum income of each insurance per
+//! person and are not paid on the income of a spouse without any income.
+//!
+//! A [`Household`] combines the tax data of both spouses (including their types of employment) with
+//! the children and other benefits of the household, so that the disposable income of the whole
+//! household can be calculated at once (see [`calculate_household`]):
+//!
+//! ```
+//! use net_income_germany::TaxData;
+//! use net_income_germany::household::{self, Household};
+//!
+//! let config = net_income_germany::config::create(net_income_germany::config::latest_year()).unwrap();
+//! let household = Household::new(
+//!     TaxData::new(60000),
+//!     TaxData::new(30000).with_self_employed(true),
+//! )
+//! .with_children(2);
+//!
+//! let result = household::calculate_household(&config, &household).unwrap();
+//! println!("Disposable income: {}", result.get_disposable_income());
+//! ```
+
+use crate::config::Config;
+use crate::error::Error;
+use crate::{Assessment, TaxData, TaxResult, housing_benefit, income_tax};
+
+/// Input data struct of a household of a jointly assessed couple with its children.
+#[derive(Clone)]
+pub struct Household {
+    /// The tax data of the first spouse (the assessment is ignored).
+    pub first: TaxData,
+
+    /// The tax data of the second spouse (the assessment is ignored).
+    pub second: TaxData,
+
+    /// The number of children for which the couple receives the child benefit.
+    pub children: u32,
+
+    /// Other yearly tax-free benefits of the household (e.g., the housing benefit), which only add to
+    /// the disposable income.
+    pub benefits: u32,
+}
+
+impl Household {
+    /// Creates the input data of a household without children and benefits from the tax data of
+    /// both spouses.
+    pub fn new(first: TaxData, second: TaxData) -> Self {
+        return Household {
+            first,
+            second,
+            children: 0,
+            benefits: 0,
+        };
+    }
+
+    /// Sets the number of children for which the couple receives the child benefit.
+    pub fn with_children(mut self, children: u32) -> Self {
+        self.children = children;
+        return self;
+    }
+
+    /// Sets the other yearly tax-free benefits of the household.
+    pub fn with_benefits(mut self, benefits: u32) -> Self {
+        self.benefits = benefits;
+        return self;
+    }
+
+    /// Returns the number of members of the household (both spouses and the children).
+    pub fn get_size(&self) -> u32 {
+        return 2 + self.children;
+    }
+}
+
+/// Result struct of the tax calculation for a household of a jointly assessed couple.
+pub struct HouseholdResult {
+    /// The social security taxes of the first spouse.
+    pub first_social_security_taxes: u32,
+
+    /// The social security taxes of the second spouse.
+    pub second_social_security_taxes: u32,
+
+    /// The joint income taxes (including the solidarity surcharge) of the couple.
+    pub income_taxes: u32,
+
+    /// The church tax of the spouses that are members of a church.
+    pub church_tax: u32,
+
+    /// The sum of both gross incomes.
+    pub gross_income: i32,
+
+    /// The net income of the couple after deducting the expenses, social security taxes and income
+    /// taxes of both spouses.
+    pub net_income: i32,
+
+    /// The yearly child benefit (Kindergeld) that is paid to the couple.
+    pub child_benefit: u32,
+
+    /// The other yearly tax-free benefits of the household.
+    pub benefits: u32,
+}
+
+impl HouseholdResult {
+    /// Returns the social security taxes of both spouses together.
+    pub fn get_social_security_taxes(&self) -> u32 {
+        return self.first_social_security_taxes + self.second_social_security_taxes;
+    }
+
+    /// Returns the disposable income of the household, which is the net income including the child
+    /// benefit and the other benefits.
+    pub fn get_disposable_income(&self) -> i64 {
+        return self.net_income as i64 + self.child_benefit as i64 + self.benefits as i64;
+    }
+
+    /// Returns whether the household with the given number of members (including the children)
+    /// might be eligible for the housing benefit (see [`crate::housing_benefit`]).
+    pub fn is_possibly_eligible_for_housing_benefit(
+        &self,
+        config: &Config,
+        household_size: u32,
+    ) -> bool {
+        return housing_benefit::is_possibly_eligible(
+            config,
+            self.net_income as i64,
+            household_size,
+        );
+    }
+
+    /// Converts the household result into a tax result of the couple (e.g., for comparing it with
+    /// [`TaxResult::diff`]).
+    pub fn to_tax_result(&self) -> TaxResult {
+        return TaxResult {
+            gross_income: self.gross_income,
+            net_income: self.net_income,
+            social_security_taxes: self.get_social_security_taxes(),
+            income_taxes: self.income_taxes,
+            church_tax: self.church_tax,
+        };
+    }
+}
+
+/// Result of the household calculation for one split of the income between the spouses.
+pub struct HouseholdSplit {
+    /// The share of the household income that is earned by the first spouse \[0,1\].
+    pub first_share: f32,
+
+    /// The result of the household calculation for this split.
+    pub result: HouseholdResult,
+}
+
+/// Calculates the social security taxes of both spouses and the joint income taxes of the couple.
+///
+/// The assessment of the given tax data is ignored, as the couple is always assessed jointly.
+pub fn calculate(
+    config: &Config,
+    first: &TaxData,
+    second: &TaxData,
+) -> Result<HouseholdResult, Error> {
+    return calculate_with_child_benefit(config, first, second, 0);
+}
+
+/// Calculates the household like [`calculate`], but includes the child benefit for the given number
+/// of children in the result.
+///
+/// The child allowances are not considered for the income taxes, which is the case for most incomes
+/// (see [`crate::children`] for the comparison of the allowances with the child benefit).
+pub fn calculate_with_child_benefit(
+    config: &Config,
+    first: &TaxData,
+    second: &TaxData,
+    children: u32,
+) -> Result<HouseholdResult, Error> {
+    return calculate_household(
+        config,
+        &Household::new(first.clone(), second.clone()).with_children(children),
+    );
+}
+
+/// Calculates the social security taxes of both spouses, the joint income taxes of the couple and
+/// the child benefit and other benefits of the given household.
+///
+/// The child allowances are not considered like for [`calculate_with_child_benefit`].
+pub fn calculate_household(
+    config: &Config,
+    household: &Household,
+) -> Result<HouseholdResult, Error> {
+    let first = &household.first;
+    let second = &household.second;
+    let (first_social_security_taxes, first_taxable_income) =
+        crate::calculate_taxable_income(config, first)?;
+    let (second_social_security_taxes, second_taxable_income) =
+        crate::calculate_taxable_income(config, second)?;
+
+    let (income_taxes, church_tax) = income_tax::calculate_separate_church_tax(
+        &config.income_tax,
+        first_taxable_income.saturating_add(second_taxable_income),
+        true,
+        first.church_member || second.church_member,
+    );
+
+    // if only one of the spouses is a member of a church, the church tax applies on the share of the
+    // joint income taxes that is attributed to this spouse in proportion to the income taxes of the
+    // spouses on their own taxable incomes
+    let church_tax = match first.church_member == second.church_member {
+        true => church_tax,
+        false => {
+            let first_income_taxes =
+                income_tax::calculate_tariff(&config.income_tax, first_taxable_income, false);
+            let second_income_taxes =
+                income_tax::calculate_tariff(&config.income_tax, second_taxable_income, false);
+            let member_income_taxes = match first.church_member {
+                true => first_income_taxes,
+                false => second_income_taxes,
+            };
+
+            match first_income_taxes + second_income_taxes {
+                0 => 0,
+                total => (church_tax as u64 * member_income_taxes as u64 / total as u64) as u32,
+            }
+        }
+    };
+
+    let gross_income = first.income as i64 + second.income as i64;
+    if gross_income > i32::MAX as i64 {
+        return Err(Error::input_too_large());
+    }
+
+    return Ok(HouseholdResult {
+        first_social_security_taxes,
+        second_social_security_taxes,
+        income_taxes,
+        church_tax,
+        gross_income: gross_income as i32,
+        net_income: (gross_income
+            - first.expenses as i64
+            - second.expenses as i64
+            - first_social_security_taxes as i64
+            - second_social_security_taxes as i64
+            - income_taxes as i64
+            - church_tax as i64) as i32,
+        child_benefit: household.children * config.children.child_benefit * 12,
+        benefits: household.benefits,
+    });
+}
+
+/// Calculates the household for different splits of the income of the given tax data between the
+/// spouses (e.g., `&[1.0, 0.5]` for a single-earner and an equally split dual-earner household).
+///
+/// The expenses are split in the same shares as the income. All other values of the tax data apply
+/// to both spouses.
+pub fn compare_splits(
+    config: &Config,
+    tax_data: &TaxData,
+    first_shares: &[f32],
+) -> Result<Vec<HouseholdSplit>, Error> {
+    let mut splits = Vec::with_capacity(first_shares.len());
+
+    for &first_share in first_shares {
+        if !(0.0..=1.0).contains(&first_share) {
+            return Err(Error::InvalidIncomeShare);
+        }
+
+        let mut first = tax_data.clone();
+        first.assessment = Assessment::Joint;
+        first.income = (tax_data.income as f64 * first_share as f64).round() as u32;
+        first.expenses = (tax_data.expenses as f64 * first_share as f64).round() as u32;
+
+        let mut second = first.clone();
+        second.income = tax_data.income - first.income;
+        second.expenses = tax_data.expenses - first.expenses;
+
+        splits.push(HouseholdSplit {
+            first_share,
+            result: calculate(config, &first, &second)?,
+        });
+    }
+
+    return Ok(splits);
+}
+
+#[cfg(all(test, feature = "year-2025"))]
+mod tests {
+    use super::*;
+    use crate::config::create as create_config;
+
+    #[test]
+    fn test_single_earner_household() {
+        let config = create_config(2025).unwrap();
+
+        let result = calculate(&config, &TaxData::new(60000), &TaxData::new(0)).unwrap();
+        let expected = crate::calculate(
+            &config,
+            &TaxData::new(60000).with_assessment(Assessment::Joint),
+        )
+        .unwrap();
+
+        assert_eq!(result.second_social_security_taxes, 0);
+        assert_eq!(result.income_taxes, expected.income_taxes);
+        assert_eq!(result.net_income, expected.net_income);
+    }
+
+    #[test]
+    fn test_church_tax_of_spouses() {
+        let config = create_config(2025).unwrap();
+        let member = TaxData::new(60000)
+            .with_assessment(Assessment::Joint)
+            .with_church_member(true);
+
+        let both = calculate(&config, &member, &TaxData::new(0).with_church_member(true)).unwrap();
+        let expected = crate::calculate(&config, &member).unwrap();
+        assert!(both.church_tax > 0);
+        assert_eq!(both.church_tax, expected.church_tax);
+        assert_eq!(both.net_income, expected.net_income);
+
+        // the member earns all of the income, so all of the joint income taxes are attributed to them
+        let one = calculate(&config, &TaxData::new(0), &member).unwrap();
+        assert_eq!(one.church_tax, both.church_tax);
+        assert_eq!(one.income_taxes, both.income_taxes);
+
+        // the church tax of a non-earning member is zero
+        let other = calculate(
+            &config,
+            &TaxData::new(0).with_church_member(true),
+            &TaxData::new(60000),
+        )
+        .unwrap();
+        assert_eq!(other.church_tax, 0);
+
+        // for equal incomes, the half of the joint income taxes is attributed to the member
+        let equal = calculate(
+            &config,
+            &TaxData::new(30000).with_church_member(true),
+            &TaxData::new(30000),
+        )
+        .unwrap();
+        let equal_both = calculate(
+            &config,
+            &TaxData::new(30000).with_church_member(true),
+            &TaxData::new(30000).with_church_member(true),
+        )
+        .unwrap();
+        assert!(equal.church_tax.abs_diff(equal_both.church_tax / 2) <= 1);
+    }
+
+    #[test]
+    fn test_single_and_dual_earner_household() {
+        let config = create_config(2025).unwrap();
+
+        let splits = compare_splits(&config, &TaxData::new(160000), &[1.0, 0.5]).unwrap();
+        let single_earner = &splits[0].result;
+        let dual_earner = &splits[1].result;
+
+        // the dual-earner household pays more social security taxes, because the maximum incomes
+        // of the insurances apply per person
+        assert_eq!(single_earner.gross_income, dual_earner.gross_income);
+        assert!(
+            single_earner.get_social_security_taxes() < dual_earner.get_social_security_taxes()
+        );
+        assert!(single_earner.net_income > dual_earner.net_income);
+
+        let diff = single_earner
+            .to_tax_result()
+            .diff(&dual_earner.to_tax_result());
+        assert!(diff.social_security_taxes.value > 0);
+    }
+
+    #[test]
+    fn test_child_benefit() {
+        let config = create_config(2025).unwrap();
+
+        let result =
+            calculate_with_child_benefit(&config, &TaxData::new(50000), &TaxData::new(20000), 2)
+                .unwrap();
+        let without_children =
+            calculate(&config, &TaxData::new(50000), &TaxData::new(20000)).unwrap();
+
+        assert_eq!(result.child_benefit, 6120);
+        assert_eq!(result.net_income, without_children.net_income);
+        assert_eq!(
+            result.get_disposable_income(),
+            without_children.get_disposable_income() + 6120
+        );
+    }
+
+    #[test]
+    fn test_household() {
+        let config = create_config(2025).unwrap();
+        let household = Household::new(
+            TaxData::new(50000),
+            TaxData::new(20000).with_self_employed(true),
+        )
+        .with_children(2)
+        .with_benefits(1000);
+
+        let result = calculate_household(&config, &household).unwrap();
+        let employed_result =
+            calculate_with_child_benefit(&config, &TaxData::new(50000), &TaxData::new(20000), 2)
+                .unwrap();
+
+        // the self-employed spouse pays the social security taxes alone
+        assert!(result.second_social_security_taxes > employed_result.second_social_security_taxes);
+        assert_eq!(result.child_benefit, 6120);
+        assert_eq!(
+            result.get_disposable_income(),
+            result.net_income as i64 + 6120 + 1000
+        );
+        assert_eq!(household.get_size(), 4);
+    }
+
+    #[test]
+    fn test_housing_benefit_hint() {
+        let config = create_config(2025).unwrap();
+
+        let result = calculate(&config, &TaxData::new(30000), &TaxData::new(0)).unwrap();
+        assert!(result.is_possibly_eligible_for_housing_benefit(&config, 4));
+        assert!(!result.is_possibly_eligible_for_housing_benefit(&config, 1));
+    }
+
+    #[test]
+    fn test_invalid_share() {
+        let config = create_config(2025).unwrap();
+        assert_eq!(
+            compare_splits(&config, &TaxData::new(60000), &[1.5]).err(),
+            Some(Error::InvalidIncomeShare)
+        );
+    }
+}
@@ -0,0 +1,55 @@
+//! Rough check of the eligibility for the housing benefit (Wohngeld).
+//!
+//! Households with a low income can receive the housing benefit as subsidy for the rent. The exact
+//! entitlement depends on the rent, the rent level of the municipality and the household income
+//! according to the housing benefit law (§ 14 WoGG). This module only gives a hint, whether the net
+//! income of a household is within the rough range of the maximum incomes, so that a detailed check
+//! is worth it.
+
+use crate::config::Config;
+
+/// Returns the rough maximum monthly net income for the housing benefit of a household with the
+/// given number of members.
+pub fn get_income_limit(config: &Config, household_size: u32) -> u32 {
+    let limits = &config.housing_benefit.income_limits;
+
+    return match household_size {
+        0 => 0,
+        1..=5 => limits[household_size as usize - 1],
+        _ => {
+            limits[limits.len() - 1]
+                + (household_size - 5) * config.housing_benefit.additional_member_limit
+        }
+    };
+}
+
+/// Returns whether a household with the given yearly net income and number of members might be
+/// eligible for the housing benefit.
+pub fn is_possibly_eligible(config: &Config, yearly_net_income: i64, household_size: u32) -> bool {
+    return yearly_net_income < get_income_limit(config, household_size) as i64 * 12;
+}
+
+#[cfg(all(test, feature = "year-2025"))]
+mod tests {
+    use super::*;
+    use crate::config::create as create_config;
+
+    #[test]
+    fn test_income_limits() {
+        let config = create_config(2025).unwrap();
+
+        assert_eq!(get_income_limit(&config, 0), 0);
+        assert_eq!(get_income_limit(&config, 1), 1580);
+        assert_eq!(get_income_limit(&config, 5), 4120);
+        assert_eq!(get_income_limit(&config, 7), 5100);
+    }
+
+    #[test]
+    fn test_eligibility_hint() {
+        let config = create_config(2025).unwrap();
+
+        assert!(is_possibly_eligible(&config, 18000, 1));
+        assert!(!is_possibly_eligible(&config, 20000, 1));
+        assert!(is_possibly_eligible(&config, 20000, 2));
+    }
+}
@@ -1,81 +1,419 @@
-use crate::config::{IncomeTaxConfig, SolidaryAdditionConfig, TaxRange};
+//! Income tax calculation (Einkommensteuertarif) including the solidarity surcharge and the church
+//! tax (Kirchensteuer) for members of a church.
+//!
+//! The income tax can be calculated on its own, without the social security calculations, e.g., for
+//! civil servants or persons being insured in foreign social security systems:
+//!
+//! ```
+//! let config = net_income_germany::config::create(net_income_germany::config::latest_year()).unwrap();
+//!
+//! // income taxes on a taxable income of 50000 for a single person
+//! let taxes = net_income_germany::income_tax::calculate(&config.income_tax, 50000, false, false);
+//! assert!(taxes > 0);
+//! ```
+
+use crate::config::{IncomeTaxConfig, SolidaryAdditionConfig, TAX_RANGE_COUNT, TaxRange};
+use crate::numeric::{Number, Numeric};
+use crate::observer::{CalculationObserver, NoObserver};
+
+/// Minimum rate \[0,1\] of the income tax withholding in tax class VI.
+const TAX_CLASS_SIX_MIN_RATE: f32 = 0.14;
+
+/// Rate \[0,1\] of the income tax withholding in tax class VI above the first and second limit.
+const TAX_CLASS_SIX_HIGH_RATE: f32 = 0.42;
+
+/// Rate \[0,1\] of the income tax withholding in tax class VI above the third limit.
+const TAX_CLASS_SIX_TOP_RATE: f32 = 0.45;
 
 impl TaxRange {
+    /// Creates a tax range between the given limits with the given lowest and maximum rate \[0,1\].
+    pub fn new(lower_limit: u32, upper_limit: u32, rate_min: f32, rate_max: f32) -> Self {
+        return TaxRange {
+            lower_limit,
+            upper_limit,
+            rate_min,
+            rate_max,
+        };
+    }
+
     /// Calculate the range from the upper and lower limit.
     pub fn range(&self) -> u32 {
         self.upper_limit - self.lower_limit
     }
 }
 
-pub fn calculate(config: &IncomeTaxConfig, taxable_income: u32, together: bool) -> u32 {
-    let tax = calculate_income_tax(&config, taxable_income, together);
+/// Calculates the income taxes (including the solidarity surcharge and, if the person is a member
+/// of a church, the church tax) for the given taxable income.
+///
+/// The taxable income is the income after all deductions (social security taxes, expenses, ...)
+/// were subtracted. If `splitting` is set, the tax splitting for married couples is applied, which
+/// means that the taxable income is the combined income of both spouses.
+pub fn calculate(
+    config: &IncomeTaxConfig,
+    taxable_income: u32,
+    splitting: bool,
+    church_member: bool,
+) -> u32 {
+    let (income_taxes, church_tax) =
+        calculate_separate_church_tax(config, taxable_income, splitting, church_member);
+
+    return income_taxes.saturating_add(church_tax);
+}
+
+/// Calculates the income taxes like [`calculate`], but returns the income taxes (including the
+/// solidarity surcharge) and the church tax separately.
+pub(crate) fn calculate_separate_church_tax(
+    config: &IncomeTaxConfig,
+    taxable_income: u32,
+    splitting: bool,
+    church_member: bool,
+) -> (u32, u32) {
+    let tax = calculate_tariff(config, taxable_income, splitting);
+
+    return add_surcharges(config, tax, splitting, church_member);
+}
+
+/// Adds the solidarity surcharge to the given income taxes according to the tariff and calculates
+/// the church tax on them for members of a church.
+///
+/// Returns the income taxes including the solidarity surcharge and, separately, the church tax.
+fn add_surcharges(
+    config: &IncomeTaxConfig,
+    tax: u32,
+    splitting: bool,
+    church_member: bool,
+) -> (u32, u32) {
     let tax_solidarity =
-        calculate_solidarity_addition(tax, together, &config.solidary_addition_config);
+        calculate_solidarity_addition(tax, splitting, &config.solidary_addition_config);
+    let tax_church = match church_member {
+        true => calculate_church_tax(config, tax),
+        false => 0,
+    };
 
-    return tax + tax_solidarity;
+    return (tax.saturating_add(tax_solidarity), tax_church);
 }
 
-fn deduct_tax_for_one_range(income: u32, tax_range: &TaxRange) -> f32 {
-    // income so small, that this tax range does not apply
-    if income <= tax_range.lower_limit {
-        return 0.0;
-    }
+/// Adds the solidarity surcharge and the church tax like [`add_surcharges`] and returns the sum.
+fn add_all_surcharges(
+    config: &IncomeTaxConfig,
+    tax: u32,
+    splitting: bool,
+    church_member: bool,
+) -> u32 {
+    let (income_taxes, church_tax) = add_surcharges(config, tax, splitting, church_member);
+    return income_taxes.saturating_add(church_tax);
+}
 
-    // remove the lower limit from the income (as everything below is taxed in lower ranges)
-    // and make sure that not more than the current tax range of the income is considered
-    let taxed_income = (income - tax_range.lower_limit).min(tax_range.range());
+/// Calculates the church tax on the given income taxes according to the tariff (without the
+/// solidarity surcharge) with the church tax rate of the configuration.
+///
+/// The church tax is not reduced by the child allowances and is not capped at a share of the
+/// taxable income (Kappung), which only some churches offer on request.
+pub fn calculate_church_tax(config: &IncomeTaxConfig, income_taxes: u32) -> u32 {
+    return calculate_church_tax_with_rate(income_taxes, Number::from_f32(config.church_tax_rate));
+}
 
-    let income_range = tax_range.range() as f32;
-    let taxed_income = taxed_income as f32;
+/// Calculates the church tax like [`calculate_church_tax`], but with the church tax rate already
+/// converted into the number type of the calculation.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        name = "calculate_church_tax",
+        level = "debug",
+        skip(church_tax_rate),
+        ret
+    )
+)]
+pub(crate) fn calculate_church_tax_with_rate(income_taxes: u32, church_tax_rate: Number) -> u32 {
+    return (Number::from_u32(income_taxes) * church_tax_rate).to_u32();
+}
 
-    let rate_diff = tax_range.rate_max - tax_range.rate_min;
-    let effective_rate_diff = taxed_income / income_range * rate_diff;
+/// Calculates the income taxes (like [`calculate`]) for the given taxable income and an additional
+/// extraordinary income that is taxed according to the Fünftelregelung (§ 34 EStG).
+///
+/// The extraordinary income is taxed with five times the tax difference that one fifth of it causes
+/// on top of the regular taxable income, which softens the progression for one-time incomes.
+pub(crate) fn calculate_with_fifth_rule(
+    config: &IncomeTaxConfig,
+    taxable_income: u32,
+    extraordinary_income: u32,
+    together: bool,
+    church_member: bool,
+) -> u32 {
+    let tax_regular = calculate_tariff(config, taxable_income, together);
+    let tax_with_fifth = calculate_tariff(
+        config,
+        taxable_income.saturating_add(extraordinary_income / 5),
+        together,
+    );
+    let tax = tax_regular.saturating_add((tax_with_fifth - tax_regular).saturating_mul(5));
 
-    let effective_rate = tax_range.rate_min + effective_rate_diff / 2.0;
+    return add_all_surcharges(config, tax, together, church_member);
+}
 
-    return taxed_income * effective_rate;
+/// Calculates the income taxes (like [`calculate`]) for the given taxable income with tax-free wage
+/// replacement benefits that are subject to the progression clause
+/// (Progressionsvorbehalt, § 32b EStG), e.g., sick pay or parental allowance.
+///
+/// The benefits are not taxed themselves, but the tax rate of the taxable income plus the benefits
+/// is applied on the taxable income.
+pub(crate) fn calculate_with_progression_clause(
+    config: &IncomeTaxConfig,
+    taxable_income: u32,
+    benefits: u32,
+    together: bool,
+    church_member: bool,
+) -> u32 {
+    let income_with_benefits = taxable_income.saturating_add(benefits);
+    let tax = match income_with_benefits {
+        0 => 0,
+        _ => {
+            // the tax rate of the income with benefits is applied to the taxable income
+            (Number::from_u32(calculate_tariff(config, income_with_benefits, together))
+                * Number::from_u32(taxable_income)
+                / Number::from_u32(income_with_benefits))
+            .to_u32()
+        }
+    };
+
+    return add_all_surcharges(config, tax, together, church_member);
+}
+
+/// Calculates the withheld income taxes (like [`calculate`]) for an income in tax class VI, in which
+/// no allowances apply (§ 39b Abs. 2 Satz 7 EStG).
+pub(crate) fn calculate_tax_class_six(
+    config: &IncomeTaxConfig,
+    taxable_income: u32,
+    church_member: bool,
+) -> u32 {
+    let limits = &config.tax_class_six;
+
+    let high_rate = Number::from_f32(TAX_CLASS_SIX_HIGH_RATE);
+    let top_rate = Number::from_f32(TAX_CLASS_SIX_TOP_RATE);
+
+    let tax = if taxable_income > limits.second_limit {
+        // above the second limit, every additional euro is taxed with the top rates
+        let tax = Number::from_u32(calculate_tax_class_six_base(config, limits.second_limit));
+        if taxable_income > limits.third_limit {
+            tax + Number::from_u32(limits.third_limit - limits.second_limit) * high_rate
+                + Number::from_u32(taxable_income - limits.third_limit) * top_rate
+        } else {
+            tax + Number::from_u32(taxable_income - limits.second_limit) * high_rate
+        }
+    } else {
+        let tax = Number::from_u32(calculate_tax_class_six_base(config, taxable_income));
+        if taxable_income > limits.first_limit {
+            // above the first limit, the rate is limited to 42%
+            let tax_limited =
+                Number::from_u32(calculate_tax_class_six_base(config, limits.first_limit))
+                    + Number::from_u32(taxable_income - limits.first_limit) * high_rate;
+            Numeric::min(tax, tax_limited)
+        } else {
+            tax
+        }
+    }
+    .to_u32();
+
+    return add_all_surcharges(config, tax, false, church_member);
+}
+
+/// Calculates the doubled tariff difference between 125% and 75% of the income, but at least 14%.
+fn calculate_tax_class_six_base(config: &IncomeTaxConfig, income: u32) -> u32 {
+    let income_number = Number::from_u32(income);
+    let tax_upper = calculate_tariff(
+        config,
+        (income_number * Number::from_f32(1.25)).to_u32(),
+        false,
+    );
+    let tax_lower = calculate_tariff(
+        config,
+        (income_number * Number::from_f32(0.75)).to_u32(),
+        false,
+    );
+    let tax = (tax_upper - tax_lower) * 2;
+
+    return tax.max((income_number * Number::from_f32(TAX_CLASS_SIX_MIN_RATE)).to_u32());
+}
+
+/// Parameters of a tax range, converted into the number type of the calculation.
+#[derive(Clone)]
+pub(crate) struct TaxRangeParameters {
+    pub(crate) lower_limit: u32,
+    pub(crate) range: u32,
+    pub(crate) income_range: Number,
+    pub(crate) rate_min: Number,
+    pub(crate) rate_diff: Number,
 }
 
-fn calculate_income_tax(config: &IncomeTaxConfig, income: u32, together: bool) -> u32 {
-    let mut tax_sum = 0.0;
+impl TaxRangeParameters {
+    /// Converts the limits and rates of the given tax range.
+    pub(crate) fn new(tax_range: &TaxRange) -> Self {
+        let rate_min = Number::from_f32(tax_range.rate_min);
+
+        return TaxRangeParameters {
+            lower_limit: tax_range.lower_limit,
+            range: tax_range.range(),
+            income_range: Number::from_u32(tax_range.range()),
+            rate_min,
+            rate_diff: Number::from_f32(tax_range.rate_max) - rate_min,
+        };
+    }
+
+    /// Calculates the taxes of the given income in this tax range.
+    fn deduct_tax(&self, income: u32) -> Number {
+        // income so small, that this tax range does not apply
+        if income <= self.lower_limit {
+            return Number::from_u32(0);
+        }
 
-    // for married couples the taxes are calculated based on half of the combined income
-    let income = if together { income / 2 } else { income };
+        // remove the lower limit from the income (as everything below is taxed in lower ranges)
+        // and make sure that not more than the current tax range of the income is considered
+        let taxed_income = Number::from_u32((income - self.lower_limit).min(self.range));
 
-    for tax_range in &config.tax_ranges {
-        let tax = deduct_tax_for_one_range(income, tax_range);
+        let effective_rate_diff = taxed_income / self.income_range * self.rate_diff;
+        let effective_rate = self.rate_min + effective_rate_diff / Number::from_u32(2);
 
-        tax_sum = tax_sum + tax;
+        return taxed_income * effective_rate;
     }
+}
+
+/// Parameters of all tax ranges of the tariff, converted into the number type of the calculation,
+/// so that the tariff can be applied on many incomes without converting them again.
+#[derive(Clone)]
+pub(crate) struct TariffParameters<'a> {
+    tax_ranges: &'a [TaxRange; TAX_RANGE_COUNT],
+    pub(crate) ranges: [TaxRangeParameters; TAX_RANGE_COUNT],
+}
+
+impl<'a> TariffParameters<'a> {
+    /// Converts the tax ranges of the given configuration.
+    pub(crate) fn new(config: &'a IncomeTaxConfig) -> Self {
+        return TariffParameters {
+            tax_ranges: &config.tax_ranges,
+            ranges: config.tax_ranges.each_ref().map(TaxRangeParameters::new),
+        };
+    }
+
+    /// Calculates the income taxes according to the tariff like [`calculate_tariff_observed`].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "calculate_tariff_observed",
+            level = "debug",
+            skip(self, observer)
+        )
+    )]
+    pub(crate) fn calculate_observed(
+        &self,
+        income: u32,
+        together: bool,
+        observer: &mut dyn CalculationObserver,
+    ) -> u32 {
+        let mut tax_sum = Number::from_u32(0);
+
+        // for married couples the taxes are calculated based on half of the combined income
+        let income = if together { income / 2 } else { income };
+
+        for (tax_range, parameters) in self.tax_ranges.iter().zip(&self.ranges) {
+            let tax = parameters.deduct_tax(income);
+            #[cfg(feature = "tracing")]
+            tracing::event!(
+                tracing::Level::TRACE,
+                lower_limit = tax_range.lower_limit,
+                upper_limit = tax_range.upper_limit,
+                tax = tax.to_f32(),
+                "calculated the taxes of the tax range"
+            );
+            observer.on_tax_range(tax_range, tax.to_f32());
+
+            tax_sum += tax;
+        }
 
-    if together {
         // the tax value needs to be doubled again after calculating with half for married couples
-        return tax_sum as u32 * 2;
-    } else {
-        return tax_sum as u32;
+        let tax = match together {
+            true => tax_sum.to_u32() * 2,
+            false => tax_sum.to_u32(),
+        };
+        #[cfg(feature = "tracing")]
+        tracing::event!(
+            tracing::Level::DEBUG,
+            income_taxes = tax,
+            "calculated the income taxes"
+        );
+
+        return tax;
     }
 }
 
-fn calculate_solidarity_addition(
+/// Calculates the income taxes according to the tariff (without the solidarity surcharge).
+pub(crate) fn calculate_tariff(config: &IncomeTaxConfig, income: u32, together: bool) -> u32 {
+    return calculate_tariff_observed(config, income, together, &mut NoObserver);
+}
+
+/// Calculates the income taxes according to the tariff like [`calculate_tariff`], but notifies the
+/// given observer about the taxes of every tax range.
+pub(crate) fn calculate_tariff_observed(
+    config: &IncomeTaxConfig,
+    income: u32,
+    together: bool,
+    observer: &mut dyn CalculationObserver,
+) -> u32 {
+    return TariffParameters::new(config).calculate_observed(income, together, observer);
+}
+
+/// Calculates the solidarity surcharge on the given income taxes.
+pub(crate) fn calculate_solidarity_addition(
     tax: u32,
     together: bool,
     solidarity_addition_config: &SolidaryAdditionConfig,
 ) -> u32 {
-    let tax_exemption_level = if together {
-        solidarity_addition_config.exemption_level * 2
-    } else {
-        solidarity_addition_config.exemption_level
-    };
+    return SolidarityAdditionParameters::new(solidarity_addition_config, together).calculate(tax);
+}
+
+/// Parameters of the solidarity surcharge, converted into the number type of the calculation, so
+/// that the surcharge can be calculated on many income taxes without converting them again.
+#[derive(Clone)]
+pub(crate) struct SolidarityAdditionParameters {
+    exemption_level: u32,
+    rate: Number,
+    max_percentage: Number,
+}
 
-    if tax < tax_exemption_level {
-        return 0;
+impl SolidarityAdditionParameters {
+    /// Converts the given configuration of the solidarity surcharge, with the doubled exemption
+    /// level for married couples if `together` is set.
+    pub(crate) fn new(solidarity_addition_config: &SolidaryAdditionConfig, together: bool) -> Self {
+        return SolidarityAdditionParameters {
+            exemption_level: match together {
+                true => solidarity_addition_config.exemption_level * 2,
+                false => solidarity_addition_config.exemption_level,
+            },
+            rate: Number::from_f32(solidarity_addition_config.rate),
+            max_percentage: Number::from_f32(solidarity_addition_config.max_percentage),
+        };
     }
 
-    let max_solidarity_addition =
-        (tax - tax_exemption_level) as f32 * solidarity_addition_config.max_percentage;
-    let solidarity_addition = tax as f32 * solidarity_addition_config.rate;
+    /// Calculates the solidarity surcharge on the given income taxes.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "calculate_solidarity_addition",
+            level = "debug",
+            skip(self),
+            ret
+        )
+    )]
+    pub(crate) fn calculate(&self, tax: u32) -> u32 {
+        if tax < self.exemption_level {
+            return 0;
+        }
+
+        let max_solidarity_addition =
+            Number::from_u32(tax - self.exemption_level) * self.max_percentage;
+        let solidarity_addition = Number::from_u32(tax) * self.rate;
 
-    return solidarity_addition.min(max_solidarity_addition) as u32;
+        return Numeric::min(solidarity_addition, max_solidarity_addition).to_u32();
+    }
 }
 
 #[cfg(test)]
@@ -83,12 +421,14 @@ mod tests {
     use super::*;
     use crate::config::create as create_config;
 
+    #[cfg(feature = "year-2024")]
     struct Data {
         i: u32,
         o: u32,
     }
 
     #[test]
+    #[cfg(feature = "year-2024")]
     fn test_tax_calculation_2024() {
         // the test data is based on the income tax calculator of the
         // German ministry of finances (https://www.bmf-steuerrechner.de)
@@ -109,6 +449,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "year-2024")]
     fn test_tax_calculation_married_2024() {
         // the test data is based on the income tax calculator of the
         // German ministry of finances (https://www.bmf-steuerrechner.de)
@@ -130,20 +471,102 @@ mod tests {
         test_tax_calculation(&test_data, 2024, true);
     }
 
+    #[cfg(feature = "year-2024")]
     fn test_tax_calculation(test_data: &Vec<Data>, year: u32, together: bool) {
         let config = create_config(year).unwrap();
 
         for data in test_data {
-            let result = calculate(&config.income_tax, data.i, together);
+            let result = calculate(&config.income_tax, data.i, together, false);
             assert_eq!(result, data.o);
         }
     }
 
+    #[test]
+    #[cfg(feature = "year-2025")]
+    fn test_fifth_rule_reduces_progression() {
+        let config = create_config(2025).unwrap();
+
+        // without extraordinary income, the Fünftelregelung leads to the regular taxes
+        assert_eq!(
+            calculate_with_fifth_rule(&config.income_tax, 40000, 0, false, false),
+            calculate(&config.income_tax, 40000, false, false)
+        );
+
+        // with extraordinary income, the taxes must be lower than the regularly taxed sum, but at
+        // least as high as the taxes on the regular income alone
+        let tax_fifth_rule =
+            calculate_with_fifth_rule(&config.income_tax, 40000, 50000, false, false);
+        assert!(tax_fifth_rule < calculate(&config.income_tax, 90000, false, false));
+        assert!(tax_fifth_rule > calculate(&config.income_tax, 40000, false, false));
+    }
+
+    #[test]
+    #[cfg(feature = "year-2025")]
+    fn test_progression_clause() {
+        let config = create_config(2025).unwrap();
+
+        // without benefits, the regular taxes apply
+        assert_eq!(
+            calculate_with_progression_clause(&config.income_tax, 40000, 0, false, false),
+            calculate(&config.income_tax, 40000, false, false)
+        );
+
+        // the benefits increase the tax rate, but are not taxed themselves
+        let tax = calculate_with_progression_clause(&config.income_tax, 20000, 10000, false, false);
+        assert!(tax > calculate(&config.income_tax, 20000, false, false));
+        assert!(tax < calculate(&config.income_tax, 30000, false, false));
+
+        // the benefits alone do not lead to any taxes
+        assert_eq!(
+            calculate_with_progression_clause(&config.income_tax, 0, 30000, false, false),
+            0
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "year-2025")]
+    fn test_tax_class_six() {
+        let config = create_config(2025).unwrap();
+
+        // no basic allowance applies in tax class six, so at least the minimum rate is withheld
+        assert_eq!(
+            calculate_tax_class_six(&config.income_tax, 10000, false),
+            1400
+        );
+
+        // the withholding is always at least as high as the regular income tax
+        for income in [5000, 13785, 20000, 34240, 60000, 250000] {
+            assert!(
+                calculate_tax_class_six(&config.income_tax, income, false)
+                    >= calculate(&config.income_tax, income, false, false)
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "year-2025")]
+    fn test_church_tax() {
+        let mut config = create_config(2025).unwrap();
+        let tariff = calculate_tariff(&config.income_tax, 50000, false);
+        let without_church_tax = calculate(&config.income_tax, 50000, false, false);
+
+        assert_eq!(
+            calculate(&config.income_tax, 50000, false, true),
+            without_church_tax + (tariff as f64 * 0.09) as u32
+        );
+
+        config.income_tax.church_tax_rate = 0.08;
+        assert_eq!(
+            calculate_church_tax(&config.income_tax, tariff),
+            (tariff as f64 * 0.08) as u32
+        );
+    }
+
     #[test]
     fn test_with_maximum_input_value() {
         let config = crate::config::Config::default();
 
-        let result = calculate(&config.income_tax, std::u32::MAX, false);
+        let result = calculate(&config.income_tax, u32::MAX, false, false);
         assert!(result > 2000000000); // check that there won't be some overflow that leads to a small result value
     }
 }
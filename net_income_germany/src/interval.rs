@@ -0,0 +1,206 @@
+//! Calculation with uncertain input values, given as intervals.
+//!
+//! For planning under uncertainty, the income, the expenses and the additional health insurance
+//! premium can be given as ranges (e.g., a freelance profit between 50000 and 70000) and the
+//! calculation returns the lower and upper bounds of the net income and of the taxes:
+//!
+//! ```
+//! use net_income_germany::interval::{self, Interval, IntervalData};
+//!
+//! let config = net_income_germany::config::create(net_income_germany::config::latest_year()).unwrap();
+//! let tax_data = net_income_germany::TaxData::new(0).with_self_employed(true);
+//!
+//! let data = IntervalData {
+//!     income: Interval::new(50000, 70000),
+//!     expenses: Interval::exact(2000),
+//!     premium_additional: Some(Interval::new(0.015, 0.029)),
+//! };
+//!
+//! let result = interval::calculate(&config, &tax_data, &data).unwrap();
+//! println!("Net income: {} to {}", result.net_income.lower, result.net_income.upper);
+//! ```
+//!
+//! The net income and the taxes are monotonic in every one of the uncertain values, so that their
+//! bounds are reached at the bounds of the input intervals. Therefore, the calculation is done for
+//! all combinations of the bounds of the input intervals.
+
+use crate::config::Config;
+use crate::error::Error;
+use crate::{TaxData, calculate as calculate_taxes};
+
+/// Closed interval of a value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Interval<T> {
+    /// The smallest value of the interval.
+    pub lower: T,
+
+    /// The largest value of the interval.
+    pub upper: T,
+}
+
+impl<T: Copy + PartialOrd> Interval<T> {
+    /// Creates the interval between the given bounds.
+    pub fn new(lower: T, upper: T) -> Self {
+        return Interval { lower, upper };
+    }
+
+    /// Creates the interval that contains only the given value.
+    pub fn exact(value: T) -> Self {
+        return Interval {
+            lower: value,
+            upper: value,
+        };
+    }
+
+    /// Returns whether the lower bound does not exceed the upper bound.
+    fn is_valid(&self) -> bool {
+        return self.lower <= self.upper;
+    }
+
+    /// Extends the interval, so that it contains the given value.
+    fn include(&mut self, value: T) {
+        if value < self.lower {
+            self.lower = value;
+        }
+        if value > self.upper {
+            self.upper = value;
+        }
+    }
+}
+
+/// Input data struct with the uncertain values of the calculation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IntervalData {
+    /// The interval of the yearly gross income.
+    pub income: Interval<u32>,
+
+    /// The interval of the yearly tax-deductible expenses.
+    pub expenses: Interval<u32>,
+
+    /// The interval of the additional premium \[0,1\] of the health insurance (Zusatzbeitrag), or
+    /// `None` to use the premium of the configuration.
+    pub premium_additional: Option<Interval<f32>>,
+}
+
+/// Result struct of the calculation with the bounds of the results.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IntervalResult {
+    /// The interval of the net income.
+    pub net_income: Interval<i32>,
+
+    /// The interval of the social security taxes.
+    pub social_security_taxes: Interval<u32>,
+
+    /// The interval of the income taxes.
+    pub income_taxes: Interval<u32>,
+}
+
+/// Calculates the bounds of the net income and of the taxes for the given uncertain values, using
+/// the given tax data for all other input values.
+pub fn calculate(
+    config: &Config,
+    tax_data: &TaxData,
+    data: &IntervalData,
+) -> Result<IntervalResult, Error> {
+    let premium_additional = data
+        .premium_additional
+        .unwrap_or(Interval::exact(config.health_insurance.premium_additional));
+    if !data.income.is_valid() || !data.expenses.is_valid() || !premium_additional.is_valid() {
+        return Err(Error::InvalidInterval);
+    }
+
+    let mut config = config.clone();
+    let mut tax_data = tax_data.clone();
+    let mut result: Option<IntervalResult> = None;
+
+    for premium in [premium_additional.lower, premium_additional.upper] {
+        config.health_insurance.premium_additional = premium;
+
+        for income in [data.income.lower, data.income.upper] {
+            for expenses in [data.expenses.lower, data.expenses.upper] {
+                tax_data.income = income;
+                tax_data.expenses = expenses;
+                let tax_result = calculate_taxes(&config, &tax_data)?;
+
+                match &mut result {
+                    Some(result) => {
+                        result.net_income.include(tax_result.net_income);
+                        result
+                            .social_security_taxes
+                            .include(tax_result.social_security_taxes);
+                        result.income_taxes.include(tax_result.income_taxes);
+                    }
+                    None => {
+                        result = Some(IntervalResult {
+                            net_income: Interval::exact(tax_result.net_income),
+                            social_security_taxes: Interval::exact(
+                                tax_result.social_security_taxes,
+                            ),
+                            income_taxes: Interval::exact(tax_result.income_taxes),
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    return result.ok_or(Error::InvalidInterval);
+}
+
+#[cfg(all(test, feature = "year-2025"))]
+mod tests {
+    use super::*;
+    use crate::config::create as create_config;
+
+    #[test]
+    fn test_bounds_of_results() {
+        let config = create_config(2025).unwrap();
+        let tax_data = TaxData::new(0).with_self_employed(true);
+        let data = IntervalData {
+            income: Interval::new(50000, 70000),
+            expenses: Interval::exact(2000),
+            premium_additional: Some(Interval::new(0.015, 0.029)),
+        };
+
+        let result = calculate(&config, &tax_data, &data).unwrap();
+
+        for (income, premium) in [(50000, 0.015), (60000, 0.02), (70000, 0.029)] {
+            let mut config = config.clone();
+            config.health_insurance.premium_additional = premium;
+            let tax_data = TaxData::new(income)
+                .with_expenses(2000)
+                .with_self_employed(true);
+            let tax_result = calculate_taxes(&config, &tax_data).unwrap();
+
+            assert!(result.net_income.lower <= tax_result.net_income);
+            assert!(tax_result.net_income <= result.net_income.upper);
+            assert!(result.income_taxes.lower <= tax_result.income_taxes);
+            assert!(tax_result.income_taxes <= result.income_taxes.upper);
+        }
+    }
+
+    #[test]
+    fn test_exact_values() {
+        let config = create_config(2025).unwrap();
+        let data = IntervalData {
+            income: Interval::exact(60000),
+            expenses: Interval::exact(0),
+            premium_additional: None,
+        };
+
+        let result = calculate(&config, &TaxData::new(0), &data).unwrap();
+        let expected = calculate_taxes(&config, &TaxData::new(60000)).unwrap();
+        assert_eq!(result.net_income, Interval::exact(expected.net_income));
+
+        let data = IntervalData {
+            income: Interval::new(70000, 50000),
+            ..data
+        };
+        assert_eq!(
+            calculate(&config, &TaxData::new(0), &data).err(),
+            Some(Error::InvalidInterval)
+        );
+    }
+}
@@ -0,0 +1,197 @@
+//! Taxation of investment funds (e.g., ETFs) according to the investment tax law (InvStG).
+//!
+//! Accumulating funds do not distribute their income, so a minimum taxation applies every year: the
+//! Vorabpauschale. It is calculated from the value of the fund at the beginning of the year and the
+//! base rate (Basiszins), limited by the actual increase in value of the fund. Depending on the type
+//! of the fund, a part of the income is exempt from taxes (Teilfreistellung).
+
+use crate::capital_income::{self, CapitalIncomeResult};
+use crate::config::Config;
+use crate::numeric::{Number, Numeric};
+
+/// Share of the base rate that is used for calculating the base income of a fund (§ 18 Abs. 1 InvStG).
+const BASE_INCOME_FACTOR: f32 = 0.7;
+
+/// Types of investment funds with different partial exemptions (§ 20 InvStG).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FundType {
+    /// Equity fund with at least 51% shares (Aktienfonds).
+    Equity,
+    /// Mixed fund with at least 25% shares (Mischfonds).
+    Mixed,
+    /// Real-estate fund with at least 51% real estate (Immobilienfonds).
+    RealEstate,
+    /// Real-estate fund with at least 51% foreign real estate (Auslands-Immobilienfonds).
+    ForeignRealEstate,
+    /// Any other fund without partial exemption (e.g., bond funds).
+    Other,
+}
+
+impl FundType {
+    /// Returns the share \[0,1\] of the fund income that is exempt from taxes.
+    pub fn get_partial_exemption(&self) -> f32 {
+        return match self {
+            FundType::Equity => 0.3,
+            FundType::Mixed => 0.15,
+            FundType::RealEstate => 0.6,
+            FundType::ForeignRealEstate => 0.8,
+            FundType::Other => 0.0,
+        };
+    }
+
+    /// Returns the part of the given fund income that remains taxable after the partial exemption.
+    pub fn get_taxable_income(&self, income: u32) -> u32 {
+        let exemption =
+            (Number::from_u32(income) * Number::from_f32(self.get_partial_exemption())).to_u32();
+        return income - exemption;
+    }
+}
+
+/// Holding of one investment fund during one year.
+#[derive(Clone)]
+pub struct FundHolding {
+    /// The type of the fund, which defines the partial exemption.
+    pub fund_type: FundType,
+
+    /// The value of the holding at the beginning of the year (or at the purchase in that year).
+    pub value_start: u32,
+
+    /// The value of the holding at the end of the year.
+    pub value_end: u32,
+
+    /// The distributions of the fund during the year.
+    pub distributions: u32,
+
+    /// The number of months \[1,12\] in which the holding was held, counted from the month of the
+    /// purchase (12 for a holding that was held for the whole year).
+    pub months_held: u32,
+}
+
+/// Result struct of the Vorabpauschale calculation for all holdings.
+pub struct VorabpauschaleResult {
+    /// The sum of the Vorabpauschalen of all holdings (before the partial exemptions).
+    pub vorabpauschale: u32,
+
+    /// The sum of the Vorabpauschalen after the partial exemptions.
+    pub taxable_vorabpauschale: u32,
+
+    /// The flat tax on the taxable Vorabpauschalen after the saver's allowance.
+    pub taxes: CapitalIncomeResult,
+}
+
+/// Calculates the Vorabpauschale for one holding (before the partial exemption).
+pub fn calculate_vorabpauschale(config: &Config, holding: &FundHolding) -> u32 {
+    let base_income = Number::from_u32(holding.value_start)
+        * Number::from_f32(config.capital_income.base_rate)
+        * Number::from_f32(BASE_INCOME_FACTOR);
+
+    // the base income is limited by the increase in value plus the distributions of the year
+    let increase = Number::from_u32(
+        holding
+            .value_end
+            .saturating_sub(holding.value_start)
+            .saturating_add(holding.distributions),
+    );
+    let vorabpauschale =
+        (Numeric::min(base_income, increase) - Number::from_u32(holding.distributions)).to_u32();
+
+    // in the year of the purchase, the Vorabpauschale is reduced by one twelfth for every full month
+    // before the month of the purchase
+    let months_held = holding.months_held.clamp(1, 12);
+    return (Number::from_u32(vorabpauschale) * Number::from_u32(months_held)
+        / Number::from_u32(12))
+    .to_u32();
+}
+
+/// Calculates the Vorabpauschalen of all given holdings and the resulting taxes.
+pub fn calculate(config: &Config, holdings: &[FundHolding], married: bool) -> VorabpauschaleResult {
+    let mut vorabpauschale = 0;
+    let mut taxable_vorabpauschale = 0;
+
+    for holding in holdings {
+        let holding_vorabpauschale = calculate_vorabpauschale(config, holding);
+
+        vorabpauschale += holding_vorabpauschale;
+        taxable_vorabpauschale += holding.fund_type.get_taxable_income(holding_vorabpauschale);
+    }
+
+    return VorabpauschaleResult {
+        vorabpauschale,
+        taxable_vorabpauschale,
+        taxes: capital_income::calculate(config, taxable_vorabpauschale, married),
+    };
+}
+
+#[cfg(all(test, feature = "year-2025"))]
+mod tests {
+    use super::*;
+    use crate::config::create as create_config;
+
+    fn create_holding(value_start: u32, value_end: u32, distributions: u32) -> FundHolding {
+        return FundHolding {
+            fund_type: FundType::Equity,
+            value_start,
+            value_end,
+            distributions,
+            months_held: 12,
+        };
+    }
+
+    #[test]
+    fn test_vorabpauschale_2025() {
+        let config = create_config(2025).unwrap();
+
+        // 100000 * 2.53% * 0.7 = 1771 (or 1770 when truncating the floating point numbers of the
+        // f32 rates)
+        let vorabpauschale = calculate_vorabpauschale(&config, &create_holding(100000, 110000, 0));
+        assert!(vorabpauschale.abs_diff(1771) <= 1);
+
+        // limited by the increase in value
+        assert_eq!(
+            calculate_vorabpauschale(&config, &create_holding(100000, 101000, 0)),
+            1000
+        );
+
+        // no Vorabpauschale in case of a loss
+        assert_eq!(
+            calculate_vorabpauschale(&config, &create_holding(100000, 90000, 0)),
+            0
+        );
+
+        // the distributions are deducted
+        assert_eq!(
+            calculate_vorabpauschale(&config, &create_holding(100000, 110000, 1000)),
+            vorabpauschale - 1000
+        );
+    }
+
+    #[test]
+    fn test_vorabpauschale_in_year_of_purchase() {
+        let config = create_config(2025).unwrap();
+        let mut holding = create_holding(100000, 110000, 0);
+        holding.months_held = 6;
+
+        assert_eq!(calculate_vorabpauschale(&config, &holding), 885);
+    }
+
+    #[test]
+    fn test_taxes_with_partial_exemption_and_allowance() {
+        let config = create_config(2025).unwrap();
+        let holdings = vec![
+            create_holding(200000, 220000, 0),
+            FundHolding {
+                fund_type: FundType::Other,
+                ..create_holding(50000, 60000, 0)
+            },
+        ];
+
+        let result = calculate(&config, &holdings, false);
+        // the f32 rates may truncate the floating point numbers by one
+        assert!(result.vorabpauschale.abs_diff(3542 + 885) <= 1);
+        assert!(result.taxable_vorabpauschale.abs_diff(2480 + 885) <= 1);
+        assert_eq!(
+            result.taxes.taxable_capital_income,
+            result.taxable_vorabpauschale - 1000
+        );
+    }
+}
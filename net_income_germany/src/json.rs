@@ -0,0 +1,140 @@
+//! Stable, versioned JSON representation of the results.
+//!
+//! APIs that are built on this crate can return this representation instead of serializing the
+//! result structs directly, so that they do not break when the fields of the structs change. The
+//! representation only changes with a new [`SCHEMA_VERSION`]:
+//!
+//! ```
+//! let config = net_income_germany::config::create(net_income_germany::config::latest_year()).unwrap();
+//! let tax_data = net_income_germany::TaxData::new(60000);
+//!
+//! let result = net_income_germany::result_v2::calculate(&config, &tax_data).unwrap();
+//! println!("{}", net_income_germany::json::breakdown_to_json(&result));
+//! ```
+//!
+//! # Schema (version 2)
+//!
+//! The representation is an object with the following fields (all amounts are yearly amounts in
+//! euros):
+//!
+//! - `schema_version`: the version of the schema (integer),
+//! - `gross_income`: the gross income before all deductions (integer),
+//! - `net_income`: the net income after all deductions (integer, can be negative),
+//! - `social_security_taxes`: the sum of all social security contributions (integer),
+//! - `income_taxes`: the sum of all taxes (integer),
+//! - `church_tax`: the church tax, which is part of the income taxes (integer, since version 2),
+//! - `deductions`: only for the breakdown (see [`breakdown_to_json`]), an object with the amount of
+//!   every deduction category: `health_insurance`, `nursing_insurance`, `retirement_insurance`,
+//!   `unemployment_insurance`, `income_tax`, `solidarity_surcharge` and `church_tax` (integers,
+//!   zero if the deduction does not apply).
+//!
+//! Newer schema versions might add fields, but do not rename or remove the fields of older versions.
+
+use crate::TaxResult;
+use crate::result_v2::{DeductionCategory, TaxResultV2};
+
+/// The version of the JSON representation.
+pub const SCHEMA_VERSION: u32 = 2;
+
+/// The deduction categories in the order of the representation.
+const CATEGORIES: [DeductionCategory; 7] = [
+    DeductionCategory::HealthInsurance,
+    DeductionCategory::NursingInsurance,
+    DeductionCategory::RetirementInsurance,
+    DeductionCategory::UnemploymentInsurance,
+    DeductionCategory::IncomeTax,
+    DeductionCategory::SolidaritySurcharge,
+    DeductionCategory::ChurchTax,
+];
+
+/// Returns the name of the given deduction category in the representation.
+fn category_name(category: DeductionCategory) -> &'static str {
+    return match category {
+        DeductionCategory::HealthInsurance => "health_insurance",
+        DeductionCategory::NursingInsurance => "nursing_insurance",
+        DeductionCategory::RetirementInsurance => "retirement_insurance",
+        DeductionCategory::UnemploymentInsurance => "unemployment_insurance",
+        DeductionCategory::IncomeTax => "income_tax",
+        DeductionCategory::SolidaritySurcharge => "solidarity_surcharge",
+        DeductionCategory::ChurchTax => "church_tax",
+    };
+}
+
+/// Returns the fields that are common to all representations.
+fn common_fields(result: &TaxResult) -> Vec<String> {
+    return vec![
+        format!("  \"schema_version\": {SCHEMA_VERSION}"),
+        format!("  \"gross_income\": {}", result.gross_income),
+        format!("  \"net_income\": {}", result.net_income),
+        format!(
+            "  \"social_security_taxes\": {}",
+            result.social_security_taxes
+        ),
+        // the income taxes of the schema include the church tax
+        format!("  \"income_taxes\": {}", result.get_total_income_taxes()),
+        format!("  \"church_tax\": {}", result.church_tax),
+    ];
+}
+
+/// Exports the given result as JSON object with the lump sums of the deductions.
+pub fn result_to_json(result: &TaxResult) -> String {
+    return format!("{{\n{}\n}}", common_fields(result).join(",\n"));
+}
+
+/// Exports the given result as JSON object with the lump sums and with the amount of every deduction
+/// category.
+pub fn breakdown_to_json(result: &TaxResultV2) -> String {
+    let deductions: Vec<String> = CATEGORIES
+        .iter()
+        .map(|category| {
+            format!(
+                "    \"{}\": {}",
+                category_name(*category),
+                result.get(*category)
+            )
+        })
+        .collect();
+
+    let mut fields = common_fields(&result.to_result());
+    fields.push(format!(
+        "  \"deductions\": {{\n{}\n  }}",
+        deductions.join(",\n")
+    ));
+
+    return format!("{{\n{}\n}}", fields.join(",\n"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "year-2025")]
+    use crate::TaxData;
+    #[cfg(feature = "year-2025")]
+    use crate::config::create as create_config;
+
+    #[test]
+    fn test_result_to_json() {
+        let result = TaxResult::new(60000, 38500, 12000, 9000).with_church_tax(500);
+
+        assert_eq!(
+            result_to_json(&result),
+            "{\n  \"schema_version\": 2,\n  \"gross_income\": 60000,\n  \"net_income\": 38500,\n  \
+             \"social_security_taxes\": 12000,\n  \"income_taxes\": 9500,\n  \"church_tax\": 500\n}"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "year-2025")]
+    fn test_breakdown_contains_all_categories() {
+        let config = create_config(2025).unwrap();
+        let result = crate::result_v2::calculate(&config, &TaxData::new(60000)).unwrap();
+        let json = breakdown_to_json(&result);
+
+        for category in CATEGORIES {
+            let field = format!("\"{}\": {}", category_name(category), result.get(category));
+            assert!(json.contains(&field));
+        }
+        assert!(json.contains(&format!("\"net_income\": {}", result.net_income)));
+        assert!(json.contains("\"church_tax\": 0"));
+    }
+}
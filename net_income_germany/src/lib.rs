@@ -2,7 +2,7 @@
 //! income tax laws.
 //!
 //! It can handle the calculations of the social security taxes for employed and for self-employed
-//! income and can also take tax splitting for married couples into account.
+//! income and can also take tax splitting for married couples (or widowed persons) into account.
 //!
 //! The following taxes and social security fees are considered for the calculation of the net
 //! income:
@@ -11,21 +11,20 @@
 //! - unemployment insurance (Arbeitslosenversicherung)
 //! - income tax (Einkommenssteuer)
 //! - solidarity surcharge (Solidaritätszuschlag)
+//! - church tax (Kirchensteuer), only for members of a church
 //!
 //! # Example
 //! ```
-//! # fn main() -> Result<(), &'static str> {
+//! # fn main() -> Result<(), net_income_germany::error::Error> {
 //! // set the necessary input data values
-//! let tax_data = net_income_germany::TaxData {
-//!     income: 80000, // the gross income of one year
-//!     expenses: 5300, // the tax-deductible expenses of one year
-//!     fixed_retirement: Some(800), // an optional fixed monthly retirement rate (otherwise percentage applies)
-//!     self_employed: false, // whether social security taxes should be calculated for a self-employed person
-//!     married: false, // whether tax splitting due to marriage should apply
-//! };
+//! let tax_data = net_income_germany::TaxData::new(80000) // the gross income of one year
+//!     .with_expenses(5300) // the tax-deductible expenses of one year
+//!     .with_fixed_retirement(Some(800)) // an optional fixed monthly retirement rate (otherwise percentage applies)
+//!     .with_self_employed(false) // whether social security taxes should be calculated for a self-employed person
+//!     .with_assessment(net_income_germany::Assessment::Single); // whether tax splitting due to marriage should apply
 //!
-//! // create the default configuration for a specific year (2024 and 2025 are supported)
-//! let config = net_income_germany::config::create(2025)?;
+//! // create the configuration of a specific year (see `config::supported_years()`)
+//! let config = net_income_germany::config::create(net_income_germany::config::latest_year())?;
 //!
 //! // do the tax calculation based on the input data values
 //! let tax_result = net_income_germany::calculate(&config, &tax_data)?;
@@ -45,23 +44,115 @@
 //! can do it as follows:
 //!
 //! ```
-//! # fn main() -> Result<(), &'static str> {
-//! let mut config = net_income_germany::config::create(2025)?;
+//! # fn main() -> Result<(), net_income_germany::error::Error> {
+//! let mut config = net_income_germany::config::create(net_income_germany::config::latest_year())?;
 //! config.health_insurance.premium_additional = 0.0025; // change the additional health insurance fee [0,1]
 //! config.health_insurance.premium_nursing_additional = 0.002; // change the additional nursing insurance fee [0,1]
 //!
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! # Features
+//!
+//! - `year-2024`, `year-2025` (default): include the configuration of the year (see
+//!   [`config::create`]). Dropping unneeded years keeps the binary small (e.g., for WASM).
+//! - `decimal`: runs the calculations on exact decimal numbers (`rust_decimal`) instead of `f64`.
+//! - `serde`: makes the input data and [`scenario::Scenario`] serializable.
+//! - `clock`: creates the configuration of the current year from the system clock (see
+//!   `config::create_current`).
+//! - `proto`: provides the message types of the Protocol Buffers schema with conversions and the
+//!   implementation of its service (see `proto` and `service`).
+//! - `arbitrary`, `proptest`, `quickcheck`: provide random input data for property-based tests from
+//!   a random number generator, with proptest or with quickcheck (see `arbitrary`).
+//! - `tracing`: emits spans and events with the intermediate values of the social security, the
+//!   income tax and the reverse calculation (with the `tracing` crate).
 
 #![forbid(unsafe_code)]
-
+#![allow(clippy::needless_return)]
+
+#[cfg(any(feature = "arbitrary", feature = "proptest", feature = "quickcheck"))]
+pub mod arbitrary;
+pub mod audit;
+pub mod basic_pension;
+pub mod batch;
+pub mod bundesland;
+pub mod capital_income;
+pub mod ceiling;
+pub mod chamber;
+pub mod children;
+pub mod company_pension;
 pub mod config;
-mod income_tax;
-mod social_security;
+#[cfg(test)]
+#[path = "../build/config_file.rs"]
+mod config_file;
+pub mod contract;
+pub mod csv;
+pub mod datev;
+pub mod diff;
+pub mod early_retirement;
+pub mod elster;
+pub mod equity;
+pub mod error;
+pub mod eur;
+pub mod flat_rate_expenses;
+pub mod founding_year;
+pub mod freelancer;
+pub mod goal_seek;
+pub mod health_insurance;
+pub mod health_insurer;
+pub mod household;
+pub mod housing_benefit;
+pub mod income_tax;
+pub mod interval;
+pub mod investment_fund;
+pub mod json;
+pub mod loss;
+pub mod maintenance;
+pub mod marginal;
+pub mod minijob;
+pub mod municipality;
+mod numeric;
+pub mod observer;
+pub mod parental_leave;
+pub mod part_time;
+pub mod payroll;
+pub mod payslip;
+pub mod pension;
+pub mod pension_gap;
+pub mod pipeline;
+pub mod privatier;
+pub mod projection;
+#[cfg(feature = "proto")]
+pub mod proto;
+pub mod provenance;
+pub mod result_v2;
+pub mod retiree;
+pub mod rounding;
+pub mod scenario;
+pub mod second_job;
+pub mod sensitivity;
+#[cfg(feature = "proto")]
+pub mod service;
+pub mod sick_pay;
+pub mod simulation;
+pub mod social_security;
+pub mod spreadsheet;
+pub mod start_up_grant;
+pub mod test_vectors;
+pub mod time_account;
+pub mod trade_tax;
+pub mod verify;
+pub mod waterfall;
+pub mod what_if;
 
 /// Input data struct for the tax calculation.
-#[derive(Clone)]
+///
+/// New fields can be added in minor releases, so the struct needs to be created with [`TaxData::new`]
+/// outside of this crate.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub struct TaxData {
     /// The gross or net income of one year (depending on whether calculate or calculate_reverse is called).
     pub income: u32,
@@ -76,11 +167,87 @@ pub struct TaxData {
     /// Whether the calculations should be done for a self-employed person.
     pub self_employed: bool,
 
-    /// Whether the income should be split for two people according to tax law.
-    pub married: bool,
+    /// The type of the income tax assessment, which defines whether the income is split for two people
+    /// according to tax law.
+    pub assessment: Assessment,
+
+    /// Whether the person is a member of a church that levies the church tax (Kirchensteuer), which
+    /// is calculated with the rate of the configuration (see [`config::IncomeTaxConfig`]).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub church_member: bool,
+}
+
+impl TaxData {
+    /// Creates the tax data for the given yearly income of an employed single person without
+    /// expenses. The other values can be set with the `with_` methods.
+    pub fn new(income: u32) -> Self {
+        return TaxData {
+            income,
+            ..Default::default()
+        };
+    }
+
+    /// Sets the yearly expenses.
+    pub fn with_expenses(mut self, expenses: u32) -> Self {
+        self.expenses = expenses;
+        return self;
+    }
+
+    /// Sets the optional fixed monthly retirement insurance rate.
+    pub fn with_fixed_retirement(mut self, fixed_retirement: Option<u32>) -> Self {
+        self.fixed_retirement = fixed_retirement;
+        return self;
+    }
+
+    /// Sets whether the calculations should be done for a self-employed person.
+    pub fn with_self_employed(mut self, self_employed: bool) -> Self {
+        self.self_employed = self_employed;
+        return self;
+    }
+
+    /// Sets the type of the income tax assessment.
+    pub fn with_assessment(mut self, assessment: Assessment) -> Self {
+        self.assessment = assessment;
+        return self;
+    }
+
+    /// Sets whether the person is a member of a church that levies the church tax.
+    pub fn with_church_member(mut self, church_member: bool) -> Self {
+        self.church_member = church_member;
+        return self;
+    }
+}
+
+/// Types of the income tax assessment (Veranlagungsart).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Assessment {
+    /// Individual assessment with the basic tariff (Einzelveranlagung, Grundtarif).
+    #[default]
+    Single,
+    /// Joint assessment of a married couple with the splitting tariff (Zusammenveranlagung).
+    Joint,
+    /// Assessment with the splitting tariff in the year after the death of the spouse
+    /// (Verwitwetensplitting, § 32a Abs. 6 Nr. 1 EStG). Other than for the joint assessment, only the
+    /// income of one person is assessed.
+    WidowedSplitting,
+}
+
+impl Assessment {
+    /// Returns whether the splitting tariff applies for this type of assessment.
+    pub fn is_splitting(&self) -> bool {
+        return match self {
+            Assessment::Single => false,
+            Assessment::Joint | Assessment::WidowedSplitting => true,
+        };
+    }
 }
 
 /// Result struct of the tax calculation.
+///
+/// New fields can be added in minor releases, so the struct needs to be created with
+/// [`TaxResult::new`] outside of this crate.
+#[non_exhaustive]
 pub struct TaxResult {
     /// The gross income before deducting social security taxes and income taxes.
     pub gross_income: i32,
@@ -91,108 +258,239 @@ pub struct TaxResult {
     /// The social security taxes that were deducted from the gross income.
     pub social_security_taxes: u32,
 
-    /// The income taxes that were deducted from the gross income.
+    /// The income taxes (including the solidarity surcharge) that were deducted from the gross
+    /// income.
     pub income_taxes: u32,
+
+    /// The church tax that was deducted from the gross income (only for members of a church).
+    pub church_tax: u32,
 }
 
 impl TaxResult {
+    /// Creates a result from the given gross income, net income, social security taxes and income
+    /// taxes without church tax (see [`TaxResult::with_church_tax`]).
+    pub fn new(
+        gross_income: i32,
+        net_income: i32,
+        social_security_taxes: u32,
+        income_taxes: u32,
+    ) -> Self {
+        return TaxResult {
+            gross_income,
+            net_income,
+            social_security_taxes,
+            income_taxes,
+            church_tax: 0,
+        };
+    }
+
+    /// Sets the church tax that was deducted from the gross income (which needs to be considered in
+    /// the net income already).
+    pub fn with_church_tax(mut self, church_tax: u32) -> Self {
+        self.church_tax = church_tax;
+        return self;
+    }
+
+    /// Returns the income taxes including the church tax.
+    pub fn get_total_income_taxes(&self) -> u32 {
+        return self.income_taxes + self.church_tax;
+    }
+
     /// Returns how much of the gross income was spent on social security and income taxes.
     pub fn get_tax_ratio(&self) -> f32 {
-        let taxes = (self.social_security_taxes + self.income_taxes) as f32;
+        let taxes = (self.social_security_taxes + self.get_total_income_taxes()) as f32;
         return taxes / (self.net_income as f32 + taxes);
     }
+
+    /// Returns the differences from this (base) result to the given other result.
+    pub fn diff(&self, other: &TaxResult) -> diff::TaxResultDiff {
+        return diff::calculate(self, other);
+    }
 }
 
 /// Calculates social security taxes and income taxes based on the given income.
 ///
 /// Returns the remaining net income and the calculated social security taxes and income taxes.
-pub fn calculate(config: &config::Config, tax_data: &TaxData) -> Result<TaxResult, &'static str> {
-    if tax_data.expenses < tax_data.income
-        && tax_data.income - tax_data.expenses > std::i32::MAX as u32
+pub fn calculate(config: &config::Config, tax_data: &TaxData) -> Result<TaxResult, error::Error> {
+    return calculate_with_observer(config, tax_data, &mut observer::NoObserver);
+}
+
+/// Calculates social security taxes and income taxes like [`calculate`], but notifies the given
+/// observer about all intermediate values of the calculation.
+pub fn calculate_with_observer(
+    config: &config::Config,
+    tax_data: &TaxData,
+    observer: &mut dyn observer::CalculationObserver,
+) -> Result<TaxResult, error::Error> {
+    let parameters = pipeline::StepParameters::new(config, tax_data);
+    return calculate_with_parameters(config, tax_data, &parameters, observer);
+}
+
+/// Calculates the taxes like [`calculate_with_observer`] with the default steps of the pipeline and
+/// the given converted configuration values (see [`pipeline::StepParameters::new`]).
+fn calculate_with_parameters(
+    config: &config::Config,
+    tax_data: &TaxData,
+    parameters: &pipeline::StepParameters,
+    observer: &mut dyn observer::CalculationObserver,
+) -> Result<TaxResult, error::Error> {
+    if tax_data.expenses < tax_data.income && tax_data.income - tax_data.expenses > i32::MAX as u32
     {
-        return Err("Input values are too large to fit for the signed output.");
+        return Err(error::Error::input_too_large());
     }
 
-    // calculate the social security taxes
-    let social_security = social_security::calculate(
-        &config.health_insurance,
-        &config.retirement_insurance,
-        &config.unemployment_insurance,
-        &tax_data,
+    let steps = pipeline::DEFAULT_STEPS.iter().copied();
+    let state = pipeline::run_with_parameters(steps, config, tax_data, parameters, observer)?;
+
+    return Ok(state.to_result());
+}
+
+/// Calculates the social security taxes and the taxable income that remains after deducting the
+/// social security taxes and the expenses from the gross income.
+///
+/// Returns the social security taxes and the taxable income.
+pub(crate) fn calculate_taxable_income(
+    config: &config::Config,
+    tax_data: &TaxData,
+) -> Result<(u32, u32), error::Error> {
+    // run the default steps up to the calculation of the taxable income
+    let state = pipeline::run(
+        pipeline::default_steps_until(pipeline::Stage::TaxableIncome),
+        config,
+        tax_data,
+        &mut observer::NoObserver,
     )?;
 
-    // reduce income by social security taxes and calculate income taxes on this
-    let deductions = social_security + tax_data.expenses;
-    let taxable_income = match deductions < tax_data.income {
-        true => tax_data.income - deductions,
-        false => 0,
-    };
-    let taxes = income_tax::calculate(&config.income_tax, taxable_income, tax_data.married);
-
-    // store the results in the result struct
-    let tax_result = TaxResult {
-        gross_income: tax_data.income as i32,
-        net_income: (tax_data.income as i64
-            - tax_data.expenses as i64
-            - social_security as i64
-            - taxes as i64) as i32,
-        social_security_taxes: social_security as u32,
-        income_taxes: taxes,
-    };
-
-    return Ok(tax_result);
+    return Ok((state.social_security_taxes, state.taxable_income));
 }
 
+/// Maximum number of iterations of the reverse calculation before it fails (the estimation usually
+/// reaches the net income within a few iterations).
+pub const MAX_REVERSE_ITERATIONS: u32 = 100;
+
 /// Calculates social security taxes and income taxes and from that the gross income based on the given net income.
 ///
 /// This is the reverse calculation of the normal tax calculation, which would calculate the taxes and the net income
 /// from the gross income.
 ///
-/// Returns the remaining net income and the calculated social security taxes and income taxes.
+/// Returns the remaining net income and the calculated social security taxes and income taxes, or
+/// an error if the estimation of the gross income does not reach the net income within
+/// [`MAX_REVERSE_ITERATIONS`] iterations.
 pub fn calculate_reverse(
     config: &config::Config,
     tax_data: &TaxData,
-) -> Result<TaxResult, &'static str> {
+) -> Result<TaxResult, error::Error> {
+    return calculate_reverse_with_observer(config, tax_data, &mut observer::NoObserver);
+}
+
+/// Calculates the gross income based on the given net income like [`calculate_reverse`], but
+/// notifies the given observer about every iteration of the estimation of the gross income.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip_all, fields(net_income = tax_data.income))
+)]
+pub fn calculate_reverse_with_observer(
+    config: &config::Config,
+    tax_data: &TaxData,
+    observer: &mut dyn observer::CalculationObserver,
+) -> Result<TaxResult, error::Error> {
     let mut estimation = tax_data.income as f32 * 1.5; // first rough estimation of the gross income
 
-    loop {
-        // use given tax data (configuration) input, but replace the income
-        // value with the estimated gross income
-        let mut estimated_tax_data = tax_data.clone();
+    // use given tax data (configuration) input, but replace the income value with the estimated
+    // gross income in every iteration (the converted configuration values don't depend on it)
+    let mut estimated_tax_data = tax_data.clone();
+    let parameters = pipeline::StepParameters::new(config, tax_data);
+
+    for _ in 0..MAX_REVERSE_ITERATIONS {
         estimated_tax_data.income = estimation as u32;
-        let estimated_tax_data = estimated_tax_data;
 
         // calculate net income from the estimated gross income value
-        let tax_result = calculate(config, &estimated_tax_data)?;
+        let tax_result = calculate_with_parameters(
+            config,
+            &estimated_tax_data,
+            &parameters,
+            &mut observer::NoObserver,
+        )?;
+        observer.on_reverse_iteration(estimated_tax_data.income, tax_result.net_income);
 
         // check how close the estimation of the gross income was by comparing
         // the calculated net income to the target net income value
         let estimation_difference = tax_result.net_income - tax_data.income as i32;
-        estimation = estimation as f32 * (1.0 - estimation_difference as f32 / estimation as f32);
+        #[cfg(feature = "tracing")]
+        tracing::event!(
+            tracing::Level::DEBUG,
+            estimated_gross_income = estimated_tax_data.income,
+            net_income = tax_result.net_income,
+            estimation_difference,
+            "estimated the gross income"
+        );
+        estimation *= 1.0 - estimation_difference as f32 / estimation;
 
         // loop until the estimation of the gross income lead to the expected net income
         if estimation_difference == 0 {
             return Ok(tax_result);
         }
     }
+
+    // the estimation does not converge, e.g., if no gross income results in the net income
+    return Err(error::Error::NetIncomeNotReachable);
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{calculate, calculate_reverse};
+    use crate::{Assessment, calculate, calculate_reverse};
     use approx::abs_diff_eq;
 
+    #[test]
+    fn test_tax_data_builder() {
+        let tax_data = crate::TaxData::new(50000)
+            .with_expenses(1000)
+            .with_fixed_retirement(Some(600))
+            .with_self_employed(true)
+            .with_assessment(Assessment::Joint)
+            .with_church_member(true);
+
+        assert_eq!(tax_data.income, 50000);
+        assert_eq!(tax_data.expenses, 1000);
+        assert_eq!(tax_data.fixed_retirement, Some(600));
+        assert!(tax_data.self_employed);
+        assert_eq!(tax_data.assessment, Assessment::Joint);
+        assert!(tax_data.church_member);
+    }
+
+    #[test]
+    fn test_church_tax() {
+        let config = crate::config::Config::default();
+        let tax_data = crate::TaxData::new(60000);
+
+        let result = calculate(&config, &tax_data).unwrap();
+        let church_result = calculate(&config, &tax_data.clone().with_church_member(true)).unwrap();
+        let church_tax =
+            crate::income_tax::calculate_church_tax(&config.income_tax, result.income_taxes);
+
+        // no solidarity surcharge applies on this income
+        assert!(church_tax > 0);
+        assert_eq!(church_result.income_taxes, result.income_taxes);
+        assert_eq!(church_result.church_tax, church_tax);
+        assert_eq!(result.church_tax, 0);
+        assert_eq!(
+            church_result.net_income,
+            result.net_income - church_tax as i32
+        );
+
+        let reverse_result = calculate_reverse(
+            &config,
+            &crate::TaxData::new(church_result.net_income as u32).with_church_member(true),
+        )
+        .unwrap();
+        assert!(reverse_result.gross_income.abs_diff(60000) <= 5);
+    }
+
     #[test]
     fn test_negative_net_income_employed() {
         let config = crate::config::Config::default();
 
-        let tax_data = crate::TaxData {
-            income: 0,
-            expenses: 1500,
-            fixed_retirement: None,
-            self_employed: false,
-            married: false,
-        };
+        let tax_data = crate::TaxData::new(0).with_expenses(1500);
 
         let result = calculate(&config, &tax_data).unwrap();
 
@@ -207,16 +505,13 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "year-2025")]
     fn test_negative_net_income_self_employed() {
         let config = crate::config::create(2025).unwrap();
 
-        let tax_data = crate::TaxData {
-            income: 0,
-            expenses: 1500,
-            fixed_retirement: None,
-            self_employed: true,
-            married: false,
-        };
+        let tax_data = crate::TaxData::new(0)
+            .with_expenses(1500)
+            .with_self_employed(true);
 
         let result = calculate(&config, &tax_data).unwrap();
 
@@ -230,17 +525,30 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(feature = "year-2025")]
+    fn test_widowed_splitting() {
+        let config = crate::config::create(2025).unwrap();
+
+        let mut tax_data = crate::TaxData::new(70000).with_assessment(Assessment::WidowedSplitting);
+        let widowed = calculate(&config, &tax_data).unwrap();
+
+        tax_data.assessment = Assessment::Joint;
+        let joint = calculate(&config, &tax_data).unwrap();
+
+        tax_data.assessment = Assessment::Single;
+        let single = calculate(&config, &tax_data).unwrap();
+
+        // the splitting tariff applies to the income of the widowed person
+        assert_eq!(widowed.income_taxes, joint.income_taxes);
+        assert!(widowed.income_taxes < single.income_taxes);
+    }
+
     #[test]
     fn test_reverse_tax_calculation() {
         let config = crate::config::Config::default();
 
-        let tax_data_gross = crate::TaxData {
-            income: 43000,
-            expenses: 1500,
-            fixed_retirement: None,
-            self_employed: false,
-            married: false,
-        };
+        let tax_data_gross = crate::TaxData::new(43000).with_expenses(1500);
 
         // calculate net income from the given gross income
         let net_income = calculate(&config, &tax_data_gross).unwrap();
@@ -257,4 +565,17 @@ mod tests {
             epsilon = 1 // the gross income can vary a bit due to rounding up of the net income
         ));
     }
+
+    #[test]
+    fn test_reverse_calculation_of_unreachable_net_income() {
+        let config = crate::config::Config::default();
+
+        // the minimum contributions of a self-employed person result in a negative net income for
+        // every small gross income
+        let tax_data = crate::TaxData::new(0).with_self_employed(true);
+        assert_eq!(
+            calculate_reverse(&config, &tax_data).err(),
+            Some(crate::error::Error::NetIncomeNotReachable)
+        );
+    }
 }
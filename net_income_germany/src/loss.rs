@@ -0,0 +1,218 @@
+//! Years with a loss (e.g., of a self-employed person with a net business loss).
+//!
+//! The input data of the tax calculation has an unsigned income, so a year with a loss is given as
+//! signed profit instead (income minus business expenses). A negative profit leads to zero income
+//! taxes and, for self-employed persons, to the minimum contributions of the health insurance. The
+//! loss itself remains usable for other years (Verlustabzug, § 10d EStG).
+//!
+//! Whenever the deductions exceed the income, the taxable income is zero. The exceeding amounts are
+//! reported as losses with their category, because only a loss of the income itself (expenses
+//! exceeding the income) is usable in other years, while exceeding social security deductions
+//! (Sonderausgaben) are lost.
+
+use crate::config::Config;
+use crate::error::Error;
+use crate::{Assessment, TaxData, TaxResult, calculate as calculate_tax};
+
+/// Input data struct for the tax calculation with a signed yearly profit.
+#[derive(Clone)]
+pub struct ProfitData {
+    /// The profit of one year (income minus business expenses), negative for a loss.
+    pub profit: i64,
+
+    /// Optional value of a fixed monthly retirement insurance rate.
+    pub fixed_retirement: Option<u32>,
+
+    /// Whether the calculations should be done for a self-employed person.
+    pub self_employed: bool,
+
+    /// The type of the income tax assessment.
+    pub assessment: Assessment,
+}
+
+impl ProfitData {
+    /// Converts the profit into the input data of the tax calculation (a loss is given as expenses
+    /// without any income).
+    pub fn to_tax_data(&self) -> Result<TaxData, Error> {
+        let amount = u32::try_from(self.profit.unsigned_abs())
+            .map_err(|_| Error::input_too_large_unsigned())?;
+
+        let (income, expenses) = match self.profit < 0 {
+            true => (0, amount),
+            false => (amount, 0),
+        };
+
+        return Ok(TaxData::new(income)
+            .with_expenses(expenses)
+            .with_fixed_retirement(self.fixed_retirement)
+            .with_self_employed(self.self_employed)
+            .with_assessment(self.assessment));
+    }
+}
+
+/// Categories of losses with different treatment in other years.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LossCategory {
+    /// The expenses exceed the income. The loss can be deducted in other years (Verlustvortrag or
+    /// Verlustrücktrag).
+    Income,
+    /// The social security deductions exceed the remaining income. The exceeding deductions cannot
+    /// be used in other years.
+    Deductions,
+}
+
+/// Amount of deductions that exceed the income and could not be considered for the taxable income.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LossResult {
+    /// The amount that exceeds the income.
+    pub amount: u32,
+
+    /// The category of the deductions that exceed the income.
+    pub category: LossCategory,
+}
+
+impl LossResult {
+    /// Returns whether the loss can be deducted in other years.
+    pub fn is_usable(&self) -> bool {
+        return self.category == LossCategory::Income;
+    }
+}
+
+/// Result struct of the tax calculation for a year with a profit or a loss.
+pub struct ProfitResult {
+    /// The result of the tax calculation. The net income is the profit after the social security
+    /// taxes and the income taxes.
+    pub tax_result: TaxResult,
+
+    /// The deductions that exceed the income (empty for a year with a taxable income).
+    pub losses: Vec<LossResult>,
+}
+
+impl ProfitResult {
+    /// Returns the loss of the year that can be deducted in other years.
+    pub fn get_usable_loss(&self) -> u32 {
+        return self
+            .losses
+            .iter()
+            .filter(|loss| loss.is_usable())
+            .map(|loss| loss.amount)
+            .sum();
+    }
+}
+
+/// Calculates the social security taxes and the income taxes for the given yearly profit.
+pub fn calculate(config: &Config, data: &ProfitData) -> Result<ProfitResult, Error> {
+    let tax_data = data.to_tax_data()?;
+
+    return Ok(ProfitResult {
+        tax_result: calculate_tax(config, &tax_data)?,
+        losses: calculate_losses(config, &tax_data)?,
+    });
+}
+
+/// Calculates the deductions that exceed the income of the given tax data per category, instead of
+/// only reducing the taxable income to zero.
+///
+/// Returns an empty list if the income covers all deductions.
+pub fn calculate_losses(config: &Config, tax_data: &TaxData) -> Result<Vec<LossResult>, Error> {
+    let (social_security_taxes, _) = crate::calculate_taxable_income(config, tax_data)?;
+    let mut losses = Vec::new();
+
+    let income_loss = tax_data.expenses.saturating_sub(tax_data.income);
+    if income_loss > 0 {
+        losses.push(LossResult {
+            amount: income_loss,
+            category: LossCategory::Income,
+        });
+    }
+
+    let remaining_income = tax_data.income.saturating_sub(tax_data.expenses);
+    let deductions_loss = social_security_taxes.saturating_sub(remaining_income);
+    if deductions_loss > 0 {
+        losses.push(LossResult {
+            amount: deductions_loss,
+            category: LossCategory::Deductions,
+        });
+    }
+
+    return Ok(losses);
+}
+
+#[cfg(all(test, feature = "year-2025"))]
+mod tests {
+    use super::*;
+    use crate::config::create as create_config;
+
+    fn create_data(profit: i64) -> ProfitData {
+        return ProfitData {
+            profit,
+            fixed_retirement: Some(0),
+            self_employed: true,
+            assessment: Assessment::Single,
+        };
+    }
+
+    #[test]
+    fn test_year_with_loss() {
+        let config = create_config(2025).unwrap();
+
+        let result = calculate(&config, &create_data(-8000)).unwrap();
+        let minimum = calculate(&config, &create_data(0)).unwrap();
+
+        assert_eq!(result.get_usable_loss(), 8000);
+        assert_eq!(result.tax_result.income_taxes, 0);
+        assert_eq!(
+            result.tax_result.social_security_taxes,
+            minimum.tax_result.social_security_taxes
+        );
+        assert_eq!(
+            result.tax_result.net_income,
+            -8000 - result.tax_result.social_security_taxes as i32
+        );
+    }
+
+    #[test]
+    fn test_year_with_profit() {
+        let config = create_config(2025).unwrap();
+
+        let result = calculate(&config, &create_data(50000)).unwrap();
+        assert!(result.losses.is_empty());
+        assert_eq!(result.tax_result.gross_income, 50000);
+        assert!(result.tax_result.income_taxes > 0);
+    }
+
+    #[test]
+    fn test_losses_per_category() {
+        let config = create_config(2025).unwrap();
+        let mut tax_data = create_data(0).to_tax_data().unwrap();
+
+        // the minimum social security taxes exceed the income
+        tax_data.income = 1000;
+        let losses = calculate_losses(&config, &tax_data).unwrap();
+        assert_eq!(losses.len(), 1);
+        assert_eq!(losses[0].category, LossCategory::Deductions);
+        assert!(!losses[0].is_usable());
+
+        // the expenses exceed the income and all social security taxes are exceeding deductions
+        tax_data.expenses = 3000;
+        let (social_security_taxes, _) =
+            crate::calculate_taxable_income(&config, &tax_data).unwrap();
+        assert_eq!(
+            calculate_losses(&config, &tax_data).unwrap(),
+            vec![
+                LossResult {
+                    amount: 2000,
+                    category: LossCategory::Income,
+                },
+                LossResult {
+                    amount: social_security_taxes,
+                    category: LossCategory::Deductions,
+                },
+            ]
+        );
+
+        // no losses if the income covers all deductions
+        tax_data.income = 50000;
+        assert!(calculate_losses(&config, &tax_data).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,157 @@
+//! Maintenance payments to a divorced or permanently separated spouse (Realsplitting).
+//!
+//! With the consent of the recipient, the payer can deduct the maintenance payments up to 13805 per
+//! year as special expenses (§ 10 Abs. 1a Nr. 1 EStG). In return, the recipient has to pay income
+//! taxes on the payments (§ 22 Nr. 1a EStG), after deducting the lump sum for income-related
+//! expenses. As the payer usually has the higher income, the tax saving of the payer is often larger
+//! than the additional taxes of the recipient, which the payer then has to compensate
+//! (Nachteilsausgleich).
+
+use crate::config::Config;
+use crate::error::Error;
+use crate::observer::CalculationObserver;
+use crate::pipeline::{CalculationState, DeductionStep, Pipeline, Stage, TaxStep};
+use crate::{TaxData, TaxResult};
+
+/// Maximum yearly maintenance payments that can be deducted by the payer.
+pub const MAINTENANCE_LIMIT: u32 = 13805;
+
+/// Lump sum for income-related expenses of the recipient (Werbungskosten-Pauschbetrag, § 9a Nr. 3
+/// EStG).
+const RECIPIENT_ALLOWANCE: u32 = 102;
+
+/// Adds income that is not subject to social security to the taxable income.
+struct TaxableIncomeAddition(u32);
+
+impl TaxStep for TaxableIncomeAddition {
+    fn apply(
+        &self,
+        state: &mut CalculationState,
+        _observer: &mut dyn CalculationObserver,
+    ) -> Result<(), Error> {
+        state.taxable_income = state.taxable_income.saturating_add(self.0);
+
+        return Ok(());
+    }
+}
+
+/// Result of the evaluation of the Realsplitting for both former spouses.
+pub struct RealsplittingResult {
+    /// The income taxes (including the church tax) that the payer saves by deducting the payments.
+    pub payer_tax_saving: u32,
+
+    /// The additional income taxes (including the church tax) of the recipient on the payments.
+    pub recipient_additional_taxes: u32,
+}
+
+impl RealsplittingResult {
+    /// Returns the benefit of the Realsplitting for both former spouses together (negative if the
+    /// additional taxes of the recipient exceed the tax saving of the payer).
+    pub fn get_benefit(&self) -> i64 {
+        return self.payer_tax_saving as i64 - self.recipient_additional_taxes as i64;
+    }
+
+    /// Returns whether the Realsplitting is beneficial for both former spouses together, if the payer
+    /// compensates the additional taxes of the recipient.
+    pub fn is_beneficial(&self) -> bool {
+        return self.get_benefit() > 0;
+    }
+}
+
+/// Calculates the taxes of the payer, who deducts the given yearly maintenance payments (up to the
+/// maximum). The net income of the result does not include the payments.
+pub fn calculate_payer(
+    config: &Config,
+    tax_data: &TaxData,
+    payments: u32,
+) -> Result<TaxResult, Error> {
+    let mut pipeline = Pipeline::default();
+    pipeline.insert_before(
+        Stage::TaxableIncome,
+        Box::new(DeductionStep(payments.min(MAINTENANCE_LIMIT))),
+    );
+
+    return pipeline.calculate(config, tax_data);
+}
+
+/// Calculates the taxes of the recipient, who has to pay income taxes on the given yearly
+/// maintenance payments (up to the maximum that can be deducted by the payer). The net income of the
+/// result includes the payments.
+pub fn calculate_recipient(
+    config: &Config,
+    tax_data: &TaxData,
+    payments: u32,
+) -> Result<TaxResult, Error> {
+    let taxed_payments = payments
+        .min(MAINTENANCE_LIMIT)
+        .saturating_sub(RECIPIENT_ALLOWANCE);
+
+    // the payments are added after the taxable income was calculated from the salary
+    let mut pipeline = Pipeline::default();
+    pipeline.insert_after(
+        Stage::TaxableIncome,
+        Box::new(TaxableIncomeAddition(taxed_payments)),
+    );
+    let result = pipeline.calculate(config, tax_data)?;
+
+    return Ok(TaxResult {
+        net_income: (result.net_income as i64 + payments as i64) as i32,
+        ..result
+    });
+}
+
+/// Evaluates whether the Realsplitting with the given yearly maintenance payments is beneficial for
+/// the payer and the recipient together.
+pub fn evaluate(
+    config: &Config,
+    payer: &TaxData,
+    recipient: &TaxData,
+    payments: u32,
+) -> Result<RealsplittingResult, Error> {
+    let payer_regular = crate::calculate(config, payer)?;
+    let payer_result = calculate_payer(config, payer, payments)?;
+    let recipient_regular = crate::calculate(config, recipient)?;
+    let recipient_result = calculate_recipient(config, recipient, payments)?;
+
+    return Ok(RealsplittingResult {
+        payer_tax_saving: payer_regular.get_total_income_taxes()
+            - payer_result.get_total_income_taxes(),
+        recipient_additional_taxes: recipient_result.get_total_income_taxes()
+            - recipient_regular.get_total_income_taxes(),
+    });
+}
+
+#[cfg(all(test, feature = "year-2025"))]
+mod tests {
+    use super::*;
+    use crate::config::create as create_config;
+
+    #[test]
+    fn test_payer_and_recipient() {
+        let config = create_config(2025).unwrap();
+
+        // payments above the maximum are not deductible
+        let payer = calculate_payer(&config, &TaxData::new(90000), 20000).unwrap();
+        let expected = calculate_payer(&config, &TaxData::new(90000), MAINTENANCE_LIMIT).unwrap();
+        assert_eq!(payer.income_taxes, expected.income_taxes);
+
+        // no income taxes for the recipient with the payments below the basic allowance
+        let recipient = calculate_recipient(&config, &TaxData::new(0), 10000).unwrap();
+        assert_eq!(recipient.income_taxes, 0);
+        assert_eq!(recipient.net_income, 10000);
+    }
+
+    #[test]
+    fn test_evaluate_realsplitting() {
+        let config = create_config(2025).unwrap();
+
+        let result = evaluate(&config, &TaxData::new(90000), &TaxData::new(10000), 12000).unwrap();
+        assert!(result.payer_tax_saving > 0);
+        assert!(result.recipient_additional_taxes > 0);
+        assert!(result.is_beneficial());
+
+        // no benefit if the recipient has a higher income than the payer
+        let result = evaluate(&config, &TaxData::new(30000), &TaxData::new(90000), 12000).unwrap();
+        assert!(!result.is_beneficial());
+    }
+}
@@ -0,0 +1,92 @@
+//! Calculation of how much of an additional income (e.g., overtime or a side job) remains as net
+//! income.
+//!
+//! The marginal burden is calculated exactly by comparing the results with and without the
+//! additional income, so that the progression of the income tax as well as the income limits of the
+//! social security insurances are considered.
+
+use crate::config::Config;
+use crate::error::Error;
+use crate::{TaxData, TaxResult, calculate as calculate_taxes};
+
+/// Result struct of the marginal income calculation.
+pub struct MarginalResult {
+    /// The tax result without the additional income.
+    pub base: TaxResult,
+
+    /// The tax result including the additional income.
+    pub with_additional_income: TaxResult,
+
+    /// The additional gross income.
+    pub additional_income: u32,
+}
+
+impl MarginalResult {
+    /// Returns how much of the additional gross income remains as net income.
+    pub fn get_additional_net_income(&self) -> i32 {
+        return self.with_additional_income.net_income - self.base.net_income;
+    }
+
+    /// Returns the share \[0,1\] of the additional gross income spent on social security and income taxes.
+    pub fn get_marginal_tax_ratio(&self) -> f32 {
+        if self.additional_income == 0 {
+            return 0.0;
+        }
+
+        return 1.0 - self.get_additional_net_income() as f32 / self.additional_income as f32;
+    }
+}
+
+/// Calculates the taxes with and without the given additional gross income on top of the income of
+/// the given tax data.
+pub fn calculate(
+    config: &Config,
+    tax_data: &TaxData,
+    additional_income: u32,
+) -> Result<MarginalResult, Error> {
+    let mut tax_data_with_additional_income = tax_data.clone();
+    tax_data_with_additional_income.income = tax_data
+        .income
+        .checked_add(additional_income)
+        .ok_or(Error::input_too_large())?;
+
+    return Ok(MarginalResult {
+        base: calculate_taxes(config, tax_data)?,
+        with_additional_income: calculate_taxes(config, &tax_data_with_additional_income)?,
+        additional_income,
+    });
+}
+
+#[cfg(all(test, feature = "year-2025"))]
+mod tests {
+    use super::*;
+    use crate::config::create as create_config;
+
+    #[test]
+    fn test_marginal_burden_drops_above_income_limits() {
+        let config = create_config(2025).unwrap();
+
+        // below the income limits, social security taxes apply on the additional income
+        let below_limits = calculate(&config, &TaxData::new(50000), 5000).unwrap();
+
+        // above the income limits, only the income taxes apply on the additional income
+        let above_limits = calculate(&config, &TaxData::new(150000), 5000).unwrap();
+        assert_eq!(
+            above_limits.with_additional_income.social_security_taxes,
+            above_limits.base.social_security_taxes
+        );
+
+        assert!(below_limits.get_marginal_tax_ratio() > 0.4);
+        assert!(above_limits.get_marginal_tax_ratio() < below_limits.get_marginal_tax_ratio());
+        assert!(above_limits.get_additional_net_income() > 2500);
+    }
+
+    #[test]
+    fn test_no_additional_income() {
+        let config = create_config(2025).unwrap();
+
+        let result = calculate(&config, &TaxData::new(50000), 0).unwrap();
+        assert_eq!(result.get_additional_net_income(), 0);
+        assert_eq!(result.get_marginal_tax_ratio(), 0.0);
+    }
+}
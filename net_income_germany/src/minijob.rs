@@ -0,0 +1,226 @@
+//! Costs of marginal employments (Minijobs) for employers and employees.
+//!
+//! For a Minijob, the employer pays flat-rate contributions to the health and retirement insurance,
+//! a flat-rate income tax and some levies, while the income of the employee stays untouched. Only
+//! the difference to the full premium of the retirement insurance is paid by the employee, unless the
+//! employee opted out of the retirement insurance (Befreiung von der Rentenversicherungspflicht).
+//!
+//! The flat-rate contributions are lower for Minijobs in private households than for Minijobs in
+//! commercial businesses.
+//!
+//! Above the income limit of a Minijob begins the transition area (Übergangsbereich, § 20 Abs. 2
+//! SGB IV), in which the social security contributions are calculated on a reduced contribution
+//! base that rises from the lower to the upper limit of the transition area to the full income (see
+//! [`midijob_reduced_base`]).
+
+use crate::config::{Config, MinijobRatesConfig};
+use crate::error::Error;
+use crate::numeric::{Number, Numeric};
+
+/// Types of Minijobs with different flat-rate contributions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MinijobType {
+    /// Minijob in a commercial business (gewerblicher Minijob).
+    Commercial,
+    /// Minijob in a private household (Minijob im Privathaushalt).
+    Household,
+}
+
+/// Input data struct for the Minijob calculation.
+#[derive(Clone)]
+pub struct MinijobData {
+    /// The monthly income of the Minijob.
+    pub monthly_income: u32,
+
+    /// The type of the Minijob.
+    pub minijob_type: MinijobType,
+
+    /// Whether the employee opted out of the retirement insurance.
+    pub retirement_opt_out: bool,
+}
+
+/// Result struct of the Minijob calculation with yearly values.
+pub struct MinijobResult {
+    /// The yearly income of the Minijob.
+    pub income: u32,
+
+    /// The flat-rate contributions of the employer for the health insurance.
+    pub employer_health_insurance: u32,
+
+    /// The flat-rate contributions of the employer for the retirement insurance.
+    pub employer_retirement_insurance: u32,
+
+    /// The flat-rate income tax that is paid by the employer.
+    pub employer_flat_tax: u32,
+
+    /// The levies that are paid by the employer.
+    pub employer_levies: u32,
+
+    /// The contributions of the employee for the retirement insurance.
+    pub employee_retirement_insurance: u32,
+
+    /// The net income of the employee.
+    pub net_income: u32,
+}
+
+impl MinijobResult {
+    /// Returns the total yearly costs of the employer (income plus all flat-rate contributions,
+    /// taxes and levies).
+    pub fn get_employer_costs(&self) -> u32 {
+        return self.income
+            + self.employer_health_insurance
+            + self.employer_retirement_insurance
+            + self.employer_flat_tax
+            + self.employer_levies;
+    }
+}
+
+/// Calculates the yearly costs of the employer and the net income of the employee for a Minijob.
+pub fn calculate(config: &Config, data: &MinijobData) -> Result<MinijobResult, Error> {
+    if data.monthly_income as f32 > config.minijob.income_limit {
+        return Err(Error::MinijobLimitExceeded);
+    }
+
+    let rates = get_rates(config, data.minijob_type);
+    let income = data.monthly_income * 12;
+    let calculate_payment =
+        |premium: Number| -> u32 { (Number::from_u32(income) * premium).to_u32() };
+
+    // the employee pays the difference between the full premium and the flat-rate premium of the
+    // employer for the retirement insurance
+    let employee_retirement_insurance = match data.retirement_opt_out {
+        true => 0,
+        false => calculate_payment(
+            Number::from_f32(config.retirement_insurance.premium)
+                - Number::from_f32(rates.retirement_insurance),
+        ),
+    };
+
+    return Ok(MinijobResult {
+        income,
+        employer_health_insurance: calculate_payment(Number::from_f32(rates.health_insurance)),
+        employer_retirement_insurance: calculate_payment(Number::from_f32(
+            rates.retirement_insurance,
+        )),
+        employer_flat_tax: calculate_payment(Number::from_f32(rates.flat_tax)),
+        employer_levies: calculate_payment(Number::from_f32(rates.levies)),
+        employee_retirement_insurance,
+        net_income: income - employee_retirement_insurance,
+    });
+}
+
+/// Returns the reduced monthly contribution base of the social security for the given monthly income
+/// in the transition area (beitragspflichtige Einnahme, § 20 Abs. 2a SGB IV), on which the
+/// contributions of the employer and the employee are calculated together.
+///
+/// Returns the income itself for incomes outside of the transition area.
+pub fn midijob_reduced_base(config: &Config, monthly_income: f32) -> f32 {
+    let lower_limit = config.midijob.lower_limit;
+    let upper_limit = config.midijob.upper_limit;
+    if monthly_income <= lower_limit || monthly_income > upper_limit {
+        return monthly_income;
+    }
+
+    let range = Number::from_f32(upper_limit) - Number::from_f32(lower_limit);
+    let factor = Number::from_f32(config.midijob.factor);
+    let lower_limit = Number::from_f32(lower_limit);
+
+    return (factor * lower_limit
+        + (Number::from_f32(upper_limit) / range - lower_limit / range * factor)
+            * (Number::from_f32(monthly_income) - lower_limit))
+        .to_f32();
+}
+
+fn get_rates(config: &Config, minijob_type: MinijobType) -> &MinijobRatesConfig {
+    return match minijob_type {
+        MinijobType::Commercial => &config.minijob.commercial,
+        MinijobType::Household => &config.minijob.household,
+    };
+}
+
+#[cfg(all(test, feature = "year-2025"))]
+mod tests {
+    use super::*;
+    use crate::config::create as create_config;
+
+    fn create_data(monthly_income: u32, minijob_type: MinijobType) -> MinijobData {
+        return MinijobData {
+            monthly_income,
+            minijob_type,
+            retirement_opt_out: false,
+        };
+    }
+
+    #[test]
+    fn test_commercial_minijob_2025() {
+        let config = create_config(2025).unwrap();
+
+        let result = calculate(&config, &create_data(501, MinijobType::Commercial)).unwrap();
+        assert_eq!(result.income, 6012);
+        assert_eq!(result.employer_health_insurance, 781);
+        assert_eq!(result.employer_retirement_insurance, 901);
+        assert_eq!(result.employer_flat_tax, 120);
+        assert_eq!(result.employer_levies, 70);
+        assert_eq!(result.get_employer_costs(), 7884);
+        assert_eq!(result.employee_retirement_insurance, 216);
+        assert_eq!(result.net_income, 5796);
+    }
+
+    #[test]
+    fn test_household_minijob_2025() {
+        let config = create_config(2025).unwrap();
+
+        let result = calculate(&config, &create_data(501, MinijobType::Household)).unwrap();
+        assert_eq!(result.employer_health_insurance, 300);
+        assert_eq!(result.employer_retirement_insurance, 300);
+        assert_eq!(result.employer_levies, 157);
+        assert_eq!(result.employee_retirement_insurance, 817);
+    }
+
+    #[test]
+    fn test_retirement_opt_out() {
+        let config = create_config(2025).unwrap();
+        let mut data = create_data(501, MinijobType::Commercial);
+        data.retirement_opt_out = true;
+
+        let result = calculate(&config, &data).unwrap();
+        assert_eq!(result.employee_retirement_insurance, 0);
+        assert_eq!(result.net_income, result.income);
+    }
+
+    #[test]
+    fn test_midijob_reduced_base() {
+        let config = create_config(2025).unwrap();
+
+        assert_eq!(midijob_reduced_base(&config, 500.0), 500.0);
+        assert_eq!(midijob_reduced_base(&config, 2500.0), 2500.0);
+        approx::assert_abs_diff_eq!(
+            midijob_reduced_base(&config, 1000.0),
+            872.28,
+            epsilon = 0.01
+        );
+
+        // the reduced base reaches the full income at the upper limit
+        approx::assert_abs_diff_eq!(
+            midijob_reduced_base(&config, 2000.0),
+            2000.0,
+            epsilon = 0.01
+        );
+        approx::assert_abs_diff_eq!(
+            midijob_reduced_base(&config, 556.01),
+            371.58,
+            epsilon = 0.01
+        );
+    }
+
+    #[test]
+    fn test_error_above_income_limit() {
+        let config = create_config(2025).unwrap();
+
+        assert!(calculate(&config, &create_data(556, MinijobType::Commercial)).is_ok());
+        assert_eq!(
+            calculate(&config, &create_data(557, MinijobType::Commercial)).err(),
+            Some(Error::MinijobLimitExceeded)
+        );
+    }
+}
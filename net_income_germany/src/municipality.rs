@@ -0,0 +1,230 @@
+//! Municipalities with their location-dependent rates.
+//!
+//! The trade tax multiplier (Gewerbesteuer-Hebesatz) is defined by every municipality, while the rate
+//! of the church tax and the split of the nursing care insurance premium depend on the federal state.
+//! This module contains the largest German cities, so that they can be selected by name or by postal
+//! code (Postleitzahl).
+
+use crate::bundesland::Bundesland;
+
+/// Municipality with its location-dependent rates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Municipality {
+    /// The name of the municipality.
+    pub name: &'static str,
+
+    /// The federal state of the municipality.
+    pub state: Bundesland,
+
+    /// The ranges (first and last code) of the postal codes of the municipality.
+    pub postal_codes: &'static [(u32, u32)],
+
+    /// The trade tax multiplier in percent (Hebesatz, e.g., 490 for 490%).
+    pub trade_tax_multiplier: u32,
+}
+
+/// The largest German municipalities with their trade tax multipliers.
+pub const MUNICIPALITIES: [Municipality; 20] = [
+    Municipality {
+        name: "Berlin",
+        state: Bundesland::Berlin,
+        postal_codes: &[(10115, 14199)],
+        trade_tax_multiplier: 410,
+    },
+    Municipality {
+        name: "Hamburg",
+        state: Bundesland::Hamburg,
+        postal_codes: &[(20095, 21149), (22041, 22769)],
+        trade_tax_multiplier: 470,
+    },
+    Municipality {
+        name: "München",
+        state: Bundesland::Bayern,
+        postal_codes: &[(80331, 81929)],
+        trade_tax_multiplier: 490,
+    },
+    Municipality {
+        name: "Köln",
+        state: Bundesland::NordrheinWestfalen,
+        postal_codes: &[(50667, 51149)],
+        trade_tax_multiplier: 475,
+    },
+    Municipality {
+        name: "Frankfurt am Main",
+        state: Bundesland::Hessen,
+        postal_codes: &[(60306, 60599), (65929, 65936)],
+        trade_tax_multiplier: 460,
+    },
+    Municipality {
+        name: "Stuttgart",
+        state: Bundesland::BadenWuerttemberg,
+        postal_codes: &[(70173, 70629)],
+        trade_tax_multiplier: 420,
+    },
+    Municipality {
+        name: "Düsseldorf",
+        state: Bundesland::NordrheinWestfalen,
+        postal_codes: &[(40210, 40629)],
+        trade_tax_multiplier: 440,
+    },
+    Municipality {
+        name: "Leipzig",
+        state: Bundesland::Sachsen,
+        postal_codes: &[(4103, 4357)],
+        trade_tax_multiplier: 460,
+    },
+    Municipality {
+        name: "Dortmund",
+        state: Bundesland::NordrheinWestfalen,
+        postal_codes: &[(44135, 44388)],
+        trade_tax_multiplier: 485,
+    },
+    Municipality {
+        name: "Essen",
+        state: Bundesland::NordrheinWestfalen,
+        postal_codes: &[(45127, 45359)],
+        trade_tax_multiplier: 480,
+    },
+    Municipality {
+        name: "Bremen",
+        state: Bundesland::Bremen,
+        postal_codes: &[(28195, 28779)],
+        trade_tax_multiplier: 470,
+    },
+    Municipality {
+        name: "Dresden",
+        state: Bundesland::Sachsen,
+        postal_codes: &[(1067, 1328)],
+        trade_tax_multiplier: 450,
+    },
+    Municipality {
+        name: "Hannover",
+        state: Bundesland::Niedersachsen,
+        postal_codes: &[(30159, 30669)],
+        trade_tax_multiplier: 480,
+    },
+    Municipality {
+        name: "Nürnberg",
+        state: Bundesland::Bayern,
+        postal_codes: &[(90402, 90491)],
+        trade_tax_multiplier: 467,
+    },
+    Municipality {
+        name: "Duisburg",
+        state: Bundesland::NordrheinWestfalen,
+        postal_codes: &[(47051, 47279)],
+        trade_tax_multiplier: 520,
+    },
+    Municipality {
+        name: "Bochum",
+        state: Bundesland::NordrheinWestfalen,
+        postal_codes: &[(44787, 44894)],
+        trade_tax_multiplier: 495,
+    },
+    Municipality {
+        name: "Wuppertal",
+        state: Bundesland::NordrheinWestfalen,
+        postal_codes: &[(42103, 42399)],
+        trade_tax_multiplier: 490,
+    },
+    Municipality {
+        name: "Bielefeld",
+        state: Bundesland::NordrheinWestfalen,
+        postal_codes: &[(33602, 33739)],
+        trade_tax_multiplier: 480,
+    },
+    Municipality {
+        name: "Bonn",
+        state: Bundesland::NordrheinWestfalen,
+        postal_codes: &[(53111, 53229)],
+        trade_tax_multiplier: 490,
+    },
+    Municipality {
+        name: "Münster",
+        state: Bundesland::NordrheinWestfalen,
+        postal_codes: &[(48143, 48167)],
+        trade_tax_multiplier: 460,
+    },
+];
+
+/// Returns the municipality with the given name (ignoring the case), if it is contained in the
+/// list of known municipalities.
+pub fn find(name: &str) -> Option<&'static Municipality> {
+    return MUNICIPALITIES
+        .iter()
+        .find(|municipality| municipality.name.to_lowercase() == name.trim().to_lowercase());
+}
+
+impl Municipality {
+    /// Returns the rate \[0,1\] of the church tax on the income taxes in the federal state of the
+    /// municipality.
+    pub fn get_church_tax_rate(&self) -> f32 {
+        return self.state.get_church_tax_rate();
+    }
+
+    /// Returns whether the employees pay a larger share of the nursing care insurance premium than
+    /// the employers in the federal state of the municipality.
+    pub fn has_nursing_split(&self) -> bool {
+        return self.state.has_nursing_split();
+    }
+
+    /// Applies the rules of the federal state of the municipality for the social security
+    /// calculation to the given configuration.
+    pub fn adapt_config(&self, config: &mut crate::config::Config) {
+        self.state.adapt_config(config);
+    }
+}
+
+/// Returns the municipality of the given five-digit postal code, if it is contained in the list of
+/// known municipalities.
+pub fn find_by_postal_code(postal_code: &str) -> Option<&'static Municipality> {
+    let postal_code = postal_code.trim();
+    if postal_code.len() != 5 {
+        return None;
+    }
+    let code: u32 = postal_code.parse().ok()?;
+
+    return MUNICIPALITIES.iter().find(|municipality| {
+        municipality
+            .postal_codes
+            .iter()
+            .any(|(first, last)| (*first..=*last).contains(&code))
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_municipality() {
+        assert_eq!(find("München").unwrap().trade_tax_multiplier, 490);
+        assert_eq!(
+            find(" frankfurt am main").unwrap().trade_tax_multiplier,
+            460
+        );
+        assert!(find("Atlantis").is_none());
+    }
+
+    #[test]
+    fn test_find_by_postal_code() {
+        let municipality = find_by_postal_code("80331").unwrap();
+        assert_eq!(municipality.name, "München");
+        assert_eq!(municipality.get_church_tax_rate(), 0.08);
+        assert!(!municipality.has_nursing_split());
+
+        let municipality = find_by_postal_code("01067").unwrap();
+        assert_eq!(municipality.name, "Dresden");
+        assert_eq!(municipality.get_church_tax_rate(), 0.09);
+        assert!(municipality.has_nursing_split());
+
+        // the towns around Hamburg belong to Niedersachsen (e.g., Lüneburg) and Schleswig-Holstein
+        assert_eq!(find_by_postal_code("21149").unwrap().name, "Hamburg");
+        assert_eq!(find_by_postal_code("22041").unwrap().name, "Hamburg");
+        assert!(find_by_postal_code("21335").is_none());
+        assert!(find_by_postal_code("21465").is_none());
+
+        assert!(find_by_postal_code("1067").is_none());
+        assert!(find_by_postal_code("99999").is_none());
+    }
+}
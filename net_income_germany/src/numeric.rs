@@ -0,0 +1,185 @@
+//! Numeric abstraction for the internal calculations.
+//!
+//! The calculations run on `f64` by default. With the `decimal` feature, they run on
+//! `rust_decimal::Decimal` instead, which gives exact decimal semantics and reproducible results
+//! independent of floating point rounding.
+
+#[cfg(feature = "decimal")]
+use std::cell::RefCell;
+#[cfg(feature = "decimal")]
+use std::fmt::{self, Write};
+use std::ops::{Add, AddAssign, Div, Mul, Sub, SubAssign};
+
+/// Number type that is used for the internal calculations.
+#[cfg(not(feature = "decimal"))]
+pub(crate) type Number = f64;
+
+/// Number type that is used for the internal calculations.
+#[cfg(feature = "decimal")]
+pub(crate) type Number = rust_decimal::Decimal;
+
+/// Operations that are needed from a number type for the calculations.
+pub(crate) trait Numeric:
+    Copy
+    + PartialOrd
+    + Add<Output = Self>
+    + AddAssign
+    + Sub<Output = Self>
+    + SubAssign
+    + Mul<Output = Self>
+    + Div<Output = Self>
+{
+    /// Converts an integer value (e.g., an income) into the number type.
+    fn from_u32(value: u32) -> Self;
+
+    /// Converts a configuration value into the number type. For decimal numbers, the shortest decimal
+    /// representation of the value is used, so that e.g. a premium of `0.146` is exactly `0.146`.
+    fn from_f32(value: f32) -> Self;
+
+    /// Converts the number into an integer value by truncating the fractional part. Negative values
+    /// result in zero and too large values in the maximum integer value.
+    fn to_u32(self) -> u32;
+
+    /// Converts the number into a floating point value (e.g., for notifying an observer).
+    fn to_f32(self) -> f32;
+
+    /// Returns the smaller of both numbers.
+    fn min(self, other: Self) -> Self {
+        return if other < self { other } else { self };
+    }
+}
+
+/// Stack buffer for the shortest decimal representation of a configuration value, so that the
+/// conversion into a decimal number does not allocate.
+#[cfg(feature = "decimal")]
+struct DecimalBuffer {
+    bytes: [u8; 48],
+    len: usize,
+}
+
+#[cfg(feature = "decimal")]
+impl Write for DecimalBuffer {
+    fn write_str(&mut self, value: &str) -> fmt::Result {
+        let end = self.len + value.len();
+        if end > self.bytes.len() {
+            return Err(fmt::Error);
+        }
+
+        self.bytes[self.len..end].copy_from_slice(value.as_bytes());
+        self.len = end;
+        return Ok(());
+    }
+}
+
+/// Parses the shortest decimal representation of the given value with the given parse function.
+///
+/// Returns `None` if the representation does not fit into the buffer or cannot be parsed.
+#[cfg(feature = "decimal")]
+fn parse_shortest<T>(value: f32, parse: impl FnOnce(&str) -> Option<T>) -> Option<T> {
+    let mut buffer = DecimalBuffer {
+        bytes: [0; 48],
+        len: 0,
+    };
+    write!(buffer, "{}", value).ok()?;
+
+    return parse(std::str::from_utf8(&buffer.bytes[..buffer.len]).ok()?);
+}
+
+impl Numeric for f64 {
+    fn from_u32(value: u32) -> Self {
+        return value as f64;
+    }
+
+    fn from_f32(value: f32) -> Self {
+        return value as f64;
+    }
+
+    fn to_u32(self) -> u32 {
+        return self as u32;
+    }
+
+    fn to_f32(self) -> f32 {
+        return self as f32;
+    }
+}
+
+/// Number of the decimal conversions of configuration values that are kept per thread.
+#[cfg(feature = "decimal")]
+const DECIMAL_CACHE_SIZE: usize = 64;
+
+#[cfg(feature = "decimal")]
+thread_local! {
+    /// The decimal numbers of the recently converted configuration values (by their bits), so that
+    /// the shortest decimal representation is only determined once per configuration value and not
+    /// in every calculation.
+    static DECIMAL_CACHE: RefCell<[Option<(u32, rust_decimal::Decimal)>; DECIMAL_CACHE_SIZE]> =
+        const { RefCell::new([None; DECIMAL_CACHE_SIZE]) };
+}
+
+#[cfg(feature = "decimal")]
+impl Numeric for rust_decimal::Decimal {
+    fn from_u32(value: u32) -> Self {
+        return rust_decimal::Decimal::from(value);
+    }
+
+    fn from_f32(value: f32) -> Self {
+        let bits = value.to_bits();
+        let index = (bits ^ (bits >> 16)) as usize % DECIMAL_CACHE_SIZE;
+
+        return DECIMAL_CACHE.with_borrow_mut(|cache| match cache[index] {
+            Some((cached_bits, number)) if cached_bits == bits => number,
+            _ => {
+                let number = parse_shortest(value, |text| text.parse().ok()).unwrap_or_default();
+                cache[index] = Some((bits, number));
+                number
+            }
+        });
+    }
+
+    fn to_u32(self) -> u32 {
+        use rust_decimal::prelude::ToPrimitive;
+        return ToPrimitive::to_u32(&self.trunc()).unwrap_or(match self.is_sign_negative() {
+            true => 0,
+            false => u32::MAX,
+        });
+    }
+
+    fn to_f32(self) -> f32 {
+        use rust_decimal::prelude::ToPrimitive;
+        return ToPrimitive::to_f32(&self).unwrap_or_default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(Number::from_f32(1248.32).to_u32(), 1248);
+        assert_eq!((Number::from_u32(1) - Number::from_u32(2)).to_u32(), 0);
+        assert!(Numeric::min(Number::from_u32(1), Number::from_u32(2)) == Number::from_u32(1));
+    }
+
+    #[test]
+    #[cfg(feature = "decimal")]
+    fn test_decimal_conversion() {
+        assert!(Number::from_f32(0.146) == Number::from_u32(146) / Number::from_u32(1000));
+
+        // the cached conversion is used for the same value again
+        assert!(Number::from_f32(0.146) == Number::from_u32(146) / Number::from_u32(1000));
+        assert!(Number::from_f32(0.0365) == Number::from_u32(365) / Number::from_u32(10000));
+    }
+
+    #[test]
+    #[cfg(feature = "decimal")]
+    fn test_representation_too_large_for_buffer() {
+        let mut buffer = DecimalBuffer {
+            bytes: [0; 48],
+            len: 0,
+        };
+
+        assert!(buffer.write_str(&"1".repeat(48)).is_ok());
+        assert!(buffer.write_str("1").is_err());
+    }
+}
@@ -0,0 +1,190 @@
+//! Observer interface for the intermediate values of the calculation.
+//!
+//! An observer can be passed to [`crate::calculate_with_observer`] to get notified about every
+//! intermediate value of the calculation (e.g., for logging, auditing or visualizations). The reverse
+//! calculation notifies the observer passed to [`crate::calculate_reverse_with_observer`] about
+//! every estimation of the gross income. Services can forward the notifications to their logging
+//! or tracing framework to debug surprising results (with the `tracing` feature, the calculation
+//! emits spans and events of these values itself). All methods
+//! of the [`CalculationObserver`] trait have empty default implementations, so that an observer only
+//! needs to implement the methods it is interested in:
+//!
+//! ```
+//! use net_income_germany::observer::CalculationObserver;
+//!
+//! struct TaxableIncomeLogger;
+//!
+//! impl CalculationObserver for TaxableIncomeLogger {
+//!     fn on_taxable_income(&mut self, taxable_income: u32) {
+//!         println!("Taxable income: {taxable_income}");
+//!     }
+//! }
+//!
+//! let config = net_income_germany::config::create(net_income_germany::config::latest_year()).unwrap();
+//! let tax_data = net_income_germany::TaxData::new(50000);
+//!
+//! net_income_germany::calculate_with_observer(&config, &tax_data, &mut TaxableIncomeLogger).unwrap();
+//! ```
+
+use crate::config::TaxRange;
+
+/// The insurances of the social security.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Insurance {
+    /// The health insurance (Krankenversicherung).
+    Health,
+    /// The nursing care insurance (Pflegeversicherung).
+    Nursing,
+    /// The retirement insurance (Rentenversicherung).
+    Retirement,
+    /// The unemployment insurance (Arbeitslosenversicherung).
+    Unemployment,
+}
+
+/// Observer that gets notified about the intermediate values of the calculation.
+pub trait CalculationObserver {
+    /// Called for every insurance of the social security with the yearly income that was used for the
+    /// calculation (after applying the income limits), the premium \[0,1\] and the resulting payment.
+    ///
+    /// For a fixed retirement insurance rate, the income and premium are zero.
+    fn on_insurance(
+        &mut self,
+        _insurance: Insurance,
+        _assessed_income: f32,
+        _premium: f32,
+        _payment: f32,
+    ) {
+    }
+
+    /// Called with the taxable income (zu versteuerndes Einkommen) after all deductions.
+    fn on_taxable_income(&mut self, _taxable_income: u32) {}
+
+    /// Called for every tax range of the income tax tariff with the taxes of this range. In case of
+    /// tax splitting, the taxes are the ones of half of the taxable income.
+    fn on_tax_range(&mut self, _tax_range: &TaxRange, _tax: f32) {}
+
+    /// Called with the income taxes according to the tariff (without the solidarity surcharge).
+    fn on_income_taxes(&mut self, _income_taxes: u32) {}
+
+    /// Called with the solidarity surcharge on the income taxes.
+    fn on_solidarity_surcharge(&mut self, _solidarity_surcharge: u32) {}
+
+    /// Called with the church tax on the income taxes (only for members of a church).
+    fn on_church_tax(&mut self, _church_tax: u32) {}
+
+    /// Called for every iteration of the reverse calculation with the estimated gross income and the
+    /// net income that results from it.
+    fn on_reverse_iteration(&mut self, _estimated_gross_income: u32, _net_income: i32) {}
+}
+
+/// Observer that ignores all notifications, used when no observer is given.
+pub(crate) struct NoObserver;
+
+impl CalculationObserver for NoObserver {}
+
+/// Observer that forwards only the notifications of the given insurances to the given observer, used
+/// when only some of the values of a social security calculation apply.
+pub(crate) struct InsuranceFilter<'a> {
+    pub(crate) observer: &'a mut dyn CalculationObserver,
+    pub(crate) insurances: &'a [Insurance],
+}
+
+impl CalculationObserver for InsuranceFilter<'_> {
+    fn on_insurance(
+        &mut self,
+        insurance: Insurance,
+        assessed_income: f32,
+        premium: f32,
+        payment: f32,
+    ) {
+        if self.insurances.contains(&insurance) {
+            self.observer
+                .on_insurance(insurance, assessed_income, premium, payment);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "year-2025"))]
+mod tests {
+    use super::*;
+    use crate::TaxData;
+    use crate::config::create as create_config;
+
+    #[derive(Default)]
+    struct RecordingObserver {
+        insurances: Vec<Insurance>,
+        tax_ranges: usize,
+        taxable_income: Option<u32>,
+        income_taxes: Option<u32>,
+    }
+
+    impl CalculationObserver for RecordingObserver {
+        fn on_insurance(&mut self, insurance: Insurance, _: f32, _: f32, _: f32) {
+            self.insurances.push(insurance);
+        }
+
+        fn on_taxable_income(&mut self, taxable_income: u32) {
+            self.taxable_income = Some(taxable_income);
+        }
+
+        fn on_tax_range(&mut self, _: &TaxRange, _: f32) {
+            self.tax_ranges += 1;
+        }
+
+        fn on_income_taxes(&mut self, income_taxes: u32) {
+            self.income_taxes = Some(income_taxes);
+        }
+    }
+
+    #[test]
+    fn test_all_intermediate_values_are_observed() {
+        let config = create_config(2025).unwrap();
+        let tax_data = TaxData::new(50000).with_expenses(1000);
+
+        let mut observer = RecordingObserver::default();
+        let result = crate::calculate_with_observer(&config, &tax_data, &mut observer).unwrap();
+
+        assert_eq!(
+            observer.insurances,
+            vec![
+                Insurance::Health,
+                Insurance::Nursing,
+                Insurance::Retirement,
+                Insurance::Unemployment
+            ]
+        );
+        assert_eq!(observer.tax_ranges, config.income_tax.tax_ranges.len());
+        assert_eq!(
+            observer.taxable_income,
+            Some(50000 - 1000 - result.social_security_taxes)
+        );
+        assert!(observer.income_taxes.unwrap() <= result.income_taxes);
+    }
+
+    #[derive(Default)]
+    struct ReverseObserver {
+        iterations: Vec<(u32, i32)>,
+    }
+
+    impl CalculationObserver for ReverseObserver {
+        fn on_reverse_iteration(&mut self, estimated_gross_income: u32, net_income: i32) {
+            self.iterations.push((estimated_gross_income, net_income));
+        }
+    }
+
+    #[test]
+    fn test_reverse_iterations_are_observed() {
+        let config = create_config(2025).unwrap();
+        let tax_data = TaxData::new(30000);
+
+        let mut observer = ReverseObserver::default();
+        let result =
+            crate::calculate_reverse_with_observer(&config, &tax_data, &mut observer).unwrap();
+
+        assert!(observer.iterations.len() > 1);
+        assert_eq!(
+            observer.iterations.last(),
+            Some(&(result.gross_income as u32, 30000))
+        );
+    }
+}
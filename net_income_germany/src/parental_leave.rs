@@ -0,0 +1,313 @@
+//! Years with maternity protection (Mutterschutz) and parental leave (Elternzeit) months.
+//!
+//! During the maternity protection, the maternity benefit (Mutterschaftsgeld) and the supplement of
+//! the employer replace the regular net salary. During the parental leave, the parental allowance
+//! (Elterngeld) is paid. All these benefits are tax-free, but subject to the progression clause
+//! (Progressionsvorbehalt), and no social security contributions apply on them, while the
+//! insurances continue.
+
+use crate::config::Config;
+use crate::error::Error;
+use crate::numeric::{Number, Numeric};
+use crate::{TaxData, income_tax};
+
+/// Minimum monthly parental allowance (§ 2 Abs. 4 BEEG).
+pub const PARENTAL_ALLOWANCE_MIN: u32 = 300;
+
+/// Maximum monthly parental allowance (§ 2 Abs. 1 BEEG).
+pub const PARENTAL_ALLOWANCE_MAX: u32 = 1800;
+
+/// Input data struct with the months of the year spent in maternity protection and parental leave.
+#[derive(Clone, Default)]
+pub struct ParentalLeaveData {
+    /// The number of months with maternity benefit instead of the salary.
+    pub maternity_months: u32,
+
+    /// The number of months with parental allowance instead of the salary.
+    pub parental_leave_months: u32,
+
+    /// Optional value of the monthly parental allowance. If this is not set, then it is estimated
+    /// from the regular net salary with [`estimate_parental_allowance`].
+    pub parental_allowance: Option<u32>,
+}
+
+/// Result struct of the calculation for a year with maternity protection and parental leave.
+pub struct ParentalLeaveResult {
+    /// The gross salary of the months without maternity protection and parental leave.
+    pub salary: u32,
+
+    /// The maternity benefit including the supplement of the employer.
+    pub maternity_benefit: u32,
+
+    /// The parental allowance of all parental leave months.
+    pub parental_allowance: u32,
+
+    /// The social security taxes on the salary.
+    pub social_security_taxes: u32,
+
+    /// The income taxes (including the church tax for members of a church) on the salary (with the
+    /// progression clause for the benefits).
+    pub income_taxes: u32,
+
+    /// The net income of the year from the salary and all benefits.
+    pub net_income: i32,
+}
+
+/// Number of months with the basic parental allowance (Basiselterngeld) of one parent.
+pub const BASIC_ALLOWANCE_MONTHS: u32 = 12;
+
+/// Number of months with the parental allowance plus (ElterngeldPlus) of one parent, as every month
+/// of the basic parental allowance can be exchanged for two months.
+pub const ALLOWANCE_PLUS_MONTHS: u32 = 24;
+
+/// Estimates the monthly parental allowance from the given monthly net salary before the birth.
+///
+/// The parental allowance is 67% of the net salary, decreasing down to 65% for net salaries above
+/// 1200 and increasing up to 100% for net salaries below 1000 (§ 2 BEEG), limited by the minimum
+/// and maximum parental allowance.
+pub fn estimate_parental_allowance(monthly_net_salary: u32) -> u32 {
+    return (monthly_net_salary * get_replacement_rate(monthly_net_salary) / 1000)
+        .clamp(PARENTAL_ALLOWANCE_MIN, PARENTAL_ALLOWANCE_MAX);
+}
+
+/// Returns the replacement rate in per mille for the given monthly net salary before the birth.
+fn get_replacement_rate(monthly_net_salary: u32) -> u32 {
+    // the rate changes by 0.1 percentage points for every 2 euros
+    if monthly_net_salary < 1000 {
+        return (670 + (1000 - monthly_net_salary) / 2).min(1000);
+    } else if monthly_net_salary > 1200 {
+        return 670u32
+            .saturating_sub((monthly_net_salary - 1200) / 2)
+            .max(650);
+    } else {
+        return 670;
+    }
+}
+
+/// Result of one variant of the parental allowance over its whole entitlement period.
+pub struct AllowanceVariantResult {
+    /// The monthly parental allowance.
+    pub monthly_allowance: u32,
+
+    /// The number of months in which the parental allowance is paid.
+    pub months: u32,
+
+    /// The sum of the parental allowance of all months.
+    pub total_allowance: u32,
+
+    /// The sum of the parental allowance and the net salary of the part-time work of all months.
+    pub total_net_income: i64,
+}
+
+/// Comparison of the basic parental allowance with the parental allowance plus.
+pub struct AllowanceComparison {
+    /// The basic parental allowance (Basiselterngeld).
+    pub basic: AllowanceVariantResult,
+
+    /// The parental allowance plus (ElterngeldPlus).
+    pub plus: AllowanceVariantResult,
+}
+
+/// Compares the basic parental allowance with the parental allowance plus for the given yearly
+/// gross salary of a planned part-time work during the parental leave. The income of the tax data
+/// is the regular yearly gross salary before the birth.
+///
+/// With a part-time work, both variants replace the given share of the loss of the net salary, but
+/// the parental allowance plus is limited by half of the basic parental allowance without a
+/// part-time work (§ 4a BEEG). The parental allowance plus is paid twice as long instead.
+pub fn compare_allowance_types(
+    config: &Config,
+    tax_data: &TaxData,
+    part_time_income: u32,
+) -> Result<AllowanceComparison, Error> {
+    if tax_data.self_employed {
+        return Err(Error::ParentalAllowanceRequiresEmployee);
+    }
+
+    let regular = crate::calculate(config, tax_data)?;
+    let monthly_net_salary =
+        ((regular.net_income as i64 + tax_data.expenses as i64).max(0) / 12) as u32;
+
+    let mut part_time_tax_data = tax_data.clone();
+    part_time_tax_data.income = part_time_income;
+    let part_time = crate::calculate(config, &part_time_tax_data)?;
+    let monthly_part_time_net_salary =
+        ((part_time.net_income as i64 + tax_data.expenses as i64).max(0) / 12) as u32;
+
+    let rate = get_replacement_rate(monthly_net_salary);
+    let replaced_loss =
+        monthly_net_salary.saturating_sub(monthly_part_time_net_salary) * rate / 1000;
+
+    let basic_allowance = replaced_loss.clamp(PARENTAL_ALLOWANCE_MIN, PARENTAL_ALLOWANCE_MAX);
+    let allowance_plus = replaced_loss
+        .min(estimate_parental_allowance(monthly_net_salary) / 2)
+        .max(PARENTAL_ALLOWANCE_MIN / 2);
+
+    let create_variant = |monthly_allowance: u32, months: u32| AllowanceVariantResult {
+        monthly_allowance,
+        months,
+        total_allowance: monthly_allowance * months,
+        total_net_income: (monthly_allowance as i64 + monthly_part_time_net_salary as i64)
+            * months as i64,
+    };
+
+    return Ok(AllowanceComparison {
+        basic: create_variant(basic_allowance, BASIC_ALLOWANCE_MONTHS),
+        plus: create_variant(allowance_plus, ALLOWANCE_PLUS_MONTHS),
+    });
+}
+
+/// Calculates the net income of a year with the given months in maternity protection and parental
+/// leave. The income of the tax data is the regular yearly gross salary.
+pub fn calculate(
+    config: &Config,
+    tax_data: &TaxData,
+    data: &ParentalLeaveData,
+) -> Result<ParentalLeaveResult, Error> {
+    if tax_data.self_employed {
+        return Err(Error::MaternityBenefitRequiresEmployee);
+    }
+    let leave_months = data
+        .maternity_months
+        .saturating_add(data.parental_leave_months);
+    if leave_months > 12 {
+        return Err(Error::InvalidBenefitMonths);
+    }
+
+    // the maternity benefit and the supplement of the employer together replace the net salary
+    let regular = crate::calculate(config, tax_data)?;
+    let monthly_net_salary =
+        ((regular.net_income as i64 + tax_data.expenses as i64).max(0) / 12) as u32;
+    let maternity_benefit = monthly_net_salary * data.maternity_months;
+
+    let monthly_parental_allowance = data
+        .parental_allowance
+        .unwrap_or_else(|| estimate_parental_allowance(monthly_net_salary));
+    let parental_allowance = monthly_parental_allowance * data.parental_leave_months;
+
+    let mut salary_tax_data = tax_data.clone();
+    salary_tax_data.income = (Number::from_u32(tax_data.income)
+        * Number::from_u32(12 - leave_months)
+        / Number::from_u32(12))
+    .to_u32();
+
+    let (social_security_taxes, taxable_income) =
+        crate::calculate_taxable_income(config, &salary_tax_data)?;
+    let income_taxes = income_tax::calculate_with_progression_clause(
+        &config.income_tax,
+        taxable_income,
+        maternity_benefit + parental_allowance,
+        tax_data.assessment.is_splitting(),
+        tax_data.church_member,
+    );
+
+    return Ok(ParentalLeaveResult {
+        salary: salary_tax_data.income,
+        maternity_benefit,
+        parental_allowance,
+        social_security_taxes,
+        income_taxes,
+        net_income: (salary_tax_data.income as i64
+            + maternity_benefit as i64
+            + parental_allowance as i64
+            - tax_data.expenses as i64
+            - social_security_taxes as i64
+            - income_taxes as i64) as i32,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "year-2025")]
+    use crate::config::create as create_config;
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn test_estimate_parental_allowance() {
+        assert_eq!(estimate_parental_allowance(1100), 737);
+        assert_eq!(estimate_parental_allowance(1220), 805);
+        assert_eq!(estimate_parental_allowance(2000), 1300);
+        assert_eq!(estimate_parental_allowance(800), 616);
+        assert_eq!(estimate_parental_allowance(100), PARENTAL_ALLOWANCE_MIN);
+        assert_eq!(estimate_parental_allowance(5000), PARENTAL_ALLOWANCE_MAX);
+    }
+
+    #[test]
+    #[cfg(feature = "year-2025")]
+    fn test_compare_allowance_types() {
+        let config = create_config(2025).unwrap();
+        let tax_data = TaxData::new(45000);
+
+        // without a part-time work, the parental allowance plus is half of the basic allowance
+        let comparison = compare_allowance_types(&config, &tax_data, 0).unwrap();
+        assert_eq!(
+            comparison.plus.monthly_allowance,
+            comparison.basic.monthly_allowance / 2
+        );
+        assert!(
+            comparison.basic.total_allowance - comparison.plus.total_allowance
+                < ALLOWANCE_PLUS_MONTHS
+        );
+
+        // with a part-time work, the parental allowance plus is paid in total
+        let comparison = compare_allowance_types(&config, &tax_data, 22500).unwrap();
+        assert_eq!(comparison.basic.months, 12);
+        assert_eq!(comparison.plus.months, 24);
+        assert!(comparison.plus.total_allowance > comparison.basic.total_allowance);
+        assert!(comparison.plus.total_net_income > comparison.basic.total_net_income);
+    }
+
+    #[test]
+    #[cfg(feature = "year-2025")]
+    fn test_birth_year() {
+        let config = create_config(2025).unwrap();
+        let tax_data = TaxData::new(60000);
+        let data = ParentalLeaveData {
+            maternity_months: 2,
+            parental_leave_months: 4,
+            parental_allowance: None,
+        };
+
+        let result = calculate(&config, &tax_data, &data).unwrap();
+        let regular = crate::calculate(&config, &tax_data).unwrap();
+        let salary_only = crate::calculate(&config, &TaxData::new(30000)).unwrap();
+
+        assert_eq!(result.salary, 30000);
+        assert_eq!(
+            result.maternity_benefit,
+            2 * (regular.net_income / 12) as u32
+        );
+        assert!(result.parental_allowance > 0);
+
+        // no social security on the benefits, but the progression clause applies
+        assert_eq!(
+            result.social_security_taxes,
+            salary_only.social_security_taxes
+        );
+        assert!(result.income_taxes > salary_only.income_taxes);
+        assert!(result.net_income < regular.net_income);
+    }
+
+    #[test]
+    #[cfg(feature = "year-2025")]
+    fn test_given_parental_allowance_and_errors() {
+        let config = create_config(2025).unwrap();
+        let tax_data = TaxData::new(60000);
+        let mut data = ParentalLeaveData {
+            parental_leave_months: 12,
+            parental_allowance: Some(PARENTAL_ALLOWANCE_MAX),
+            ..Default::default()
+        };
+
+        let result = calculate(&config, &tax_data, &data).unwrap();
+        assert_eq!(result.parental_allowance, 12 * PARENTAL_ALLOWANCE_MAX);
+        assert_eq!(result.income_taxes, 0);
+        assert_eq!(result.net_income, 12 * PARENTAL_ALLOWANCE_MAX as i32);
+
+        data.maternity_months = 1;
+        assert_eq!(
+            calculate(&config, &tax_data, &data).err(),
+            Some(Error::InvalidBenefitMonths)
+        );
+    }
+}
@@ -0,0 +1,124 @@
+//! What-if analysis for reducing the working hours.
+//!
+//! Due to the progression of the income tax, a reduction of the working hours (and therefore of the
+//! gross income) leads to a smaller reduction of the net income. For example, working 80% of the
+//! hours often still results in about 87% of the net income.
+
+use crate::config::Config;
+use crate::error::Error;
+use crate::{TaxData, TaxResult, calculate as calculate_taxes};
+
+/// Number of working days per year of a full-time (five days a week) employment, considering
+/// weekends, vacation and public holidays.
+pub const WORKING_DAYS_PER_YEAR: u32 = 220;
+
+/// Result struct of the part-time analysis.
+pub struct PartTimeResult {
+    /// The tax result with the current working hours.
+    pub current: TaxResult,
+
+    /// The tax result with the reduced working hours.
+    pub reduced: TaxResult,
+
+    /// The number of additional free days per year due to the reduced working hours.
+    pub free_days: f32,
+}
+
+impl PartTimeResult {
+    /// Returns the ratio of the net income with reduced hours to the net income with current hours
+    /// (or `None` if the net income with current hours is zero).
+    pub fn get_net_ratio(&self) -> Option<f32> {
+        return match self.current.net_income {
+            0 => None,
+            net_income => Some(self.reduced.net_income as f32 / net_income as f32),
+        };
+    }
+
+    /// Returns how much net income is given up for every additional free day.
+    pub fn get_cost_per_free_day(&self) -> f32 {
+        if self.free_days <= 0.0 {
+            return 0.0;
+        }
+
+        return (self.current.net_income - self.reduced.net_income) as f32 / self.free_days;
+    }
+}
+
+/// Calculates the net income for the given tax data with the current working hours and with the
+/// income reduced proportionally to the target working hours.
+///
+/// The working hours can be given per week or per month, as only their ratio is relevant.
+pub fn calculate(
+    config: &Config,
+    tax_data: &TaxData,
+    current_hours: f32,
+    target_hours: f32,
+) -> Result<PartTimeResult, Error> {
+    if current_hours <= 0.0 || target_hours < 0.0 || target_hours > current_hours {
+        return Err(Error::InvalidTargetHours);
+    }
+
+    let ratio = target_hours / current_hours;
+
+    let mut reduced_tax_data = tax_data.clone();
+    reduced_tax_data.income = (tax_data.income as f32 * ratio) as u32;
+
+    return Ok(PartTimeResult {
+        current: calculate_taxes(config, tax_data)?,
+        reduced: calculate_taxes(config, &reduced_tax_data)?,
+        free_days: WORKING_DAYS_PER_YEAR as f32 * (1.0 - ratio),
+    });
+}
+
+#[cfg(all(test, feature = "year-2025"))]
+mod tests {
+    use super::*;
+    use crate::config::create as create_config;
+    use approx::abs_diff_eq;
+
+    #[test]
+    fn test_net_ratio_above_hours_ratio() {
+        let config = create_config(2025).unwrap();
+        let tax_data = TaxData::new(60000);
+
+        let result = calculate(&config, &tax_data, 40.0, 32.0).unwrap();
+
+        assert_eq!(result.reduced.gross_income, 48000);
+        assert!(abs_diff_eq!(result.free_days, 44.0, epsilon = 0.01));
+
+        // the net income is reduced by less than the working hours
+        assert_eq!(result.current.net_income, 37413);
+        assert_eq!(result.reduced.net_income, 31083);
+        assert!(abs_diff_eq!(
+            result.get_net_ratio().unwrap(),
+            31083.0 / 37413.0,
+            epsilon = 0.0001
+        ));
+        assert!(result.get_cost_per_free_day() > 0.0);
+    }
+
+    #[test]
+    fn test_no_net_ratio_without_net_income() {
+        let config = create_config(2025).unwrap();
+        let tax_data = TaxData::new(0);
+
+        let result = calculate(&config, &tax_data, 40.0, 32.0).unwrap();
+        assert_eq!(result.current.net_income, 0);
+        assert_eq!(result.get_net_ratio(), None);
+    }
+
+    #[test]
+    fn test_error_on_invalid_hours() {
+        let config = create_config(2025).unwrap();
+        let tax_data = TaxData::new(60000);
+
+        assert_eq!(
+            calculate(&config, &tax_data, 0.0, 0.0).err(),
+            Some(Error::InvalidTargetHours)
+        );
+        assert_eq!(
+            calculate(&config, &tax_data, 40.0, 45.0).err(),
+            Some(Error::InvalidTargetHours)
+        );
+    }
+}
@@ -0,0 +1,314 @@
+//! Monthly payroll view of the annual calculation for employees.
+//!
+//! The regular salary of every month is subject to social security up to the monthly income limits.
+//! A bonus is a one-time payment (Einmalzahlung, § 23a SGB IV), which is only subject to social
+//! security as far as the income limits of all months of the year up to the payment were not used up
+//! by the regular salary. The part of a bonus above these limits stays free of social security.
+//!
+//! The income taxes of the regular salary are distributed evenly over the months, and the bonus
+//! month additionally carries the income taxes that the bonus adds to the annual income taxes (as
+//! for other payments, sonstige Bezüge, § 39b Abs. 3 EStG). The church tax of members of a church
+//! is withheld on the wage tax in the same way.
+
+use crate::config::Config;
+use crate::error::Error;
+use crate::numeric::{Number, Numeric};
+use crate::social_security::{self, SocialSecurityData, SocialSecurityResult};
+use crate::{Assessment, TaxData, income_tax};
+
+/// Input data struct for the monthly payroll.
+#[derive(Clone)]
+pub struct PayrollData {
+    /// The regular monthly gross salary.
+    pub monthly_salary: u32,
+
+    /// The gross amount of an optional bonus together with the month \[1,12\] of its payment.
+    pub bonus: Option<(u32, u32)>,
+
+    /// The type of the income tax assessment.
+    pub assessment: Assessment,
+
+    /// Whether the employee is a member of a church and pays the church tax.
+    pub church_member: bool,
+}
+
+/// Result of one month of the payroll.
+pub struct PayrollMonth {
+    /// The month \[1,12\].
+    pub month: u32,
+
+    /// The gross income of the month, including the bonus.
+    pub gross_income: u32,
+
+    /// The employee share of the health insurance.
+    pub health_insurance: u32,
+
+    /// The employee share of the nursing care insurance.
+    pub nursing_insurance: u32,
+
+    /// The employee share of the retirement insurance.
+    pub retirement_insurance: u32,
+
+    /// The employee share of the unemployment insurance.
+    pub unemployment_insurance: u32,
+
+    /// The income taxes on the wage (Lohnsteuer).
+    pub wage_tax: u32,
+
+    /// The solidarity surcharge on the wage tax.
+    pub solidarity_surcharge: u32,
+
+    /// The church tax on the wage tax (only for members of a church).
+    pub church_tax: u32,
+
+    /// The net income of the month.
+    pub net_income: i32,
+}
+
+impl PayrollMonth {
+    /// Returns the income taxes including the solidarity surcharge.
+    pub fn get_income_taxes(&self) -> u32 {
+        return self.wage_tax + self.solidarity_surcharge;
+    }
+
+    /// Returns the sum of all social security contributions of the month.
+    pub fn get_social_security_taxes(&self) -> u32 {
+        return self.health_insurance
+            + self.nursing_insurance
+            + self.retirement_insurance
+            + self.unemployment_insurance;
+    }
+}
+
+/// Calculates the annual income taxes, the solidarity surcharge and the church tax for the given
+/// gross income.
+fn calculate_annual_income_taxes(
+    config: &Config,
+    income: u32,
+    data: &PayrollData,
+) -> Result<(u32, u32, u32), Error> {
+    if income > i32::MAX as u32 {
+        return Err(Error::input_too_large());
+    }
+
+    let tax_data = TaxData::new(income)
+        .with_assessment(data.assessment)
+        .with_church_member(data.church_member);
+    let (_, taxable_income) = crate::calculate_taxable_income(config, &tax_data)?;
+
+    let income_taxes = income_tax::calculate_tariff(
+        &config.income_tax,
+        taxable_income,
+        data.assessment.is_splitting(),
+    );
+    let solidarity_surcharge = income_tax::calculate_solidarity_addition(
+        income_taxes,
+        data.assessment.is_splitting(),
+        &config.income_tax.solidary_addition_config,
+    );
+    let church_tax = match data.church_member {
+        true => income_tax::calculate_church_tax(&config.income_tax, income_taxes),
+        false => 0,
+    };
+
+    return Ok((income_taxes, solidarity_surcharge, church_tax));
+}
+
+/// Returns the part of the given annual value that is cumulated up to the given month, so that the
+/// value is distributed without rounding differences over the months.
+fn get_cumulated_share(annual: u32, month: u32) -> u32 {
+    return (Number::from_u32(annual) * Number::from_u32(month) / Number::from_u32(12)).to_u32();
+}
+
+/// Calculates the social security contributions of the given cumulated income of the given number
+/// of months.
+fn calculate_cumulated_social_security(
+    config: &Config,
+    income: u32,
+    months: u32,
+) -> Result<SocialSecurityResult, Error> {
+    if months == 0 {
+        return Ok(SocialSecurityResult {
+            health_insurance: 0,
+            nursing_insurance: 0,
+            retirement_insurance: 0,
+            unemployment_insurance: 0,
+            total: 0,
+        });
+    }
+
+    return social_security::calculate_for_months(
+        &config.health_insurance,
+        &config.retirement_insurance,
+        &config.unemployment_insurance,
+        &SocialSecurityData {
+            income,
+            fixed_retirement: None,
+            self_employed: false,
+        },
+        months,
+    );
+}
+
+/// Calculates the twelve monthly payrolls of a year for the given salary and optional bonus.
+pub fn calculate(config: &Config, data: &PayrollData) -> Result<Vec<PayrollMonth>, Error> {
+    let (bonus, bonus_month) = data.bonus.unwrap_or((0, 0));
+    if data.bonus.is_some() && !(1..=12).contains(&bonus_month) {
+        return Err(Error::InvalidBonusMonth);
+    }
+
+    let regular_income = data
+        .monthly_salary
+        .checked_mul(12)
+        .ok_or(Error::input_too_large())?;
+    let (regular_wage_tax, regular_solidarity_surcharge, regular_church_tax) =
+        calculate_annual_income_taxes(config, regular_income, data)?;
+    let (total_wage_tax, total_solidarity_surcharge, total_church_tax) =
+        calculate_annual_income_taxes(config, regular_income.saturating_add(bonus), data)?;
+
+    let mut months = Vec::new();
+
+    for month in 1..=12 {
+        // the regular contributions are calculated on the cumulated salary, so that the rounding
+        // differences do not add up over the months
+        let previous = calculate_cumulated_social_security(
+            config,
+            data.monthly_salary * (month - 1),
+            month - 1,
+        )?;
+        let regular =
+            calculate_cumulated_social_security(config, data.monthly_salary * month, month)?;
+        let current = match month == bonus_month {
+            true => calculate_cumulated_social_security(
+                config,
+                (data.monthly_salary * month).saturating_add(bonus),
+                month,
+            )?,
+            false => regular,
+        };
+
+        // the bonus month additionally carries the taxes that the bonus adds to the annual taxes
+        let (mut wage_tax, mut solidarity_surcharge, mut church_tax) = (
+            get_cumulated_share(regular_wage_tax, month)
+                - get_cumulated_share(regular_wage_tax, month - 1),
+            get_cumulated_share(regular_solidarity_surcharge, month)
+                - get_cumulated_share(regular_solidarity_surcharge, month - 1),
+            get_cumulated_share(regular_church_tax, month)
+                - get_cumulated_share(regular_church_tax, month - 1),
+        );
+        if month == bonus_month {
+            wage_tax += total_wage_tax - regular_wage_tax;
+            solidarity_surcharge += total_solidarity_surcharge - regular_solidarity_surcharge;
+            church_tax += total_church_tax - regular_church_tax;
+        }
+
+        let gross_income = match month == bonus_month {
+            true => data.monthly_salary.saturating_add(bonus),
+            false => data.monthly_salary,
+        };
+        let payroll_month = PayrollMonth {
+            month,
+            gross_income,
+            health_insurance: current.health_insurance - previous.health_insurance,
+            nursing_insurance: current.nursing_insurance - previous.nursing_insurance,
+            retirement_insurance: current.retirement_insurance - previous.retirement_insurance,
+            unemployment_insurance: current.unemployment_insurance
+                - previous.unemployment_insurance,
+            wage_tax,
+            solidarity_surcharge,
+            church_tax,
+            net_income: 0,
+        };
+        months.push(PayrollMonth {
+            net_income: gross_income as i32
+                - payroll_month.get_social_security_taxes() as i32
+                - payroll_month.get_income_taxes() as i32
+                - church_tax as i32,
+            ..payroll_month
+        });
+    }
+
+    return Ok(months);
+}
+
+#[cfg(all(test, feature = "year-2025"))]
+mod tests {
+    use super::*;
+    use crate::config::create as create_config;
+
+    fn create_data(monthly_salary: u32, bonus: Option<(u32, u32)>) -> PayrollData {
+        return PayrollData {
+            monthly_salary,
+            bonus,
+            assessment: Assessment::Single,
+            church_member: false,
+        };
+    }
+
+    #[test]
+    fn test_months_add_up_to_annual_result() {
+        let config = create_config(2025).unwrap();
+
+        for (monthly_salary, bonus) in [(3000, None), (5000, Some((20000, 12))), (9000, None)] {
+            let months = calculate(&config, &create_data(monthly_salary, bonus)).unwrap();
+            assert_eq!(months.len(), 12);
+
+            let annual = crate::calculate(
+                &config,
+                &TaxData::new(monthly_salary * 12 + bonus.map_or(0, |(bonus, _)| bonus)),
+            )
+            .unwrap();
+            let income_taxes: u32 = months.iter().map(|month| month.get_income_taxes()).sum();
+            let net_income: i32 = months.iter().map(|month| month.net_income).sum();
+
+            assert_eq!(income_taxes, annual.income_taxes);
+            assert!((net_income - annual.net_income).abs() <= 2);
+        }
+    }
+
+    #[test]
+    fn test_church_tax_adds_up_to_annual_result() {
+        let config = create_config(2025).unwrap();
+        let data = PayrollData {
+            church_member: true,
+            ..create_data(5000, Some((20000, 12)))
+        };
+
+        let months = calculate(&config, &data).unwrap();
+        let annual =
+            crate::calculate(&config, &TaxData::new(80000).with_church_member(true)).unwrap();
+        let church_tax: u32 = months.iter().map(|month| month.church_tax).sum();
+        let net_income: i32 = months.iter().map(|month| month.net_income).sum();
+
+        assert!(months[0].church_tax > 0);
+        assert!(months[11].church_tax > months[0].church_tax);
+        assert_eq!(church_tax, annual.church_tax);
+        assert!((net_income - annual.net_income).abs() <= 2);
+    }
+
+    #[test]
+    fn test_bonus_above_income_limits() {
+        let config = create_config(2025).unwrap();
+
+        // the bonus in the last month is only partially subject to social security, because the
+        // income limits of the previous months were not used up
+        let months = calculate(&config, &create_data(6000, Some((30000, 12)))).unwrap();
+        assert_eq!(months[11].gross_income, 36000);
+        assert!(months[11].health_insurance > months[0].health_insurance);
+        assert!(months[11].health_insurance < months[0].health_insurance * 6);
+        assert!(months[11].wage_tax > months[0].wage_tax * 5);
+
+        // a bonus in the first month is subject to the income limits of the first month only
+        let months = calculate(&config, &create_data(4000, Some((30000, 1)))).unwrap();
+        assert_eq!(
+            months[0].get_social_security_taxes(),
+            calculate(&config, &create_data(8050, None)).unwrap()[0].get_social_security_taxes()
+        );
+        assert_eq!(months[1].health_insurance, months[11].health_insurance);
+
+        assert_eq!(
+            calculate(&config, &create_data(6000, Some((30000, 13)))).err(),
+            Some(Error::InvalidBonusMonth)
+        );
+    }
+}
@@ -0,0 +1,158 @@
+//! Payslips (Entgeltabrechnungen) with the standard line items of a German payslip.
+//!
+//! The payslips are created from the monthly payroll (see [`crate::payroll`]). The church tax of
+//! members of a church is withheld with the rate of the configuration, which can be adapted to the
+//! federal state of the employee (see [`crate::bundesland::Bundesland::adapt_config`]):
+//!
+//! ```
+//! use net_income_germany::bundesland::Bundesland;
+//! use net_income_germany::payroll::PayrollData;
+//!
+//! let mut config =
+//!     net_income_germany::config::create(net_income_germany::config::latest_year()).unwrap();
+//! Bundesland::Bayern.adapt_config(&mut config);
+//! let data = PayrollData {
+//!     monthly_salary: 4500,
+//!     bonus: None,
+//!     assessment: net_income_germany::Assessment::Single,
+//!     church_member: true,
+//! };
+//!
+//! let payslips = net_income_germany::payslip::create(&config, &data).unwrap();
+//! println!("Nettoverdienst im Januar: {}", payslips[0].net_income);
+//! ```
+
+use crate::config::Config;
+use crate::error::Error;
+use crate::payroll::{self, PayrollData, PayrollMonth};
+
+/// Payslip of one month with all standard line items.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Payslip {
+    /// The month \[1,12\].
+    pub month: u32,
+
+    /// The gross income that is subject to the wage tax (Steuerbrutto).
+    pub tax_gross_income: u32,
+
+    /// The gross income that is subject to social security (SV-Brutto), before applying the income
+    /// limits of the insurances.
+    pub social_security_gross_income: u32,
+
+    /// The wage tax (Lohnsteuer, LSt).
+    pub wage_tax: u32,
+
+    /// The church tax (Kirchensteuer, KiSt).
+    pub church_tax: u32,
+
+    /// The solidarity surcharge (Solidaritätszuschlag, SolZ).
+    pub solidarity_surcharge: u32,
+
+    /// The employee share of the health insurance (KV).
+    pub health_insurance: u32,
+
+    /// The employee share of the nursing care insurance (PV).
+    pub nursing_insurance: u32,
+
+    /// The employee share of the retirement insurance (RV).
+    pub retirement_insurance: u32,
+
+    /// The employee share of the unemployment insurance (AV).
+    pub unemployment_insurance: u32,
+
+    /// The net income after all statutory deductions (Nettoverdienst).
+    pub net_income: i32,
+}
+
+impl Payslip {
+    /// Creates the payslip of the given payroll month.
+    pub fn new(month: &PayrollMonth) -> Self {
+        return Payslip {
+            month: month.month,
+            tax_gross_income: month.gross_income,
+            social_security_gross_income: month.gross_income,
+            wage_tax: month.wage_tax,
+            church_tax: month.church_tax,
+            solidarity_surcharge: month.solidarity_surcharge,
+            health_insurance: month.health_insurance,
+            nursing_insurance: month.nursing_insurance,
+            retirement_insurance: month.retirement_insurance,
+            unemployment_insurance: month.unemployment_insurance,
+            net_income: month.net_income,
+        };
+    }
+
+    /// Returns the sum of the taxes (Steuerrechtliche Abzüge).
+    pub fn get_tax_deductions(&self) -> u32 {
+        return self.wage_tax + self.church_tax + self.solidarity_surcharge;
+    }
+
+    /// Returns the sum of the social security contributions (Sozialversicherungsrechtliche Abzüge).
+    pub fn get_social_security_deductions(&self) -> u32 {
+        return self.health_insurance
+            + self.nursing_insurance
+            + self.retirement_insurance
+            + self.unemployment_insurance;
+    }
+}
+
+/// Creates the twelve payslips of a year.
+pub fn create(config: &Config, data: &PayrollData) -> Result<Vec<Payslip>, Error> {
+    return Ok(payroll::calculate(config, data)?
+        .iter()
+        .map(Payslip::new)
+        .collect());
+}
+
+#[cfg(all(test, feature = "year-2025"))]
+mod tests {
+    use super::*;
+    use crate::Assessment;
+    use crate::config::create as create_config;
+
+    #[test]
+    fn test_line_items_add_up() {
+        let config = create_config(2025).unwrap();
+        let data = PayrollData {
+            monthly_salary: 4500,
+            bonus: Some((3000, 11)),
+            assessment: Assessment::Single,
+            church_member: true,
+        };
+
+        let payslips = create(&config, &data).unwrap();
+        for payslip in &payslips {
+            assert_eq!(
+                payslip.net_income,
+                payslip.tax_gross_income as i32
+                    - payslip.get_tax_deductions() as i32
+                    - payslip.get_social_security_deductions() as i32
+            );
+        }
+
+        assert_eq!(payslips[10].tax_gross_income, 7500);
+        assert!(
+            payslips[0]
+                .church_tax
+                .abs_diff(payslips[0].wage_tax * 9 / 100)
+                <= 1
+        );
+    }
+
+    #[test]
+    fn test_without_church_tax() {
+        let config = create_config(2025).unwrap();
+        let data = PayrollData {
+            monthly_salary: 4500,
+            bonus: None,
+            assessment: Assessment::Single,
+            church_member: false,
+        };
+
+        let months = payroll::calculate(&config, &data).unwrap();
+        let payslip = Payslip::new(&months[0]);
+        assert_eq!(payslip.church_tax, 0);
+        assert_eq!(payslip.net_income, months[0].net_income);
+    }
+}
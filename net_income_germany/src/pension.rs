@@ -0,0 +1,352 @@
+//! Statutory pension (gesetzliche Rente) with deductions for early retirement.
+//!
+//! Every month of retirement before the regular retirement age reduces the pension by 0.3%
+//! (Abschlag, § 77 SGB VI). Insured persons from the age of 50 can offset this deduction with special
+//! payments into the retirement insurance (§ 187a SGB VI). The payments buy pension points, which are
+//! reduced by the same deduction. They are retirement provision expenses and therefore deductible
+//! from the taxable income, together with the regular contributions to the retirement insurance up
+//! to the maximum retirement provision expenses (§ 10 Abs. 3 EStG).
+//!
+//! Retirees pay the contributions of the health insurance of retirees on the pension (see
+//! [`crate::retiree`]) and income taxes on the taxable share of the pension, which depends on the
+//! year in which the pension starts (Besteuerungsanteil, § 22 Nr. 1 EStG). The taxable share
+//! increases by 0.5 percentage points for every later year until it reaches 100% for pensions that
+//! start in 2058.
+
+use crate::config::Config;
+use crate::error::Error;
+use crate::numeric::{Number, Numeric};
+use crate::pipeline::{DeductionStep, Pipeline, Stage};
+use crate::retiree::{self, RetireeData};
+use crate::{Assessment, TaxData, income_tax, social_security};
+
+/// Deduction \[0,1\] of the pension for every month of retirement before the regular retirement age.
+const DEDUCTION_PER_MONTH: f32 = 0.003;
+
+/// Maximum number of months of retirement before the regular retirement age.
+pub const MAX_EARLY_MONTHS: u32 = 60;
+
+/// Lump sum for income-related expenses on pensions (Werbungskosten-Pauschbetrag, § 9a Nr. 3 EStG).
+pub(crate) const PENSION_ALLOWANCE: u32 = 102;
+
+/// Increase \[0,1\] of the taxable share for every year that the pension starts later.
+const TAXABLE_SHARE_INCREASE: f32 = 0.005;
+
+/// Result struct of the calculation of the net pension.
+pub struct PensionResult {
+    /// The yearly gross pension.
+    pub pension: u32,
+
+    /// The contributions of the retiree to the health and nursing care insurance.
+    pub health_insurance: u32,
+
+    /// The income taxes (including the solidarity surcharge and the church tax for members of a
+    /// church) on the taxable share of the pension.
+    pub income_taxes: u32,
+
+    /// The net pension after the contributions and the income taxes.
+    pub net_income: i64,
+}
+
+/// Input data struct for the special payment to offset the deductions of an early retirement.
+#[derive(Clone)]
+pub struct SpecialPaymentData {
+    /// The special payment into the retirement insurance.
+    pub payment: u32,
+
+    /// The expected monthly pension at the regular retirement age (without deductions).
+    pub monthly_pension: u32,
+
+    /// The number of months of retirement before the regular retirement age.
+    pub early_months: u32,
+}
+
+/// Result struct of the calculation of a special payment to offset the deductions.
+pub struct SpecialPaymentResult {
+    /// The monthly deduction of the pension because of the early retirement.
+    pub pension_deduction: u32,
+
+    /// The part of the monthly deduction that is offset by the special payment.
+    pub offset_pension: u32,
+
+    /// The special payment that would offset the whole deduction.
+    pub full_offset_payment: u32,
+
+    /// The part of the special payment that is deductible from the taxable income.
+    pub deductible_payment: u32,
+
+    /// The income taxes that are saved by deducting the special payment.
+    pub tax_saving: u32,
+}
+
+/// Returns the factor \[0,1\] with which the pension points are multiplied for a retirement the given
+/// number of months before the regular retirement age (Zugangsfaktor).
+pub fn get_access_factor(early_months: u32) -> f32 {
+    return (Number::from_u32(1)
+        - Number::from_f32(DEDUCTION_PER_MONTH) * Number::from_u32(early_months))
+    .to_f32();
+}
+
+/// Calculates the monthly deduction of the given monthly pension for a retirement the given number of
+/// months before the regular retirement age.
+pub fn calculate_pension_deduction(monthly_pension: u32, early_months: u32) -> u32 {
+    return (Number::from_u32(monthly_pension)
+        * Number::from_f32(DEDUCTION_PER_MONTH)
+        * Number::from_u32(early_months))
+    .to_u32();
+}
+
+/// Returns the taxable share \[0,1\] of pensions that start in the given year, which needs to be
+/// the year of the configuration or a later year (earlier years are treated like the year of the
+/// configuration).
+pub fn get_taxable_share(config: &Config, start_year: u32) -> f32 {
+    let later_years = start_year.saturating_sub(config.origin.year);
+
+    return (Number::from_f32(config.pension.taxable_share)
+        + Number::from_f32(TAXABLE_SHARE_INCREASE) * Number::from_u32(later_years))
+    .to_f32()
+    .min(1.0);
+}
+
+/// Calculates the net pension of a compulsorily insured retiree for the given yearly statutory
+/// pension without any other income. The pension is assumed to start in the year of the
+/// configuration.
+pub fn calculate_net_pension(
+    config: &Config,
+    pension: u32,
+    assessment: Assessment,
+    church_member: bool,
+) -> PensionResult {
+    return calculate_net_pension_of_year(
+        config,
+        pension,
+        config.origin.year,
+        assessment,
+        church_member,
+    );
+}
+
+/// Calculates the net pension like [`calculate_net_pension`], but for a pension that starts in the
+/// given year (see [`get_taxable_share`]). All other values are taken from the configuration.
+pub fn calculate_net_pension_of_year(
+    config: &Config,
+    pension: u32,
+    start_year: u32,
+    assessment: Assessment,
+    church_member: bool,
+) -> PensionResult {
+    let health_insurance = retiree::calculate(
+        config,
+        &RetireeData {
+            statutory_pension: pension,
+            company_pension: 0,
+            other_income: 0,
+            voluntarily_insured: false,
+        },
+    )
+    .get_total();
+
+    let taxable_income = (Number::from_u32(pension)
+        * Number::from_f32(get_taxable_share(config, start_year)))
+    .to_u32()
+    .saturating_sub(PENSION_ALLOWANCE)
+    .saturating_sub(health_insurance);
+    let income_taxes = income_tax::calculate(
+        &config.income_tax,
+        taxable_income,
+        assessment.is_splitting(),
+        church_member,
+    );
+
+    return PensionResult {
+        pension,
+        health_insurance,
+        income_taxes,
+        net_income: pension as i64 - health_insurance as i64 - income_taxes as i64,
+    };
+}
+
+/// Returns the price of one pension point, which is the retirement insurance premium on the average
+/// income.
+fn get_pension_point_price(config: &Config) -> Number {
+    return Number::from_u32(config.pension.average_income)
+        * Number::from_f32(config.retirement_insurance.premium);
+}
+
+/// Calculates the deductions of an early retirement, how much of them is offset by the special
+/// payment, and the income taxes that are saved by deducting the payment in the year of the payment.
+pub fn calculate_special_payment(
+    config: &Config,
+    tax_data: &TaxData,
+    data: &SpecialPaymentData,
+) -> Result<SpecialPaymentResult, Error> {
+    if data.early_months > MAX_EARLY_MONTHS {
+        return Err(Error::RetirementTooEarly);
+    }
+
+    let pension_deduction = calculate_pension_deduction(data.monthly_pension, data.early_months);
+
+    // the bought pension points are reduced by the deduction as well
+    let pension_per_payment = Number::from_f32(get_access_factor(data.early_months))
+        * Number::from_f32(config.pension.pension_value)
+        / get_pension_point_price(config);
+    let offset_pension = (Number::from_u32(data.payment) * pension_per_payment)
+        .to_u32()
+        .min(pension_deduction);
+    let full_offset_payment = match data.early_months {
+        0 => 0,
+        _ => (Number::from_u32(pension_deduction) / pension_per_payment).to_u32(),
+    };
+
+    let (deductible_payment, tax_saving) =
+        calculate_provision_deduction(config, tax_data, data.payment)?;
+
+    return Ok(SpecialPaymentResult {
+        pension_deduction,
+        offset_pension,
+        full_offset_payment,
+        deductible_payment,
+        tax_saving,
+    });
+}
+
+/// Calculates which part of the given additional retirement provision expenses (e.g., a special
+/// payment or contributions to a Basisrente) is deductible from the taxable income and how much
+/// income taxes are saved by deducting it.
+///
+/// Returns the deductible part and the saved income taxes.
+pub(crate) fn calculate_provision_deduction(
+    config: &Config,
+    tax_data: &TaxData,
+    payment: u32,
+) -> Result<(u32, u32), Error> {
+    // the regular contributions of the employee and the employer use up the maximum first
+    let retirement_insurance = social_security::calculate(
+        &config.health_insurance,
+        &config.retirement_insurance,
+        &config.unemployment_insurance,
+        &tax_data.into(),
+    )?
+    .retirement_insurance;
+    let contributions = match tax_data.self_employed {
+        true => retirement_insurance,
+        false => retirement_insurance * 2,
+    };
+    let deductible_payment = payment.min(
+        config
+            .pension
+            .retirement_provision_limit
+            .saturating_sub(contributions),
+    );
+
+    let regular = crate::calculate(config, tax_data)?;
+    let mut pipeline = Pipeline::default();
+    pipeline.insert_before(
+        Stage::TaxableIncome,
+        Box::new(DeductionStep(deductible_payment)),
+    );
+    let with_payment = pipeline.calculate(config, tax_data)?;
+
+    return Ok((
+        deductible_payment,
+        regular.get_total_income_taxes() - with_payment.get_total_income_taxes(),
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "year-2025")]
+    use crate::Assessment;
+    #[cfg(feature = "year-2025")]
+    use crate::config::create as create_config;
+    #[cfg(feature = "year-2025")]
+    #[test]
+    fn test_pension_deduction() {
+        assert_eq!(calculate_pension_deduction(2000, 0), 0);
+        assert_eq!(calculate_pension_deduction(2000, 24), 144);
+        assert_eq!(get_access_factor(24), 0.928);
+    }
+
+    #[test]
+    #[cfg(feature = "year-2025")]
+    fn test_net_pension() {
+        let config = create_config(2025).unwrap();
+
+        // no income taxes for a low pension
+        let result = calculate_net_pension(&config, 12000, Assessment::Single, false);
+        assert_eq!(result.income_taxes, 0);
+        assert!(result.health_insurance > 0);
+
+        let result = calculate_net_pension(&config, 30000, Assessment::Single, false);
+        assert!(result.income_taxes > 0);
+        assert_eq!(
+            result.net_income,
+            30000 - result.health_insurance as i64 - result.income_taxes as i64
+        );
+
+        // members of a church additionally pay the church tax on the pension
+        let church_result = calculate_net_pension(&config, 30000, Assessment::Single, true);
+        assert!(church_result.income_taxes > result.income_taxes);
+    }
+
+    #[test]
+    #[cfg(feature = "year-2025")]
+    fn test_taxable_share_of_later_years() {
+        let config = create_config(2025).unwrap();
+        assert_eq!(get_taxable_share(&config, 2025), 0.835);
+        assert_eq!(get_taxable_share(&config, 2024), 0.835);
+        assert_eq!(get_taxable_share(&config, 2045), 0.935);
+        assert_eq!(get_taxable_share(&config, 2058), 1.0);
+        assert_eq!(get_taxable_share(&config, 2070), 1.0);
+
+        let result = calculate_net_pension_of_year(&config, 30000, 2045, Assessment::Single, false);
+        assert!(
+            result.income_taxes
+                > calculate_net_pension(&config, 30000, Assessment::Single, false).income_taxes
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "year-2025")]
+    fn test_special_payment() {
+        let config = create_config(2025).unwrap();
+        let data = SpecialPaymentData {
+            payment: 10000,
+            monthly_pension: 2000,
+            early_months: 24,
+        };
+
+        let result = calculate_special_payment(&config, &TaxData::new(70000), &data).unwrap();
+        assert_eq!(result.pension_deduction, 144);
+        assert!(result.offset_pension > 0 && result.offset_pension < 144);
+        assert!(result.full_offset_payment > 10000);
+        assert_eq!(result.deductible_payment, 10000);
+        assert!(result.tax_saving > 3000);
+    }
+
+    #[test]
+    #[cfg(feature = "year-2025")]
+    fn test_deductible_payment_limited_by_contributions() {
+        let config = create_config(2025).unwrap();
+        let data = SpecialPaymentData {
+            payment: 30000,
+            monthly_pension: 2000,
+            early_months: 24,
+        };
+
+        // the regular contributions on the maximum income are 17966
+        let result = calculate_special_payment(&config, &TaxData::new(100000), &data).unwrap();
+        assert_eq!(result.deductible_payment, 29344 - 17966);
+        assert_eq!(result.offset_pension, 120);
+
+        let data = SpecialPaymentData {
+            early_months: 61,
+            ..data
+        };
+        assert_eq!(
+            calculate_special_payment(&config, &TaxData::new(100000), &data).err(),
+            Some(Error::RetirementTooEarly)
+        );
+    }
+}
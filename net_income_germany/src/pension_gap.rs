@@ -0,0 +1,166 @@
+//! Pension gap (Rentenlücke) between the expected statutory pension and a target net income.
+//!
+//! The pension points (Entgeltpunkte) that are earned until the retirement are estimated from the
+//! yearly gross income: every year earns the ratio of the income (up to the maximum income of the
+//! retirement insurance) to the average income of all insured persons. The expected pension is the
+//! sum of the pension points (including the already earned ones, e.g., from the Renteninformation)
+//! times the pension value of one pension point.
+//!
+//! The net pension is calculated with the contributions of the health insurance of retirees and the
+//! income taxes on the taxable share of the year of the retirement (see [`crate::pension`]). All
+//! other values are the ones of the configuration, so that the results are in today's money
+//! (without increases of incomes and pensions).
+
+use crate::Assessment;
+use crate::config::Config;
+use crate::error::Error;
+use crate::numeric::{Number, Numeric};
+use crate::pension::{self, PensionResult};
+
+/// Input data struct for the pension gap analysis.
+#[derive(Clone)]
+pub struct PensionGapData {
+    /// The pension points that were already earned (e.g., as stated by the Renteninformation).
+    pub pension_points: f32,
+
+    /// The yearly gross income of the remaining working years, on which retirement insurance
+    /// contributions are paid.
+    pub income: u32,
+
+    /// The year in which the pension starts, which needs to be the year of the configuration or a
+    /// later year (the years in between are the remaining working years).
+    pub retirement_year: u32,
+
+    /// The monthly net income that is targeted in the retirement.
+    pub target_net_income: u32,
+
+    /// The type of the income tax assessment in the retirement.
+    pub assessment: Assessment,
+
+    /// Whether the person is a member of a church and pays the church tax on the pension.
+    pub church_member: bool,
+}
+
+/// Result struct of the pension gap analysis.
+pub struct PensionGapResult {
+    /// The pension points at the retirement (already earned and estimated ones).
+    pub pension_points: f32,
+
+    /// The expected monthly gross pension.
+    pub monthly_pension: u32,
+
+    /// The yearly net pension with the contributions and the income taxes.
+    pub net_pension: PensionResult,
+
+    /// The expected monthly net pension.
+    pub monthly_net_pension: i64,
+
+    /// The monthly gap between the target net income and the net pension (zero if the net pension
+    /// reaches the target).
+    pub monthly_gap: u32,
+}
+
+/// Estimates the pension points that are earned with the given yearly gross income in the given
+/// number of years.
+pub fn estimate_pension_points(config: &Config, income: u32, years: u32) -> f32 {
+    let max_income =
+        Number::from_f32(config.retirement_insurance.max_income) * Number::from_u32(12);
+    let income = Number::from_u32(income).min(max_income);
+
+    return (income / Number::from_u32(config.pension.average_income) * Number::from_u32(years))
+        .to_f32();
+}
+
+/// Calculates the expected net pension and its monthly gap to the target net income.
+pub fn calculate(config: &Config, data: &PensionGapData) -> Result<PensionGapResult, Error> {
+    if data.retirement_year < config.origin.year {
+        return Err(Error::RetirementYearInPast);
+    }
+
+    let working_years = data.retirement_year - config.origin.year;
+    let pension_points = (Number::from_f32(data.pension_points)
+        + Number::from_f32(estimate_pension_points(config, data.income, working_years)))
+    .to_f32();
+    let monthly_pension = (Number::from_f32(pension_points)
+        * Number::from_f32(config.pension.pension_value))
+    .to_u32();
+
+    let net_pension = pension::calculate_net_pension_of_year(
+        config,
+        monthly_pension * 12,
+        data.retirement_year,
+        data.assessment,
+        data.church_member,
+    );
+    let monthly_net_pension = net_pension.net_income / 12;
+
+    return Ok(PensionGapResult {
+        pension_points,
+        monthly_pension,
+        net_pension,
+        monthly_net_pension,
+        monthly_gap: (data.target_net_income as i64 - monthly_net_pension).max(0) as u32,
+    });
+}
+
+#[cfg(all(test, feature = "year-2025"))]
+mod tests {
+    use super::*;
+    use crate::config::create as create_config;
+
+    fn create_data(pension_points: f32, income: u32, retirement_year: u32) -> PensionGapData {
+        return PensionGapData {
+            pension_points,
+            income,
+            retirement_year,
+            target_net_income: 2500,
+            assessment: Assessment::Single,
+            church_member: false,
+        };
+    }
+
+    #[test]
+    fn test_estimate_pension_points() {
+        let config = create_config(2025).unwrap();
+        let average_income = config.pension.average_income;
+
+        assert_eq!(estimate_pension_points(&config, average_income, 10), 10.0);
+        assert_eq!(
+            estimate_pension_points(&config, average_income * 3 / 2, 0),
+            0.0
+        );
+
+        // the income is only considered up to the maximum income of the retirement insurance
+        assert_eq!(
+            estimate_pension_points(&config, 200000, 1),
+            estimate_pension_points(&config, 96600, 1)
+        );
+    }
+
+    #[test]
+    fn test_pension_gap() {
+        let config = create_config(2025).unwrap();
+
+        let result = calculate(&config, &create_data(20.0, 0, 2025)).unwrap();
+        assert_eq!(result.monthly_pension, 815);
+        assert_eq!(
+            result.monthly_net_pension,
+            result.net_pension.net_income / 12
+        );
+        assert_eq!(result.monthly_gap as i64, 2500 - result.monthly_net_pension);
+
+        // additional working years reduce the gap
+        let later = calculate(&config, &create_data(20.0, 60000, 2045)).unwrap();
+        assert!(later.pension_points > 40.0);
+        assert!(later.monthly_gap < result.monthly_gap);
+
+        // no gap if the net pension exceeds the target
+        let high = calculate(&config, &create_data(80.0, 0, 2025)).unwrap();
+        assert_eq!(high.monthly_gap, 0);
+
+        assert_eq!(
+            calculate(&config, &create_data(20.0, 0, 2024)).err(),
+            Some(Error::RetirementYearInPast)
+        );
+    }
+}
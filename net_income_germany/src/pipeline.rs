@@ -0,0 +1,562 @@
+//! Calculation pipeline consisting of an ordered list of steps.
+//!
+//! The net income calculation is split into steps (social security, deductions, income tax tariff,
+//! solidarity surcharge, church tax) that implement the [`TaxStep`] trait. The steps are executed
+//! in order and modify a shared [`CalculationState`]. Custom steps (e.g., for a company-specific
+//! benefit) can be inserted before or after any [`Stage`] of the default calculation:
+//!
+//! ```
+//! use net_income_germany::error::Error;
+//! use net_income_germany::observer::CalculationObserver;
+//! use net_income_germany::pipeline::{CalculationState, Pipeline, Stage, TaxStep};
+//!
+//! /// Allowance that reduces the taxable income by a fixed amount.
+//! struct Allowance(u32);
+//!
+//! impl TaxStep for Allowance {
+//!     fn apply(
+//!         &self,
+//!         state: &mut CalculationState,
+//!         _observer: &mut dyn CalculationObserver,
+//!     ) -> Result<(), Error> {
+//!         state.deductions += self.0;
+//!         Ok(())
+//!     }
+//! }
+//!
+//! let config = net_income_germany::config::create(net_income_germany::config::latest_year()).unwrap();
+//! let tax_data = net_income_germany::TaxData::new(50000);
+//!
+//! // insert the allowance before the taxable income is calculated from the deductions
+//! let mut pipeline = Pipeline::default();
+//! pipeline.insert_before(Stage::TaxableIncome, Box::new(Allowance(1000)));
+//!
+//! let tax_result = pipeline.calculate(&config, &tax_data).unwrap();
+//! assert!(tax_result.income_taxes < net_income_germany::calculate(&config, &tax_data).unwrap().income_taxes);
+//! ```
+
+use crate::config::Config;
+use crate::error::Error;
+use std::borrow::Cow;
+
+use crate::numeric::{Number, Numeric};
+use crate::observer::{CalculationObserver, NoObserver};
+use crate::{TaxData, TaxResult, income_tax, social_security};
+
+/// Configuration values that the default steps need for the tax data of a calculation, converted
+/// once into the number type of the calculation, so that repeated calculations with the same
+/// configuration (e.g., by the reverse calculation) do not convert them again.
+#[derive(Clone)]
+pub(crate) struct StepParameters<'a> {
+    social_security: social_security::SocialSecurityParameters,
+    tariff: income_tax::TariffParameters<'a>,
+    solidarity_addition: income_tax::SolidarityAdditionParameters,
+    church_tax_rate: Number,
+}
+
+impl<'a> StepParameters<'a> {
+    /// Converts the values of the given configuration that apply for the given tax data.
+    pub(crate) fn new(config: &'a Config, tax_data: &TaxData) -> Self {
+        return StepParameters {
+            social_security: social_security::SocialSecurityParameters::new(
+                &config.health_insurance,
+                &config.retirement_insurance,
+                &config.unemployment_insurance,
+                tax_data.self_employed,
+            ),
+            tariff: income_tax::TariffParameters::new(&config.income_tax),
+            solidarity_addition: income_tax::SolidarityAdditionParameters::new(
+                &config.income_tax.solidary_addition_config,
+                tax_data.assessment.is_splitting(),
+            ),
+            church_tax_rate: Number::from_f32(config.income_tax.church_tax_rate),
+        };
+    }
+}
+
+/// Intermediate state of the calculation that is passed through all steps of the pipeline.
+pub struct CalculationState<'a> {
+    /// The configuration that is used for the calculation.
+    pub config: &'a Config,
+
+    /// The input data of the calculation.
+    pub tax_data: &'a TaxData,
+
+    /// The social security taxes that are deducted from the gross income.
+    pub social_security_taxes: u32,
+
+    /// The sum of all amounts that are deducted from the gross income to get the taxable income.
+    pub deductions: u32,
+
+    /// The taxable income on which the income tax tariff is applied.
+    pub taxable_income: u32,
+
+    /// The income taxes according to the income tax tariff.
+    pub income_taxes: u32,
+
+    /// The solidarity surcharge on the income taxes.
+    pub solidarity_surcharge: u32,
+
+    /// The church tax on the income taxes.
+    pub church_tax: u32,
+
+    /// The converted configuration values for the default steps.
+    parameters: Cow<'a, StepParameters<'a>>,
+}
+
+impl<'a> CalculationState<'a> {
+    /// Creates the initial state of a calculation for the given configuration and tax data.
+    pub fn new(config: &'a Config, tax_data: &'a TaxData) -> Self {
+        let parameters = Cow::Owned(StepParameters::new(config, tax_data));
+        return CalculationState::with_parameters(config, tax_data, parameters);
+    }
+
+    /// Creates the initial state of a calculation like [`CalculationState::new`], but with the
+    /// given converted configuration values.
+    fn with_parameters(
+        config: &'a Config,
+        tax_data: &'a TaxData,
+        parameters: Cow<'a, StepParameters<'a>>,
+    ) -> Self {
+        return CalculationState {
+            config,
+            tax_data,
+            social_security_taxes: 0,
+            deductions: 0,
+            taxable_income: 0,
+            income_taxes: 0,
+            solidarity_surcharge: 0,
+            church_tax: 0,
+            parameters,
+        };
+    }
+
+    /// Creates the tax result from the current state of the calculation.
+    pub fn to_result(&self) -> TaxResult {
+        let income_taxes = self.income_taxes + self.solidarity_surcharge;
+
+        return TaxResult {
+            gross_income: self.tax_data.income as i32,
+            net_income: (self.tax_data.income as i64
+                - self.tax_data.expenses as i64
+                - self.social_security_taxes as i64
+                - income_taxes as i64
+                - self.church_tax as i64) as i32,
+            social_security_taxes: self.social_security_taxes,
+            income_taxes,
+            church_tax: self.church_tax,
+        };
+    }
+}
+
+/// Stages of the default calculation, at which custom steps can be inserted into the pipeline
+/// (see [`Pipeline::insert_before`] and [`Pipeline::insert_after`]) independent of the position of
+/// the steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    /// Calculation of the social security taxes ([`SocialSecurityStep`]).
+    SocialSecurity,
+    /// Deduction of the tax-deductible expenses ([`ExpensesStep`]).
+    Expenses,
+    /// Calculation of the taxable income from the deductions ([`TaxableIncomeStep`]).
+    TaxableIncome,
+    /// Calculation of the income taxes with the tariff ([`TariffStep`]).
+    Tariff,
+    /// Calculation of the solidarity surcharge ([`SolidaritySurchargeStep`]).
+    SolidaritySurcharge,
+    /// Calculation of the church tax ([`ChurchTaxStep`]).
+    ChurchTax,
+}
+
+/// One step of the calculation pipeline.
+pub trait TaxStep {
+    /// Applies the step on the given state of the calculation and notifies the observer about the
+    /// intermediate values.
+    fn apply(
+        &self,
+        state: &mut CalculationState,
+        observer: &mut dyn CalculationObserver,
+    ) -> Result<(), Error>;
+
+    /// Returns the stage of the default calculation that this step implements, or `None` for
+    /// additional steps. A step that replaces a default step needs to return the stage of it.
+    fn stage(&self) -> Option<Stage> {
+        return None;
+    }
+}
+
+/// Calculates the social security taxes and adds them to the deductions.
+pub struct SocialSecurityStep;
+
+impl TaxStep for SocialSecurityStep {
+    fn apply(
+        &self,
+        state: &mut CalculationState,
+        observer: &mut dyn CalculationObserver,
+    ) -> Result<(), Error> {
+        state.social_security_taxes = state
+            .parameters
+            .social_security
+            .calculate_observed(
+                state.tax_data.income,
+                state.tax_data.fixed_retirement,
+                observer,
+            )
+            .total;
+        state.deductions = state.deductions.saturating_add(state.social_security_taxes);
+
+        return Ok(());
+    }
+
+    fn stage(&self) -> Option<Stage> {
+        return Some(Stage::SocialSecurity);
+    }
+}
+
+/// Adds the tax-deductible expenses to the deductions.
+pub struct ExpensesStep;
+
+impl TaxStep for ExpensesStep {
+    fn apply(
+        &self,
+        state: &mut CalculationState,
+        _observer: &mut dyn CalculationObserver,
+    ) -> Result<(), Error> {
+        state.deductions = state.deductions.saturating_add(state.tax_data.expenses);
+
+        return Ok(());
+    }
+
+    fn stage(&self) -> Option<Stage> {
+        return Some(Stage::Expenses);
+    }
+}
+
+/// Adds a fixed amount to the deductions (e.g., for a part of the gross income that is subject to
+/// social security, but exempt from income taxes).
+pub struct DeductionStep(pub u32);
+
+impl TaxStep for DeductionStep {
+    fn apply(
+        &self,
+        state: &mut CalculationState,
+        _observer: &mut dyn CalculationObserver,
+    ) -> Result<(), Error> {
+        state.deductions = state.deductions.saturating_add(self.0);
+
+        return Ok(());
+    }
+}
+
+/// Calculates the taxable income by subtracting the deductions from the gross income.
+pub struct TaxableIncomeStep;
+
+impl TaxStep for TaxableIncomeStep {
+    fn apply(
+        &self,
+        state: &mut CalculationState,
+        observer: &mut dyn CalculationObserver,
+    ) -> Result<(), Error> {
+        state.taxable_income = state.tax_data.income.saturating_sub(state.deductions);
+        observer.on_taxable_income(state.taxable_income);
+
+        return Ok(());
+    }
+
+    fn stage(&self) -> Option<Stage> {
+        return Some(Stage::TaxableIncome);
+    }
+}
+
+/// Calculates the income taxes from the taxable income with the income tax tariff.
+pub struct TariffStep;
+
+impl TaxStep for TariffStep {
+    fn apply(
+        &self,
+        state: &mut CalculationState,
+        observer: &mut dyn CalculationObserver,
+    ) -> Result<(), Error> {
+        state.income_taxes = state.parameters.tariff.calculate_observed(
+            state.taxable_income,
+            state.tax_data.assessment.is_splitting(),
+            observer,
+        );
+        observer.on_income_taxes(state.income_taxes);
+
+        return Ok(());
+    }
+
+    fn stage(&self) -> Option<Stage> {
+        return Some(Stage::Tariff);
+    }
+}
+
+/// Calculates the solidarity surcharge on the income taxes.
+pub struct SolidaritySurchargeStep;
+
+impl TaxStep for SolidaritySurchargeStep {
+    fn apply(
+        &self,
+        state: &mut CalculationState,
+        observer: &mut dyn CalculationObserver,
+    ) -> Result<(), Error> {
+        state.solidarity_surcharge = state
+            .parameters
+            .solidarity_addition
+            .calculate(state.income_taxes);
+        observer.on_solidarity_surcharge(state.solidarity_surcharge);
+
+        return Ok(());
+    }
+
+    fn stage(&self) -> Option<Stage> {
+        return Some(Stage::SolidaritySurcharge);
+    }
+}
+
+/// Calculates the church tax on the income taxes, if the person is a member of a church.
+pub struct ChurchTaxStep;
+
+impl TaxStep for ChurchTaxStep {
+    fn apply(
+        &self,
+        state: &mut CalculationState,
+        observer: &mut dyn CalculationObserver,
+    ) -> Result<(), Error> {
+        if state.tax_data.church_member {
+            state.church_tax = income_tax::calculate_church_tax_with_rate(
+                state.income_taxes,
+                state.parameters.church_tax_rate,
+            );
+            observer.on_church_tax(state.church_tax);
+        }
+
+        return Ok(());
+    }
+
+    fn stage(&self) -> Option<Stage> {
+        return Some(Stage::ChurchTax);
+    }
+}
+
+/// The steps of the default calculation, as used by [`crate::calculate`].
+pub(crate) const DEFAULT_STEPS: [&dyn TaxStep; 6] = [
+    &SocialSecurityStep,
+    &ExpensesStep,
+    &TaxableIncomeStep,
+    &TariffStep,
+    &SolidaritySurchargeStep,
+    &ChurchTaxStep,
+];
+
+/// Returns the default steps up to and including the step of the given stage.
+pub(crate) fn default_steps_until(stage: Stage) -> impl Iterator<Item = &'static dyn TaxStep> {
+    let position = DEFAULT_STEPS
+        .iter()
+        .position(|step| step.stage() == Some(stage))
+        .expect("every stage has a default step");
+
+    return DEFAULT_STEPS[..=position].iter().copied();
+}
+
+/// Runs the given steps in order on a new calculation state.
+pub(crate) fn run<'a, 's>(
+    steps: impl IntoIterator<Item = &'s dyn TaxStep>,
+    config: &'a Config,
+    tax_data: &'a TaxData,
+    observer: &mut dyn CalculationObserver,
+) -> Result<CalculationState<'a>, Error> {
+    return run_state(steps, CalculationState::new(config, tax_data), observer);
+}
+
+/// Runs the given steps in order like [`run`], but with the given converted configuration values,
+/// which need to be converted for the given configuration and tax data (see [`StepParameters::new`]).
+pub(crate) fn run_with_parameters<'a, 's>(
+    steps: impl IntoIterator<Item = &'s dyn TaxStep>,
+    config: &'a Config,
+    tax_data: &'a TaxData,
+    parameters: &'a StepParameters<'a>,
+    observer: &mut dyn CalculationObserver,
+) -> Result<CalculationState<'a>, Error> {
+    let state = CalculationState::with_parameters(config, tax_data, Cow::Borrowed(parameters));
+    return run_state(steps, state, observer);
+}
+
+fn run_state<'a, 's>(
+    steps: impl IntoIterator<Item = &'s dyn TaxStep>,
+    mut state: CalculationState<'a>,
+    observer: &mut dyn CalculationObserver,
+) -> Result<CalculationState<'a>, Error> {
+    for step in steps {
+        step.apply(&mut state, observer)?;
+    }
+
+    return Ok(state);
+}
+
+/// Ordered list of calculation steps that can be extended by custom steps.
+pub struct Pipeline {
+    steps: Vec<Box<dyn TaxStep>>,
+}
+
+impl Default for Pipeline {
+    /// Create the pipeline with the steps of the default calculation.
+    fn default() -> Self {
+        return Pipeline {
+            steps: vec![
+                Box::new(SocialSecurityStep),
+                Box::new(ExpensesStep),
+                Box::new(TaxableIncomeStep),
+                Box::new(TariffStep),
+                Box::new(SolidaritySurchargeStep),
+                Box::new(ChurchTaxStep),
+            ],
+        };
+    }
+}
+
+impl Pipeline {
+    /// Creates a pipeline without any steps.
+    pub fn new() -> Self {
+        return Pipeline { steps: Vec::new() };
+    }
+
+    /// Appends the given step at the end of the pipeline.
+    pub fn push(&mut self, step: Box<dyn TaxStep>) {
+        self.steps.push(step);
+    }
+
+    /// Inserts the given step at the given position of the pipeline.
+    ///
+    /// Panics if the index is larger than the number of steps.
+    pub fn insert(&mut self, index: usize, step: Box<dyn TaxStep>) {
+        self.steps.insert(index, step);
+    }
+
+    /// Inserts the given step directly before the step of the given stage.
+    ///
+    /// Panics if the pipeline contains no step of the given stage.
+    pub fn insert_before(&mut self, stage: Stage, step: Box<dyn TaxStep>) {
+        let index = self.position(stage);
+        self.steps.insert(index, step);
+    }
+
+    /// Inserts the given step directly after the step of the given stage.
+    ///
+    /// Panics if the pipeline contains no step of the given stage.
+    pub fn insert_after(&mut self, stage: Stage, step: Box<dyn TaxStep>) {
+        let index = self.position(stage);
+        self.steps.insert(index + 1, step);
+    }
+
+    /// Replaces the step of the given stage with the given step, which needs to implement the same
+    /// stage (see [`TaxStep::stage`]).
+    ///
+    /// Panics if the pipeline contains no step of the given stage.
+    pub fn replace(&mut self, stage: Stage, step: Box<dyn TaxStep>) {
+        let index = self.position(stage);
+        self.steps[index] = step;
+    }
+
+    /// Returns the position of the step of the given stage.
+    fn position(&self, stage: Stage) -> usize {
+        return self
+            .steps
+            .iter()
+            .position(|step| step.stage() == Some(stage))
+            .unwrap_or_else(|| panic!("the pipeline contains no step of the stage {stage:?}"));
+    }
+
+    /// Returns the number of steps of the pipeline.
+    pub fn len(&self) -> usize {
+        return self.steps.len();
+    }
+
+    /// Returns whether the pipeline contains no steps.
+    pub fn is_empty(&self) -> bool {
+        return self.steps.is_empty();
+    }
+
+    /// Runs all steps of the pipeline and returns the resulting tax result.
+    pub fn calculate(&self, config: &Config, tax_data: &TaxData) -> Result<TaxResult, Error> {
+        return self.calculate_with_observer(config, tax_data, &mut NoObserver);
+    }
+
+    /// Runs all steps of the pipeline like [`Pipeline::calculate`] and notifies the given observer
+    /// about the intermediate values.
+    pub fn calculate_with_observer(
+        &self,
+        config: &Config,
+        tax_data: &TaxData,
+        observer: &mut dyn CalculationObserver,
+    ) -> Result<TaxResult, Error> {
+        let steps = self.steps.iter().map(|step| step.as_ref());
+
+        return Ok(run(steps, config, tax_data, observer)?.to_result());
+    }
+}
+
+#[cfg(all(test, feature = "year-2025"))]
+mod tests {
+    use super::*;
+    use crate::config::create as create_config;
+
+    #[test]
+    fn test_default_pipeline_equals_calculate() {
+        let config = create_config(2025).unwrap();
+
+        for income in [0, 20000, 50000, 150000] {
+            let tax_data = TaxData::new(income).with_expenses(1500);
+
+            let expected = crate::calculate(&config, &tax_data).unwrap();
+            let result = Pipeline::default().calculate(&config, &tax_data).unwrap();
+
+            assert_eq!(result.net_income, expected.net_income);
+            assert_eq!(result.income_taxes, expected.income_taxes);
+            assert_eq!(result.social_security_taxes, expected.social_security_taxes);
+        }
+    }
+
+    #[test]
+    fn test_empty_pipeline() {
+        let config = create_config(2025).unwrap();
+        let tax_data = TaxData::new(50000);
+
+        let pipeline = Pipeline::new();
+        assert!(pipeline.is_empty());
+
+        // without any steps, the gross income is the net income
+        let result = pipeline.calculate(&config, &tax_data).unwrap();
+        assert_eq!(result.net_income, 50000);
+    }
+
+    #[test]
+    fn test_insert_at_stages() {
+        let config = create_config(2025).unwrap();
+        let tax_data = TaxData::new(50000);
+        let expected = crate::calculate(&config, &tax_data).unwrap();
+
+        // a deduction before the taxable income reduces it, but one after it has no effect anymore
+        let mut pipeline = Pipeline::default();
+        pipeline.insert_after(Stage::TaxableIncome, Box::new(DeductionStep(1000)));
+        let result = pipeline.calculate(&config, &tax_data).unwrap();
+        assert_eq!(result.income_taxes, expected.income_taxes);
+
+        pipeline.insert_before(Stage::TaxableIncome, Box::new(DeductionStep(1000)));
+        assert_eq!(pipeline.len(), 8);
+        let result = pipeline.calculate(&config, &tax_data).unwrap();
+        assert!(result.income_taxes < expected.income_taxes);
+
+        // the stages are still found after the replacement of a default step
+        pipeline.replace(Stage::Tariff, Box::new(TariffStep));
+        pipeline.insert_after(Stage::ChurchTax, Box::new(DeductionStep(1000)));
+        assert_eq!(pipeline.len(), 9);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_insert_at_missing_stage() {
+        Pipeline::new().insert_before(Stage::Tariff, Box::new(DeductionStep(1000)));
+    }
+}
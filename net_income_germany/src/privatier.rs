@@ -0,0 +1,175 @@
+//! Calculation for persons living off their capital (Privatier, FIRE).
+//!
+//! Without any employment or self-employment income, a person needs to be voluntarily insured in
+//! the state-operated health insurance. The contributions are assessed on the capital income (but
+//! at least on the minimum income of the health insurance) and the capital income is taxed with
+//! the flat tax on capital income.
+//!
+//! Withdrawals from an investment consist of the invested capital and the gain on it. Only the gain
+//! is capital income, so that the share of the gain in the withdrawals needs to be given.
+
+use crate::capital_income;
+use crate::config::Config;
+use crate::error::Error;
+use crate::social_security::{self, SocialSecurityData};
+
+/// Input data struct for the privatier calculation.
+#[derive(Clone)]
+pub struct PrivatierData {
+    /// The yearly amount that is withdrawn from the investments.
+    pub withdrawal: u32,
+
+    /// The share \[0,1\] of the withdrawal that is capital income (gains, dividends, interest).
+    pub gain_ratio: f32,
+
+    /// Whether the saver's allowance for married couples should apply.
+    pub married: bool,
+}
+
+/// Result struct of the privatier calculation.
+pub struct PrivatierResult {
+    /// The yearly amount that is withdrawn from the investments.
+    pub withdrawal: u32,
+
+    /// The capital income part of the withdrawal.
+    pub capital_income: u32,
+
+    /// The contributions for the voluntary health and nursing care insurance.
+    pub health_insurance: u32,
+
+    /// The flat tax and the solidarity surcharge on the capital income.
+    pub capital_income_taxes: u32,
+
+    /// The amount that remains for living after health insurance and taxes.
+    pub net_income: i64,
+}
+
+/// Calculates the health insurance contributions and capital income taxes for the given yearly
+/// withdrawal and the remaining net income.
+pub fn calculate(config: &Config, data: &PrivatierData) -> Result<PrivatierResult, Error> {
+    if !(0.0..=1.0).contains(&data.gain_ratio) {
+        return Err(Error::InvalidGainRatio);
+    }
+
+    let capital_income = (data.withdrawal as f64 * data.gain_ratio as f64) as u32;
+
+    // voluntarily insured persons pay the same premiums as self-employed persons without sick pay,
+    // but no retirement or unemployment insurance
+    let social_security = social_security::calculate(
+        &config.health_insurance,
+        &config.retirement_insurance,
+        &config.unemployment_insurance,
+        &SocialSecurityData {
+            income: capital_income,
+            fixed_retirement: Some(0),
+            self_employed: true,
+        },
+    )?;
+    let health_insurance = social_security.total;
+
+    let capital_income_taxes =
+        capital_income::calculate(config, capital_income, data.married).get_taxes();
+
+    return Ok(PrivatierResult {
+        withdrawal: data.withdrawal,
+        capital_income,
+        health_insurance,
+        capital_income_taxes,
+        net_income: data.withdrawal as i64 - health_insurance as i64 - capital_income_taxes as i64,
+    });
+}
+
+/// Calculates which yearly withdrawal is needed to have the given net income for living after health
+/// insurance and taxes (using the gain ratio of the given data and ignoring its withdrawal).
+pub fn calculate_withdrawal_need(
+    config: &Config,
+    data: &PrivatierData,
+    net_income: u32,
+) -> Result<PrivatierResult, Error> {
+    let mut data = data.clone();
+
+    // the net income increases with the withdrawal, so a binary search finds the minimal withdrawal
+    let mut lower: u32 = net_income;
+    let mut upper: u32 = net_income
+        .saturating_mul(2)
+        .max(net_income.saturating_add(10000));
+
+    data.withdrawal = upper;
+    if calculate(config, &data)?.net_income < net_income as i64 {
+        return Err(Error::NoWithdrawalFound);
+    }
+
+    while lower < upper {
+        data.withdrawal = lower + (upper - lower) / 2;
+
+        if calculate(config, &data)?.net_income < net_income as i64 {
+            lower = data.withdrawal + 1;
+        } else {
+            upper = data.withdrawal;
+        }
+    }
+
+    data.withdrawal = lower;
+    return calculate(config, &data);
+}
+
+#[cfg(all(test, feature = "year-2025"))]
+mod tests {
+    use super::*;
+    use crate::config::create as create_config;
+
+    #[test]
+    fn test_minimum_health_insurance_on_low_capital_income() {
+        let config = create_config(2025).unwrap();
+        let data = PrivatierData {
+            withdrawal: 30000,
+            gain_ratio: 0.2,
+            married: false,
+        };
+
+        let result = calculate(&config, &data).unwrap();
+        assert_eq!(result.capital_income, 6000);
+
+        // the capital income is below the minimum income of the health insurance
+        let min_income = (config.health_insurance.min_income * 12.0) as u32;
+        assert!(result.health_insurance > (min_income as f32 * 0.2) as u32);
+        assert_eq!(result.capital_income_taxes, 1318);
+        assert_eq!(
+            result.net_income,
+            30000 - result.health_insurance as i64 - result.capital_income_taxes as i64
+        );
+    }
+
+    #[test]
+    fn test_withdrawal_need() {
+        let config = create_config(2025).unwrap();
+        let data = PrivatierData {
+            withdrawal: 0,
+            gain_ratio: 0.5,
+            married: false,
+        };
+
+        let result = calculate_withdrawal_need(&config, &data, 36000).unwrap();
+        assert!(result.net_income >= 36000);
+
+        // one euro less would not be enough
+        let mut data = data.clone();
+        data.withdrawal = result.withdrawal - 1;
+        assert!(calculate(&config, &data).unwrap().net_income < 36000);
+    }
+
+    #[test]
+    fn test_error_on_invalid_gain_ratio() {
+        let config = create_config(2025).unwrap();
+        let data = PrivatierData {
+            withdrawal: 30000,
+            gain_ratio: 1.5,
+            married: false,
+        };
+
+        assert_eq!(
+            calculate(&config, &data).err(),
+            Some(Error::InvalidGainRatio)
+        );
+    }
+}
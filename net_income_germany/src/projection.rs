@@ -0,0 +1,101 @@
+//! Projection of the taxes and the net income over multiple years.
+//!
+//! Every year of the projection is calculated with its own configuration and tax data, which allows
+//! to model changes of the income (or the tax rules) over time and to compare the summed up results
+//! of different scenarios.
+
+use crate::config::Config;
+use crate::error::Error;
+use crate::{TaxData, TaxResult, calculate as calculate_year};
+
+/// Input data of one year of the projection.
+pub struct ProjectionYear<'a> {
+    /// The configuration that applies for this year.
+    pub config: &'a Config,
+
+    /// The tax data (income, expenses, ...) of this year.
+    pub tax_data: TaxData,
+}
+
+/// Result struct of the projection, containing the results per year and their sums.
+pub struct ProjectionResult {
+    /// The tax results of every year of the projection (in the order of the input years).
+    pub years: Vec<TaxResult>,
+
+    /// The sum of the gross incomes of all years.
+    pub gross_income: i64,
+
+    /// The sum of the net incomes of all years.
+    pub net_income: i64,
+
+    /// The sum of the social security taxes of all years.
+    pub social_security_taxes: u64,
+
+    /// The sum of the income taxes of all years.
+    pub income_taxes: u64,
+
+    /// The sum of the church taxes of all years.
+    pub church_tax: u64,
+}
+
+/// Calculates the taxes for every given year and sums up the results.
+pub fn calculate(years: &[ProjectionYear]) -> Result<ProjectionResult, Error> {
+    let mut result = ProjectionResult {
+        years: Vec::with_capacity(years.len()),
+        gross_income: 0,
+        net_income: 0,
+        social_security_taxes: 0,
+        income_taxes: 0,
+        church_tax: 0,
+    };
+
+    for year in years {
+        let tax_result = calculate_year(year.config, &year.tax_data)?;
+
+        result.gross_income += tax_result.gross_income as i64;
+        result.net_income += tax_result.net_income as i64;
+        result.social_security_taxes += tax_result.social_security_taxes as u64;
+        result.income_taxes += tax_result.income_taxes as u64;
+        result.church_tax += tax_result.church_tax as u64;
+        result.years.push(tax_result);
+    }
+
+    return Ok(result);
+}
+
+#[cfg(all(test, feature = "year-2024", feature = "year-2025"))]
+mod tests {
+    use super::*;
+    use crate::config::create as create_config;
+
+    #[test]
+    fn test_sums_of_multiple_years() {
+        let config_2024 = create_config(2024).unwrap();
+        let config_2025 = create_config(2025).unwrap();
+
+        let tax_data = TaxData::new(50000).with_expenses(1000);
+
+        let result = calculate(&[
+            ProjectionYear {
+                config: &config_2024,
+                tax_data: tax_data.clone(),
+            },
+            ProjectionYear {
+                config: &config_2025,
+                tax_data: tax_data.clone(),
+            },
+        ])
+        .unwrap();
+
+        assert_eq!(result.years.len(), 2);
+        assert_eq!(result.gross_income, 100000);
+        assert_eq!(
+            result.net_income,
+            result.years[0].net_income as i64 + result.years[1].net_income as i64
+        );
+        assert_eq!(
+            result.income_taxes,
+            result.years[0].income_taxes as u64 + result.years[1].income_taxes as u64
+        );
+    }
+}
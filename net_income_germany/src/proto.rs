@@ -0,0 +1,254 @@
+//! Message types of the Protocol Buffers schema and their conversions.
+//!
+//! The schema is defined in `proto/net_income_germany.proto` of the crate (package
+//! `net_income_germany.v1`). The types of this module are generated from the schema with prost, so
+//! that the messages can be encoded and decoded with [`prost::Message`] and the enumerations are
+//! stored as `i32` fields. This allows to embed the calculation into systems that exchange the
+//! messages (e.g., payroll systems with gRPC interfaces) without ad-hoc mapping code:
+//!
+//! ```
+//! use net_income_germany::proto;
+//!
+//! let message = proto::TaxData {
+//!     income: 60000,
+//!     expenses: 0,
+//!     fixed_retirement: None,
+//!     self_employed: false,
+//!     assessment: proto::Assessment::Joint as i32,
+//!     church_member: true,
+//! };
+//!
+//! let config = net_income_germany::config::create(net_income_germany::config::latest_year()).unwrap();
+//! let tax_data = net_income_germany::TaxData::try_from(message).unwrap();
+//! let result = proto::TaxResult::from(&net_income_germany::calculate(&config, &tax_data).unwrap());
+//! println!("Net income: {}", result.net_income);
+//! ```
+
+use std::collections::BTreeMap;
+
+use crate::error::Error;
+use crate::result_v2::{DeductionCategory as Category, TaxResultV2};
+
+// the message types are generated from the schema by the build script
+include!(concat!(env!("OUT_DIR"), "/net_income_germany.v1.rs"));
+
+impl From<crate::Assessment> for Assessment {
+    fn from(assessment: crate::Assessment) -> Self {
+        return match assessment {
+            crate::Assessment::Single => Assessment::Single,
+            crate::Assessment::Joint => Assessment::Joint,
+            crate::Assessment::WidowedSplitting => Assessment::WidowedSplitting,
+        };
+    }
+}
+
+impl TryFrom<Assessment> for crate::Assessment {
+    type Error = Error;
+
+    /// Converts the assessment of a message, which fails if no assessment was specified.
+    fn try_from(assessment: Assessment) -> Result<Self, Self::Error> {
+        return match assessment {
+            Assessment::Unspecified => Err(Error::MissingAssessment),
+            Assessment::Single => Ok(crate::Assessment::Single),
+            Assessment::Joint => Ok(crate::Assessment::Joint),
+            Assessment::WidowedSplitting => Ok(crate::Assessment::WidowedSplitting),
+        };
+    }
+}
+
+impl From<Category> for DeductionCategory {
+    fn from(category: Category) -> Self {
+        return match category {
+            Category::HealthInsurance => DeductionCategory::HealthInsurance,
+            Category::NursingInsurance => DeductionCategory::NursingInsurance,
+            Category::RetirementInsurance => DeductionCategory::RetirementInsurance,
+            Category::UnemploymentInsurance => DeductionCategory::UnemploymentInsurance,
+            Category::IncomeTax => DeductionCategory::IncomeTax,
+            Category::SolidaritySurcharge => DeductionCategory::SolidaritySurcharge,
+            Category::ChurchTax => DeductionCategory::ChurchTax,
+        };
+    }
+}
+
+impl TryFrom<DeductionCategory> for Category {
+    type Error = Error;
+
+    /// Converts the category of a message, which fails if no category was specified.
+    fn try_from(category: DeductionCategory) -> Result<Self, Self::Error> {
+        return match category {
+            DeductionCategory::Unspecified => Err(Error::UnknownDeductionCategory),
+            DeductionCategory::HealthInsurance => Ok(Category::HealthInsurance),
+            DeductionCategory::NursingInsurance => Ok(Category::NursingInsurance),
+            DeductionCategory::RetirementInsurance => Ok(Category::RetirementInsurance),
+            DeductionCategory::UnemploymentInsurance => Ok(Category::UnemploymentInsurance),
+            DeductionCategory::IncomeTax => Ok(Category::IncomeTax),
+            DeductionCategory::SolidaritySurcharge => Ok(Category::SolidaritySurcharge),
+            DeductionCategory::ChurchTax => Ok(Category::ChurchTax),
+        };
+    }
+}
+
+impl From<&crate::TaxData> for TaxData {
+    fn from(tax_data: &crate::TaxData) -> Self {
+        return TaxData {
+            income: tax_data.income,
+            expenses: tax_data.expenses,
+            fixed_retirement: tax_data.fixed_retirement,
+            self_employed: tax_data.self_employed,
+            assessment: Assessment::from(tax_data.assessment) as i32,
+            church_member: tax_data.church_member,
+        };
+    }
+}
+
+impl TryFrom<TaxData> for crate::TaxData {
+    type Error = Error;
+
+    /// Converts the message into the input data, which fails for an unknown or unspecified
+    /// assessment.
+    fn try_from(message: TaxData) -> Result<Self, Self::Error> {
+        return Ok(crate::TaxData::new(message.income)
+            .with_expenses(message.expenses)
+            .with_fixed_retirement(message.fixed_retirement)
+            .with_self_employed(message.self_employed)
+            .with_assessment(
+                Assessment::try_from(message.assessment)
+                    .map_err(|_| Error::UnknownAssessment)?
+                    .try_into()?,
+            )
+            .with_church_member(message.church_member));
+    }
+}
+
+impl From<&crate::TaxResult> for TaxResult {
+    fn from(result: &crate::TaxResult) -> Self {
+        return TaxResult {
+            gross_income: result.gross_income,
+            net_income: result.net_income,
+            social_security_taxes: result.social_security_taxes,
+            income_taxes: result.income_taxes,
+            church_tax: result.church_tax,
+        };
+    }
+}
+
+impl From<TaxResult> for crate::TaxResult {
+    fn from(message: TaxResult) -> Self {
+        return crate::TaxResult::new(
+            message.gross_income,
+            message.net_income,
+            message.social_security_taxes,
+            message.income_taxes,
+        )
+        .with_church_tax(message.church_tax);
+    }
+}
+
+impl From<&TaxResultV2> for TaxResultBreakdown {
+    fn from(result: &TaxResultV2) -> Self {
+        return TaxResultBreakdown {
+            gross_income: result.gross_income,
+            net_income: result.net_income,
+            deductions: result
+                .deductions
+                .iter()
+                .map(|(category, amount)| Deduction {
+                    category: DeductionCategory::from(*category) as i32,
+                    amount: *amount,
+                })
+                .collect(),
+        };
+    }
+}
+
+impl TryFrom<TaxResultBreakdown> for TaxResultV2 {
+    type Error = Error;
+
+    /// Converts the message into the result, which fails for an unknown or unspecified deduction
+    /// category.
+    fn try_from(message: TaxResultBreakdown) -> Result<Self, Self::Error> {
+        let mut deductions = BTreeMap::new();
+        for deduction in message.deductions {
+            let category = DeductionCategory::try_from(deduction.category)
+                .map_err(|_| Error::UnknownDeductionCategory)?;
+            deductions.insert(Category::try_from(category)?, deduction.amount);
+        }
+
+        return Ok(TaxResultV2 {
+            gross_income: message.gross_income,
+            net_income: message.net_income,
+            deductions,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::create as create_config;
+
+    #[test]
+    fn test_tax_data_roundtrip() {
+        let tax_data = crate::TaxData::new(80000)
+            .with_expenses(1200)
+            .with_fixed_retirement(Some(500))
+            .with_assessment(crate::Assessment::WidowedSplitting)
+            .with_church_member(true);
+
+        let message = TaxData::from(&tax_data);
+        assert_eq!(message.assessment, 3);
+        assert!(message.church_member);
+
+        let converted = crate::TaxData::try_from(message).unwrap();
+        assert_eq!(TaxData::from(&converted), message);
+
+        let invalid = TaxData {
+            assessment: 4,
+            ..message
+        };
+        assert_eq!(
+            crate::TaxData::try_from(invalid).err(),
+            Some(Error::UnknownAssessment)
+        );
+
+        // the default value of the field means that no assessment was specified
+        let unspecified = TaxData {
+            assessment: 0,
+            ..message
+        };
+        assert_eq!(
+            crate::TaxData::try_from(unspecified).err(),
+            Some(Error::MissingAssessment)
+        );
+    }
+
+    #[test]
+    fn test_message_encoding() {
+        let message = TaxData::from(&crate::TaxData::new(60000).with_fixed_retirement(Some(0)));
+
+        let encoded = prost::Message::encode_to_vec(&message);
+        let decoded = <TaxData as prost::Message>::decode(encoded.as_slice()).unwrap();
+        assert_eq!(decoded, message);
+        assert_eq!(decoded.fixed_retirement, Some(0));
+    }
+
+    #[test]
+    fn test_breakdown_roundtrip() {
+        let config = create_config(crate::config::latest_year()).unwrap();
+        let result = crate::result_v2::calculate(&config, &crate::TaxData::new(60000)).unwrap();
+
+        let message = TaxResultBreakdown::from(&result);
+        assert_eq!(message.deductions.len(), result.deductions.len());
+        assert_eq!(TaxResultV2::try_from(message.clone()).unwrap(), result);
+
+        let mut invalid = message;
+        invalid.deductions[0].category = 8;
+        assert!(TaxResultV2::try_from(invalid.clone()).is_err());
+
+        invalid.deductions[0].category = 0;
+        assert_eq!(
+            TaxResultV2::try_from(invalid).err(),
+            Some(Error::UnknownDeductionCategory)
+        );
+    }
+}
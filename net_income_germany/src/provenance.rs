@@ -0,0 +1,180 @@
+//! Legal sources of the configuration values.
+//!
+//! Every section of the configuration of a year is based on statutory provisions and on the
+//! publications that set the values for that year (e.g., the annual regulation of the social
+//! security values, Sozialversicherungsrechengrößen-Verordnung). The sources can be retrieved with
+//! [`crate::config::Config::provenance`] to trace every parameter back to its origin:
+//!
+//! ```
+//! let config = net_income_germany::config::create(net_income_germany::config::latest_year()).unwrap();
+//!
+//! for source in config.provenance() {
+//!     println!("{}: {} ({}, from {})", source.section, source.law, source.publication, source.valid_from);
+//! }
+//! ```
+
+use crate::error::Error;
+
+/// Legal source of one section of the configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct LegalSource {
+    /// The name of the section of the configuration (the field of [`crate::config::Config`]).
+    pub section: &'static str,
+
+    /// The statutory provisions that define the values.
+    pub law: &'static str,
+
+    /// The publication that sets the values for the year (e.g., in the Bundesgesetzblatt, BGBl).
+    pub publication: &'static str,
+
+    /// The date (YYYY-MM-DD) from which the values are valid.
+    pub valid_from: &'static str,
+}
+
+impl LegalSource {
+    const fn new(
+        section: &'static str,
+        law: &'static str,
+        publication: &'static str,
+        valid_from: &'static str,
+    ) -> Self {
+        return LegalSource {
+            section,
+            law,
+            publication,
+            valid_from,
+        };
+    }
+}
+
+/// Publications that set the values of one year and the dates from which they are valid, as read
+/// from the `sources` table of the configuration file of the year.
+struct YearSources {
+    social_security_publication: &'static str,
+    income_tax_publication: &'static str,
+    valid_from: &'static str,
+    pension_valid_from: &'static str,
+}
+
+// the sources of the included years (`year_sources`) are generated from the files in the `config`
+// directory by the build script
+include!(concat!(env!("OUT_DIR"), "/sources.rs"));
+
+/// Sources that are the same in all years (besides the date from which they are valid).
+const fn common_sources(valid_from: &'static str) -> [LegalSource; 8] {
+    return [
+        LegalSource::new(
+            "capital_income",
+            "§ 20, § 32d EStG, § 18 InvStG",
+            "Basiszins: Bekanntmachung des Bundesministeriums der Finanzen",
+            valid_from,
+        ),
+        LegalSource::new(
+            "retiree_insurance",
+            "§ 226, § 229, § 237 SGB V",
+            "Sozialversicherungsrechengrößen-Verordnung (Bezugsgröße)",
+            valid_from,
+        ),
+        LegalSource::new(
+            "minijob",
+            "§ 8, § 8a SGB IV, § 249b SGB V, § 172 SGB VI, § 40a EStG",
+            "Mindestlohnanpassungsverordnung",
+            valid_from,
+        ),
+        LegalSource::new(
+            "company_pension",
+            "§ 3 Nr. 63 EStG, § 1 Abs. 1 Nr. 9 SvEV, § 100 EStG",
+            "Sozialversicherungsrechengrößen-Verordnung (Beitragsbemessungsgrenze)",
+            valid_from,
+        ),
+        LegalSource::new(
+            "children",
+            "§ 31, § 32 Abs. 6, § 66 EStG",
+            "Jahressteuergesetze",
+            valid_from,
+        ),
+        LegalSource::new("vat", "§ 12, § 19 UStG", "Umsatzsteuergesetz", valid_from),
+        LegalSource::new(
+            "housing_benefit",
+            "§ 19 WoGG",
+            "Wohngeld-Plus-Gesetz, Wohngeld-Erhöhungsverordnung",
+            valid_from,
+        ),
+        LegalSource::new(
+            "midijob",
+            "§ 20 Abs. 2, 2a SGB IV",
+            "Faktor F: Bekanntmachung des Bundesministeriums für Arbeit und Soziales",
+            valid_from,
+        ),
+    ];
+}
+
+/// Returns the legal sources of all sections of the configuration of the given year (besides the
+/// origin of the configuration, which is not based on a legal source).
+///
+/// This function supports the years 2024 and 2025 and returns an error for every other year.
+pub fn get(year: u32) -> Result<Vec<LegalSource>, Error> {
+    let YearSources {
+        social_security_publication,
+        income_tax_publication,
+        valid_from,
+        pension_valid_from,
+    } = year_sources(year).ok_or(Error::unsupported_year(year))?;
+
+    let mut sources = vec![
+        LegalSource::new(
+            "health_insurance",
+            "§ 6, § 223, § 240, § 241, § 242 SGB V, § 55 SGB XI",
+            social_security_publication,
+            valid_from,
+        ),
+        LegalSource::new(
+            "retirement_insurance",
+            "§ 157, § 158, § 159 SGB VI",
+            social_security_publication,
+            valid_from,
+        ),
+        LegalSource::new(
+            "unemployment_insurance",
+            "§ 341 SGB III",
+            social_security_publication,
+            valid_from,
+        ),
+        LegalSource::new(
+            "income_tax",
+            "§ 32a, § 39b EStG, § 3, § 4 SolZG 1995",
+            income_tax_publication,
+            valid_from,
+        ),
+        LegalSource::new(
+            "pension",
+            "§ 22 Nr. 1, § 10 Abs. 3 EStG, § 68, § 69 SGB VI",
+            "Rentenwertbestimmungsverordnung",
+            pension_valid_from,
+        ),
+    ];
+    sources.extend(common_sources(valid_from));
+
+    return Ok(sources);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_all_sections_have_sources() {
+        for year in crate::config::supported_years() {
+            let sources = get(*year).unwrap();
+            assert_eq!(sources.len(), 13);
+            assert!(
+                sources
+                    .iter()
+                    .all(|source| source.valid_from.starts_with(&year.to_string()))
+            );
+        }
+
+        assert!(get(2023).is_err());
+    }
+}
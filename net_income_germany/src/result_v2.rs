@@ -0,0 +1,246 @@
+//! Result type with the deductions per category.
+//!
+//! Other than [`crate::TaxResult`], which contains the deductions as two lump sums (social security
+//! taxes and income taxes), the [`TaxResultV2`] contains a map from the category of each deduction to
+//! its amount. New taxes and contributions can be added as new categories without changing the
+//! result type:
+//!
+//! ```
+//! use net_income_germany::result_v2::DeductionCategory;
+//!
+//! let config = net_income_germany::config::create(net_income_germany::config::latest_year()).unwrap();
+//! let tax_data = net_income_germany::TaxData::new(60000);
+//!
+//! let result = net_income_germany::result_v2::calculate(&config, &tax_data).unwrap();
+//! println!("Health insurance: {}", result.get(DeductionCategory::HealthInsurance));
+//!
+//! for (category, amount) in &result.deductions {
+//!     println!("{category:?}: {amount}");
+//! }
+//! ```
+
+use std::collections::BTreeMap;
+
+use crate::config::Config;
+use crate::error::Error;
+use crate::observer::{CalculationObserver, Insurance};
+use crate::{TaxData, TaxResult, calculate_with_observer};
+
+/// Categories of the deductions from the gross income.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum DeductionCategory {
+    /// The health insurance (Krankenversicherung).
+    HealthInsurance,
+
+    /// The nursing care insurance (Pflegeversicherung).
+    NursingInsurance,
+
+    /// The retirement insurance (Rentenversicherung).
+    RetirementInsurance,
+
+    /// The unemployment insurance (Arbeitslosenversicherung).
+    UnemploymentInsurance,
+
+    /// The income tax according to the tariff (Einkommensteuer).
+    IncomeTax,
+
+    /// The solidarity surcharge (Solidaritätszuschlag).
+    SolidaritySurcharge,
+
+    /// The church tax (Kirchensteuer).
+    ChurchTax,
+}
+
+impl DeductionCategory {
+    /// Returns whether the category belongs to the social security contributions (otherwise it
+    /// belongs to the taxes).
+    pub fn is_social_security(&self) -> bool {
+        return match self {
+            DeductionCategory::HealthInsurance
+            | DeductionCategory::NursingInsurance
+            | DeductionCategory::RetirementInsurance
+            | DeductionCategory::UnemploymentInsurance => true,
+            DeductionCategory::IncomeTax
+            | DeductionCategory::SolidaritySurcharge
+            | DeductionCategory::ChurchTax => false,
+        };
+    }
+}
+
+impl From<Insurance> for DeductionCategory {
+    fn from(insurance: Insurance) -> Self {
+        return match insurance {
+            Insurance::Health => DeductionCategory::HealthInsurance,
+            Insurance::Nursing => DeductionCategory::NursingInsurance,
+            Insurance::Retirement => DeductionCategory::RetirementInsurance,
+            Insurance::Unemployment => DeductionCategory::UnemploymentInsurance,
+        };
+    }
+}
+
+/// Result struct of the tax calculation with the deductions per category.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct TaxResultV2 {
+    /// The gross income before all deductions.
+    pub gross_income: i32,
+
+    /// The net income after all deductions.
+    pub net_income: i32,
+
+    /// The amounts of all deductions that apply, by their category.
+    pub deductions: BTreeMap<DeductionCategory, u32>,
+}
+
+impl TaxResultV2 {
+    /// Returns the amount of the deduction of the given category, or zero if it does not apply.
+    pub fn get(&self, category: DeductionCategory) -> u32 {
+        return self.deductions.get(&category).copied().unwrap_or(0);
+    }
+
+    /// Returns the sum of all social security contributions.
+    pub fn get_social_security_taxes(&self) -> u32 {
+        return self.sum(true);
+    }
+
+    /// Returns the sum of all taxes.
+    pub fn get_income_taxes(&self) -> u32 {
+        return self.sum(false);
+    }
+
+    /// Returns the result with the lump sums of the deductions.
+    pub fn to_result(&self) -> TaxResult {
+        let church_tax = self.get(DeductionCategory::ChurchTax);
+
+        return TaxResult::new(
+            self.gross_income,
+            self.net_income,
+            self.get_social_security_taxes(),
+            self.get_income_taxes() - church_tax,
+        )
+        .with_church_tax(church_tax);
+    }
+
+    fn sum(&self, social_security: bool) -> u32 {
+        return self
+            .deductions
+            .iter()
+            .filter(|(category, _)| category.is_social_security() == social_security)
+            .map(|(_, amount)| amount)
+            .sum();
+    }
+}
+
+/// Observer that records the payments per insurance and the solidarity surcharge.
+#[derive(Default)]
+struct CategoryObserver {
+    insurances: Vec<(Insurance, f32)>,
+    solidarity_surcharge: u32,
+}
+
+impl CalculationObserver for CategoryObserver {
+    fn on_insurance(&mut self, insurance: Insurance, _: f32, _: f32, payment: f32) {
+        self.insurances.push((insurance, payment));
+    }
+
+    fn on_solidarity_surcharge(&mut self, solidarity_surcharge: u32) {
+        self.solidarity_surcharge = solidarity_surcharge;
+    }
+}
+
+/// Calculates social security taxes and income taxes like [`crate::calculate`], but returns the
+/// deductions per category.
+///
+/// As the social security taxes are rounded once after summing up, the rounding difference is added
+/// to the last insurance, so that the deductions add up to the lump sums of [`crate::calculate`].
+pub fn calculate(config: &Config, tax_data: &TaxData) -> Result<TaxResultV2, Error> {
+    let mut observer = CategoryObserver::default();
+    let result = calculate_with_observer(config, tax_data, &mut observer)?;
+
+    let mut deductions = BTreeMap::new();
+
+    let mut remaining_social_security = result.social_security_taxes as i64;
+    let insurances = observer.insurances.len();
+    for (index, (insurance, payment)) in observer.insurances.into_iter().enumerate() {
+        let amount = match index + 1 == insurances {
+            true => remaining_social_security,
+            false => payment.round() as i64,
+        };
+        remaining_social_security -= amount;
+
+        deductions.insert(DeductionCategory::from(insurance), amount.max(0) as u32);
+    }
+
+    deductions.insert(
+        DeductionCategory::IncomeTax,
+        result.income_taxes - observer.solidarity_surcharge,
+    );
+    deductions.insert(
+        DeductionCategory::SolidaritySurcharge,
+        observer.solidarity_surcharge,
+    );
+    if tax_data.church_member {
+        deductions.insert(DeductionCategory::ChurchTax, result.church_tax);
+    }
+
+    return Ok(TaxResultV2 {
+        gross_income: result.gross_income,
+        net_income: result.net_income,
+        deductions,
+    });
+}
+
+#[cfg(all(test, feature = "year-2025"))]
+mod tests {
+    use super::*;
+    use crate::config::create as create_config;
+
+    #[test]
+    fn test_categories_add_up_to_lump_sums() {
+        let config = create_config(2025).unwrap();
+
+        for income in [0, 30000, 75000, 250000] {
+            let tax_data = TaxData::new(income).with_expenses(1200);
+            let expected = crate::calculate(&config, &tax_data).unwrap();
+            let result = calculate(&config, &tax_data).unwrap();
+
+            assert_eq!(
+                result.get_social_security_taxes(),
+                expected.social_security_taxes
+            );
+            assert_eq!(result.get_income_taxes(), expected.income_taxes);
+            assert_eq!(result.to_result().net_income, expected.net_income);
+        }
+    }
+
+    #[test]
+    fn test_missing_category() {
+        let config = create_config(2025).unwrap();
+        let result = calculate(&config, &TaxData::new(50000)).unwrap();
+
+        assert_eq!(result.deductions.len(), 6);
+        assert_eq!(result.get(DeductionCategory::ChurchTax), 0);
+        assert!(result.get(DeductionCategory::RetirementInsurance) > 0);
+    }
+
+    #[test]
+    fn test_church_tax() {
+        let config = create_config(2025).unwrap();
+        let tax_data = TaxData::new(60000).with_church_member(true);
+        let result = calculate(&config, &tax_data).unwrap();
+
+        assert_eq!(result.deductions.len(), 7);
+        assert_eq!(
+            result.get(DeductionCategory::ChurchTax),
+            (result.get(DeductionCategory::IncomeTax) as f64 * 0.09) as u32
+        );
+
+        let expected = crate::calculate(&config, &tax_data).unwrap();
+        assert_eq!(result.get_income_taxes(), expected.get_total_income_taxes());
+        assert_eq!(result.to_result().church_tax, expected.church_tax);
+        assert_eq!(result.to_result().income_taxes, expected.income_taxes);
+    }
+}
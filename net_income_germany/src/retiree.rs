@@ -0,0 +1,183 @@
+//! Health and nursing care insurance of retirees (Krankenversicherung der Rentner, KVdR).
+//!
+//! Retirees pay contributions on different kinds of income with different rules:
+//! - statutory pension: half of the general premium and of the additional premium is paid by the
+//!   pension insurance, the premium of the nursing care insurance is paid fully by the retiree
+//! - company pensions (Betriebsrenten): the full premiums apply, but for the health insurance only
+//!   on the part above the allowance and for the nursing care insurance only if the company pensions
+//!   exceed the allowance at all (Freigrenze)
+//! - other income (e.g., rental income): only for voluntarily insured retirees, with the reduced
+//!   general premium and at least on the minimum income of the health insurance
+//!
+//! All kinds of income are considered in the order above up to the maximum income of the health
+//! insurance.
+
+use crate::config::Config;
+use crate::numeric::{Number, Numeric};
+
+/// Input data struct for the health insurance calculation of a retiree.
+#[derive(Clone)]
+pub struct RetireeData {
+    /// The yearly statutory pension (gesetzliche Rente).
+    pub statutory_pension: u32,
+
+    /// The yearly company pensions and other pension benefits (Versorgungsbezüge).
+    pub company_pension: u32,
+
+    /// The yearly other income (e.g., rental or capital income).
+    pub other_income: u32,
+
+    /// Whether the retiree is voluntarily insured (freiwillig versichert) instead of compulsorily
+    /// insured in the KVdR, so that contributions also apply on the other income.
+    pub voluntarily_insured: bool,
+}
+
+/// Result struct of the health insurance calculation of a retiree.
+pub struct RetireeResult {
+    /// The yearly payment of the retiree for the health insurance.
+    pub health_insurance: u32,
+
+    /// The yearly payment of the retiree for the nursing care insurance.
+    pub nursing_insurance: u32,
+
+    /// The yearly payment of the pension insurance for the health insurance of the retiree.
+    pub pension_insurance_share: u32,
+}
+
+impl RetireeResult {
+    /// Returns the sum of the payments of the retiree.
+    pub fn get_total(&self) -> u32 {
+        return self.health_insurance + self.nursing_insurance;
+    }
+}
+
+/// Calculates the yearly health and nursing care insurance contributions of a retiree.
+pub fn calculate(config: &Config, data: &RetireeData) -> RetireeResult {
+    let health_insurance_config = &config.health_insurance;
+    let mut remaining_income =
+        Number::from_f32(health_insurance_config.max_income) * Number::from_u32(12);
+    let allowance =
+        Number::from_f32(config.retiree_insurance.company_pension_allowance) * Number::from_u32(12);
+
+    let premium_general = Number::from_f32(health_insurance_config.premium_general);
+    let premium_additional = Number::from_f32(health_insurance_config.premium_additional);
+    let premium_nursing = Number::from_f32(health_insurance_config.premium_nursing)
+        + Number::from_f32(health_insurance_config.premium_nursing_additional);
+
+    // the statutory pension is considered first, with half of the health insurance premium paid by
+    // the pension insurance
+    let statutory_pension = take_income(&mut remaining_income, data.statutory_pension);
+    let health_premium_share = (premium_general + premium_additional) / Number::from_u32(2);
+    let mut health_insurance = statutory_pension * health_premium_share;
+    let pension_insurance_share = statutory_pension * health_premium_share;
+    let mut nursing_insurance = statutory_pension * premium_nursing;
+
+    // company pensions are only considered above the allowance for the health insurance and only if
+    // they exceed the allowance for the nursing care insurance
+    let company_pension = Number::from_u32(data.company_pension);
+    if company_pension > allowance {
+        let nursing_income = take_income(&mut remaining_income, data.company_pension);
+        let health_income = Numeric::min(nursing_income, company_pension - allowance);
+
+        health_insurance += health_income * (premium_general + premium_additional);
+        nursing_insurance += nursing_income * premium_nursing;
+    }
+
+    // other income is only considered for voluntarily insured retirees, which need to pay at least
+    // the contributions on the minimum income
+    if data.voluntarily_insured {
+        let min_income =
+            (Number::from_f32(health_insurance_config.min_income) * Number::from_u32(12)).to_u32();
+        let pensions = data.statutory_pension.saturating_add(data.company_pension);
+        let other_income = take_income(
+            &mut remaining_income,
+            data.other_income.max(min_income.saturating_sub(pensions)),
+        );
+
+        let premium_general_reduced =
+            Number::from_f32(health_insurance_config.premium_general_reduced);
+        health_insurance += other_income * (premium_general_reduced + premium_additional);
+        nursing_insurance += other_income * premium_nursing;
+    }
+
+    return RetireeResult {
+        health_insurance: health_insurance.to_u32(),
+        nursing_insurance: nursing_insurance.to_u32(),
+        pension_insurance_share: pension_insurance_share.to_u32(),
+    };
+}
+
+/// Takes the given income from the remaining income up to the maximum income of the health
+/// insurance and returns the part of the income that is considered.
+fn take_income(remaining_income: &mut Number, income: u32) -> Number {
+    let income = Numeric::min(Number::from_u32(income), *remaining_income);
+    *remaining_income -= income;
+
+    return income;
+}
+
+#[cfg(all(test, feature = "year-2025"))]
+mod tests {
+    use super::*;
+    use crate::config::create as create_config;
+
+    fn create_data(statutory_pension: u32, company_pension: u32) -> RetireeData {
+        return RetireeData {
+            statutory_pension,
+            company_pension,
+            other_income: 0,
+            voluntarily_insured: false,
+        };
+    }
+
+    #[test]
+    fn test_statutory_pension_2025() {
+        let config = create_config(2025).unwrap();
+
+        let result = calculate(&config, &create_data(20001, 0));
+        assert_eq!(result.health_insurance, 1705);
+        assert_eq!(result.pension_insurance_share, 1705);
+        assert_eq!(result.nursing_insurance, 840);
+    }
+
+    #[test]
+    fn test_company_pension_allowance_2025() {
+        let config = create_config(2025).unwrap();
+
+        // below the allowance, no contributions apply on the company pension
+        let result = calculate(&config, &create_data(0, 2000));
+        assert_eq!(result.get_total(), 0);
+
+        // above the allowance, the health insurance applies on the part above the allowance, but the
+        // nursing care insurance on the full company pension
+        let result = calculate(&config, &create_data(0, 6010));
+        assert_eq!(result.health_insurance, 641);
+        assert_eq!(result.nursing_insurance, 252);
+        assert_eq!(result.pension_insurance_share, 0);
+    }
+
+    #[test]
+    fn test_other_income_only_for_voluntarily_insured() {
+        let config = create_config(2025).unwrap();
+        let mut data = create_data(20001, 0);
+        data.other_income = 10010;
+
+        let compulsorily_insured = calculate(&config, &data);
+        data.voluntarily_insured = true;
+        let voluntarily_insured = calculate(&config, &data);
+
+        assert_eq!(compulsorily_insured.health_insurance, 1705);
+        assert_eq!(voluntarily_insured.health_insurance, 3351);
+        assert_eq!(voluntarily_insured.nursing_insurance, 1260);
+    }
+
+    #[test]
+    fn test_maximum_income() {
+        let config = create_config(2025).unwrap();
+
+        // the statutory pension already reaches the maximum income of the health insurance
+        let result = calculate(&config, &create_data(80000, 20000));
+        let max_income = config.health_insurance.max_income * 12.0;
+        assert_eq!(result.nursing_insurance, (max_income as f64 * 0.042) as u32);
+    }
+}
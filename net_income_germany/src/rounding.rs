@@ -0,0 +1,166 @@
+//! Rounding of input values with cent precision to whole euros.
+//!
+//! The calculations work on whole euros, as the income tax law rounds the taxable income down to
+//! full euros (§ 32a Abs. 1 EStG). Payroll systems usually work with cents, so the input data can
+//! also be given in cents (or parsed from decimal strings like `"4523,17"`) and is rounded to whole
+//! euros before the calculation. The results are therefore in whole euros as well and not accurate
+//! to the cent (e.g., for the social security contributions, which are calculated on cents by the
+//! payroll systems). The input data is rounded as follows:
+//! - the income is rounded down to full euros
+//! - the expenses are rounded so that the difference between income and expenses is the exact
+//!   difference rounded down to full euros
+//! - the fixed monthly retirement insurance rate is rounded commercially to full euros
+
+use crate::config::Config;
+use crate::error::Error;
+use crate::{Assessment, TaxData, TaxResult, calculate as calculate_euros};
+
+/// Input data struct for the tax calculation with all amounts in cents.
+#[derive(Clone)]
+pub struct CentTaxData {
+    /// The gross income of one year in cents.
+    pub income: u64,
+
+    /// The expenses of one year in cents that will be deducted from the gross income.
+    pub expenses: u64,
+
+    /// Optional value of a fixed monthly retirement insurance rate in cents.
+    pub fixed_retirement: Option<u64>,
+
+    /// Whether the calculations should be done for a self-employed person.
+    pub self_employed: bool,
+
+    /// The type of the income tax assessment.
+    pub assessment: Assessment,
+
+    /// Whether the person is a member of a church and pays the church tax.
+    pub church_member: bool,
+}
+
+impl CentTaxData {
+    /// Rounds the input data to whole euros according to the rounding rules of this module.
+    pub fn round_to_euros(&self) -> Result<TaxData, Error> {
+        let income = to_euros(self.income / 100)?;
+
+        // keep the exact difference between income and expenses (rounded down), as that is what is
+        // taxed in the end
+        let expenses = match self.income >= self.expenses {
+            true => income - to_euros((self.income - self.expenses) / 100)?,
+            false => to_euros(self.expenses.div_ceil(100))?,
+        };
+
+        let fixed_retirement = match self.fixed_retirement {
+            Some(fixed_retirement) => Some(to_euros((fixed_retirement + 50) / 100)?),
+            None => None,
+        };
+
+        return Ok(TaxData::new(income)
+            .with_expenses(expenses)
+            .with_fixed_retirement(fixed_retirement)
+            .with_self_employed(self.self_employed)
+            .with_assessment(self.assessment)
+            .with_church_member(self.church_member));
+    }
+}
+
+/// Calculates social security taxes and income taxes like [`crate::calculate`] for input data with
+/// cent precision, which is rounded to whole euros first (see [`CentTaxData::round_to_euros`]).
+pub fn calculate(config: &Config, tax_data: &CentTaxData) -> Result<TaxResult, Error> {
+    return calculate_euros(config, &tax_data.round_to_euros()?);
+}
+
+/// Parses a decimal string with euros and optional cents (e.g., `"4523.17"`, `"4523,17"` or
+/// `"4523"`) into cents.
+pub fn parse_cents(text: &str) -> Result<u64, Error> {
+    let text = text.trim();
+    let (euros, cents) = match text.find(['.', ',']) {
+        Some(index) => (&text[..index], &text[index + 1..]),
+        None => (text, ""),
+    };
+
+    let is_digits = |value: &str| value.bytes().all(|byte| byte.is_ascii_digit());
+    if euros.is_empty() || !is_digits(euros) || !is_digits(cents) || cents.len() > 2 {
+        return Err(Error::InvalidAmount);
+    }
+
+    let euros: u64 = euros.parse().map_err(|_| Error::AmountTooLarge)?;
+    let cents: u64 = match cents.len() {
+        0 => 0,
+        1 => cents.parse::<u64>().unwrap() * 10,
+        _ => cents.parse().unwrap(),
+    };
+
+    return euros
+        .checked_mul(100)
+        .and_then(|value| value.checked_add(cents))
+        .ok_or(Error::AmountTooLarge);
+}
+
+fn to_euros(value: u64) -> Result<u32, Error> {
+    return u32::try_from(value).map_err(|_| Error::input_too_large_unsigned());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "year-2025")]
+    use crate::config::create as create_config;
+
+    fn create_tax_data(income: u64, expenses: u64) -> CentTaxData {
+        return CentTaxData {
+            income,
+            expenses,
+            fixed_retirement: None,
+            self_employed: false,
+            assessment: Assessment::Single,
+            church_member: false,
+        };
+    }
+
+    #[test]
+    fn test_rounding_to_euros() {
+        // 100.50 - 0.40 = 100.10, which is rounded down to 100
+        let tax_data = create_tax_data(10050, 40).round_to_euros().unwrap();
+        assert_eq!(tax_data.income, 100);
+        assert_eq!(tax_data.expenses, 0);
+
+        // 100.50 - 0.60 = 99.90, which is rounded down to 99
+        let tax_data = create_tax_data(10050, 60).round_to_euros().unwrap();
+        assert_eq!(tax_data.income - tax_data.expenses, 99);
+
+        // expenses larger than the income are rounded up
+        let tax_data = create_tax_data(10050, 20010).round_to_euros().unwrap();
+        assert_eq!(tax_data.expenses, 201);
+
+        let mut tax_data = create_tax_data(0, 0);
+        tax_data.fixed_retirement = Some(61150);
+        assert_eq!(
+            tax_data.round_to_euros().unwrap().fixed_retirement,
+            Some(612)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "year-2025")]
+    fn test_calculate_with_cents() {
+        let config = create_config(2025).unwrap();
+        let expected = calculate_euros(&config, &TaxData::new(60000).with_expenses(1000)).unwrap();
+
+        let result = calculate(&config, &create_tax_data(6000099, 100099)).unwrap();
+        assert_eq!(result.net_income, expected.net_income);
+    }
+
+    #[test]
+    fn test_parse_cents() {
+        assert_eq!(parse_cents("4523.17"), Ok(452317));
+        assert_eq!(parse_cents("4523,1"), Ok(452310));
+        assert_eq!(parse_cents(" 4523 "), Ok(452300));
+        assert_eq!(parse_cents("4523.175").err(), Some(Error::InvalidAmount));
+        assert_eq!(parse_cents("-12").err(), Some(Error::InvalidAmount));
+        assert_eq!(parse_cents(",50").err(), Some(Error::InvalidAmount));
+        assert_eq!(
+            parse_cents("99999999999999999999").err(),
+            Some(Error::AmountTooLarge)
+        );
+    }
+}
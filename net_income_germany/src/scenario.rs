@@ -0,0 +1,244 @@
+//! Scenarios bundling the configuration and the input data of a calculation.
+//!
+//! A scenario contains everything that is needed to repeat a calculation: the year of the
+//! configuration, the changes to the default configuration of that year and the tax data. With the
+//! `serde` feature, scenarios can be serialized, so that they can be stored and shared.
+//!
+//! Presets fill in the typical input values of common situations:
+//!
+//! ```
+//! use net_income_germany::scenario::Scenario;
+//!
+//! let result = Scenario::married_freelancer(70000, 3000).run().unwrap();
+//! println!("Net income: {}", result.net_income);
+//! ```
+
+use crate::config::{self, Config};
+use crate::error::Error;
+use crate::what_if::{Levy, LevyToggle};
+use crate::{Assessment, TaxData, TaxResult, calculate};
+
+/// Changes to the default configuration of a year. Every value that is not set keeps the default.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConfigOverrides {
+    /// Additional premium value \[0,1\] of the health insurance (Zusatzbeitrag).
+    pub health_premium_additional: Option<f32>,
+
+    /// Additional premium value \[0,1\] of the nursing insurance (Zuschlag für Kinderlose).
+    pub nursing_premium_additional: Option<f32>,
+
+    /// Levies that are switched off or scaled (see [`crate::what_if`]).
+    pub levy_toggles: Vec<LevyToggle>,
+
+    /// Rate \[0,1\] of the church tax of the federal state (see
+    /// [`crate::bundesland::Bundesland::get_church_tax_rate`]).
+    pub church_tax_rate: Option<f32>,
+}
+
+impl ConfigOverrides {
+    /// Applies all set values on the given configuration.
+    pub fn apply(&self, config: &mut Config) {
+        if let Some(premium) = self.health_premium_additional {
+            config.health_insurance.premium_additional = premium;
+            config.record_modification("health_insurance.premium_additional");
+        }
+        if let Some(premium) = self.nursing_premium_additional {
+            config.health_insurance.premium_nursing_additional = premium;
+            config.record_modification("health_insurance.premium_nursing_additional");
+        }
+        if let Some(rate) = self.church_tax_rate {
+            config.income_tax.church_tax_rate = rate;
+            config.record_modification("income_tax.church_tax_rate");
+        }
+        for toggle in &self.levy_toggles {
+            toggle.apply(config);
+        }
+    }
+}
+
+/// A complete calculation setup that can be stored and executed again.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Scenario {
+    /// Name of the scenario (e.g., "current job" or "offer A").
+    pub label: String,
+
+    /// The year of the configuration that is used for the calculation.
+    pub year: u32,
+
+    /// The changes to the default configuration of the year.
+    pub config_overrides: ConfigOverrides,
+
+    /// The input data of the calculation.
+    pub tax_data: TaxData,
+}
+
+impl Scenario {
+    /// Creates a scenario with the given label and tax data for the latest supported year and
+    /// without any changes to its default configuration.
+    pub fn new(label: &str, tax_data: TaxData) -> Self {
+        return Scenario {
+            label: label.to_string(),
+            year: config::latest_year(),
+            config_overrides: ConfigOverrides::default(),
+            tax_data,
+        };
+    }
+
+    /// Creates the scenario of a childless single employee with the given yearly gross income.
+    pub fn single_employee(income: u32) -> Self {
+        return Scenario::new("single employee", TaxData::new(income));
+    }
+
+    /// Creates the scenario of a single employee with children (so that the nursing surcharge for
+    /// childless persons does not apply) and the given yearly gross income.
+    pub fn employee_with_children(income: u32) -> Self {
+        let mut scenario = Scenario::new("employee with children", TaxData::new(income));
+        scenario.config_overrides.levy_toggles = vec![LevyToggle::off(Levy::NursingSurcharge)];
+
+        return scenario;
+    }
+
+    /// Creates the scenario of a childless married employee with tax splitting and the given
+    /// yearly gross income of the couple.
+    pub fn married_employee(income: u32) -> Self {
+        return Scenario::new(
+            "married employee",
+            TaxData::new(income).with_assessment(Assessment::Joint),
+        );
+    }
+
+    /// Creates the scenario of a childless single freelancer with the given yearly profit and
+    /// tax-deductible expenses.
+    pub fn single_freelancer(income: u32, expenses: u32) -> Self {
+        return Scenario::new(
+            "single freelancer",
+            TaxData::new(income)
+                .with_expenses(expenses)
+                .with_self_employed(true),
+        );
+    }
+
+    /// Creates the scenario of a childless married freelancer with tax splitting and the given
+    /// yearly profit and tax-deductible expenses of the couple.
+    pub fn married_freelancer(income: u32, expenses: u32) -> Self {
+        return Scenario::new(
+            "married freelancer",
+            TaxData::new(income)
+                .with_expenses(expenses)
+                .with_self_employed(true)
+                .with_assessment(Assessment::Joint),
+        );
+    }
+
+    /// Creates the scenario of a working student (Werkstudent) with the given yearly gross income.
+    ///
+    /// Working students are exempt from the health, nursing care and unemployment insurance of the
+    /// employment (§ 6 Abs. 1 Nr. 3 SGB V, § 27 Abs. 4 SGB III) and only pay the retirement
+    /// insurance.
+    pub fn student_job(income: u32) -> Self {
+        let mut scenario = Scenario::new("student job", TaxData::new(income));
+        scenario.config_overrides.levy_toggles = vec![
+            LevyToggle::off(Levy::HealthInsurance),
+            LevyToggle::off(Levy::NursingInsurance),
+            LevyToggle::off(Levy::UnemploymentInsurance),
+        ];
+
+        return scenario;
+    }
+
+    /// Creates the configuration of the scenario (the default configuration of the year with the
+    /// overrides applied).
+    ///
+    /// Returns [`Error::UnsupportedYear`] if there is no configuration for the year of the scenario.
+    pub fn create_config(&self) -> Result<Config, Error> {
+        let mut config = config::create(self.year)?;
+        self.config_overrides.apply(&mut config);
+
+        return Ok(config);
+    }
+
+    /// Executes the calculation of the scenario.
+    pub fn run(&self) -> Result<TaxResult, Error> {
+        return calculate(&self.create_config()?, &self.tax_data);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_scenario(year: u32) -> Scenario {
+        return Scenario {
+            label: String::from("test"),
+            year,
+            config_overrides: ConfigOverrides::default(),
+            tax_data: TaxData::new(80000),
+        };
+    }
+
+    #[test]
+    #[cfg(feature = "year-2025")]
+    fn test_run_with_and_without_overrides() {
+        let mut scenario = create_scenario(2025);
+        let default_result = scenario.run().unwrap();
+
+        // a lower additional health insurance premium leads to lower social security taxes
+        scenario.config_overrides.health_premium_additional = Some(0.01);
+        let result = scenario.run().unwrap();
+
+        assert!(result.social_security_taxes < default_result.social_security_taxes);
+        assert_eq!(
+            scenario
+                .create_config()
+                .unwrap()
+                .health_insurance
+                .premium_additional,
+            0.01
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "year-2025")]
+    fn test_run_with_levy_toggles() {
+        let mut scenario = create_scenario(2025);
+        let default_result = scenario.run().unwrap();
+
+        scenario.config_overrides.levy_toggles =
+            vec![LevyToggle::off(crate::what_if::Levy::UnemploymentInsurance)];
+        let result = scenario.run().unwrap();
+
+        assert!(result.net_income > default_result.net_income);
+    }
+
+    #[test]
+    fn test_presets() {
+        let employee = Scenario::single_employee(40000).run().unwrap();
+        assert_eq!(Scenario::single_employee(40000).year, config::latest_year());
+
+        let parent = Scenario::employee_with_children(40000).run().unwrap();
+        assert!(parent.social_security_taxes < employee.social_security_taxes);
+
+        let married = Scenario::married_employee(40000).run().unwrap();
+        assert!(married.income_taxes < employee.income_taxes);
+
+        let freelancer = Scenario::single_freelancer(40000, 2000).run().unwrap();
+        let married_freelancer = Scenario::married_freelancer(40000, 2000).run().unwrap();
+        assert!(married_freelancer.income_taxes < freelancer.income_taxes);
+
+        // working students pay only the retirement insurance (9.3% as employee share)
+        let student = Scenario::student_job(12000).run().unwrap();
+        assert_eq!(student.social_security_taxes, 1116);
+    }
+
+    #[test]
+    fn test_error_on_unknown_year() {
+        let scenario = create_scenario(2000);
+
+        let error = scenario.create_config().unwrap_err();
+        assert_eq!(error, Error::unsupported_year(2000));
+        assert_eq!(error.code(), "unsupported_year");
+        assert_eq!(scenario.run().err(), Some(error));
+    }
+}
@@ -0,0 +1,127 @@
+//! Calculation of a second employment that is taxed in tax class VI.
+//!
+//! The income taxes of a second job are withheld in tax class VI, in which no allowances apply. With
+//! the annual income tax assessment, both incomes are taxed together and the difference to the
+//! withheld taxes is refunded or needs to be paid back.
+
+use crate::config::Config;
+use crate::error::Error;
+use crate::{TaxData, TaxResult, calculate as calculate_taxes, income_tax};
+
+/// Result struct of the second job calculation.
+pub struct SecondJobResult {
+    /// The tax result of the main job alone (with the income taxes withheld for it).
+    pub main_job: TaxResult,
+
+    /// The social security taxes on the income of the second job.
+    pub second_job_social_security_taxes: u32,
+
+    /// The income taxes (including the church tax for members of a church) withheld in tax class VI
+    /// for the second job.
+    pub second_job_withheld_income_taxes: u32,
+
+    /// The tax result of the annual income tax assessment of both incomes together.
+    pub assessment: TaxResult,
+}
+
+impl SecondJobResult {
+    /// Returns the income taxes (including the church tax) withheld for both jobs during the year.
+    pub fn get_withheld_income_taxes(&self) -> u32 {
+        return self.main_job.get_total_income_taxes() + self.second_job_withheld_income_taxes;
+    }
+
+    /// Returns the result of the annual assessment: positive values are a refund, negative values
+    /// need to be paid back.
+    pub fn get_reconciliation(&self) -> i64 {
+        return self.get_withheld_income_taxes() as i64
+            - self.assessment.get_total_income_taxes() as i64;
+    }
+}
+
+/// Calculates the withheld taxes of the main job and of the second job with the given gross income,
+/// as well as the annual assessment of both incomes together.
+pub fn calculate(
+    config: &Config,
+    tax_data: &TaxData,
+    second_job_income: u32,
+) -> Result<SecondJobResult, Error> {
+    if tax_data.self_employed {
+        return Err(Error::SecondJobRequiresEmployee);
+    }
+
+    let main_job = calculate_taxes(config, tax_data)?;
+
+    let mut combined_tax_data = tax_data.clone();
+    combined_tax_data.income = tax_data
+        .income
+        .checked_add(second_job_income)
+        .ok_or(Error::input_too_large())?;
+    let assessment = calculate_taxes(config, &combined_tax_data)?;
+
+    // the social security taxes of the second job are the ones that are added by its income (which
+    // is zero in case that the main job already exceeds the income limits)
+    let second_job_social_security_taxes =
+        assessment.social_security_taxes - main_job.social_security_taxes;
+
+    let second_job_withheld_income_taxes = income_tax::calculate_tax_class_six(
+        &config.income_tax,
+        second_job_income.saturating_sub(second_job_social_security_taxes),
+        tax_data.church_member,
+    );
+
+    return Ok(SecondJobResult {
+        main_job,
+        second_job_social_security_taxes,
+        second_job_withheld_income_taxes,
+        assessment,
+    });
+}
+
+#[cfg(all(test, feature = "year-2025"))]
+mod tests {
+    use super::*;
+    use crate::config::create as create_config;
+
+    #[test]
+    fn test_back_payment_with_high_main_income() {
+        let config = create_config(2025).unwrap();
+
+        let result = calculate(&config, &TaxData::new(60000), 10000).unwrap();
+
+        // the withholding in tax class VI is lower than the top rate of the assessed income
+        assert!(result.second_job_withheld_income_taxes > 0);
+        assert!(result.second_job_social_security_taxes > 0);
+        assert!(result.get_reconciliation() < 0);
+
+        // the church tax is withheld in tax class VI as well
+        let tax_data = TaxData::new(60000).with_church_member(true);
+        let church_result = calculate(&config, &tax_data, 10000).unwrap();
+        assert!(
+            church_result.second_job_withheld_income_taxes
+                > result.second_job_withheld_income_taxes
+        );
+        assert!(church_result.get_reconciliation() < result.get_reconciliation());
+    }
+
+    #[test]
+    fn test_refund_with_low_main_income() {
+        let config = create_config(2025).unwrap();
+
+        // the basic allowance is not used up by the main job, but is not considered in class VI
+        let result = calculate(&config, &TaxData::new(6000), 6000).unwrap();
+        assert_eq!(result.assessment.income_taxes, 0);
+        assert!(result.get_reconciliation() > 0);
+    }
+
+    #[test]
+    fn test_error_for_self_employed() {
+        let config = create_config(2025).unwrap();
+        let mut tax_data = TaxData::new(60000);
+        tax_data.self_employed = true;
+
+        assert_eq!(
+            calculate(&config, &tax_data, 10000).err(),
+            Some(Error::SecondJobRequiresEmployee)
+        );
+    }
+}
@@ -0,0 +1,174 @@
+//! Sensitivity of the net income to the parameters of the configuration.
+//!
+//! Every parameter (e.g., a premium, an income ceiling or the limits of the tax ranges) is changed
+//! by the same relative amount, one at a time, and the effect on the net income is reported. This
+//! shows which assumptions of a configuration a result depends on the most:
+//!
+//! ```
+//! let config = net_income_germany::config::create(net_income_germany::config::latest_year()).unwrap();
+//! let tax_data = net_income_germany::TaxData::new(60000);
+//!
+//! // increase every parameter by 1%
+//! for sensitivity in net_income_germany::sensitivity::analyze(&config, &tax_data, 0.01).unwrap() {
+//!     println!("{}: {}", sensitivity.parameter, sensitivity.net_income_change);
+//! }
+//! ```
+
+use crate::config::Config;
+use crate::error::Error;
+use crate::numeric::{Number, Numeric};
+use crate::{TaxData, calculate};
+
+/// Effect of the change of one parameter on the net income.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sensitivity {
+    /// The path of the changed parameter (e.g., "health_insurance.premium_additional").
+    pub parameter: &'static str,
+
+    /// The change of the net income (positive if the net income increases).
+    pub net_income_change: i64,
+
+    /// The relative change of the net income divided by the relative change of the parameter
+    /// (e.g., -0.1 if the net income decreases by 0.1% for every 1% of the parameter).
+    pub elasticity: f32,
+}
+
+/// Function that scales one parameter of the configuration by the given factor.
+type Scale = fn(&mut Config, f32);
+
+/// The parameters that are changed, with the functions that scale them.
+const PARAMETERS: [(&str, Scale); 13] = [
+    ("health_insurance.premium_general", |config, factor| {
+        config.health_insurance.premium_general *= factor;
+    }),
+    ("health_insurance.premium_additional", |config, factor| {
+        config.health_insurance.premium_additional *= factor;
+    }),
+    ("health_insurance.premium_nursing", |config, factor| {
+        config.health_insurance.premium_nursing *= factor;
+    }),
+    (
+        "health_insurance.premium_nursing_additional",
+        |config, factor| {
+            config.health_insurance.premium_nursing_additional *= factor;
+        },
+    ),
+    ("health_insurance.max_income", |config, factor| {
+        config.health_insurance.max_income *= factor;
+    }),
+    ("retirement_insurance.premium", |config, factor| {
+        config.retirement_insurance.premium *= factor;
+    }),
+    ("retirement_insurance.max_income", |config, factor| {
+        config.retirement_insurance.max_income *= factor;
+    }),
+    ("unemployment_insurance.premium", |config, factor| {
+        config.unemployment_insurance.premium *= factor;
+    }),
+    ("unemployment_insurance.max_income", |config, factor| {
+        config.unemployment_insurance.max_income *= factor;
+    }),
+    ("income_tax.tax_ranges.limits", |config, factor| {
+        for range in &mut config.income_tax.tax_ranges {
+            range.lower_limit = scale_limit(range.lower_limit, factor);
+            range.upper_limit = scale_limit(range.upper_limit, factor);
+        }
+    }),
+    ("income_tax.tax_ranges.rates", |config, factor| {
+        for range in &mut config.income_tax.tax_ranges {
+            range.rate_min *= factor;
+            range.rate_max *= factor;
+        }
+    }),
+    (
+        "income_tax.solidary_addition_config.exemption_level",
+        |config, factor| {
+            let soli = &mut config.income_tax.solidary_addition_config;
+            soli.exemption_level = scale_limit(soli.exemption_level, factor);
+        },
+    ),
+    (
+        "income_tax.solidary_addition_config.rate",
+        |config, factor| {
+            config.income_tax.solidary_addition_config.rate *= factor;
+        },
+    ),
+];
+
+/// Scales the given limit by the given factor, but keeps the open upper limit of the last tax range.
+fn scale_limit(limit: u32, factor: f32) -> u32 {
+    return match limit {
+        u32::MAX => limit,
+        _ => (Number::from_u32(limit) * Number::from_f32(factor)).to_u32(),
+    };
+}
+
+/// Changes every parameter of the given configuration by the given relative change (e.g., 0.01 for
+/// an increase of 1%) and returns the effects on the net income, ordered by their absolute size
+/// (largest first).
+pub fn analyze(
+    config: &Config,
+    tax_data: &TaxData,
+    relative_change: f32,
+) -> Result<Vec<Sensitivity>, Error> {
+    let net_income = calculate(config, tax_data)?.net_income as i64;
+
+    let mut sensitivities = Vec::with_capacity(PARAMETERS.len());
+    for (parameter, scale) in PARAMETERS {
+        let mut changed_config = config.clone();
+        scale(&mut changed_config, 1.0 + relative_change);
+
+        let net_income_change =
+            calculate(&changed_config, tax_data)?.net_income as i64 - net_income;
+        let elasticity = match net_income == 0 || relative_change == 0.0 {
+            true => 0.0,
+            false => net_income_change as f32 / net_income.abs() as f32 / relative_change,
+        };
+
+        sensitivities.push(Sensitivity {
+            parameter,
+            net_income_change,
+            elasticity,
+        });
+    }
+
+    sensitivities.sort_by_key(|sensitivity| std::cmp::Reverse(sensitivity.net_income_change.abs()));
+
+    return Ok(sensitivities);
+}
+
+#[cfg(all(test, feature = "year-2025"))]
+mod tests {
+    use super::*;
+    use crate::config::create as create_config;
+
+    #[test]
+    fn test_sensitivities() {
+        let config = create_config(2025).unwrap();
+        let sensitivities = analyze(&config, &TaxData::new(60000), 0.1).unwrap();
+
+        assert_eq!(sensitivities.len(), PARAMETERS.len());
+        assert!(
+            sensitivities
+                .windows(2)
+                .all(|pair| pair[0].net_income_change.abs() >= pair[1].net_income_change.abs())
+        );
+
+        let get = |parameter: &str| {
+            return sensitivities
+                .iter()
+                .find(|sensitivity| sensitivity.parameter == parameter)
+                .unwrap()
+                .net_income_change;
+        };
+
+        // higher premiums and rates decrease the net income, higher tax range limits increase it
+        assert!(get("retirement_insurance.premium") < 0);
+        assert!(get("income_tax.tax_ranges.rates") < 0);
+        assert!(get("income_tax.tax_ranges.limits") > 0);
+
+        // the income is below all income ceilings and below the exemption of the solidarity surcharge
+        assert_eq!(get("retirement_insurance.max_income"), 0);
+        assert_eq!(get("income_tax.solidary_addition_config.rate"), 0);
+    }
+}
@@ -0,0 +1,225 @@
+//! Implementation of the RPCs of the `NetIncomeCalculator` service of the Protocol Buffers schema.
+//!
+//! The functions handle the request messages of the service (see [`crate::proto`]) independently of
+//! the transport, so that a gRPC server (e.g., the tonic server of the `net-income-germany-grpc`
+//! crate) only needs to forward the requests and to convert the errors into status codes (e.g., with
+//! [`Error::code`]). The configurations are only created once per year (see [`config::cached`]):
+//!
+//! ```
+//! use net_income_germany::{proto, service};
+//!
+//! let request = proto::SweepCurveRequest {
+//!     year: net_income_germany::config::latest_year(),
+//!     tax_data: Some(proto::TaxData {
+//!         assessment: proto::Assessment::Single as i32,
+//!         ..proto::TaxData::default()
+//!     }),
+//!     from: 20000,
+//!     to: 100000,
+//!     step: 10000,
+//! };
+//!
+//! // the results are streamed one by one
+//! for result in service::sweep_curve(&request).unwrap() {
+//!     println!("Net income: {}", result.unwrap().net_income);
+//! }
+//! ```
+
+use crate::config::{self, Config};
+use crate::error::Error;
+use crate::proto::{
+    CalculateRequest, ConfigSummary, GetConfigRequest, SweepCurveRequest, TaxData, TaxResult,
+};
+
+/// Maximum number of gross incomes of the range of a `SweepCurve` request, which limits the work of
+/// a single request.
+pub const MAX_SWEEP_POINTS: u32 = 10000;
+
+/// Returns the configuration and the input data of a request.
+fn prepare(
+    year: u32,
+    tax_data: Option<TaxData>,
+) -> Result<(&'static Config, crate::TaxData), Error> {
+    let config = config::cached(year)?;
+    let tax_data = tax_data.ok_or(Error::MissingTaxData)?;
+
+    return Ok((config, crate::TaxData::try_from(tax_data)?));
+}
+
+/// Handles the `Calculate` RPC: calculates the net income from the gross income.
+pub fn calculate(request: &CalculateRequest) -> Result<TaxResult, Error> {
+    let (config, tax_data) = prepare(request.year, request.tax_data)?;
+
+    return Ok(TaxResult::from(&crate::calculate(config, &tax_data)?));
+}
+
+/// Handles the `CalculateReverse` RPC: calculates the gross income from the net income.
+pub fn calculate_reverse(request: &CalculateRequest) -> Result<TaxResult, Error> {
+    let (config, tax_data) = prepare(request.year, request.tax_data)?;
+
+    return Ok(TaxResult::from(&crate::calculate_reverse(
+        config, &tax_data,
+    )?));
+}
+
+/// Handles the `GetConfig` RPC: returns the main values of the configuration of the year.
+pub fn get_config(request: &GetConfigRequest) -> Result<ConfigSummary, Error> {
+    let config = config::cached(request.year)?;
+
+    return Ok(ConfigSummary {
+        year: config.origin.year,
+        health_insurance_premium_general: config.health_insurance.premium_general,
+        health_insurance_premium_additional: config.health_insurance.premium_additional,
+        health_insurance_premium_nursing: config.health_insurance.premium_nursing,
+        health_insurance_max_income: config.health_insurance.max_income,
+        retirement_insurance_premium: config.retirement_insurance.premium,
+        retirement_insurance_max_income: config.retirement_insurance.max_income,
+        unemployment_insurance_premium: config.unemployment_insurance.premium,
+        income_tax_basic_allowance: config.income_tax.tax_ranges[0].upper_limit,
+    });
+}
+
+/// Handles the `SweepCurve` RPC: returns the results for all gross incomes of the requested range.
+///
+/// The request is validated before the first calculation, which fails for ranges with more than
+/// [`MAX_SWEEP_POINTS`] gross incomes. The results are calculated lazily, so that a server can stream
+/// every result as soon as it is available.
+pub fn sweep_curve(
+    request: &SweepCurveRequest,
+) -> Result<impl Iterator<Item = Result<TaxResult, Error>> + use<>, Error> {
+    if request.step == 0 {
+        return Err(Error::InvalidStepSize);
+    }
+    let points = match request.to.checked_sub(request.from) {
+        Some(range) => range / request.step + 1,
+        None => 0,
+    };
+    if points > MAX_SWEEP_POINTS {
+        return Err(Error::RangeTooLarge {
+            limit: MAX_SWEEP_POINTS,
+        });
+    }
+    let (config, tax_data) = prepare(request.year, request.tax_data)?;
+
+    return Ok((request.from..=request.to)
+        .step_by(request.step as usize)
+        .map(move |income| {
+            let mut tax_data = tax_data.clone();
+            tax_data.income = income;
+            return Ok(TaxResult::from(&crate::calculate(config, &tax_data)?));
+        }));
+}
+
+#[cfg(all(test, feature = "year-2025"))]
+mod tests {
+    use super::*;
+    use crate::proto::Assessment;
+
+    /// Creates the input data of a single person with the given income.
+    fn create_tax_data(income: u32) -> TaxData {
+        return TaxData {
+            income,
+            assessment: Assessment::Single as i32,
+            ..TaxData::default()
+        };
+    }
+
+    #[test]
+    fn test_calculate_and_reverse() {
+        let request = CalculateRequest {
+            year: 2025,
+            tax_data: Some(create_tax_data(60000)),
+        };
+        let result = calculate(&request).unwrap();
+        assert_eq!(result.gross_income, 60000);
+
+        let reverse_request = CalculateRequest {
+            year: 2025,
+            tax_data: Some(create_tax_data(result.net_income as u32)),
+        };
+        let reverse_result = calculate_reverse(&reverse_request).unwrap();
+        assert!(reverse_result.gross_income.abs_diff(60000) <= 10);
+
+        let missing = CalculateRequest {
+            year: 2025,
+            tax_data: None,
+        };
+        assert!(calculate(&missing).is_err());
+        assert_eq!(
+            calculate(&CalculateRequest {
+                year: 2000,
+                ..request
+            })
+            .unwrap_err()
+            .code(),
+            "unsupported_year"
+        );
+
+        // the default value of the assessment means that it was not specified
+        let unspecified = CalculateRequest {
+            year: 2025,
+            tax_data: Some(TaxData::default()),
+        };
+        assert_eq!(
+            calculate(&unspecified).err(),
+            Some(Error::MissingAssessment)
+        );
+    }
+
+    #[test]
+    fn test_get_config() {
+        let summary = get_config(&GetConfigRequest { year: 2025 }).unwrap();
+        assert_eq!(summary.year, 2025);
+        assert_eq!(summary.income_tax_basic_allowance, 12096);
+    }
+
+    #[test]
+    fn test_sweep_curve() {
+        let request = SweepCurveRequest {
+            year: 2025,
+            tax_data: Some(create_tax_data(0)),
+            from: 20000,
+            to: 100000,
+            step: 20000,
+        };
+
+        let results: Vec<TaxResult> = sweep_curve(&request)
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(results.len(), 5);
+        assert!(
+            results
+                .windows(2)
+                .all(|pair| pair[0].net_income < pair[1].net_income)
+        );
+
+        assert!(sweep_curve(&SweepCurveRequest { step: 0, ..request }).is_err());
+
+        // the number of incomes of the range is limited
+        let largest = SweepCurveRequest {
+            from: 0,
+            to: MAX_SWEEP_POINTS - 1,
+            step: 1,
+            ..request
+        };
+        assert!(sweep_curve(&largest).is_ok());
+        assert_eq!(
+            sweep_curve(&SweepCurveRequest {
+                to: MAX_SWEEP_POINTS,
+                ..largest
+            })
+            .err()
+            .map(|error| error.code()),
+            Some("range_too_large")
+        );
+
+        // an empty range contains no incomes
+        let empty = SweepCurveRequest {
+            from: 1,
+            to: 0,
+            ..largest
+        };
+        assert_eq!(sweep_curve(&empty).unwrap().count(), 0);
+    }
+}
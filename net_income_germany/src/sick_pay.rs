@@ -0,0 +1,190 @@
+//! Years with sick leave periods in which sick pay (Krankengeld) is received.
+//!
+//! After the continued payment of the salary by the employer ends, the health insurance pays sick
+//! pay of 70% of the regular gross salary (up to the maximum income of the health insurance), but
+//! at most 90% of the regular net salary (§ 47 SGB V). The months with sick pay have no salary, so
+//! the contributory income of the year is reduced.
+//!
+//! From the sick pay, the employee share of the retirement, unemployment and nursing care
+//! insurance is deducted, while no health insurance contributions apply. The remaining sick pay is
+//! tax-free, but subject to the progression clause (Progressionsvorbehalt).
+
+use crate::config::Config;
+use crate::error::Error;
+use crate::numeric::{Number, Numeric};
+use crate::{TaxData, income_tax};
+
+/// Share \[0,1\] of the regular gross salary that is paid as sick pay.
+const SICK_PAY_GROSS_RATE: f32 = 0.7;
+
+/// Maximum share \[0,1\] of the regular net salary that is paid as sick pay.
+const SICK_PAY_NET_RATE: f32 = 0.9;
+
+/// Result struct of the calculation for a year with sick leave periods.
+pub struct SickLeaveResult {
+    /// The gross salary of the months without sick leave.
+    pub salary: u32,
+
+    /// The gross sick pay of the months with sick leave.
+    pub sick_pay: u32,
+
+    /// The contributions to the retirement, unemployment and nursing care insurance that are
+    /// deducted from the sick pay.
+    pub sick_pay_contributions: u32,
+
+    /// The social security taxes on the salary.
+    pub social_security_taxes: u32,
+
+    /// The income taxes (including the church tax for members of a church) on the salary (with the
+    /// progression clause for the sick pay).
+    pub income_taxes: u32,
+
+    /// The net income of the year from the salary and the sick pay.
+    pub net_income: i32,
+}
+
+/// Calculates the net income of a year in which the given number of months \[0,12\] is spent on sick
+/// pay instead of the salary. The income of the tax data is the regular yearly gross salary.
+pub fn calculate(
+    config: &Config,
+    tax_data: &TaxData,
+    sick_months: u32,
+) -> Result<SickLeaveResult, Error> {
+    if tax_data.self_employed {
+        return Err(Error::SickPayRequiresEmployee);
+    }
+    if sick_months > 12 {
+        return Err(Error::InvalidSickPayMonths);
+    }
+
+    let monthly_sick_pay = calculate_monthly_sick_pay(config, tax_data)?;
+    let sick_pay = (monthly_sick_pay * Number::from_u32(sick_months)).to_u32();
+
+    // the sick person pays the employee share of all insurances except the health insurance
+    let contribution_rate = (Number::from_f32(config.retirement_insurance.premium)
+        + Number::from_f32(config.unemployment_insurance.premium)
+        + Number::from_f32(config.health_insurance.premium_nursing))
+        / Number::from_u32(2)
+        + Number::from_f32(config.health_insurance.premium_nursing_additional);
+    let sick_pay_contributions = (Number::from_u32(sick_pay) * contribution_rate).to_u32();
+
+    let mut salary_tax_data = tax_data.clone();
+    salary_tax_data.income = (Number::from_u32(tax_data.income)
+        * Number::from_u32(12 - sick_months)
+        / Number::from_u32(12))
+    .to_u32();
+
+    let (social_security_taxes, taxable_income) =
+        crate::calculate_taxable_income(config, &salary_tax_data)?;
+    let income_taxes = income_tax::calculate_with_progression_clause(
+        &config.income_tax,
+        taxable_income,
+        sick_pay - sick_pay_contributions,
+        tax_data.assessment.is_splitting(),
+        tax_data.church_member,
+    );
+
+    return Ok(SickLeaveResult {
+        salary: salary_tax_data.income,
+        sick_pay,
+        sick_pay_contributions,
+        social_security_taxes,
+        income_taxes,
+        net_income: (salary_tax_data.income as i64 + sick_pay as i64
+            - sick_pay_contributions as i64
+            - tax_data.expenses as i64
+            - social_security_taxes as i64
+            - income_taxes as i64) as i32,
+    });
+}
+
+/// Calculates the gross sick pay of one month from the regular gross and net salary.
+fn calculate_monthly_sick_pay(config: &Config, tax_data: &TaxData) -> Result<Number, Error> {
+    let regular = crate::calculate(config, tax_data)?;
+    let regular_net_salary =
+        Number::from_u32((regular.net_income as i64 + tax_data.expenses as i64).max(0) as u32)
+            / Number::from_u32(12);
+
+    let gross_salary = Numeric::min(
+        Number::from_u32(tax_data.income) / Number::from_u32(12),
+        Number::from_f32(config.health_insurance.max_income),
+    );
+
+    return Ok(Numeric::min(
+        gross_salary * Number::from_f32(SICK_PAY_GROSS_RATE),
+        regular_net_salary * Number::from_f32(SICK_PAY_NET_RATE),
+    ));
+}
+
+#[cfg(all(test, feature = "year-2025"))]
+mod tests {
+    use super::*;
+    use crate::config::create as create_config;
+
+    #[test]
+    fn test_year_without_sick_leave() {
+        let config = create_config(2025).unwrap();
+        let tax_data = TaxData::new(48000);
+
+        let result = calculate(&config, &tax_data, 0).unwrap();
+        let expected = crate::calculate(&config, &tax_data).unwrap();
+
+        assert_eq!(result.sick_pay, 0);
+        assert_eq!(result.net_income, expected.net_income);
+        assert_eq!(result.income_taxes, expected.income_taxes);
+
+        let tax_data = tax_data.with_church_member(true);
+        let result = calculate(&config, &tax_data, 0).unwrap();
+        let expected = crate::calculate(&config, &tax_data).unwrap();
+        assert_eq!(result.net_income, expected.net_income);
+        assert_eq!(result.income_taxes, expected.get_total_income_taxes());
+    }
+
+    #[test]
+    fn test_year_with_sick_leave() {
+        let config = create_config(2025).unwrap();
+        let tax_data = TaxData::new(48000);
+
+        let result = calculate(&config, &tax_data, 3).unwrap();
+        let regular = crate::calculate(&config, &tax_data).unwrap();
+        let salary_only = crate::calculate(&config, &TaxData::new(36000)).unwrap();
+
+        // the sick pay is at most 70% of the gross salary
+        assert_eq!(result.salary, 36000);
+        assert!(result.sick_pay <= 3 * 2800);
+        assert!(result.sick_pay_contributions > 0);
+
+        // the progression clause increases the taxes on the salary
+        assert!(result.income_taxes > salary_only.income_taxes);
+        assert!(result.net_income < regular.net_income);
+        assert!(result.net_income > salary_only.net_income);
+    }
+
+    #[test]
+    fn test_sick_pay_limited_by_maximum_income() {
+        let config = create_config(2025).unwrap();
+
+        let result = calculate(&config, &TaxData::new(200000), 12).unwrap();
+        let max_sick_pay = config.health_insurance.max_income * SICK_PAY_GROSS_RATE * 12.0;
+
+        assert_eq!(result.salary, 0);
+        assert!(result.sick_pay as f32 <= max_sick_pay);
+        assert_eq!(result.income_taxes, 0);
+    }
+
+    #[test]
+    fn test_errors() {
+        let config = create_config(2025).unwrap();
+        let mut tax_data = TaxData::new(48000);
+
+        assert_eq!(
+            calculate(&config, &tax_data, 13).err(),
+            Some(Error::InvalidSickPayMonths)
+        );
+        tax_data.self_employed = true;
+        assert_eq!(
+            calculate(&config, &tax_data, 3).err(),
+            Some(Error::SickPayRequiresEmployee)
+        );
+    }
+}
@@ -0,0 +1,124 @@
+//! Monte Carlo simulation of the net income for volatile incomes.
+//!
+//! The yearly income is drawn repeatedly from a distribution given by the user (e.g., for the
+//! volatile revenue of a freelancer) and the net income is calculated for every draw. The resulting
+//! distribution of the net income can then be evaluated by its percentiles.
+//!
+//! The crate does not depend on a random number generator, so the distribution is given as a
+//! closure that returns one income value per call:
+//!
+//! ```
+//! let config = net_income_germany::config::create(net_income_germany::config::latest_year()).unwrap();
+//! let tax_data = net_income_germany::TaxData::new(0) // will be replaced by the drawn incomes
+//!     .with_self_employed(true);
+//!
+//! // simple linear congruential generator for incomes between 40000 and 80000
+//! let mut state: u32 = 42;
+//! let result = net_income_germany::simulation::simulate(&config, &tax_data, 1000, || {
+//!     state = state.wrapping_mul(1664525).wrapping_add(1013904223);
+//!     40000 + state % 40000
+//! })
+//! .unwrap();
+//!
+//! println!("Median net income: {}", result.get_percentile(0.5));
+//! ```
+
+use crate::config::Config;
+use crate::error::Error;
+use crate::{TaxData, calculate};
+
+/// Result struct of the simulation with the calculated net incomes of all draws.
+pub struct SimulationResult {
+    /// The net incomes of all draws, sorted in ascending order.
+    pub net_incomes: Vec<i32>,
+}
+
+impl SimulationResult {
+    /// Returns the net income at the given percentile \[0,1\] of all draws (using the nearest rank).
+    pub fn get_percentile(&self, percentile: f32) -> i32 {
+        if self.net_incomes.is_empty() {
+            return 0;
+        }
+
+        let percentile = percentile.clamp(0.0, 1.0);
+        let index = ((self.net_incomes.len() - 1) as f32 * percentile).round() as usize;
+
+        return self.net_incomes[index];
+    }
+
+    /// Returns the average net income of all draws.
+    pub fn get_mean(&self) -> f32 {
+        if self.net_incomes.is_empty() {
+            return 0.0;
+        }
+
+        let sum: i64 = self.net_incomes.iter().map(|&income| income as i64).sum();
+        return sum as f32 / self.net_incomes.len() as f32;
+    }
+}
+
+/// Draws the given number of yearly incomes from the given distribution and calculates the net
+/// income for every draw, using the given tax data for all other input values.
+pub fn simulate(
+    config: &Config,
+    tax_data: &TaxData,
+    draws: usize,
+    mut distribution: impl FnMut() -> u32,
+) -> Result<SimulationResult, Error> {
+    let mut net_incomes = Vec::with_capacity(draws);
+    let mut tax_data = tax_data.clone();
+
+    for _ in 0..draws {
+        tax_data.income = distribution();
+        net_incomes.push(calculate(config, &tax_data)?.net_income);
+    }
+
+    net_incomes.sort_unstable();
+
+    return Ok(SimulationResult { net_incomes });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "year-2025")]
+    use crate::config::create as create_config;
+
+    #[test]
+    #[cfg(feature = "year-2025")]
+    fn test_percentiles_of_simulation() {
+        let config = create_config(2025).unwrap();
+        let tax_data = TaxData::new(0);
+
+        // draw the incomes 10000, 20000, ..., 100000 repeatedly
+        let mut draw = 0;
+        let result = simulate(&config, &tax_data, 100, || {
+            draw += 1;
+            (draw % 10 + 1) * 10000
+        })
+        .unwrap();
+
+        assert_eq!(result.net_incomes.len(), 100);
+
+        assert_net_income(&config, 10000, result.get_percentile(0.0));
+        assert_net_income(&config, 100000, result.get_percentile(1.0));
+        assert!(result.get_percentile(0.25) < result.get_percentile(0.75));
+        assert!(result.get_mean() > result.get_percentile(0.0) as f32);
+    }
+
+    #[cfg(feature = "year-2025")]
+    fn assert_net_income(config: &Config, income: u32, net_income: i32) {
+        let tax_data = TaxData::new(income);
+        assert_eq!(calculate(config, &tax_data).unwrap().net_income, net_income);
+    }
+
+    #[test]
+    fn test_empty_simulation() {
+        let result = SimulationResult {
+            net_incomes: Vec::new(),
+        };
+
+        assert_eq!(result.get_percentile(0.5), 0);
+        assert_eq!(result.get_mean(), 0.0);
+    }
+}
@@ -1,94 +1,392 @@
+//! Social security calculation (health, nursing care, retirement and unemployment insurance).
+//!
+//! The social security taxes can be calculated on their own, without the income tax calculation:
+//!
+//! ```
+//! use net_income_germany::social_security::{SocialSecurityData, calculate};
+//!
+//! let config = net_income_germany::config::create(net_income_germany::config::latest_year()).unwrap();
+//! let data = SocialSecurityData {
+//!     income: 50000,
+//!     fixed_retirement: None,
+//!     self_employed: false,
+//! };
+//!
+//! let result = calculate(
+//!     &config.health_insurance,
+//!     &config.retirement_insurance,
+//!     &config.unemployment_insurance,
+//!     &data,
+//! )
+//! .unwrap();
+//! println!("Health insurance: {}", result.health_insurance);
+//! ```
+
 use crate::TaxData;
 use crate::config::{
     HealthInsuranceConfig, RetirementInsuranceConfig, UnemploymentInsuranceConfig,
 };
+use crate::error::Error;
+use crate::numeric::{Number, Numeric};
+use crate::observer::{CalculationObserver, Insurance, NoObserver};
+
+/// Input data struct for the social security calculation.
+#[derive(Clone)]
+pub struct SocialSecurityData {
+    /// The gross income of one year.
+    pub income: u32,
+
+    /// Optional value of a fixed monthly retirement insurance rate. If this is set, then this rate is used for every
+    /// month. Otherwise, the retirement insurance rate is calculated by a percentage of the income.
+    pub fixed_retirement: Option<u32>,
+
+    /// Whether the calculations should be done for a self-employed person.
+    pub self_employed: bool,
+}
+
+impl From<&TaxData> for SocialSecurityData {
+    fn from(tax_data: &TaxData) -> Self {
+        return SocialSecurityData {
+            income: tax_data.income,
+            fixed_retirement: tax_data.fixed_retirement,
+            self_employed: tax_data.self_employed,
+        };
+    }
+}
 
-/// Calculate the social security payment from the given health and retirement insurance configuration and the tax data (yearly income).
+/// Result struct of the social security calculation with the yearly payments per insurance.
+pub struct SocialSecurityResult {
+    /// The payment for the health insurance.
+    pub health_insurance: u32,
+
+    /// The payment for the nursing care insurance.
+    pub nursing_insurance: u32,
+
+    /// The payment for the retirement insurance.
+    pub retirement_insurance: u32,
+
+    /// The payment for the unemployment insurance.
+    pub unemployment_insurance: u32,
+
+    /// The sum of all payments (rounded once after summing up, so that it can differ slightly from
+    /// the sum of the rounded payments per insurance).
+    pub total: u32,
+}
+
+/// Calculate the social security payment from the given health and retirement insurance configuration and the
+/// social security data (yearly income).
 pub fn calculate(
     health_insurance_config: &HealthInsuranceConfig,
     retirement_insurance_config: &RetirementInsuranceConfig,
     unemployment_insurance_config: &UnemploymentInsuranceConfig,
-    tax_data: &TaxData,
-) -> Result<u32, &'static str> {
-    // for self-employed persons there is a minimum income that needs to be
-    // used for the health insurance calculations in case that the actual
-    // income is lower
-    let income_for_health_insurance = match tax_data.self_employed {
-        true => {
-            let min_income_year = health_insurance_config.min_income * 12.0;
-            tax_data.income.max(min_income_year as u32)
-        }
-        false => tax_data.income,
-    };
+    data: &SocialSecurityData,
+) -> Result<SocialSecurityResult, Error> {
+    return calculate_observed(
+        health_insurance_config,
+        retirement_insurance_config,
+        unemployment_insurance_config,
+        data,
+        &mut NoObserver,
+    );
+}
 
-    // calculate health insurance based on the given gross income (limited by the maximum configured income value)
-    let health_insurance = calculate_social_insurance(
-        income_for_health_insurance,
-        calculate_health_insurance_premium(health_insurance_config, tax_data),
-        health_insurance_config.max_income,
+/// Calculate the social security payment like [`calculate`], but notify the given observer about the
+/// intermediate values of every insurance.
+pub(crate) fn calculate_observed(
+    health_insurance_config: &HealthInsuranceConfig,
+    retirement_insurance_config: &RetirementInsuranceConfig,
+    unemployment_insurance_config: &UnemploymentInsuranceConfig,
+    data: &SocialSecurityData,
+    observer: &mut dyn CalculationObserver,
+) -> Result<SocialSecurityResult, Error> {
+    let parameters = SocialSecurityParameters::new(
+        health_insurance_config,
+        retirement_insurance_config,
+        unemployment_insurance_config,
+        data.self_employed,
     );
 
-    // calculate retirement insurance either from a given fixed value or as percentage from income
-    let retirement_insurance = match tax_data.fixed_retirement {
-        Some(fixed_retirement) => (fixed_retirement * 12) as f32,
-        None => calculate_social_insurance(
-            tax_data.income,
-            calculate_retirement_insurance_premium(retirement_insurance_config, tax_data),
-            retirement_insurance_config.max_income,
-        ),
-    };
+    return Ok(parameters.calculate_observed(data.income, data.fixed_retirement, observer));
+}
+
+/// Premiums and income limits of the social security configuration for an employed or a
+/// self-employed person, converted into the number type of the calculation, so that the payments
+/// can be calculated for many incomes without converting them again.
+#[derive(Clone)]
+pub(crate) struct SocialSecurityParameters {
+    self_employed: bool,
+    min_income_health_insurance: u32,
+    max_income_health_insurance: Number,
+    max_income_retirement_insurance: Number,
+    max_income_unemployment_insurance: Number,
+    premium_health_insurance: Number,
+    premium_nursing_insurance: Number,
+    premium_retirement_insurance: Number,
+    premium_unemployment_insurance: Number,
+}
+
+impl SocialSecurityParameters {
+    /// Converts the premiums and income limits of the given configurations.
+    pub(crate) fn new(
+        health_insurance_config: &HealthInsuranceConfig,
+        retirement_insurance_config: &RetirementInsuranceConfig,
+        unemployment_insurance_config: &UnemploymentInsuranceConfig,
+        self_employed: bool,
+    ) -> Self {
+        let data = SocialSecurityData {
+            income: 0,
+            fixed_retirement: None,
+            self_employed,
+        };
+
+        return SocialSecurityParameters {
+            self_employed,
+            // for self-employed persons there is a minimum income that needs to be used for the
+            // health insurance calculations in case that the actual income is lower
+            min_income_health_insurance: match self_employed {
+                true => (health_insurance_config.min_income * 12.0) as u32,
+                false => 0,
+            },
+            max_income_health_insurance: yearly_max_income(health_insurance_config.max_income),
+            max_income_retirement_insurance: yearly_max_income(
+                retirement_insurance_config.max_income,
+            ),
+            max_income_unemployment_insurance: yearly_max_income(
+                unemployment_insurance_config.max_income,
+            ),
+            premium_health_insurance: calculate_health_insurance_premium(
+                health_insurance_config,
+                &data,
+            ),
+            premium_nursing_insurance: calculate_nursing_insurance_premium(
+                health_insurance_config,
+                &data,
+            ),
+            premium_retirement_insurance: calculate_retirement_insurance_premium(
+                retirement_insurance_config,
+                &data,
+            ),
+            premium_unemployment_insurance: Number::from_f32(unemployment_insurance_config.premium)
+                / Number::from_u32(2),
+        };
+    }
 
-    let unemployment_insurance = match tax_data.self_employed {
-        true => 0.0,
-        false => calculate_social_insurance(
-            tax_data.income,
-            unemployment_insurance_config.premium / 2.0,
-            unemployment_insurance_config.max_income,
-        ),
+    /// Calculate the social security payment for the given yearly income like
+    /// [`calculate_observed`].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "calculate_observed",
+            level = "debug",
+            skip(self, fixed_retirement, observer),
+            fields(self_employed = self.self_employed)
+        )
+    )]
+    pub(crate) fn calculate_observed(
+        &self,
+        income: u32,
+        fixed_retirement: Option<u32>,
+        observer: &mut dyn CalculationObserver,
+    ) -> SocialSecurityResult {
+        let income_for_health_insurance = income.max(self.min_income_health_insurance);
+
+        // calculate health and nursing care insurance based on the given gross income (limited by
+        // the maximum configured income value)
+        let health_insurance = calculate_social_insurance(
+            income_for_health_insurance,
+            self.premium_health_insurance,
+            self.max_income_health_insurance,
+            Insurance::Health,
+            observer,
+        );
+        let nursing_insurance = calculate_social_insurance(
+            income_for_health_insurance,
+            self.premium_nursing_insurance,
+            self.max_income_health_insurance,
+            Insurance::Nursing,
+            observer,
+        );
+
+        // calculate retirement insurance either from a given fixed value or as percentage from
+        // income
+        let retirement_insurance = match fixed_retirement {
+            Some(fixed_retirement) => {
+                let retirement_insurance = Number::from_u32(fixed_retirement * 12);
+                observer.on_insurance(
+                    Insurance::Retirement,
+                    0.0,
+                    0.0,
+                    retirement_insurance.to_f32(),
+                );
+                retirement_insurance
+            }
+            None => calculate_social_insurance(
+                income,
+                self.premium_retirement_insurance,
+                self.max_income_retirement_insurance,
+                Insurance::Retirement,
+                observer,
+            ),
+        };
+
+        let unemployment_insurance = match self.self_employed {
+            true => {
+                observer.on_insurance(Insurance::Unemployment, 0.0, 0.0, 0.0);
+                Number::from_u32(0)
+            }
+            false => calculate_social_insurance(
+                income,
+                self.premium_unemployment_insurance,
+                self.max_income_unemployment_insurance,
+                Insurance::Unemployment,
+                observer,
+            ),
+        };
+
+        let result = SocialSecurityResult {
+            health_insurance: health_insurance.to_u32(),
+            nursing_insurance: nursing_insurance.to_u32(),
+            retirement_insurance: retirement_insurance.to_u32(),
+            unemployment_insurance: unemployment_insurance.to_u32(),
+            total: (health_insurance
+                + nursing_insurance
+                + retirement_insurance
+                + unemployment_insurance)
+                .to_u32(),
+        };
+        #[cfg(feature = "tracing")]
+        tracing::event!(
+            tracing::Level::DEBUG,
+            total = result.total,
+            "calculated the social security taxes"
+        );
+
+        return result;
+    }
+}
+
+/// Converts the given maximum monthly income (monthly upper income limit) into the maximum yearly
+/// income that is considered for the premiums.
+fn yearly_max_income(max_monthly_value: f32) -> Number {
+    return Number::from_f32(max_monthly_value) * Number::from_u32(12);
+}
+
+/// Calculate the social security payment like [`calculate`], but for the income of the given number
+/// of months of a year, so that the income limits apply proportionally to these months.
+///
+/// The income is extrapolated to the whole year and the resulting payments are then reduced to the
+/// given months again. A fixed retirement insurance rate is a monthly rate, so that it is applied for
+/// the given months only as well.
+pub(crate) fn calculate_for_months(
+    health_insurance_config: &HealthInsuranceConfig,
+    retirement_insurance_config: &RetirementInsuranceConfig,
+    unemployment_insurance_config: &UnemploymentInsuranceConfig,
+    data: &SocialSecurityData,
+    months: u32,
+) -> Result<SocialSecurityResult, Error> {
+    let yearly_income =
+        Number::from_u32(data.income) * Number::from_u32(12) / Number::from_u32(months);
+
+    let result = calculate_observed(
+        health_insurance_config,
+        retirement_insurance_config,
+        unemployment_insurance_config,
+        &SocialSecurityData {
+            income: yearly_income.to_u32(),
+            ..data.clone()
+        },
+        &mut NoObserver,
+    )?;
+
+    let reduce = |payment: u32| {
+        (Number::from_u32(payment) * Number::from_u32(months) / Number::from_u32(12)).to_u32()
     };
 
-    return Ok((health_insurance + retirement_insurance + unemployment_insurance) as u32);
+    return Ok(SocialSecurityResult {
+        health_insurance: reduce(result.health_insurance),
+        nursing_insurance: reduce(result.nursing_insurance),
+        retirement_insurance: reduce(result.retirement_insurance),
+        unemployment_insurance: reduce(result.unemployment_insurance),
+        total: reduce(result.total),
+    });
 }
 
 /// Calculate the social security payment (for one insurance) based on the given yearly income and premium percentage.
 ///
-/// The premium is limited by the maximum monthly income value to be considered for the calculation.
+/// The premium is limited by the maximum yearly income value to be considered for the calculation.
 fn calculate_social_insurance(
     yearly_income: u32, // the yearly income on which the social security payment is calculated
-    premium_percentage: f32, // how much of the income needs to be payed for the insurance
-    max_monthly_value: f32, // the maximum monthly income that is considered for the premium (monthly upper income limit)
-) -> f32 {
-    let effective_income = (yearly_income as f32).min(max_monthly_value * 12.0);
-    return effective_income * premium_percentage;
+    premium_percentage: Number, // how much of the income needs to be payed for the insurance
+    max_income: Number, // the maximum yearly income that is considered for the premium (see `yearly_max_income`)
+    insurance: Insurance, // the insurance that is calculated (for notifying the observer)
+    observer: &mut dyn CalculationObserver,
+) -> Number {
+    let effective_income = Numeric::min(Number::from_u32(yearly_income), max_income);
+    let payment = effective_income * premium_percentage;
+    #[cfg(feature = "tracing")]
+    tracing::event!(
+        tracing::Level::DEBUG,
+        ?insurance,
+        assessed_income = effective_income.to_f32(),
+        premium = premium_percentage.to_f32(),
+        payment = payment.to_f32(),
+        "calculated the insurance payment"
+    );
+    observer.on_insurance(
+        insurance,
+        effective_income.to_f32(),
+        premium_percentage.to_f32(),
+        payment.to_f32(),
+    );
+
+    return payment;
+}
+
+pub(crate) fn calculate_health_insurance_premium(
+    health_insurance_config: &HealthInsuranceConfig,
+    data: &SocialSecurityData,
+) -> Number {
+    let premium_additional = Number::from_f32(health_insurance_config.premium_additional);
+
+    if data.self_employed {
+        return Number::from_f32(health_insurance_config.premium_general_reduced)
+            + premium_additional;
+    } else {
+        // the employer is paying half of the premium for an employee
+        return (Number::from_f32(health_insurance_config.premium_general) + premium_additional)
+            / Number::from_u32(2);
+    }
 }
 
-fn calculate_health_insurance_premium(
+pub(crate) fn calculate_nursing_insurance_premium(
     health_insurance_config: &HealthInsuranceConfig,
-    tax_data: &TaxData,
-) -> f32 {
-    if tax_data.self_employed {
-        return health_insurance_config.premium_general_reduced
-            + health_insurance_config.premium_additional
-            + health_insurance_config.premium_nursing
-            + health_insurance_config.premium_nursing_additional;
+    data: &SocialSecurityData,
+) -> Number {
+    let premium_nursing = Number::from_f32(health_insurance_config.premium_nursing);
+    let premium_nursing_additional =
+        Number::from_f32(health_insurance_config.premium_nursing_additional);
+
+    if data.self_employed {
+        return premium_nursing + premium_nursing_additional;
     } else {
-        return (health_insurance_config.premium_general
-            + health_insurance_config.premium_additional
-            + health_insurance_config.premium_nursing)
-            / 2.0
-            + health_insurance_config.premium_nursing_additional;
+        // the additional premium for childless persons is not shared with the employer
+        return premium_nursing / Number::from_u32(2)
+            + Number::from_f32(health_insurance_config.premium_nursing_employee_surcharge)
+            + premium_nursing_additional;
     }
 }
 
-fn calculate_retirement_insurance_premium(
+pub(crate) fn calculate_retirement_insurance_premium(
     retirement_insurance_config: &RetirementInsuranceConfig,
-    tax_data: &TaxData,
-) -> f32 {
-    if tax_data.self_employed {
-        return retirement_insurance_config.premium;
+    data: &SocialSecurityData,
+) -> Number {
+    let premium = Number::from_f32(retirement_insurance_config.premium);
+
+    if data.self_employed {
+        return premium;
     } else {
         // the employer is paying half of the premium for an employee
-        return retirement_insurance_config.premium / 2.0;
+        return premium / Number::from_u32(2);
     }
 }
 
@@ -103,6 +401,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "year-2024")]
     fn test_social_security_calculation_2024() {
         let test_data = vec![
             Data { i: 12000, o: 2496 },
@@ -116,6 +415,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "year-2025")]
     fn test_social_security_calculation_self_employed_2025() {
         let test_data = vec![
             Data { i: 12000, o: 3093 },
@@ -137,44 +437,48 @@ mod tests {
         let config = create_config(year).unwrap();
 
         for data in test_data {
-            let tax_data = TaxData {
+            let social_security_data = SocialSecurityData {
                 income: data.i,
-                expenses: 0,
-                fixed_retirement: fixed_retirement,
-                self_employed: self_employed,
-                married: false,
+                fixed_retirement,
+                self_employed,
             };
 
             let result = calculate(
                 &config.health_insurance,
                 &config.retirement_insurance,
                 &config.unemployment_insurance,
-                &tax_data,
+                &social_security_data,
             )
             .unwrap();
-            assert_eq!(result, data.o);
+            assert_eq!(result.total, data.o);
         }
     }
 
     #[test]
+    #[cfg(feature = "year-2025")]
     fn test_with_maximum_input_value() {
         let config = crate::config::Config::default();
 
-        let tax_data = TaxData {
+        let social_security_data = SocialSecurityData {
             income: u32::MAX,
-            expenses: 0,
             fixed_retirement: None,
             self_employed: false,
-            married: false,
         };
 
         let result = calculate(
             &config.health_insurance,
             &config.retirement_insurance,
             &config.unemployment_insurance,
-            &tax_data,
+            &social_security_data,
         )
         .unwrap();
-        assert_eq!(result, 17466);
+        assert_eq!(result.total, 17466);
+        assert!(
+            result.health_insurance
+                + result.nursing_insurance
+                + result.retirement_insurance
+                + result.unemployment_insurance
+                <= result.total
+        );
     }
 }
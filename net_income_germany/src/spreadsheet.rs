@@ -0,0 +1,359 @@
+//! Export of the calculation as spreadsheet with live formulas.
+//!
+//! The export writes a flat OpenDocument spreadsheet (`.fods`, a single XML file that LibreOffice,
+//! OpenOffice and other office suites open directly). The input values and the parameters of the
+//! configuration are written into cells, and the social security taxes, the income tax tariff, the
+//! solidarity surcharge and the church tax are written as formulas referencing these cells. The
+//! incomes (or any other value) can therefore still be changed within the spreadsheet:
+//!
+//! ```
+//! let config = net_income_germany::config::create(net_income_germany::config::latest_year()).unwrap();
+//! let tax_data = net_income_germany::TaxData::new(60000);
+//!
+//! let document = net_income_germany::spreadsheet::to_fods(&config, &tax_data);
+//! std::fs::write(std::env::temp_dir().join("net-income.fods"), document).unwrap();
+//! ```
+//!
+//! The formulas follow the calculation of [`crate::calculate`] (the social security taxes and the
+//! expenses are deducted from the gross income to get the taxable income and the net income), so
+//! that the results only differ by the rounding of the spreadsheet application.
+
+use crate::TaxData;
+use crate::config::Config;
+use crate::numeric::Numeric;
+use crate::social_security::{
+    SocialSecurityData, calculate_health_insurance_premium, calculate_nursing_insurance_premium,
+    calculate_retirement_insurance_premium,
+};
+
+/// Cell of the spreadsheet.
+enum Cell {
+    /// A label.
+    Text(&'static str),
+
+    /// An input value.
+    Number(f64),
+
+    /// A formula with cell references like `B2` or `B26:B29`.
+    Formula(&'static str),
+}
+
+/// Converts a configuration value into a cell value with its shortest decimal representation (e.g.,
+/// `0.146` instead of `0.14599999785423279`).
+fn from_f32(value: f32) -> f64 {
+    return value.to_string().parse().unwrap_or(value as f64);
+}
+
+/// Returns the rows of the spreadsheet for the given configuration and input data.
+///
+/// The formulas reference the cells of the rows by their position, so that the order of the rows
+/// must not be changed without changing the formulas.
+fn create_rows(config: &Config, tax_data: &TaxData) -> Vec<Vec<Cell>> {
+    let data = SocialSecurityData::from(tax_data);
+    let health = &config.health_insurance;
+    let min_income_health = match tax_data.self_employed {
+        true => from_f32(health.min_income) * 12.0,
+        false => 0.0,
+    };
+    let premium_unemployment = match tax_data.self_employed {
+        true => 0.0,
+        false => from_f32(config.unemployment_insurance.premium) / 2.0,
+    };
+    let solidarity = &config.income_tax.solidary_addition_config;
+    let church_tax_rate = match tax_data.church_member {
+        true => from_f32(config.income_tax.church_tax_rate),
+        false => 0.0,
+    };
+
+    let mut rows = vec![
+        // rows 1 to 17: the input values and the parameters of the configuration
+        vec![Cell::Text("Input values")],
+        vec![
+            Cell::Text("Gross income"),
+            Cell::Number(tax_data.income as f64),
+        ],
+        vec![
+            Cell::Text("Tax-deductible expenses"),
+            Cell::Number(tax_data.expenses as f64),
+        ],
+        vec![
+            Cell::Text("Tax splitting (1 if it applies)"),
+            Cell::Number(tax_data.assessment.is_splitting() as u8 as f64),
+        ],
+        vec![
+            Cell::Text("Fixed monthly retirement insurance (0 for the premium)"),
+            Cell::Number(tax_data.fixed_retirement.unwrap_or(0) as f64),
+        ],
+        vec![
+            Cell::Text("Minimum income of the health insurance"),
+            Cell::Number(min_income_health),
+        ],
+        vec![
+            Cell::Text("Premium of the health insurance"),
+            Cell::Number(from_f32(
+                calculate_health_insurance_premium(health, &data).to_f32(),
+            )),
+        ],
+        vec![
+            Cell::Text("Premium of the nursing care insurance"),
+            Cell::Number(from_f32(
+                calculate_nursing_insurance_premium(health, &data).to_f32(),
+            )),
+        ],
+        vec![
+            Cell::Text("Premium of the retirement insurance"),
+            Cell::Number(from_f32(
+                calculate_retirement_insurance_premium(&config.retirement_insurance, &data)
+                    .to_f32(),
+            )),
+        ],
+        vec![
+            Cell::Text("Premium of the unemployment insurance"),
+            Cell::Number(premium_unemployment),
+        ],
+        vec![
+            Cell::Text("Maximum income of the health insurance"),
+            Cell::Number(from_f32(health.max_income) * 12.0),
+        ],
+        vec![
+            Cell::Text("Maximum income of the retirement insurance"),
+            Cell::Number(from_f32(config.retirement_insurance.max_income) * 12.0),
+        ],
+        vec![
+            Cell::Text("Maximum income of the unemployment insurance"),
+            Cell::Number(from_f32(config.unemployment_insurance.max_income) * 12.0),
+        ],
+        vec![
+            Cell::Text("Exemption level of the solidarity surcharge"),
+            Cell::Number(solidarity.exemption_level as f64),
+        ],
+        vec![
+            Cell::Text("Rate of the solidarity surcharge"),
+            Cell::Number(from_f32(solidarity.rate)),
+        ],
+        vec![
+            Cell::Text("Maximum percentage of the solidarity surcharge"),
+            Cell::Number(from_f32(solidarity.max_percentage)),
+        ],
+        vec![
+            Cell::Text("Rate of the church tax (0 if not a member of a church)"),
+            Cell::Number(church_tax_rate),
+        ],
+        // rows 18 to 23: the tax ranges with the taxes of the income (row 32) in every range
+        vec![
+            Cell::Text("Lower limit"),
+            Cell::Text("Upper limit"),
+            Cell::Text("Lowest rate"),
+            Cell::Text("Maximum rate"),
+            Cell::Text("Taxes in the range"),
+        ],
+    ];
+
+    for (index, tax_range) in config.income_tax.tax_ranges.iter().enumerate() {
+        rows.push(vec![
+            Cell::Number(tax_range.lower_limit as f64),
+            Cell::Number(tax_range.upper_limit as f64),
+            Cell::Number(from_f32(tax_range.rate_min)),
+            Cell::Number(from_f32(tax_range.rate_max)),
+            Cell::Formula(RANGE_FORMULAS[index]),
+        ]);
+    }
+
+    // rows 25 to 37: the results (the formulas start in row 26)
+    rows.push(vec![]);
+    rows.push(vec![Cell::Text("Results")]);
+    for (label, formula) in RESULT_FORMULAS {
+        rows.push(vec![Cell::Text(label), Cell::Formula(formula)]);
+    }
+
+    return rows;
+}
+
+/// The formulas of the taxes in every tax range (rows 19 to 23) on the income of row 32.
+const RANGE_FORMULAS: [&str; crate::config::TAX_RANGE_COUNT] = [
+    "IF($B$32<=A19;0;MIN($B$32-A19;B19-A19)*(C19+MIN($B$32-A19;B19-A19)/(B19-A19)*(D19-C19)/2))",
+    "IF($B$32<=A20;0;MIN($B$32-A20;B20-A20)*(C20+MIN($B$32-A20;B20-A20)/(B20-A20)*(D20-C20)/2))",
+    "IF($B$32<=A21;0;MIN($B$32-A21;B21-A21)*(C21+MIN($B$32-A21;B21-A21)/(B21-A21)*(D21-C21)/2))",
+    "IF($B$32<=A22;0;MIN($B$32-A22;B22-A22)*(C22+MIN($B$32-A22;B22-A22)/(B22-A22)*(D22-C22)/2))",
+    "IF($B$32<=A23;0;MIN($B$32-A23;B23-A23)*(C23+MIN($B$32-A23;B23-A23)/(B23-A23)*(D23-C23)/2))",
+];
+
+/// The labels and formulas of the results (rows 26 to 37).
+const RESULT_FORMULAS: [(&str, &str); 12] = [
+    ("Health insurance", "MIN(MAX(B2;B6);B11)*B7"),
+    ("Nursing care insurance", "MIN(MAX(B2;B6);B11)*B8"),
+    ("Retirement insurance", "IF(B5>0;12*B5;MIN(B2;B12)*B9)"),
+    ("Unemployment insurance", "MIN(B2;B13)*B10"),
+    ("Social security taxes", "TRUNC(SUM(B26:B29))"),
+    ("Taxable income", "MAX(B2-B30-B3;0)"),
+    ("Income for the tariff", "IF(B4=1;TRUNC(B31/2);B31)"),
+    ("Income tax", "IF(B4=1;2;1)*TRUNC(SUM(E19:E23))"),
+    (
+        "Solidarity surcharge",
+        "IF(B33<IF(B4=1;2;1)*B14;0;TRUNC(MIN(B33*B15;(B33-IF(B4=1;2;1)*B14)*B16)))",
+    ),
+    ("Church tax", "TRUNC(B33*B17)"),
+    ("Income taxes", "B33+B34+B35"),
+    ("Net income", "B2-B30-B36-B3"),
+];
+
+/// Converts the cell references of the given formula (e.g., `B2`, `$B$32` or `B27:B30`) into the
+/// references of OpenDocument formulas (e.g., `[.B2]`, `[.$B$32]` or `[.B27:.B30]`).
+fn to_odf_formula(formula: &str) -> String {
+    let characters: Vec<char> = formula.chars().collect();
+    let mut result = String::from("of:=");
+    let mut index = 0;
+
+    // returns the end of the cell reference that starts at the given index, if any
+    let reference_end = |start: usize| {
+        let mut end = start;
+        let skip = |predicate: fn(&char) -> bool, end: &mut usize| {
+            let begin = *end;
+            if characters.get(*end) == Some(&'$') {
+                *end += 1;
+            }
+            while characters.get(*end).is_some_and(predicate) {
+                *end += 1;
+            }
+            return *end > begin && characters[*end - 1] != '$';
+        };
+
+        let has_column = skip(char::is_ascii_uppercase, &mut end);
+        let has_row = skip(char::is_ascii_digit, &mut end);
+
+        // function names can contain digits as well (e.g., `LOG10(`)
+        let is_function = characters.get(end) == Some(&'(');
+        return (has_column && has_row && !is_function).then_some(end);
+    };
+
+    while index < characters.len() {
+        let is_start = index == 0 || !characters[index - 1].is_ascii_alphanumeric();
+        match reference_end(index).filter(|_| is_start) {
+            Some(end) => {
+                let mut reference: String = characters[index..end].iter().collect();
+                index = end;
+
+                if characters.get(index) == Some(&':')
+                    && let Some(range_end) = reference_end(index + 1)
+                {
+                    let range: String = characters[index + 1..range_end].iter().collect();
+                    reference = format!("{reference}:.{range}");
+                    index = range_end;
+                }
+                result.push_str(&format!("[.{reference}]"));
+            }
+            None => {
+                result.push(characters[index]);
+                index += 1;
+            }
+        }
+    }
+
+    return result;
+}
+
+/// Escapes the special characters of XML in the given text.
+fn escape(text: &str) -> String {
+    return text
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;");
+}
+
+/// Exports the calculation for the given configuration and input data as flat OpenDocument
+/// spreadsheet (`.fods`).
+pub fn to_fods(config: &Config, tax_data: &TaxData) -> String {
+    let mut table = String::new();
+
+    for row in create_rows(config, tax_data) {
+        table.push_str("<table:table-row>");
+        if row.is_empty() {
+            table.push_str("<table:table-cell/>");
+        }
+        for cell in row {
+            table.push_str(&match cell {
+                Cell::Text(text) => format!(
+                    "<table:table-cell office:value-type=\"string\"><text:p>{}</text:p></table:table-cell>",
+                    escape(text)
+                ),
+                Cell::Number(value) => format!(
+                    "<table:table-cell office:value-type=\"float\" office:value=\"{value}\"/>"
+                ),
+                Cell::Formula(formula) => format!(
+                    "<table:table-cell table:formula=\"{}\" office:value-type=\"float\"/>",
+                    escape(&to_odf_formula(formula))
+                ),
+            });
+        }
+        table.push_str("</table:table-row>\n");
+    }
+
+    return format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <office:document \
+         xmlns:office=\"urn:oasis:names:tc:opendocument:xmlns:office:1.0\" \
+         xmlns:table=\"urn:oasis:names:tc:opendocument:xmlns:table:1.0\" \
+         xmlns:text=\"urn:oasis:names:tc:opendocument:xmlns:text:1.0\" \
+         xmlns:of=\"urn:oasis:names:tc:opendocument:xmlns:of:1.2\" \
+         office:version=\"1.3\" \
+         office:mimetype=\"application/vnd.oasis.opendocument.spreadsheet\">\n\
+         <office:body><office:spreadsheet><table:table table:name=\"Net income\">\n\
+         {table}\
+         </table:table></office:spreadsheet></office:body></office:document>\n"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "year-2025")]
+    use crate::config::create as create_config;
+
+    #[test]
+    fn test_odf_formula() {
+        assert_eq!(
+            to_odf_formula("TRUNC(SUM(B26:B29))"),
+            "of:=TRUNC(SUM([.B26:.B29]))"
+        );
+        assert_eq!(
+            to_odf_formula("IF($B$32<=A19;0;B19-A19)"),
+            "of:=IF([.$B$32]<=[.A19];0;[.B19]-[.A19])"
+        );
+        assert_eq!(to_odf_formula("LOG10(B2)"), "of:=LOG10([.B2])");
+    }
+
+    #[test]
+    #[cfg(feature = "year-2025")]
+    fn test_rows_match_formulas() {
+        let config = create_config(2025).unwrap();
+        let rows = create_rows(&config, &TaxData::new(60000));
+
+        // the formulas reference the cells by their position
+        assert!(matches!(rows[1][1], Cell::Number(60000.0)));
+        assert!(matches!(rows[18][0], Cell::Number(0.0)));
+        assert!(matches!(rows[22][4], Cell::Formula(_)));
+        assert!(matches!(rows[25][0], Cell::Text("Health insurance")));
+        assert!(matches!(rows[31][0], Cell::Text("Income for the tariff")));
+        assert!(matches!(rows[33][0], Cell::Text("Solidarity surcharge")));
+        assert!(matches!(rows[34][0], Cell::Text("Church tax")));
+        assert!(matches!(rows[36][0], Cell::Text("Net income")));
+        assert_eq!(rows.len(), 37);
+    }
+
+    #[test]
+    #[cfg(feature = "year-2025")]
+    fn test_fods_document() {
+        let config = create_config(2025).unwrap();
+        let document = to_fods(&config, &TaxData::new(60000).with_self_employed(true));
+
+        assert!(document.starts_with("<?xml"));
+        assert!(document.contains("office:value=\"60000\""));
+        assert!(document.contains("table:formula=\"of:=[.B2]-[.B30]-[.B36]-[.B3]\""));
+        assert!(document.contains("[.$B$32]&lt;=[.A19]"));
+        assert_eq!(
+            document.matches("<table:table-row>").count(),
+            document.matches("</table:table-row>").count()
+        );
+    }
+}
@@ -0,0 +1,171 @@
+//! Start-up grant for founders out of unemployment (Gründungszuschuss, § 93 SGB III).
+//!
+//! The start-up grant is tax-free and, unlike most other wage replacement benefits, not subject to
+//! the progression clause (Progressionsvorbehalt). But it is considered as income for the
+//! assessment of the health and nursing care insurance contributions of the self-employed founder
+//! (but not for the retirement insurance contributions).
+
+use crate::config::Config;
+use crate::error::Error;
+use crate::observer::{CalculationObserver, Insurance, InsuranceFilter};
+use crate::pipeline::{CalculationState, DeductionStep, Pipeline, Stage, TaxStep};
+use crate::social_security::{self, SocialSecurityData};
+use crate::{TaxData, TaxResult};
+
+/// Calculates the taxes and the net income of a self-employed founder that receives the given yearly
+/// start-up grant in addition to the income of the given tax data.
+///
+/// The gross income and the net income of the result include the start-up grant.
+pub fn calculate(config: &Config, tax_data: &TaxData, grant: u32) -> Result<TaxResult, Error> {
+    if !tax_data.self_employed {
+        return Err(Error::StartUpGrantRequiresSelfEmployed);
+    }
+
+    // the grant is considered for the health and nursing care insurance, but exempted before the
+    // taxable income is calculated from the deductions
+    let mut tax_data = tax_data.clone();
+    tax_data.income = tax_data.income.saturating_add(grant);
+
+    let mut pipeline = Pipeline::default();
+    pipeline.replace(
+        Stage::SocialSecurity,
+        Box::new(GrantSocialSecurityStep(grant)),
+    );
+    pipeline.insert_before(Stage::TaxableIncome, Box::new(DeductionStep(grant)));
+
+    return pipeline.calculate(config, &tax_data);
+}
+
+/// Calculates the social security taxes like the [`crate::pipeline::SocialSecurityStep`], but
+/// assesses the retirement insurance contributions without the given start-up grant (which is part
+/// of the income).
+struct GrantSocialSecurityStep(u32);
+
+impl TaxStep for GrantSocialSecurityStep {
+    fn apply(
+        &self,
+        state: &mut CalculationState,
+        observer: &mut dyn CalculationObserver,
+    ) -> Result<(), Error> {
+        let data = SocialSecurityData::from(state.tax_data);
+        let with_grant = social_security::calculate_observed(
+            &state.config.health_insurance,
+            &state.config.retirement_insurance,
+            &state.config.unemployment_insurance,
+            &data,
+            &mut InsuranceFilter {
+                observer,
+                insurances: &[
+                    Insurance::Health,
+                    Insurance::Nursing,
+                    Insurance::Unemployment,
+                ],
+            },
+        )?;
+        let without_grant = social_security::calculate_observed(
+            &state.config.health_insurance,
+            &state.config.retirement_insurance,
+            &state.config.unemployment_insurance,
+            &SocialSecurityData {
+                income: data.income.saturating_sub(self.0),
+                ..data
+            },
+            &mut InsuranceFilter {
+                observer,
+                insurances: &[Insurance::Retirement],
+            },
+        )?;
+
+        // the unemployment insurance does not apply for the self-employed founder
+        state.social_security_taxes = with_grant.total
+            - (with_grant.retirement_insurance - without_grant.retirement_insurance);
+        state.deductions = state.deductions.saturating_add(state.social_security_taxes);
+
+        return Ok(());
+    }
+
+    fn stage(&self) -> Option<Stage> {
+        return Some(Stage::SocialSecurity);
+    }
+}
+
+#[cfg(all(test, feature = "year-2025"))]
+mod tests {
+    use super::*;
+    use crate::config::create as create_config;
+
+    #[test]
+    fn test_grant_is_tax_free_but_assessed_for_health_insurance() {
+        let config = create_config(2025).unwrap();
+
+        let tax_data = TaxData::new(30000)
+            .with_fixed_retirement(Some(0))
+            .with_self_employed(true);
+
+        let result = calculate(&config, &tax_data, 10000).unwrap();
+        let without_grant = crate::calculate(&config, &tax_data).unwrap();
+        let taxed_grant = crate::calculate(
+            &config,
+            &TaxData {
+                income: 40000,
+                ..tax_data.clone()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(result.gross_income, 40000);
+        assert_eq!(
+            result.social_security_taxes,
+            taxed_grant.social_security_taxes
+        );
+
+        // the higher social security taxes reduce the income taxes on the other income
+        assert!(result.income_taxes < without_grant.income_taxes);
+        assert_eq!(
+            result.net_income,
+            40000 - result.social_security_taxes as i32 - result.income_taxes as i32
+        );
+    }
+
+    #[test]
+    fn test_grant_is_not_assessed_for_retirement_insurance() {
+        let config = create_config(2025).unwrap();
+        let tax_data = TaxData::new(30000).with_self_employed(true);
+
+        let result = calculate(&config, &tax_data, 10000).unwrap();
+
+        let social_security = |income| {
+            social_security::calculate(
+                &config.health_insurance,
+                &config.retirement_insurance,
+                &config.unemployment_insurance,
+                &SocialSecurityData {
+                    income,
+                    fixed_retirement: None,
+                    self_employed: true,
+                },
+            )
+            .unwrap()
+        };
+        let with_grant = social_security(40000);
+        let without_grant = social_security(30000);
+
+        // only the health and nursing care insurance contributions are assessed on the grant
+        let expected = with_grant.health_insurance
+            + with_grant.nursing_insurance
+            + without_grant.retirement_insurance;
+        // the payments per insurance are rounded, the total is rounded once after summing them up
+        assert!(result.social_security_taxes.abs_diff(expected) <= 2);
+        assert!(result.social_security_taxes < with_grant.total);
+    }
+
+    #[test]
+    fn test_error_for_employed_persons() {
+        let config = create_config(2025).unwrap();
+
+        assert_eq!(
+            calculate(&config, &TaxData::new(30000), 10000).err(),
+            Some(Error::StartUpGrantRequiresSelfEmployed)
+        );
+    }
+}
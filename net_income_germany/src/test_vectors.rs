@@ -0,0 +1,203 @@
+//! Curated reference values for conformance tests.
+//!
+//! The vectors contain the expected results of selected inputs per year, independent of the
+//! configuration of this crate: the income taxes of the tariff (§ 32a EStG, as calculated by the
+//! BMF-Steuerrechner) and the social security contributions of employees. Other implementations
+//! (e.g., WASM wrappers or ports to other languages) can reuse them for their own tests:
+//!
+//! ```
+//! for vector in net_income_germany::test_vectors::income_taxes(net_income_germany::config::latest_year()) {
+//!     let taxes = net_income_germany::income_tax::calculate(
+//!         &net_income_germany::config::create(vector.year).unwrap().income_tax,
+//!         vector.taxable_income,
+//!         vector.splitting,
+//!         false,
+//!     );
+//!
+//!     // the calculated taxes additionally contain the solidarity surcharge
+//!     assert!(taxes + 2 >= vector.income_tax);
+//! }
+//! ```
+
+/// Expected income taxes of the tariff for a taxable income.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct IncomeTaxVector {
+    /// The year of the tariff.
+    pub year: u32,
+
+    /// The taxable income (zu versteuerndes Einkommen).
+    pub taxable_income: u32,
+
+    /// Whether the tax splitting for married couples applies.
+    pub splitting: bool,
+
+    /// The income taxes according to the tariff (without the solidarity surcharge).
+    pub income_tax: u32,
+}
+
+/// Expected social security contributions of a childless employee (outside of Saxony and with the
+/// additional premium of Techniker Krankenkasse) for a yearly gross income.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ContributionVector {
+    /// The year of the contributions.
+    pub year: u32,
+
+    /// The yearly gross income.
+    pub gross_income: u32,
+
+    /// The employee share of the health insurance in cents.
+    pub health_insurance_cents: u32,
+
+    /// The employee share of the nursing care insurance (including the surcharge for childless
+    /// persons) in cents.
+    pub nursing_insurance_cents: u32,
+
+    /// The employee share of the retirement insurance in cents.
+    pub retirement_insurance_cents: u32,
+
+    /// The employee share of the unemployment insurance in cents.
+    pub unemployment_insurance_cents: u32,
+}
+
+impl ContributionVector {
+    /// Returns the sum of all contributions in cents.
+    pub fn get_total_cents(&self) -> u32 {
+        return self.health_insurance_cents
+            + self.nursing_insurance_cents
+            + self.retirement_insurance_cents
+            + self.unemployment_insurance_cents;
+    }
+}
+
+const fn tax_vector(
+    year: u32,
+    taxable_income: u32,
+    splitting: bool,
+    income_tax: u32,
+) -> IncomeTaxVector {
+    return IncomeTaxVector {
+        year,
+        taxable_income,
+        splitting,
+        income_tax,
+    };
+}
+
+const fn contribution_vector(year: u32, gross_income: u32, cents: [u32; 4]) -> ContributionVector {
+    return ContributionVector {
+        year,
+        gross_income,
+        health_insurance_cents: cents[0],
+        nursing_insurance_cents: cents[1],
+        retirement_insurance_cents: cents[2],
+        unemployment_insurance_cents: cents[3],
+    };
+}
+
+/// The expected income taxes of all years.
+pub const INCOME_TAX: [IncomeTaxVector; 28] = [
+    tax_vector(2024, 10000, false, 0),
+    tax_vector(2024, 15000, false, 548),
+    tax_vector(2024, 20000, false, 1725),
+    tax_vector(2024, 30000, false, 4412),
+    tax_vector(2024, 40000, false, 7461),
+    tax_vector(2024, 50000, false, 10872),
+    tax_vector(2024, 60000, false, 14646),
+    tax_vector(2024, 80000, false, 22963),
+    tax_vector(2024, 100000, false, 31363),
+    tax_vector(2024, 150000, false, 52363),
+    tax_vector(2024, 300000, false, 116028),
+    tax_vector(2024, 60000, true, 8824),
+    tax_vector(2024, 100000, true, 21744),
+    tax_vector(2024, 300000, true, 104726),
+    tax_vector(2025, 10000, false, 0),
+    tax_vector(2025, 15000, false, 485),
+    tax_vector(2025, 20000, false, 1639),
+    tax_vector(2025, 30000, false, 4303),
+    tax_vector(2025, 40000, false, 7320),
+    tax_vector(2025, 50000, false, 10691),
+    tax_vector(2025, 60000, false, 14415),
+    tax_vector(2025, 80000, false, 22688),
+    tax_vector(2025, 100000, false, 31088),
+    tax_vector(2025, 150000, false, 52088),
+    tax_vector(2025, 300000, false, 115753),
+    tax_vector(2025, 60000, true, 8606),
+    tax_vector(2025, 100000, true, 21382),
+    tax_vector(2025, 300000, true, 104176),
+];
+
+/// The expected social security contributions of all years.
+pub const CONTRIBUTIONS: [ContributionVector; 6] = [
+    contribution_vector(2024, 30000, [237000, 69000, 279000, 39000]),
+    contribution_vector(2024, 50000, [395000, 115000, 465000, 65000]),
+    contribution_vector(2024, 100000, [490590, 142830, 842580, 117780]),
+    contribution_vector(2025, 30000, [255750, 72000, 279000, 39000]),
+    contribution_vector(2025, 50000, [426250, 120000, 465000, 65000]),
+    contribution_vector(2025, 100000, [563929, 158760, 898380, 125580]),
+];
+
+/// Returns the expected income taxes of the given year.
+pub fn income_taxes(year: u32) -> impl Iterator<Item = &'static IncomeTaxVector> {
+    return INCOME_TAX.iter().filter(move |vector| vector.year == year);
+}
+
+/// Returns the expected social security contributions of the given year.
+pub fn contributions(year: u32) -> impl Iterator<Item = &'static ContributionVector> {
+    return CONTRIBUTIONS
+        .iter()
+        .filter(move |vector| vector.year == year);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(all(feature = "year-2024", feature = "year-2025"))]
+    use crate::TaxData;
+    #[cfg(all(feature = "year-2024", feature = "year-2025"))]
+    use crate::config::create as create_config;
+    #[cfg(all(feature = "year-2024", feature = "year-2025"))]
+    use crate::result_v2::{self, DeductionCategory};
+
+    #[test]
+    #[cfg(all(feature = "year-2024", feature = "year-2025"))]
+    fn test_contributions_match_calculation() {
+        for vector in CONTRIBUTIONS {
+            let config = create_config(vector.year).unwrap();
+            let result = result_v2::calculate(&config, &TaxData::new(vector.gross_income)).unwrap();
+
+            for (category, cents) in [
+                (
+                    DeductionCategory::HealthInsurance,
+                    vector.health_insurance_cents,
+                ),
+                (
+                    DeductionCategory::NursingInsurance,
+                    vector.nursing_insurance_cents,
+                ),
+                (
+                    DeductionCategory::RetirementInsurance,
+                    vector.retirement_insurance_cents,
+                ),
+                (
+                    DeductionCategory::UnemploymentInsurance,
+                    vector.unemployment_insurance_cents,
+                ),
+            ] {
+                assert!((result.get(category) * 100).abs_diff(cents) <= 100);
+            }
+            assert!(
+                (result.get_social_security_taxes() * 100).abs_diff(vector.get_total_cents())
+                    <= 100
+            );
+        }
+    }
+
+    #[test]
+    fn test_vectors_of_year() {
+        assert_eq!(income_taxes(2025).count(), 14);
+        assert_eq!(contributions(2024).count(), 3);
+        assert_eq!(income_taxes(2023).count(), 0);
+    }
+}
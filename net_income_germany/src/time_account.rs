@@ -0,0 +1,120 @@
+//! Deferral of gross salary into a working-time account (Zeitwertkonto).
+//!
+//! Salary that is deposited into a working-time account is neither subject to social security
+//! taxes nor to income taxes in the year of the deposit. Instead, both apply when the credit is paid
+//! out in a later year (e.g., during a sabbatical with a lower or no regular income). The progression
+//! of the income tax usually makes the deferral of income into a year with a lower income worthwhile.
+
+use crate::TaxData;
+use crate::config::Config;
+use crate::error::Error;
+use crate::projection::{self, ProjectionResult, ProjectionYear};
+
+/// Result struct of the working-time account calculation.
+pub struct TimeAccountResult {
+    /// The projection of both years without using the working-time account.
+    pub without_deferral: ProjectionResult,
+
+    /// The projection of both years with the deferral of the salary into the working-time account.
+    pub with_deferral: ProjectionResult,
+}
+
+impl TimeAccountResult {
+    /// Returns how much more net income (summed up over both years) results from the deferral.
+    pub fn get_net_income_gain(&self) -> i64 {
+        return self.with_deferral.net_income - self.without_deferral.net_income;
+    }
+}
+
+/// Calculates the net effect of depositing the given amount of the gross salary into a working-time
+/// account in the deposit year and paying it out in the payout year.
+///
+/// The tax data of both years contain the regular income without any deposit or payout.
+pub fn calculate(
+    deposit_config: &Config,
+    deposit_tax_data: &TaxData,
+    payout_config: &Config,
+    payout_tax_data: &TaxData,
+    deferred_amount: u32,
+) -> Result<TimeAccountResult, Error> {
+    if deferred_amount > deposit_tax_data.income {
+        return Err(Error::DeferredAmountTooLarge);
+    }
+
+    let without_deferral = projection::calculate(&[
+        ProjectionYear {
+            config: deposit_config,
+            tax_data: deposit_tax_data.clone(),
+        },
+        ProjectionYear {
+            config: payout_config,
+            tax_data: payout_tax_data.clone(),
+        },
+    ])?;
+
+    // move the deferred amount out of the deposit year into the payout year
+    let mut deposit_tax_data = deposit_tax_data.clone();
+    deposit_tax_data.income -= deferred_amount;
+    let mut payout_tax_data = payout_tax_data.clone();
+    payout_tax_data.income = payout_tax_data.income.saturating_add(deferred_amount);
+
+    let with_deferral = projection::calculate(&[
+        ProjectionYear {
+            config: deposit_config,
+            tax_data: deposit_tax_data,
+        },
+        ProjectionYear {
+            config: payout_config,
+            tax_data: payout_tax_data,
+        },
+    ])?;
+
+    return Ok(TimeAccountResult {
+        without_deferral,
+        with_deferral,
+    });
+}
+
+#[cfg(all(test, feature = "year-2025"))]
+mod tests {
+    use super::*;
+    use crate::config::create as create_config;
+
+    #[test]
+    fn test_deferral_into_sabbatical_year() {
+        let config = create_config(2025).unwrap();
+
+        let result = calculate(
+            &config,
+            &TaxData::new(90000),
+            &config,
+            &TaxData::new(0),
+            30000,
+        )
+        .unwrap();
+
+        // the summed up gross income does not change, but the progression leads to more net income
+        assert_eq!(
+            result.with_deferral.gross_income,
+            result.without_deferral.gross_income
+        );
+        assert!(result.get_net_income_gain() > 0);
+    }
+
+    #[test]
+    fn test_error_on_too_large_deferral() {
+        let config = create_config(2025).unwrap();
+
+        assert_eq!(
+            calculate(
+                &config,
+                &TaxData::new(20000),
+                &config,
+                &TaxData::new(0),
+                30000,
+            )
+            .err(),
+            Some(Error::DeferredAmountTooLarge)
+        );
+    }
+}
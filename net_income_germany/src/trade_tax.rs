@@ -0,0 +1,157 @@
+//! Trade tax (Gewerbesteuer) of trade businesses.
+//!
+//! The trade tax is calculated on the profit of the business (Gewerbeertrag), rounded down to full
+//! 100 and reduced by an allowance for natural persons. The base amount (Steuermessbetrag) of 3.5% of
+//! that profit is multiplied with the multiplier of the municipality (Hebesatz). Freelancers
+//! (Freiberufler) do not pay any trade tax.
+//!
+//! The trade tax is not deductible from the taxable income (§ 4 Abs. 5b EStG), but up to 4 times the
+//! base amount is credited against the income taxes (§ 35 EStG), at most the trade tax itself.
+//!
+//! The multipliers of the largest municipalities are available in [`crate::municipality`]:
+//!
+//! ```
+//! let config = net_income_germany::config::create(net_income_germany::config::latest_year()).unwrap();
+//! let tax_data = net_income_germany::TaxData::new(80000)
+//!     .with_fixed_retirement(Some(0))
+//!     .with_self_employed(true);
+//!
+//! let municipality = net_income_germany::municipality::find("München").unwrap();
+//! let result =
+//!     net_income_germany::trade_tax::calculate(&config, &tax_data, municipality.trade_tax_multiplier)
+//!         .unwrap();
+//! println!("Trade tax: {}", result.trade_tax);
+//! ```
+
+use crate::config::Config;
+use crate::error::Error;
+use crate::numeric::{Number, Numeric};
+use crate::{TaxData, TaxResult, income_tax};
+
+/// Allowance on the profit for natural persons and partnerships (§ 11 Abs. 1 GewStG).
+const ALLOWANCE: u32 = 24500;
+
+/// Rate \[0,1\] of the profit for the base amount (Steuermesszahl).
+const BASE_RATE: f32 = 0.035;
+
+/// Factor of the base amount that is credited against the income taxes.
+const CREDIT_FACTOR: u32 = 4;
+
+/// Minimum trade tax multiplier of a municipality in percent (§ 16 Abs. 4 GewStG).
+pub const MIN_MULTIPLIER: u32 = 200;
+
+/// Result struct of the tax calculation of a trade business.
+pub struct TradeTaxResult {
+    /// The base amount of the trade tax (Steuermessbetrag).
+    pub base_amount: u32,
+
+    /// The trade tax that is paid to the municipality.
+    pub trade_tax: u32,
+
+    /// The part of the trade tax that is credited against the income taxes.
+    pub income_tax_credit: u32,
+
+    /// The result of the tax calculation. The income taxes are reduced by the credit and the net
+    /// income is reduced by the trade tax.
+    pub tax_result: TaxResult,
+}
+
+/// Calculates the base amount and the trade tax for the given profit and multiplier in percent.
+///
+/// Returns the base amount and the trade tax.
+pub fn calculate_trade_tax(profit: u32, multiplier: u32) -> (u32, u32) {
+    let taxable_profit = (profit / 100 * 100).saturating_sub(ALLOWANCE);
+    let base_amount = (Number::from_u32(taxable_profit) * Number::from_f32(BASE_RATE)).to_u32();
+    let trade_tax = (Number::from_u32(base_amount) * Number::from_u32(multiplier)
+        / Number::from_u32(100))
+    .to_u32();
+
+    return (base_amount, trade_tax);
+}
+
+/// Calculates social security taxes, income taxes and the trade tax of a trade business with the
+/// given trade tax multiplier in percent.
+///
+/// The profit of the business is the income minus the expenses of the tax data.
+pub fn calculate(
+    config: &Config,
+    tax_data: &TaxData,
+    multiplier: u32,
+) -> Result<TradeTaxResult, Error> {
+    if multiplier < MIN_MULTIPLIER {
+        return Err(Error::TradeTaxMultiplierTooLow);
+    }
+
+    let regular = crate::calculate(config, tax_data)?;
+    let (base_amount, trade_tax) = calculate_trade_tax(
+        tax_data.income.saturating_sub(tax_data.expenses),
+        multiplier,
+    );
+
+    let (_, taxable_income) = crate::calculate_taxable_income(config, tax_data)?;
+    let together = tax_data.assessment.is_splitting();
+    let tariff = income_tax::calculate_tariff(&config.income_tax, taxable_income, together);
+
+    let income_tax_credit = (base_amount * CREDIT_FACTOR).min(trade_tax).min(tariff);
+    let income_taxes = tariff - income_tax_credit;
+    let income_taxes = income_taxes
+        + income_tax::calculate_solidarity_addition(
+            income_taxes,
+            together,
+            &config.income_tax.solidary_addition_config,
+        );
+
+    return Ok(TradeTaxResult {
+        base_amount,
+        trade_tax,
+        income_tax_credit,
+        tax_result: TaxResult {
+            gross_income: regular.gross_income,
+            net_income: (regular.net_income as i64 + regular.income_taxes as i64
+                - income_taxes as i64
+                - trade_tax as i64) as i32,
+            social_security_taxes: regular.social_security_taxes,
+            income_taxes,
+            // the credit of the trade tax does not reduce the church tax (§ 51a Abs. 2 Satz 3 EStG)
+            church_tax: regular.church_tax,
+        },
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "year-2025")]
+    use crate::config::create as create_config;
+
+    #[test]
+    fn test_trade_tax() {
+        assert_eq!(calculate_trade_tax(20000, 400), (0, 0));
+        assert_eq!(calculate_trade_tax(64599, 400), (1400, 5600));
+        assert_eq!(calculate_trade_tax(64500, 490), (1400, 6860));
+    }
+
+    #[test]
+    #[cfg(feature = "year-2025")]
+    fn test_income_tax_credit() {
+        let config = create_config(2025).unwrap();
+        let tax_data = TaxData::new(64500)
+            .with_fixed_retirement(Some(0))
+            .with_self_employed(true);
+
+        // the trade tax is fully credited for a multiplier of 400% (without solidarity surcharge)
+        let result = calculate(&config, &tax_data, 400).unwrap();
+        let regular = crate::calculate(&config, &tax_data).unwrap();
+        assert_eq!(result.income_tax_credit, 5600);
+        assert_eq!(result.tax_result.net_income, regular.net_income);
+
+        // the credit is limited to 4 times the base amount
+        let result = calculate(&config, &tax_data, 490).unwrap();
+        assert_eq!(result.income_tax_credit, 5600);
+
+        assert_eq!(
+            calculate(&config, &tax_data, 150).err(),
+            Some(Error::TradeTaxMultiplierTooLow)
+        );
+    }
+}
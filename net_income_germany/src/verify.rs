@@ -0,0 +1,220 @@
+//! Invariant checks for configurations.
+//!
+//! Custom configurations (e.g., with changed premiums or tax ranges) can be checked with these
+//! functions before trusting their results: the net income must increase monotonically with the
+//! gross income and the income taxes must not contain any discontinuities. Additionally, the reverse
+//! calculation from the net income needs to lead back to the gross income (see [`verify_roundtrip`]).
+
+use crate::config::{Config, IncomeTaxConfig};
+use crate::error::Error;
+use crate::{TaxData, calculate, calculate_reverse, income_tax};
+
+/// Result of the calculation from the gross income to the net income and back to the gross income.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoundtripResult {
+    /// The gross income of the given tax data.
+    pub gross_income: u32,
+
+    /// The net income calculated from the gross income.
+    pub net_income: i32,
+
+    /// The gross income calculated back from the net income.
+    pub reverse_gross_income: i32,
+
+    /// The deviation of the gross income calculated back from the original gross income.
+    pub deviation: i64,
+
+    /// Whether the absolute deviation is within the given tolerance.
+    pub is_consistent: bool,
+}
+
+/// Calculates the net income from the gross income of the given tax data, then calculates the
+/// gross income back from this net income and reports the deviation between both gross incomes.
+///
+/// As several gross incomes can lead to the same (rounded) net income, a small deviation is expected
+/// and can be allowed with the given tolerance.
+pub fn verify_roundtrip(
+    config: &Config,
+    tax_data: &TaxData,
+    tolerance: u32,
+) -> Result<RoundtripResult, Error> {
+    let net_income = calculate(config, tax_data)?.net_income;
+    if net_income <= 0 {
+        return Err(Error::NetIncomeNotPositive);
+    }
+
+    let mut reverse_tax_data = tax_data.clone();
+    reverse_tax_data.income = net_income as u32;
+    let reverse_gross_income = calculate_reverse(config, &reverse_tax_data)?.gross_income;
+
+    let deviation = reverse_gross_income as i64 - tax_data.income as i64;
+    return Ok(RoundtripResult {
+        gross_income: tax_data.income,
+        net_income,
+        reverse_gross_income,
+        deviation,
+        is_consistent: deviation.unsigned_abs() <= tolerance as u64,
+    });
+}
+
+/// Checks that the net income is monotonically increasing with the gross income for all incomes in
+/// the given range (with the given step size between two incomes), using the given tax data for all
+/// other input values.
+///
+/// Returns all incomes at which the net income is lower than for the previous income.
+pub fn check_monotonicity(
+    config: &Config,
+    tax_data: &TaxData,
+    from: u32,
+    to: u32,
+    step: u32,
+) -> Result<Vec<u32>, Error> {
+    if step == 0 {
+        return Err(Error::InvalidStepSize);
+    }
+
+    let mut violations = Vec::new();
+    let mut tax_data = tax_data.clone();
+    let mut previous_net_income = None;
+
+    for income in (from..=to).step_by(step as usize) {
+        tax_data.income = income;
+        let net_income = calculate(config, &tax_data)?.net_income;
+
+        if previous_net_income.is_some_and(|previous| net_income < previous) {
+            violations.push(income);
+        }
+        previous_net_income = Some(net_income);
+    }
+
+    return Ok(violations);
+}
+
+/// Checks that the income taxes have no discontinuities for all taxable incomes in the given range
+/// (with the given step size between two incomes). A discontinuity is assumed, when the taxes grow
+/// by more than the income grows (plus one for rounding).
+///
+/// Returns all taxable incomes at which a discontinuity was detected.
+pub fn check_continuity(
+    config: &IncomeTaxConfig,
+    splitting: bool,
+    from: u32,
+    to: u32,
+    step: u32,
+) -> Result<Vec<u32>, Error> {
+    if step == 0 {
+        return Err(Error::InvalidStepSize);
+    }
+
+    let mut violations = Vec::new();
+    let mut previous_tax: Option<u32> = None;
+
+    for income in (from..=to).step_by(step as usize) {
+        let tax = income_tax::calculate(config, income, splitting, false);
+
+        if previous_tax.is_some_and(|previous| tax.abs_diff(previous) > step + 1) {
+            violations.push(income);
+        }
+        previous_tax = Some(tax);
+    }
+
+    return Ok(violations);
+}
+
+/// Checks that the tax ranges of the given configuration start at zero and that every tax range
+/// starts where the previous one ends.
+///
+/// Returns the indices of all tax ranges that do not fulfill this.
+pub fn check_tax_ranges(config: &IncomeTaxConfig) -> Vec<usize> {
+    let mut violations = Vec::new();
+    let mut expected_lower_limit = 0;
+
+    for (index, tax_range) in config.tax_ranges.iter().enumerate() {
+        if tax_range.lower_limit != expected_lower_limit
+            || tax_range.upper_limit < tax_range.lower_limit
+        {
+            violations.push(index);
+        }
+        expected_lower_limit = tax_range.upper_limit;
+    }
+
+    return violations;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "year-2025")]
+    use crate::Assessment;
+    use crate::config::create as create_config;
+
+    #[test]
+    fn test_default_configurations_are_valid() {
+        for year in crate::config::supported_years() {
+            let config = create_config(*year).unwrap();
+            let tax_data = TaxData::new(0);
+
+            assert!(
+                check_monotonicity(&config, &tax_data, 0, 300000, 100)
+                    .unwrap()
+                    .is_empty()
+            );
+            assert!(
+                check_continuity(&config.income_tax, false, 0, 300000, 100)
+                    .unwrap()
+                    .is_empty()
+            );
+            assert!(check_tax_ranges(&config.income_tax).is_empty());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "year-2025")]
+    fn test_detects_invalid_tax_ranges() {
+        let mut config = create_config(2025).unwrap();
+
+        // create a gap between the second and third tax range with a rate above 100% afterwards
+        config.income_tax.tax_ranges[2].lower_limit += 1000;
+        config.income_tax.tax_ranges[2].rate_min = 1.5;
+
+        assert_eq!(check_tax_ranges(&config.income_tax), vec![2]);
+        assert!(
+            !check_continuity(&config.income_tax, false, 17000, 20000, 10)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "year-2025")]
+    fn test_verify_roundtrip() {
+        let config = create_config(2025).unwrap();
+        let mut tax_data = TaxData::new(0)
+            .with_expenses(1200)
+            .with_assessment(Assessment::Joint);
+
+        for income in [20000, 55555, 130000] {
+            tax_data.income = income;
+            let result = verify_roundtrip(&config, &tax_data, 2).unwrap();
+            assert!(result.is_consistent, "{result:?}");
+            assert_eq!(result.gross_income, income);
+        }
+
+        tax_data.income = 0;
+        assert_eq!(
+            verify_roundtrip(&config, &tax_data, 2).err(),
+            Some(Error::NetIncomeNotPositive)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "year-2025")]
+    fn test_error_on_zero_step() {
+        let config = create_config(2025).unwrap();
+
+        assert_eq!(
+            check_continuity(&config.income_tax, false, 0, 100, 0).err(),
+            Some(Error::InvalidStepSize)
+        );
+    }
+}
@@ -0,0 +1,139 @@
+//! Decomposition of the result into an ordered waterfall from the gross to the net income.
+//!
+//! The waterfall starts with the gross income, is followed by every deduction as negative amount and
+//! ends with the net income, so that it can directly be used for waterfall charts:
+//!
+//! ```
+//! let config = net_income_germany::config::create(net_income_germany::config::latest_year()).unwrap();
+//! let tax_data = net_income_germany::TaxData::new(60000);
+//!
+//! for step in net_income_germany::waterfall::calculate(&config, &tax_data).unwrap() {
+//!     println!("{}: {}", step.label, step.amount);
+//! }
+//! ```
+
+use crate::TaxData;
+use crate::config::Config;
+use crate::error::Error;
+use crate::result_v2::{self, DeductionCategory};
+
+/// Kind of a step of the waterfall.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StepKind {
+    /// A total value (the gross income at the start and the net income at the end).
+    Total,
+
+    /// A deduction from the previous total.
+    Deduction,
+}
+
+/// One step of the waterfall.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct WaterfallStep {
+    /// The label of the step (e.g., "Health insurance").
+    pub label: &'static str,
+
+    /// The amount of the step, which is negative for deductions.
+    pub amount: i64,
+
+    /// The kind of the step.
+    pub kind: StepKind,
+}
+
+/// Returns the label of the given category of the deductions.
+fn get_label(category: DeductionCategory) -> &'static str {
+    return match category {
+        DeductionCategory::HealthInsurance => "Health insurance",
+        DeductionCategory::NursingInsurance => "Nursing care insurance",
+        DeductionCategory::RetirementInsurance => "Retirement insurance",
+        DeductionCategory::UnemploymentInsurance => "Unemployment insurance",
+        DeductionCategory::IncomeTax => "Income tax",
+        DeductionCategory::SolidaritySurcharge => "Solidarity surcharge",
+        DeductionCategory::ChurchTax => "Church tax",
+    };
+}
+
+/// Calculates the waterfall from the gross income over the expenses, the insurances of the social
+/// security, the income taxes and the solidarity surcharge to the net income.
+///
+/// The amounts of all steps add up to the net income (see [`crate::result_v2::calculate`] for the
+/// rounding of the insurances).
+pub fn calculate(config: &Config, tax_data: &TaxData) -> Result<Vec<WaterfallStep>, Error> {
+    let result = result_v2::calculate(config, tax_data)?;
+
+    let deduction = |label, amount: u32| WaterfallStep {
+        label,
+        amount: -(amount as i64),
+        kind: StepKind::Deduction,
+    };
+
+    let mut steps = vec![
+        WaterfallStep {
+            label: "Gross income",
+            amount: result.gross_income as i64,
+            kind: StepKind::Total,
+        },
+        deduction("Expenses", tax_data.expenses),
+    ];
+
+    for (category, amount) in &result.deductions {
+        steps.push(deduction(get_label(*category), *amount));
+    }
+
+    steps.push(WaterfallStep {
+        label: "Net income",
+        amount: result.net_income as i64,
+        kind: StepKind::Total,
+    });
+
+    return Ok(steps);
+}
+
+#[cfg(all(test, feature = "year-2025"))]
+mod tests {
+    use super::*;
+    use crate::config::create as create_config;
+
+    #[test]
+    fn test_steps_add_up_to_net_income() {
+        let config = create_config(2025).unwrap();
+
+        for income in [0, 12345, 60000, 150000, 333333] {
+            let steps = calculate(&config, &TaxData::new(income).with_expenses(1500)).unwrap();
+            let (net_income, deductions) = steps.split_last().unwrap();
+
+            assert_eq!(net_income.label, "Net income");
+            assert_eq!(
+                deductions.iter().map(|step| step.amount).sum::<i64>(),
+                net_income.amount
+            );
+        }
+    }
+
+    #[test]
+    fn test_order_of_steps() {
+        let config = create_config(2025).unwrap();
+
+        let steps = calculate(&config, &TaxData::new(150000).with_expenses(1500)).unwrap();
+        let labels: Vec<&str> = steps.iter().map(|step| step.label).collect();
+        assert_eq!(
+            labels,
+            vec![
+                "Gross income",
+                "Expenses",
+                "Health insurance",
+                "Nursing care insurance",
+                "Retirement insurance",
+                "Unemployment insurance",
+                "Income tax",
+                "Solidarity surcharge",
+                "Net income"
+            ]
+        );
+        assert_eq!(steps[0].kind, StepKind::Total);
+        assert_eq!(steps[1].amount, -1500);
+        assert!(steps[7].amount < 0);
+    }
+}
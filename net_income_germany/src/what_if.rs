@@ -0,0 +1,207 @@
+//! What-if calculations with individual levies switched off or scaled.
+//!
+//! This allows to quantify the effect of a political change on the net income, e.g., what the
+//! abolishment of the solidarity surcharge or a doubled unemployment insurance premium would mean:
+//!
+//! ```
+//! use net_income_germany::what_if::{self, Levy, LevyToggle};
+//!
+//! let config = net_income_germany::config::create(net_income_germany::config::latest_year()).unwrap();
+//! let tax_data = net_income_germany::TaxData::new(150000);
+//!
+//! let result = what_if::compare(&config, &tax_data, &[LevyToggle::off(Levy::SolidaritySurcharge)])
+//!     .unwrap();
+//! assert!(result.get_net_income_change() > 0);
+//! ```
+//!
+//! The church tax can only be toggled for members of a church (see [`TaxData::church_member`]).
+
+use crate::config::Config;
+use crate::error::Error;
+use crate::{TaxData, TaxResult, calculate};
+
+/// Levies that can be switched off or scaled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Levy {
+    /// The solidarity surcharge (Solidaritätszuschlag).
+    SolidaritySurcharge,
+
+    /// The unemployment insurance (Arbeitslosenversicherung).
+    UnemploymentInsurance,
+
+    /// The additional nursing care insurance premium for childless persons (Zuschlag für
+    /// Kinderlose).
+    NursingSurcharge,
+
+    /// The health insurance with its general and additional premiums (Krankenversicherung).
+    HealthInsurance,
+
+    /// The nursing care insurance with all of its premiums (Pflegeversicherung).
+    NursingInsurance,
+
+    /// The church tax (Kirchensteuer).
+    ChurchTax,
+}
+
+/// Scaling of a levy, where a factor of zero switches the levy off.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LevyToggle {
+    /// The levy that is scaled.
+    pub levy: Levy,
+
+    /// The factor with which the rate of the levy is multiplied.
+    pub factor: f32,
+}
+
+impl LevyToggle {
+    /// Creates a toggle that switches the given levy off.
+    pub fn off(levy: Levy) -> Self {
+        return LevyToggle { levy, factor: 0.0 };
+    }
+
+    /// Applies the scaling of the levy on the given configuration.
+    pub fn apply(&self, config: &mut Config) {
+        match self.levy {
+            Levy::SolidaritySurcharge => {
+                let soli = &mut config.income_tax.solidary_addition_config;
+                soli.rate *= self.factor;
+                soli.max_percentage *= self.factor;
+                config.record_modification("income_tax.solidary_addition_config.rate");
+                config.record_modification("income_tax.solidary_addition_config.max_percentage");
+            }
+            Levy::UnemploymentInsurance => {
+                config.unemployment_insurance.premium *= self.factor;
+                config.record_modification("unemployment_insurance.premium");
+            }
+            Levy::NursingSurcharge => {
+                config.health_insurance.premium_nursing_additional *= self.factor;
+                config.record_modification("health_insurance.premium_nursing_additional");
+            }
+            Levy::HealthInsurance => {
+                let health = &mut config.health_insurance;
+                health.premium_general *= self.factor;
+                health.premium_general_reduced *= self.factor;
+                health.premium_additional *= self.factor;
+                config.record_modification("health_insurance.premium_general");
+                config.record_modification("health_insurance.premium_general_reduced");
+                config.record_modification("health_insurance.premium_additional");
+            }
+            Levy::NursingInsurance => {
+                let health = &mut config.health_insurance;
+                health.premium_nursing *= self.factor;
+                health.premium_nursing_additional *= self.factor;
+                health.premium_nursing_employee_surcharge *= self.factor;
+                config.record_modification("health_insurance.premium_nursing");
+                config.record_modification("health_insurance.premium_nursing_additional");
+                config.record_modification("health_insurance.premium_nursing_employee_surcharge");
+            }
+            Levy::ChurchTax => {
+                config.income_tax.church_tax_rate *= self.factor;
+                config.record_modification("income_tax.church_tax_rate");
+            }
+        }
+    }
+}
+
+/// Result of the comparison of the regular calculation with the what-if calculation.
+pub struct WhatIfResult {
+    /// The result with the regular levies.
+    pub baseline: TaxResult,
+
+    /// The result with the toggled levies.
+    pub what_if: TaxResult,
+}
+
+impl WhatIfResult {
+    /// Returns the change of the net income by the toggled levies (positive if the net income
+    /// increases).
+    pub fn get_net_income_change(&self) -> i64 {
+        return self.what_if.net_income as i64 - self.baseline.net_income as i64;
+    }
+}
+
+/// Compares the regular calculation with the calculation where the given levies are toggled.
+pub fn compare(
+    config: &Config,
+    tax_data: &TaxData,
+    toggles: &[LevyToggle],
+) -> Result<WhatIfResult, Error> {
+    let mut what_if_config = config.clone();
+    for toggle in toggles {
+        toggle.apply(&mut what_if_config);
+    }
+
+    return Ok(WhatIfResult {
+        baseline: calculate(config, tax_data)?,
+        what_if: calculate(&what_if_config, tax_data)?,
+    });
+}
+
+#[cfg(all(test, feature = "year-2025"))]
+mod tests {
+    use super::*;
+    use crate::config::create as create_config;
+
+    #[test]
+    fn test_switch_off_levies() {
+        let config = create_config(2025).unwrap();
+
+        // no solidarity surcharge is paid on a medium income anyway
+        let result = compare(
+            &config,
+            &TaxData::new(50000),
+            &[LevyToggle::off(Levy::SolidaritySurcharge)],
+        )
+        .unwrap();
+        assert_eq!(result.get_net_income_change(), 0);
+
+        // the employee share of the unemployment insurance premium of 2.6% is saved
+        let result = compare(
+            &config,
+            &TaxData::new(50000),
+            &[LevyToggle::off(Levy::UnemploymentInsurance)],
+        )
+        .unwrap();
+        assert_eq!(
+            result.baseline.social_security_taxes - result.what_if.social_security_taxes,
+            650
+        );
+        assert!(result.get_net_income_change() > 0);
+        assert!(result.get_net_income_change() < 650);
+    }
+
+    #[test]
+    fn test_scale_levies() {
+        let config = create_config(2025).unwrap();
+        let toggles = [
+            LevyToggle::off(Levy::SolidaritySurcharge),
+            LevyToggle {
+                levy: Levy::NursingSurcharge,
+                factor: 2.0,
+            },
+        ];
+
+        let result = compare(&config, &TaxData::new(150000), &toggles).unwrap();
+        assert!(result.what_if.income_taxes < result.baseline.income_taxes);
+        assert!(result.what_if.social_security_taxes > result.baseline.social_security_taxes);
+    }
+
+    #[test]
+    fn test_switch_off_church_tax() {
+        let config = create_config(2025).unwrap();
+        let toggles = [LevyToggle::off(Levy::ChurchTax)];
+
+        let result = compare(&config, &TaxData::new(50000), &toggles).unwrap();
+        assert_eq!(result.get_net_income_change(), 0);
+
+        let result = compare(
+            &config,
+            &TaxData::new(50000).with_church_member(true),
+            &toggles,
+        )
+        .unwrap();
+        assert!(result.get_net_income_change() > 500);
+    }
+}
@@ -0,0 +1,63 @@
+//! Checks that the core calculation does not allocate any heap memory.
+//!
+//! The check is an integration test with its own global allocator that counts the allocations of
+//! the current thread, as the library itself does not allow unsafe code.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use net_income_germany::{Assessment, TaxData, calculate, config, income_tax};
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|allocations| allocations.set(allocations.get() + 1));
+        // SAFETY: the layout is passed on unchanged to the system allocator
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        // SAFETY: the memory was allocated by the system allocator with the same layout
+        unsafe { System.dealloc(ptr, layout) };
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Returns the number of allocations of the current thread while running the given function.
+fn count_allocations(function: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(|allocations| allocations.get());
+    function();
+    ALLOCATIONS.with(|allocations| allocations.get()) - before
+}
+
+#[test]
+fn test_calculate_does_not_allocate() {
+    let config = config::create(config::latest_year()).unwrap();
+
+    for tax_data in [
+        TaxData::new(45000),
+        TaxData::new(120000)
+            .with_expenses(3000)
+            .with_assessment(Assessment::Joint),
+        TaxData::new(70000)
+            .with_self_employed(true)
+            .with_fixed_retirement(Some(600)),
+    ] {
+        let allocations = count_allocations(|| {
+            calculate(&config, &tax_data).unwrap();
+        });
+        assert_eq!(allocations, 0);
+    }
+
+    let allocations = count_allocations(|| {
+        income_tax::calculate(&config.income_tax, 60000, false, false);
+    });
+    assert_eq!(allocations, 0);
+}
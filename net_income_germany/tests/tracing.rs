@@ -0,0 +1,91 @@
+//! Checks that the calculation emits the spans and events of the `tracing` feature.
+//!
+//! The check is an integration test with its own subscriber that records the names of all spans and
+//! the messages of all events.
+
+#![cfg(feature = "tracing")]
+
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+
+use net_income_germany::{TaxData, calculate_reverse, config};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+#[derive(Clone, Default)]
+struct RecordingSubscriber {
+    records: Arc<Mutex<Vec<String>>>,
+}
+
+impl RecordingSubscriber {
+    fn push(&self, record: String) {
+        self.records.lock().unwrap().push(record);
+    }
+
+    fn contains(&self, record: &str) -> bool {
+        self.records.lock().unwrap().iter().any(|r| r == record)
+    }
+}
+
+struct MessageVisitor(Option<String>);
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        if field.name() == "message" {
+            self.0 = Some(format!("{value:?}"));
+        }
+    }
+}
+
+impl Subscriber for RecordingSubscriber {
+    fn enabled(&self, _metadata: &Metadata) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes) -> Id {
+        self.push(span.metadata().name().to_string());
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _span: &Id, _values: &Record) {}
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, event: &Event) {
+        let mut visitor = MessageVisitor(None);
+        event.record(&mut visitor);
+        if let Some(message) = visitor.0 {
+            self.push(message);
+        }
+    }
+
+    fn enter(&self, _span: &Id) {}
+
+    fn exit(&self, _span: &Id) {}
+}
+
+#[test]
+fn test_reverse_calculation_is_traced() {
+    let config = config::create(config::latest_year()).unwrap();
+    let subscriber = RecordingSubscriber::default();
+
+    tracing::subscriber::with_default(subscriber.clone(), || {
+        calculate_reverse(&config, &TaxData::new(30000).with_church_member(true)).unwrap();
+    });
+
+    for record in [
+        "calculate_reverse_with_observer",
+        "estimated the gross income",
+        "calculate_observed",
+        "calculated the insurance payment",
+        "calculated the social security taxes",
+        "calculate_tariff_observed",
+        "calculated the taxes of the tax range",
+        "calculated the income taxes",
+        "calculate_solidarity_addition",
+        "calculate_church_tax",
+    ] {
+        assert!(subscriber.contains(record), "missing record: {record}");
+    }
+}
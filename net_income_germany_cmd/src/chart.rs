@@ -0,0 +1,142 @@
+//! Rendering of the net income and the marginal tax ratio over an income range as SVG chart.
+
+use net_income_germany::TaxData;
+use net_income_germany::config::Config;
+use net_income_germany::error::Error;
+
+/// Width of the chart in pixels.
+const WIDTH: f32 = 800.0;
+
+/// Height of the chart in pixels.
+const HEIGHT: f32 = 500.0;
+
+/// Margin around the plot area for the axis labels in pixels.
+const MARGIN: f32 = 70.0;
+
+/// Number of intervals between the ticks of every axis.
+const TICKS: u32 = 5;
+
+/// Additional gross income that is used to calculate the marginal tax ratio of every point.
+const MARGINAL_INCOME: u32 = 100;
+
+/// One calculated point of the chart.
+pub struct ChartPoint {
+    /// The gross income of the point.
+    pub gross_income: u32,
+
+    /// The net income for the gross income.
+    pub net_income: i32,
+
+    /// The share \[0,1\] of an additional gross income that is spent on social security and income
+    /// taxes.
+    pub marginal_tax_ratio: f32,
+}
+
+/// Calculates the points of the chart for all incomes from `from` up to `to` in steps of `step`.
+pub fn calculate_points(
+    config: &Config,
+    tax_data: &TaxData,
+    from: u32,
+    to: u32,
+    step: u32,
+) -> Result<Vec<ChartPoint>, Error> {
+    let mut income_data = tax_data.clone();
+
+    (from..=to)
+        .step_by(step as usize)
+        .map(|income| {
+            income_data.income = income;
+            let result =
+                net_income_germany::marginal::calculate(config, &income_data, MARGINAL_INCOME)?;
+
+            Ok(ChartPoint {
+                gross_income: income,
+                net_income: result.base.net_income,
+                marginal_tax_ratio: result.get_marginal_tax_ratio(),
+            })
+        })
+        .collect()
+}
+
+/// Renders the given points as SVG document with the gross and the net income on the left axis and
+/// the marginal tax ratio on the right axis.
+pub fn render(points: &[ChartPoint]) -> String {
+    let min_income = points.first().map_or(0, |point| point.gross_income) as f32;
+    let max_income = points.last().map_or(0, |point| point.gross_income) as f32;
+    let income_range = (max_income - min_income).max(1.0);
+    let max_amount = max_income.max(1.0);
+
+    let plot_width = WIDTH - 2.0 * MARGIN;
+    let plot_height = HEIGHT - 2.0 * MARGIN;
+    let x = |income: f32| MARGIN + (income - min_income) / income_range * plot_width;
+    let y = |share: f32| HEIGHT - MARGIN - share.clamp(0.0, 1.0) * plot_height;
+
+    let polyline = |values: &dyn Fn(&ChartPoint) -> f32| {
+        points
+            .iter()
+            .map(|point| {
+                format!(
+                    "{:.1},{:.1}",
+                    x(point.gross_income as f32),
+                    y(values(point))
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(" ")
+    };
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{WIDTH}\" height=\"{HEIGHT}\" \
+         font-family=\"sans-serif\" font-size=\"12\">\n\
+         <rect width=\"100%\" height=\"100%\" fill=\"white\"/>\n"
+    );
+
+    // axes with the ticks of the incomes (left and bottom) and of the marginal tax ratio (right)
+    svg.push_str(&format!(
+        "<path d=\"M{left},{top} V{bottom} H{right} V{top}\" fill=\"none\" stroke=\"black\"/>\n",
+        left = MARGIN,
+        top = MARGIN,
+        bottom = HEIGHT - MARGIN,
+        right = WIDTH - MARGIN,
+    ));
+    for tick in 0..=TICKS {
+        let share = tick as f32 / TICKS as f32;
+        svg.push_str(&format!(
+            "<text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"middle\">{:.0}</text>\n\
+             <text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"end\">{:.0}</text>\n\
+             <text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"start\">{:.0}%</text>\n",
+            x(min_income + share * income_range),
+            HEIGHT - MARGIN + 20.0,
+            min_income + share * income_range,
+            MARGIN - 8.0,
+            y(share) + 4.0,
+            share * max_amount,
+            WIDTH - MARGIN + 8.0,
+            y(share) + 4.0,
+            share * 100.0,
+        ));
+    }
+
+    // the gross income as reference line, the net income and the marginal tax ratio as curves
+    svg.push_str(&format!(
+        "<polyline points=\"{}\" fill=\"none\" stroke=\"gray\" stroke-dasharray=\"4\"/>\n\
+         <polyline points=\"{}\" fill=\"none\" stroke=\"steelblue\" stroke-width=\"2\"/>\n\
+         <polyline points=\"{}\" fill=\"none\" stroke=\"firebrick\" stroke-width=\"2\"/>\n",
+        polyline(&|point| point.gross_income as f32 / max_amount),
+        polyline(&|point| point.net_income as f32 / max_amount),
+        polyline(&|point| point.marginal_tax_ratio),
+    ));
+    svg.push_str(&format!(
+        "<text x=\"{MARGIN}\" y=\"{:.1}\" fill=\"steelblue\">Net income</text>\n\
+         <text x=\"{:.1}\" y=\"{:.1}\" fill=\"firebrick\">Marginal tax ratio</text>\n\
+         <text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"middle\">Gross income</text>\n\
+         </svg>\n",
+        MARGIN - 20.0,
+        WIDTH / 2.0,
+        MARGIN - 20.0,
+        WIDTH / 2.0,
+        HEIGHT - MARGIN + 45.0,
+    ));
+
+    svg
+}
@@ -4,17 +4,48 @@
 //! ```
 //! $ net-income-germany-cmd --income 80000
 //! ```
+//!
+//! The income can be given multiple times to print one result per income:
+//! ```
+//! $ net-income-germany-cmd --income 45000 --income 60000 --income 80000
+//! ```
+//!
+//! Input values can be stored as named scenario and calculated again later by name:
+//! ```
+//! $ net-income-germany-cmd --income 60000 --save-scenario "current job"
+//! $ net-income-germany-cmd --scenario "current job" --scenario "offer A"
+//! ```
+//!
+//! The result can be stored as JSON and compared with a later run (e.g., after a new year):
+//! ```
+//! $ net-income-germany-cmd --income 80000 --year 2024 --json > result.json
+//! $ net-income-germany-cmd --income 80000 --year 2025 --baseline result.json
+//! ```
+//!
+//! The `chart` subcommand renders the net income over an income range as SVG chart instead:
+//! ```
+//! $ net-income-germany-cmd chart --to 150000 --output chart.svg
+//! ```
 
-use clap::Parser;
+mod chart;
+mod scenarios;
+
+use clap::{Parser, Subcommand};
+use std::fs;
+use std::path::PathBuf;
 use std::process;
 
 /// Command line arguments of the application.
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 struct Args {
-    /// Annual income before taxes, social security and tax-deductible expenses (or net income in case of --reverse)
-    #[arg(short, long)]
-    income: u32,
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Annual income before taxes, social security and tax-deductible expenses (or net income in case of --reverse), can be given multiple times for one result per income
+    #[arg(short, long, required_unless_present_any = ["target_net_ratio", "scenario"])]
+    income: Vec<u32>,
 
     /// Tax-deductible expenses
     #[arg(short, long, default_value_t = 0)]
@@ -32,13 +63,480 @@ struct Args {
     #[arg(short, long)]
     married: bool,
 
-    /// For which year the taxes should be calculated
-    #[arg(short, long, default_value_t = 2025)]
-    year: u32,
+    /// Calculate with tax splitting for the year after the death of the spouse
+    #[arg(long, conflicts_with = "married")]
+    widowed: bool,
+
+    /// For which year the taxes should be calculated (or `latest` for the latest supported year or `current` for the current year)
+    #[arg(short, long, default_value = "2025", value_parser = parse_year)]
+    year: Year,
 
     /// When set, the income is interpreted as net income and the gross income will be calculated from it
     #[arg(short, long)]
     reverse: bool,
+
+    /// Monthly premium of a private health insurance (the employer subsidy is deducted for employees)
+    #[arg(long, conflicts_with_all = ["fixed_health", "reverse"])]
+    private_health: Option<u32>,
+
+    /// Fixed monthly amount that is paid for the health insurance instead of the percentage of the income
+    #[arg(long, conflicts_with = "reverse")]
+    fixed_health: Option<u32>,
+
+    /// Additional premium [0,1] of the statutory health insurance company (defaults to the Zusatzbeitrag of Techniker Krankenkasse)
+    #[arg(long)]
+    additional_premium: Option<f32>,
+
+    /// Statutory health insurance company whose additional premium of the year applies (e.g., tk, barmer, aok-bayern)
+    #[arg(long, conflicts_with = "additional_premium", value_parser = parse_health_insurer)]
+    krankenkasse: Option<&'static net_income_germany::health_insurer::HealthInsurer>,
+
+    /// Calculate for a member of a church, who pays the church tax with the rate of the given federal state (e.g., BY or NW)
+    #[arg(long, value_parser = parse_federal_state)]
+    church_tax: Option<net_income_germany::bundesland::Bundesland>,
+
+    /// Calculate for a person with children, so that the surcharge of the nursing care insurance for childless persons does not apply
+    #[arg(long)]
+    has_children: bool,
+
+    /// File into which the waterfall of the result is written as tidy data (income, component, amount)
+    #[arg(long, conflicts_with_all = ["private_health", "fixed_health", "reverse"])]
+    plot_data: Option<PathBuf>,
+
+    /// Maximum income of a sweep from the income in steps of --sweep-step (written into the plot data)
+    #[arg(long, requires = "plot_data")]
+    sweep_to: Option<u32>,
+
+    /// Step size of the incomes of a sweep
+    #[arg(long, default_value_t = 1000, requires = "sweep_to", value_parser = clap::value_parser!(u32).range(1..))]
+    sweep_step: u32,
+
+    /// File into which the calculation is written as spreadsheet with live formulas (flat OpenDocument, .fods), requires a single income
+    #[arg(long, conflicts_with_all = ["private_health", "fixed_health", "reverse"])]
+    spreadsheet: Option<PathBuf>,
+
+    /// Print the results as JSON (see the `json` module of the net-income-germany crate)
+    #[arg(long, conflicts_with_all = ["compare_employment", "scenario"])]
+    json: bool,
+
+    /// JSON file of a previous run (see --json) whose result is compared with every result of this run
+    #[arg(long, conflicts_with_all = ["json", "compare_employment", "target_net_ratio", "scenario"])]
+    baseline: Option<PathBuf>,
+
+    /// Compare the net income of an employed person with the one of a self-employed person with the same income
+    #[arg(long, conflicts_with_all = ["self_employed", "reverse", "private_health", "fixed_health", "plot_data"])]
+    compare_employment: bool,
+
+    /// Search the smallest gross income at which the net ratio falls to the given value [0,1] (instead of calculating the income)
+    #[arg(long, conflicts_with_all = ["income", "reverse", "private_health", "fixed_health", "plot_data", "compare_employment"])]
+    target_net_ratio: Option<f32>,
+
+    /// Store the input values under the given name in the configuration directory of the user (requires a single income)
+    #[arg(long, conflicts_with_all = ["reverse", "private_health", "fixed_health"])]
+    save_scenario: Option<String>,
+
+    /// Calculate the stored scenario with the given name (instead of the other input values), can be given multiple times to compare the scenarios
+    #[arg(long, conflicts_with_all = ["income", "save_scenario", "target_net_ratio", "compare_employment", "plot_data"])]
+    scenario: Vec<String>,
+
+    /// Invoiced rate per day of the self-employed person in the comparison (instead of the income)
+    #[arg(long, requires = "compare_employment")]
+    day_rate: Option<u32>,
+
+    /// Invoiced days of the year of the self-employed person in the comparison
+    #[arg(long, default_value_t = 200, requires = "day_rate")]
+    days: u32,
+}
+
+/// Subcommands of the application.
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Renders an SVG chart of the net income and the marginal tax ratio over an income range
+    Chart(ChartArgs),
+}
+
+/// Command line arguments of the chart subcommand.
+#[derive(clap::Args, Debug)]
+struct ChartArgs {
+    /// Smallest annual gross income of the chart
+    #[arg(long, default_value_t = 0)]
+    from: u32,
+
+    /// Largest annual gross income of the chart
+    #[arg(long, default_value_t = 150000)]
+    to: u32,
+
+    /// Step size of the gross incomes of the chart
+    #[arg(long, default_value_t = 1000, value_parser = clap::value_parser!(u32).range(1..))]
+    step: u32,
+
+    /// Calculate social security and income taxes for a self-employed person
+    #[arg(short, long)]
+    self_employed: bool,
+
+    /// Calculate with tax splitting for a married couple
+    #[arg(short, long)]
+    married: bool,
+
+    /// For which year the taxes should be calculated (or `latest` for the latest supported year or `current` for the current year)
+    #[arg(short, long, default_value = "2025", value_parser = parse_year)]
+    year: Year,
+
+    /// File into which the SVG chart is written
+    #[arg(short, long)]
+    output: PathBuf,
+}
+
+/// Year of the configuration, given as number or as keyword.
+#[derive(Clone, Copy, Debug)]
+enum Year {
+    /// A specific year (e.g., 2025).
+    Number(u32),
+
+    /// The latest year that the library supports.
+    Latest,
+
+    /// The current calendar year (or the latest supported year, if it is not supported yet).
+    Current,
+}
+
+/// Parses the year argument, which is either a number or one of the keywords `latest` and `current`.
+fn parse_year(value: &str) -> Result<Year, String> {
+    match value {
+        "latest" => Ok(Year::Latest),
+        "current" => Ok(Year::Current),
+        _ => value.parse().map(Year::Number).map_err(|_| {
+            format!("`{value}` is neither a year nor one of the keywords `latest` and `current`")
+        }),
+    }
+}
+
+/// Parses the federal state from its name or abbreviation.
+fn parse_federal_state(value: &str) -> Result<net_income_germany::bundesland::Bundesland, String> {
+    value.parse().map_err(|_| {
+        let abbreviations: Vec<_> = net_income_germany::bundesland::Bundesland::ALL
+            .iter()
+            .map(|state| state.abbreviation())
+            .collect();
+        format!(
+            "unknown federal state `{value}` (known are {})",
+            abbreviations.join(", ")
+        )
+    })
+}
+
+/// Parses the health insurance company from its key or name.
+fn parse_health_insurer(
+    value: &str,
+) -> Result<&'static net_income_germany::health_insurer::HealthInsurer, String> {
+    net_income_germany::health_insurer::find(value).ok_or_else(|| {
+        let keys: Vec<_> = net_income_germany::health_insurer::HEALTH_INSURERS
+            .iter()
+            .map(|insurer| insurer.key)
+            .collect();
+        format!(
+            "unknown health insurance company `{value}` (known are {})",
+            keys.join(", ")
+        )
+    })
+}
+
+/// Creates the tax configuration for the given year and prints a warning, if the current year is
+/// not supported and the latest supported year is used instead.
+fn create_config(
+    year: Year,
+) -> Result<net_income_germany::config::Config, net_income_germany::error::Error> {
+    match year {
+        Year::Number(year) => net_income_germany::config::create(year),
+        Year::Latest => {
+            net_income_germany::config::create(net_income_germany::config::latest_year())
+        }
+        Year::Current => {
+            let current = net_income_germany::config::create_current()?;
+            if let Some(warning) = current.warning {
+                eprintln!(
+                    "Warning: {warning} Using the year {} instead.",
+                    current.config.origin().year
+                );
+            }
+            Ok(current.config)
+        }
+    }
+}
+
+/// Calculates the points of the chart for the given arguments and writes the SVG chart into the
+/// output file.
+fn write_chart(args: &ChartArgs) -> Result<(), String> {
+    if args.from > args.to {
+        return Err(String::from(
+            "The smallest income needs to be less or equal to the largest income.",
+        ));
+    }
+
+    let config = create_config(args.year).map_err(|err| err.to_string())?;
+    let tax_data = net_income_germany::TaxData::new(args.from)
+        .with_self_employed(args.self_employed)
+        .with_assessment(match args.married {
+            true => net_income_germany::Assessment::Joint,
+            false => net_income_germany::Assessment::Single,
+        });
+
+    let points = chart::calculate_points(&config, &tax_data, args.from, args.to, args.step)
+        .map_err(|err| err.to_string())?;
+    fs::write(&args.output, chart::render(&points)).map_err(|err| err.to_string())
+}
+
+/// Prints a notice about the default values of the configuration that were assumed, because the
+/// corresponding arguments were not given.
+fn print_assumptions(args: &Args, config: &net_income_germany::config::Config) {
+    if args.additional_premium.is_none() && args.krankenkasse.is_none() {
+        eprintln!(
+            "Notice: Assumed the additional health insurance premium of Techniker Krankenkasse ({:.2} %), which differs per insurance company (set it with --additional-premium or --krankenkasse).",
+            config.health_insurance.premium_additional * 100.0
+        );
+    }
+    if !args.has_children {
+        eprintln!(
+            "Notice: Assumed the surcharge of the nursing care insurance for childless persons ({:.2} %), which does not apply for parents (omit it with --has-children).",
+            config.health_insurance.premium_nursing_additional * 100.0
+        );
+    }
+}
+
+/// Formats the given tax result as one row of the output.
+fn format_result(tax_result: &net_income_germany::TaxResult) -> String {
+    format!(
+        "Gross income: {}, net income: {}, social security taxes: {}, income taxes: {}, church tax: {}, net ratio: {}",
+        tax_result.gross_income,
+        tax_result.net_income,
+        tax_result.social_security_taxes,
+        tax_result.income_taxes,
+        tax_result.church_tax,
+        1.0 - tax_result.get_tax_ratio()
+    )
+}
+
+/// Formats the given tax result as JSON object or as one row of the output.
+fn format_output(tax_result: &net_income_germany::TaxResult, json: bool) -> String {
+    match json {
+        true => net_income_germany::json::result_to_json(tax_result),
+        false => format_result(tax_result),
+    }
+}
+
+/// Formats the given difference as absolute value and, if available, as percentage.
+fn format_value_diff(diff: &net_income_germany::diff::ValueDiff) -> String {
+    format!(
+        "{}{}",
+        diff.value,
+        diff.percentage.map_or(String::new(), |percentage| format!(
+            " ({:.1} %)",
+            percentage * 100.0
+        ))
+    )
+}
+
+/// Loads the result of a previous run from the given JSON file (as printed with --json).
+///
+/// The income taxes of the JSON representation include the church tax, which is separated again for
+/// the comparison with the income taxes of a result. Baselines of the schema version 1 have no
+/// separate church tax, so their church tax is considered as part of the income taxes.
+fn load_baseline(path: &PathBuf) -> Result<net_income_germany::TaxResult, String> {
+    let invalid =
+        |reason: String| format!("The baseline {} is invalid ({reason}).", path.display());
+
+    let json = fs::read_to_string(path).map_err(|err| invalid(err.to_string()))?;
+    let value: serde_json::Value =
+        serde_json::from_str(&json).map_err(|err| invalid(err.to_string()))?;
+    let field = |name: &str| {
+        value[name]
+            .as_i64()
+            .ok_or_else(|| invalid(format!("the field `{name}` is missing")))
+    };
+
+    let schema_version = field("schema_version")?;
+    if schema_version > net_income_germany::json::SCHEMA_VERSION as i64 {
+        return Err(invalid(format!(
+            "the schema version {schema_version} is not supported"
+        )));
+    }
+
+    let church_tax = match schema_version {
+        1 => 0,
+        _ => field("church_tax")?,
+    };
+
+    Ok(net_income_germany::TaxResult::new(
+        field("gross_income")? as i32,
+        field("net_income")? as i32,
+        field("social_security_taxes")? as u32,
+        (field("income_taxes")? - church_tax) as u32,
+    )
+    .with_church_tax(church_tax as u32))
+}
+
+/// Formats the differences from the given baseline to the given tax result as one row of the
+/// output.
+fn format_baseline_diff(
+    baseline: &net_income_germany::TaxResult,
+    tax_result: &net_income_germany::TaxResult,
+) -> String {
+    let diff = baseline.diff(tax_result);
+    format!(
+        "Difference to the baseline: gross income: {}, net income: {}, social security taxes: {}, income taxes: {}, church tax: {}",
+        format_value_diff(&diff.gross_income),
+        format_value_diff(&diff.net_income),
+        format_value_diff(&diff.social_security_taxes),
+        format_value_diff(&diff.income_taxes),
+        format_value_diff(&diff.church_tax)
+    )
+}
+
+/// Prints the results of an employed and of a self-employed person and the difference of their net
+/// incomes. The revenue of the self-employed person is either the income of the given tax data or
+/// the given day rate times the given days.
+fn print_employment_comparison(
+    config: &net_income_germany::config::Config,
+    tax_data: &net_income_germany::TaxData,
+    day_rate: Option<(u32, u32)>,
+) -> Result<(), net_income_germany::error::Error> {
+    let employed =
+        net_income_germany::calculate(config, &tax_data.clone().with_self_employed(false))?;
+    let self_employed = match day_rate {
+        Some((day_rate, days)) => {
+            net_income_germany::freelancer::calculate(
+                config,
+                &net_income_germany::freelancer::FreelancerData {
+                    day_rate,
+                    days,
+                    expenses: 0,
+                    input_vat: 0,
+                    vat_scheme: net_income_germany::freelancer::VatScheme::Regular,
+                    fixed_retirement: tax_data.fixed_retirement,
+                    assessment: tax_data.assessment,
+                },
+            )?
+            .tax_result
+        }
+        None => net_income_germany::calculate(config, &tax_data.clone().with_self_employed(true))?,
+    };
+
+    let diff = employed.diff(&self_employed);
+    println!("Employed: {}", format_result(&employed));
+    println!("Self-employed: {}", format_result(&self_employed));
+    println!(
+        "Difference of the net income (self-employed minus employed): {}",
+        format_value_diff(&diff.net_income)
+    );
+
+    Ok(())
+}
+
+/// Stores the input values of the given arguments as scenario with the given name.
+fn save_scenario(
+    name: &str,
+    args: &Args,
+    config: &net_income_germany::config::Config,
+    tax_data: &net_income_germany::TaxData,
+) -> Result<(), String> {
+    let [income] = args.income[..] else {
+        return Err(String::from("A scenario requires exactly one income."));
+    };
+
+    let mut scenario = net_income_germany::scenario::Scenario {
+        label: name.to_string(),
+        year: config.origin().year,
+        config_overrides: net_income_germany::scenario::ConfigOverrides {
+            health_premium_additional: (args.additional_premium.is_some()
+                || args.krankenkasse.is_some())
+            .then_some(config.health_insurance.premium_additional),
+            nursing_premium_additional: args
+                .has_children
+                .then_some(config.health_insurance.premium_nursing_additional),
+            church_tax_rate: args.church_tax.map(|_| config.income_tax.church_tax_rate),
+            ..Default::default()
+        },
+        tax_data: tax_data.clone(),
+    };
+    scenario.tax_data.income = income;
+
+    let path = scenarios::save(&scenario)?;
+    eprintln!("Saved the scenario `{name}` into {}.", path.display());
+
+    Ok(())
+}
+
+/// Calculates the stored scenarios with the given names and prints one result row per scenario
+/// and the differences of the net incomes to the first scenario.
+fn print_scenarios(names: &[String]) -> Result<(), String> {
+    let mut base_result: Option<net_income_germany::TaxResult> = None;
+
+    for name in names {
+        let tax_result = scenarios::load(name)?
+            .run()
+            .map_err(|err| err.to_string())?;
+        let difference = base_result.as_ref().map_or(String::new(), |base| {
+            format!(
+                ", net income difference to {}: {}",
+                names[0],
+                base.diff(&tax_result).net_income.value
+            )
+        });
+        println!("{name}: {}{difference}", format_result(&tax_result));
+
+        base_result.get_or_insert(tax_result);
+    }
+
+    Ok(())
+}
+
+/// Writes the waterfalls of all incomes from each of the given incomes up to the given maximum
+/// income (or only of the incomes themselves) into the given file in long format.
+fn write_plot_data(
+    path: &PathBuf,
+    config: &net_income_germany::config::Config,
+    tax_data: &net_income_germany::TaxData,
+    incomes: &[u32],
+    sweep_to: Option<u32>,
+    sweep_step: u32,
+) -> Result<(), String> {
+    let mut plot_data = String::from("income,component,amount\n");
+    let mut income_data = tax_data.clone();
+
+    for first_income in incomes {
+        for income in
+            (*first_income..=sweep_to.unwrap_or(*first_income)).step_by(sweep_step as usize)
+        {
+            income_data.income = income;
+            for step in net_income_germany::waterfall::calculate(config, &income_data)
+                .map_err(|err| err.to_string())?
+            {
+                plot_data.push_str(&format!("{},{},{}\n", income, step.label, step.amount));
+            }
+        }
+    }
+
+    fs::write(path, plot_data).map_err(|err| err.to_string())
+}
+
+/// Writes the calculation of the single given income as spreadsheet into the given file.
+fn write_spreadsheet(
+    path: &PathBuf,
+    config: &net_income_germany::config::Config,
+    tax_data: &net_income_germany::TaxData,
+    incomes: &[u32],
+) -> Result<(), String> {
+    let [income] = incomes[..] else {
+        return Err(String::from("A spreadsheet requires exactly one income."));
+    };
+
+    let mut tax_data = tax_data.clone();
+    tax_data.income = income;
+    fs::write(
+        path,
+        net_income_germany::spreadsheet::to_fods(config, &tax_data),
+    )
+    .map_err(|err| err.to_string())
 }
 
 /// Parses command line arguments, calls the net-income-germany crate then for
@@ -47,39 +545,148 @@ struct Args {
 fn main() {
     let args = Args::parse();
 
-    let tax_data = net_income_germany::TaxData {
-        income: args.income,
-        expenses: args.expenses,
-        fixed_retirement: args.fixed_retirement,
-        self_employed: args.self_employed,
-        married: args.married,
-    };
+    if let Some(Command::Chart(chart_args)) = &args.command {
+        write_chart(chart_args).unwrap_or_else(|err| {
+            eprintln!("Failed to create the chart: {err}");
+            process::exit(1);
+        });
+        return;
+    }
+
+    if !args.scenario.is_empty() {
+        print_scenarios(&args.scenario).unwrap_or_else(|err| {
+            eprintln!("Failed to calculate the scenarios: {err}");
+            process::exit(1);
+        });
+        return;
+    }
+
+    let mut tax_data = net_income_germany::TaxData::new(0)
+        .with_expenses(args.expenses)
+        .with_fixed_retirement(args.fixed_retirement)
+        .with_self_employed(args.self_employed)
+        .with_assessment(match (args.married, args.widowed) {
+            (true, _) => net_income_germany::Assessment::Joint,
+            (false, true) => net_income_germany::Assessment::WidowedSplitting,
+            (false, false) => net_income_germany::Assessment::Single,
+        })
+        .with_church_member(args.church_tax.is_some());
 
     // create the tax configuration for the given year
-    let config: net_income_germany::config::Config = net_income_germany::config::create(args.year)
-        .unwrap_or_else(|err| {
+    let mut config: net_income_germany::config::Config =
+        create_config(args.year).unwrap_or_else(|err| {
+            eprintln!("Failed to calculate the taxes: {err}");
+            process::exit(1);
+        });
+    net_income_germany::scenario::ConfigOverrides {
+        health_premium_additional: args.additional_premium,
+        nursing_premium_additional: args.has_children.then_some(0.0),
+        church_tax_rate: args.church_tax.map(|state| state.get_church_tax_rate()),
+        ..Default::default()
+    }
+    .apply(&mut config);
+    if let Some(insurer) = args.krankenkasse {
+        insurer.apply(&mut config).unwrap_or_else(|err| {
             eprintln!("Failed to calculate the taxes: {err}");
             process::exit(1);
         });
+    }
 
     // Calculate the taxes with the configuration and the given tax data. This
     // can be either gross income to net income or net income to gross income
     // (reverse).
-    let tax_result = match args.reverse {
-        false => net_income_germany::calculate(&config, &tax_data),
-        true => net_income_germany::calculate_reverse(&config, &tax_data),
+    let health_insurance = match (args.private_health, args.fixed_health) {
+        (Some(premium), _) => {
+            net_income_germany::health_insurance::HealthInsurance::Private(premium)
+        }
+        (None, Some(amount)) => {
+            net_income_germany::health_insurance::HealthInsurance::Fixed(amount)
+        }
+        (None, None) => net_income_germany::health_insurance::HealthInsurance::Statutory,
+    };
+    if health_insurance == net_income_germany::health_insurance::HealthInsurance::Statutory {
+        print_assumptions(&args, &config);
+    }
+
+    if let Some(name) = &args.save_scenario {
+        save_scenario(name, &args, &config, &tax_data).unwrap_or_else(|err| {
+            eprintln!("Failed to save the scenario: {err}");
+            process::exit(1);
+        });
     }
-    .unwrap_or_else(|err| {
-        eprintln!("Failed to calculate the taxes: {err}");
-        process::exit(1);
+
+    if let Some(net_ratio) = args.target_net_ratio {
+        let tax_result =
+            net_income_germany::goal_seek::find_net_ratio(&config, &tax_data, net_ratio)
+                .unwrap_or_else(|err| {
+                    eprintln!("Failed to find the gross income: {err}");
+                    process::exit(1);
+                });
+        println!("{}", format_output(&tax_result, args.json));
+        return;
+    }
+
+    let baseline = args.baseline.as_ref().map(|path| {
+        load_baseline(path).unwrap_or_else(|err| {
+            eprintln!("Failed to load the baseline: {err}");
+            process::exit(1);
+        })
     });
 
-    println!(
-        "Gross income: {}, net income: {}, social security taxes: {}, income taxes: {}, net ratio: {}",
-        tax_result.gross_income,
-        tax_result.net_income,
-        tax_result.social_security_taxes,
-        tax_result.income_taxes,
-        1.0 - tax_result.get_tax_ratio()
-    )
+    // print one result row per given income
+    for income in &args.income {
+        tax_data.income = *income;
+        if args.compare_employment {
+            print_employment_comparison(
+                &config,
+                &tax_data,
+                args.day_rate.map(|day_rate| (day_rate, args.days)),
+            )
+            .unwrap_or_else(|err| {
+                eprintln!("Failed to calculate the taxes: {err}");
+                process::exit(1);
+            });
+            continue;
+        }
+
+        let tax_result = match args.reverse {
+            false => net_income_germany::health_insurance::calculate(
+                &config,
+                &tax_data,
+                health_insurance,
+            ),
+            true => net_income_germany::calculate_reverse(&config, &tax_data),
+        }
+        .unwrap_or_else(|err| {
+            eprintln!("Failed to calculate the taxes: {err}");
+            process::exit(1);
+        });
+
+        println!("{}", format_output(&tax_result, args.json));
+        if let Some(baseline) = &baseline {
+            println!("{}", format_baseline_diff(baseline, &tax_result));
+        }
+    }
+
+    if let Some(path) = &args.plot_data {
+        write_plot_data(
+            path,
+            &config,
+            &tax_data,
+            &args.income,
+            args.sweep_to,
+            args.sweep_step,
+        )
+        .unwrap_or_else(|err| {
+            eprintln!("Failed to write the plot data: {err}");
+            process::exit(1);
+        });
+    }
+
+    if let Some(path) = &args.spreadsheet {
+        write_spreadsheet(path, &config, &tax_data, &args.income).unwrap_or_else(|err| {
+            eprintln!("Failed to write the spreadsheet: {err}");
+            process::exit(1);
+        });
+    }
 }
@@ -0,0 +1,57 @@
+//! Storage of named scenarios in the configuration directory of the user, so that recurring
+//! calculations (e.g., "current job" and "offer A") can be repeated and compared by name.
+
+use net_income_germany::scenario::Scenario;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+/// Returns the directory in which the scenarios are stored (e.g., `~/.config/net-income-germany/scenarios`).
+fn get_directory() -> Result<PathBuf, String> {
+    let config_directory = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("APPDATA").map(PathBuf::from))
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .ok_or("The configuration directory of the user could not be determined.")?;
+
+    Ok(config_directory
+        .join("net-income-germany")
+        .join("scenarios"))
+}
+
+/// Returns the path of the file of the scenario with the given name.
+fn get_path(name: &str) -> Result<PathBuf, String> {
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == ' ' || c == '-' || c == '_')
+    {
+        return Err(format!(
+            "The scenario name `{name}` may only contain letters, digits, spaces, dashes and underscores."
+        ));
+    }
+
+    Ok(get_directory()?.join(format!("{name}.json")))
+}
+
+/// Writes the given scenario into the file of its label and returns the path of the file.
+pub fn save(scenario: &Scenario) -> Result<PathBuf, String> {
+    let path = get_path(&scenario.label)?;
+    if let Some(directory) = path.parent() {
+        fs::create_dir_all(directory).map_err(|err| err.to_string())?;
+    }
+
+    let json = serde_json::to_string_pretty(scenario).map_err(|err| err.to_string())?;
+    fs::write(&path, json).map_err(|err| err.to_string())?;
+
+    Ok(path)
+}
+
+/// Reads the scenario with the given name.
+pub fn load(name: &str) -> Result<Scenario, String> {
+    let path = get_path(name)?;
+    let json = fs::read_to_string(&path)
+        .map_err(|err| format!("The scenario `{name}` could not be read ({err})."))?;
+
+    serde_json::from_str(&json).map_err(|err| format!("The scenario `{name}` is invalid ({err})."))
+}
@@ -28,6 +28,42 @@ fn calculate_reverse_for_current_year() -> Result<(), Box<dyn std::error::Error>
     Ok(())
 }
 
+#[test]
+fn calculate_with_church_tax() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("net-income-germany-cmd")?;
+
+    cmd.arg("--income")
+        .arg("80000")
+        .arg("--church-tax")
+        .arg("BY");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(" 46883,"))
+        .stdout(predicate::str::contains(
+            "income taxes: 16122, church tax: 1289,",
+        ));
+
+    let mut cmd = Command::cargo_bin("net-income-germany-cmd")?;
+    cmd.arg("--income")
+        .arg("80000")
+        .arg("--church-tax")
+        .arg("NW");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(" 46722,"));
+
+    let mut cmd = Command::cargo_bin("net-income-germany-cmd")?;
+    cmd.arg("--income")
+        .arg("80000")
+        .arg("--church-tax")
+        .arg("Atlantis");
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("unknown federal state"));
+
+    Ok(())
+}
+
 #[test]
 fn error_on_unknown_year() -> Result<(), Box<dyn std::error::Error>> {
     let mut cmd = Command::cargo_bin("net-income-germany-cmd")?;
@@ -36,7 +72,8 @@ fn error_on_unknown_year() -> Result<(), Box<dyn std::error::Error>> {
     cmd.arg("--year").arg("2000");
     cmd.assert()
         .failure()
-        .stderr(predicate::str::contains("No configuration available"));
+        .stderr(predicate::str::contains("No configuration available"))
+        .stderr(predicate::str::contains("the nearest one is 2024"));
 
     Ok(())
 }
@@ -45,11 +82,339 @@ fn error_on_unknown_year() -> Result<(), Box<dyn std::error::Error>> {
 fn error_on_too_large_inputs() -> Result<(), Box<dyn std::error::Error>> {
     let mut cmd = Command::cargo_bin("net-income-germany-cmd")?;
 
+    cmd.arg("--income").arg((i32::MAX as u32 + 1).to_string());
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("too large"))
+        .stderr(predicate::str::contains("the limit is 2147483647"));
+
+    Ok(())
+}
+
+#[test]
+fn calculate_with_private_health_insurance() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("net-income-germany-cmd")?;
+
+    cmd.arg("--income").arg("80000");
+    cmd.arg("--private-health").arg("600");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(" 50306,"))
+        .stdout(predicate::str::contains(" 12080,"));
+
+    Ok(())
+}
+
+#[test]
+fn write_plot_data_of_sweep() -> Result<(), Box<dyn std::error::Error>> {
+    let path = std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join("plot_data.csv");
+    let mut cmd = Command::cargo_bin("net-income-germany-cmd")?;
+
+    cmd.arg("--income").arg("80000");
+    cmd.arg("--plot-data").arg(&path);
+    cmd.arg("--sweep-to").arg("82000");
+    cmd.assert().success();
+
+    let plot_data = std::fs::read_to_string(&path)?;
+    assert!(plot_data.starts_with("income,component,amount\n80000,Gross income,80000\n"));
+    assert!(plot_data.contains("81000,Net income,"));
+    assert!(plot_data.ends_with("82000,Net income,49230\n"));
+
+    Ok(())
+}
+
+#[test]
+fn compare_with_baseline() -> Result<(), Box<dyn std::error::Error>> {
+    let path = std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join("baseline.json");
+    let mut cmd = Command::cargo_bin("net-income-germany-cmd")?;
+
+    cmd.arg("--income").arg("80000").arg("--json");
+    let output = cmd.assert().success().get_output().stdout.clone();
+    assert!(String::from_utf8(output.clone())?.contains("\"net_income\": 48172"));
+    std::fs::write(&path, output)?;
+
+    let mut cmd = Command::cargo_bin("net-income-germany-cmd")?;
+    cmd.arg("--income")
+        .arg("80000")
+        .arg("--baseline")
+        .arg(&path);
+    cmd.assert().success().stdout(predicate::str::contains(
+        "Difference to the baseline: gross income: 0 (0.0 %), net income: 0 (0.0 %)",
+    ));
+
+    let mut cmd = Command::cargo_bin("net-income-germany-cmd")?;
+    cmd.arg("--income")
+        .arg("90000")
+        .arg("--baseline")
+        .arg(&path);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("gross income: 10000 (12.5 %)"));
+
+    // the church tax is compared separately from the income taxes
+    let mut cmd = Command::cargo_bin("net-income-germany-cmd")?;
+    cmd.arg("--income")
+        .arg("80000")
+        .arg("--church-tax")
+        .arg("BY")
+        .arg("--json");
+    let output = cmd.assert().success().get_output().stdout.clone();
+    std::fs::write(&path, output)?;
+
+    let mut cmd = Command::cargo_bin("net-income-germany-cmd")?;
+    cmd.arg("--income")
+        .arg("80000")
+        .arg("--church-tax")
+        .arg("BY")
+        .arg("--baseline")
+        .arg(&path);
+    cmd.assert().success().stdout(predicate::str::contains(
+        "income taxes: 0 (0.0 %), church tax: 0 (0.0 %)",
+    ));
+
+    std::fs::write(&path, "{\"schema_version\": 1}")?;
+    let mut cmd = Command::cargo_bin("net-income-germany-cmd")?;
+    cmd.arg("--income")
+        .arg("80000")
+        .arg("--baseline")
+        .arg(&path);
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "the field `gross_income` is missing",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn write_spreadsheet() -> Result<(), Box<dyn std::error::Error>> {
+    let path = std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join("net_income.fods");
+    let mut cmd = Command::cargo_bin("net-income-germany-cmd")?;
+
+    cmd.arg("--income").arg("80000");
+    cmd.arg("--spreadsheet").arg(&path);
+    cmd.assert().success();
+
+    let document = std::fs::read_to_string(&path)?;
+    assert!(document.contains("office:value=\"80000\""));
+    assert!(document.contains("table:formula=\"of:=[.B2]-[.B30]-[.B36]-[.B3]\""));
+
+    let mut cmd = Command::cargo_bin("net-income-germany-cmd")?;
     cmd.arg("--income")
-        .arg((std::i32::MAX as u32 + 1).to_string());
+        .arg("80000")
+        .arg("--income")
+        .arg("90000");
+    cmd.arg("--spreadsheet").arg(&path);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("exactly one income"));
+
+    Ok(())
+}
+
+#[test]
+fn render_chart() -> Result<(), Box<dyn std::error::Error>> {
+    let path = std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join("chart.svg");
+    let mut cmd = Command::cargo_bin("net-income-germany-cmd")?;
+
+    cmd.arg("chart").arg("--to").arg("100000");
+    cmd.arg("--output").arg(&path);
+    cmd.assert().success();
+
+    let svg = std::fs::read_to_string(&path)?;
+    assert!(svg.starts_with("<svg"));
+    assert_eq!(svg.matches("<polyline").count(), 3);
+    assert!(svg.contains(">100000</text>"));
+
+    Ok(())
+}
+
+#[test]
+fn error_on_invalid_chart_range() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("net-income-germany-cmd")?;
+
+    cmd.arg("chart")
+        .arg("--from")
+        .arg("50000")
+        .arg("--to")
+        .arg("40000");
+    cmd.arg("--output").arg("chart.svg");
     cmd.assert()
         .failure()
-        .stderr(predicate::str::contains("too large"));
+        .stderr(predicate::str::contains("smallest income"));
+
+    Ok(())
+}
+
+#[test]
+fn calculate_for_latest_year() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("net-income-germany-cmd")?;
+
+    cmd.arg("--income").arg("80000").arg("--year").arg("latest");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(" 48172,"));
+
+    let mut cmd = Command::cargo_bin("net-income-germany-cmd")?;
+
+    cmd.arg("--income").arg("80000").arg("--year").arg("next");
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "neither a year nor one of the keywords",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn notice_about_assumed_defaults() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("net-income-germany-cmd")?;
+
+    cmd.arg("--income").arg("80000");
+    cmd.assert()
+        .success()
+        .stderr(predicate::str::contains("Techniker Krankenkasse (2.45 %)"))
+        .stderr(predicate::str::contains("childless persons (0.60 %)"));
+
+    let mut cmd = Command::cargo_bin("net-income-germany-cmd")?;
+
+    cmd.arg("--income").arg("80000");
+    cmd.arg("--additional-premium").arg("0.017");
+    cmd.arg("--has-children");
+    cmd.assert()
+        .success()
+        .stderr(predicate::str::contains("Notice").not())
+        .stdout(predicate::str::contains(" 80000,"))
+        .stdout(predicate::str::contains(" 48172,").not());
+
+    Ok(())
+}
+
+#[test]
+fn calculate_with_health_insurer() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("net-income-germany-cmd")?;
+
+    cmd.arg("--income").arg("80000");
+    cmd.arg("--krankenkasse").arg("aok-bayern");
+    cmd.assert()
+        .success()
+        .stderr(predicate::str::contains("Techniker Krankenkasse").not())
+        .stdout(predicate::str::contains(" 80000,"));
+
+    let mut cmd = Command::cargo_bin("net-income-germany-cmd")?;
+
+    cmd.arg("--income").arg("80000");
+    cmd.arg("--krankenkasse").arg("unknown");
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("known are tk, barmer"));
+
+    Ok(())
+}
+
+#[test]
+fn calculate_multiple_incomes() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("net-income-germany-cmd")?;
+
+    cmd.arg("--income").arg("45000");
+    cmd.arg("--income").arg("80000");
+    let output = cmd.assert().success().get_output().stdout.clone();
+    let output = String::from_utf8(output)?;
+
+    assert_eq!(output.lines().count(), 2);
+    assert!(output.lines().next().unwrap().contains(" 45000,"));
+    assert!(output.lines().nth(1).unwrap().contains(" 48172,"));
+
+    Ok(())
+}
+
+#[test]
+fn compare_employment() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("net-income-germany-cmd")?;
+
+    cmd.arg("--income").arg("80000").arg("--compare-employment");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Employed: Gross income: 80000, net income: 48172,",
+        ))
+        .stdout(predicate::str::contains(
+            "Self-employed: Gross income: 80000,",
+        ))
+        .stdout(predicate::str::contains("Difference of the net income"));
+
+    let mut cmd = Command::cargo_bin("net-income-germany-cmd")?;
+
+    cmd.arg("--income").arg("80000").arg("--compare-employment");
+    cmd.arg("--day-rate").arg("800").arg("--days").arg("150");
+    cmd.assert().success().stdout(predicate::str::contains(
+        "Self-employed: Gross income: 120000,",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn search_target_net_ratio() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("net-income-germany-cmd")?;
+
+    cmd.arg("--target-net-ratio").arg("0.65");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("net ratio: 0.6"));
+
+    let mut cmd = Command::cargo_bin("net-income-germany-cmd")?;
+
+    cmd.arg("--target-net-ratio").arg("0.1");
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("No income was found"));
+
+    Ok(())
+}
+
+#[test]
+fn save_and_compare_scenarios() -> Result<(), Box<dyn std::error::Error>> {
+    let config_directory = std::env::temp_dir().join(format!(
+        "net-income-germany-scenarios-{}",
+        std::process::id()
+    ));
+
+    for (name, income) in [("current job", "60000"), ("offer A", "66000")] {
+        let mut cmd = Command::cargo_bin("net-income-germany-cmd")?;
+
+        cmd.env("XDG_CONFIG_HOME", &config_directory);
+        cmd.arg("--income")
+            .arg(income)
+            .arg("--save-scenario")
+            .arg(name);
+        cmd.assert()
+            .success()
+            .stderr(predicate::str::contains("Saved the scenario"));
+    }
+
+    let mut cmd = Command::cargo_bin("net-income-germany-cmd")?;
+
+    cmd.env("XDG_CONFIG_HOME", &config_directory);
+    cmd.arg("--scenario").arg("current job");
+    cmd.arg("--scenario").arg("offer A");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "current job: Gross income: 60000,",
+        ))
+        .stdout(predicate::str::contains("offer A: Gross income: 66000,"))
+        .stdout(predicate::str::contains(
+            "net income difference to current job: ",
+        ));
+
+    let mut cmd = Command::cargo_bin("net-income-germany-cmd")?;
+
+    cmd.env("XDG_CONFIG_HOME", &config_directory);
+    cmd.arg("--scenario").arg("offer B");
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("could not be read"));
+
+    std::fs::remove_dir_all(config_directory)?;
 
     Ok(())
 }
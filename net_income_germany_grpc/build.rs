@@ -0,0 +1,17 @@
+//! Generates the server and the client of the `NetIncomeCalculator` service of the Protocol Buffers
+//! schema of the library.
+//!
+//! The message types are not generated again, but the ones of the `proto` feature of the library are
+//! used. The schema is compiled with protox, so that no installation of protoc is needed.
+
+fn main() {
+    let schema = "../net_income_germany/proto/net_income_germany.proto";
+    println!("cargo::rerun-if-changed={schema}");
+
+    let file_descriptors = protox::compile([schema], ["../net_income_germany/proto"])
+        .unwrap_or_else(|err| panic!("{schema} is not a valid schema: {err}"));
+    tonic_prost_build::configure()
+        .extern_path(".net_income_germany.v1", "::net_income_germany::proto")
+        .compile_fds(file_descriptors)
+        .expect("the service needs to be generated from the schema");
+}
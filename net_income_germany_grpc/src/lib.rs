@@ -0,0 +1,132 @@
+//! gRPC server of the net income calculation.
+//!
+//! Implements the `NetIncomeCalculator` service of the Protocol Buffers schema of the library
+//! (`proto/net_income_germany.proto`) with tonic. The requests are handled by the transport
+//! independent functions of [`net_income_germany::service`], so that this crate only forwards the
+//! requests and converts the errors of the calculation into status codes.
+//!
+//! The calculations run on the thread pool for blocking tasks, so that they do not block the worker
+//! threads of the server. The results of the `SweepCurve` RPC are streamed one by one, so that
+//! clients get the first results while the following ones are still calculated.
+
+use std::net::SocketAddr;
+use std::pin::Pin;
+
+use net_income_germany::error::Error;
+use net_income_germany::proto::{
+    CalculateRequest, ConfigSummary, GetConfigRequest, SweepCurveRequest, TaxResult,
+};
+use net_income_germany::service;
+use tokio::sync::mpsc;
+use tokio_stream::Stream;
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::metadata::MetadataValue;
+use tonic::transport::Server;
+use tonic::{Request, Response, Status};
+
+/// The server and the client of the service, generated from the schema.
+pub mod generated {
+    tonic::include_proto!("net_income_germany.v1");
+}
+
+pub use generated::net_income_calculator_client::NetIncomeCalculatorClient;
+pub use generated::net_income_calculator_server::{NetIncomeCalculator, NetIncomeCalculatorServer};
+
+/// Name of the metadata entry of an error status with the code of the error (see [`Error::code`]).
+pub const ERROR_CODE_METADATA: &str = "x-error-code";
+
+/// Number of results of the `SweepCurve` RPC that are calculated ahead of the stream.
+const SWEEP_CURVE_BUFFER: usize = 16;
+
+/// Converts an error of the calculation into a status with the English message of the error and
+/// its code as metadata.
+///
+/// All errors of the calculation are caused by invalid values of the request, except for the
+/// unsupported years, for which there is no configuration.
+fn to_status(error: Error) -> Status {
+    let mut status = match error {
+        Error::UnsupportedYear { .. } => Status::not_found(error.to_string()),
+        _ => Status::invalid_argument(error.to_string()),
+    };
+    status.metadata_mut().insert(
+        ERROR_CODE_METADATA,
+        MetadataValue::from_static(error.code()),
+    );
+
+    status
+}
+
+/// Runs the given calculation on the thread pool for blocking tasks, so that a long calculation
+/// does not block a worker thread of the server.
+async fn run_blocking<T: Send + 'static>(
+    calculation: impl FnOnce() -> Result<T, Error> + Send + 'static,
+) -> Result<T, Status> {
+    tokio::task::spawn_blocking(calculation)
+        .await
+        .map_err(|err| Status::internal(err.to_string()))?
+        .map_err(to_status)
+}
+
+/// Implementation of the `NetIncomeCalculator` service.
+#[derive(Debug, Default)]
+pub struct Calculator;
+
+#[tonic::async_trait]
+impl NetIncomeCalculator for Calculator {
+    async fn calculate(
+        &self,
+        request: Request<CalculateRequest>,
+    ) -> Result<Response<TaxResult>, Status> {
+        let request = request.into_inner();
+        let result = run_blocking(move || service::calculate(&request)).await?;
+        Ok(Response::new(result))
+    }
+
+    async fn calculate_reverse(
+        &self,
+        request: Request<CalculateRequest>,
+    ) -> Result<Response<TaxResult>, Status> {
+        let request = request.into_inner();
+        let result = run_blocking(move || service::calculate_reverse(&request)).await?;
+        Ok(Response::new(result))
+    }
+
+    async fn get_config(
+        &self,
+        request: Request<GetConfigRequest>,
+    ) -> Result<Response<ConfigSummary>, Status> {
+        let summary = service::get_config(request.get_ref()).map_err(to_status)?;
+        Ok(Response::new(summary))
+    }
+
+    type SweepCurveStream = Pin<Box<dyn Stream<Item = Result<TaxResult, Status>> + Send>>;
+
+    async fn sweep_curve(
+        &self,
+        request: Request<SweepCurveRequest>,
+    ) -> Result<Response<Self::SweepCurveStream>, Status> {
+        // the request is validated before the stream starts, so that invalid requests fail directly
+        let results = service::sweep_curve(request.get_ref()).map_err(to_status)?;
+
+        // the results are calculated on the thread pool for blocking tasks and sent to the stream
+        // one by one, until the client cancels the stream
+        let (sender, receiver) = mpsc::channel(SWEEP_CURVE_BUFFER);
+        tokio::task::spawn_blocking(move || {
+            for result in results {
+                if sender.blocking_send(result.map_err(to_status)).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Ok(Response::new(Box::pin(ReceiverStream::new(receiver))))
+    }
+}
+
+/// Serves the service on the given address until the server fails.
+pub async fn serve(address: SocketAddr) -> Result<(), tonic::transport::Error> {
+    Server::builder()
+        .add_service(NetIncomeCalculatorServer::new(Calculator))
+        .serve(address)
+        .await
+}
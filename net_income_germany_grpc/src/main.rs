@@ -0,0 +1,32 @@
+//! Serves the net income calculation over gRPC.
+//!
+//! The server listens on the local port 50051 by default:
+//! ```
+//! $ net-income-germany-grpc --address 0.0.0.0:50051
+//! ```
+
+use clap::Parser;
+use std::net::SocketAddr;
+use std::process;
+
+/// Command line arguments of the server.
+#[derive(Parser, Debug)]
+#[command(version, about, long_about = None)]
+struct Args {
+    /// Address on which the server listens for requests
+    #[arg(short, long, default_value = "127.0.0.1:50051")]
+    address: SocketAddr,
+}
+
+#[tokio::main]
+async fn main() {
+    let args = Args::parse();
+
+    println!("Serving the net income calculation on {}", args.address);
+    net_income_germany_grpc::serve(args.address)
+        .await
+        .unwrap_or_else(|err| {
+            eprintln!("Failed to serve the net income calculation: {err}");
+            process::exit(1);
+        });
+}
@@ -0,0 +1,159 @@
+use net_income_germany::config;
+use net_income_germany::proto::{
+    Assessment, CalculateRequest, GetConfigRequest, SweepCurveRequest, TaxData, TaxResult,
+};
+use net_income_germany_grpc::{
+    Calculator, ERROR_CODE_METADATA, NetIncomeCalculatorClient, NetIncomeCalculatorServer,
+};
+use tokio::net::TcpListener;
+use tokio_stream::StreamExt;
+use tokio_stream::wrappers::TcpListenerStream;
+use tonic::Code;
+use tonic::transport::{Channel, Server};
+
+/// Starts the server on a free local port and returns a client that is connected to it.
+async fn start_server() -> NetIncomeCalculatorClient<Channel> {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+
+    tokio::spawn(
+        Server::builder()
+            .add_service(NetIncomeCalculatorServer::new(Calculator))
+            .serve_with_incoming(TcpListenerStream::new(listener)),
+    );
+
+    NetIncomeCalculatorClient::connect(format!("http://{address}"))
+        .await
+        .unwrap()
+}
+
+fn create_request(income: u32) -> CalculateRequest {
+    CalculateRequest {
+        year: config::latest_year(),
+        tax_data: Some(TaxData {
+            income,
+            assessment: Assessment::Single as i32,
+            ..TaxData::default()
+        }),
+    }
+}
+
+#[tokio::test]
+async fn test_calculate_and_reverse() {
+    let mut client = start_server().await;
+
+    let result = client
+        .calculate(create_request(60000))
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(result.gross_income, 60000);
+    assert!(result.net_income > 0);
+
+    let reverse_result = client
+        .calculate_reverse(create_request(result.net_income as u32))
+        .await
+        .unwrap()
+        .into_inner();
+    assert!(reverse_result.gross_income.abs_diff(60000) <= 10);
+}
+
+#[tokio::test]
+async fn test_get_config() {
+    let mut client = start_server().await;
+
+    let summary = client
+        .get_config(GetConfigRequest {
+            year: config::latest_year(),
+        })
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(summary.year, config::latest_year());
+    assert!(summary.income_tax_basic_allowance > 0);
+}
+
+#[tokio::test]
+async fn test_sweep_curve_is_streamed() {
+    let mut client = start_server().await;
+
+    let request = SweepCurveRequest {
+        year: config::latest_year(),
+        tax_data: create_request(0).tax_data,
+        from: 20000,
+        to: 100000,
+        step: 20000,
+    };
+    let results: Vec<TaxResult> = client
+        .sweep_curve(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .map(|result| result.unwrap())
+        .collect()
+        .await;
+    assert_eq!(
+        results
+            .iter()
+            .map(|result| result.gross_income)
+            .collect::<Vec<_>>(),
+        [20000, 40000, 60000, 80000, 100000]
+    );
+
+    let status = client
+        .sweep_curve(SweepCurveRequest { step: 0, ..request })
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), Code::InvalidArgument);
+
+    let status = client
+        .sweep_curve(SweepCurveRequest {
+            to: u32::MAX,
+            step: 1,
+            ..request
+        })
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), Code::InvalidArgument);
+}
+
+#[tokio::test]
+async fn test_errors_are_converted_into_status_codes() {
+    let mut client = start_server().await;
+
+    let status = client
+        .calculate(CalculateRequest {
+            year: 2000,
+            ..create_request(60000)
+        })
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), Code::NotFound);
+    assert_eq!(
+        status.metadata().get(ERROR_CODE_METADATA).unwrap(),
+        "unsupported_year"
+    );
+
+    let status = client
+        .calculate(CalculateRequest {
+            tax_data: None,
+            ..create_request(60000)
+        })
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), Code::InvalidArgument);
+}
+
+#[tokio::test]
+async fn test_unreachable_net_income_fails() {
+    let mut client = start_server().await;
+
+    let mut request = create_request(0);
+    request.tax_data.as_mut().unwrap().self_employed = true;
+    let status = client.calculate_reverse(request).await.unwrap_err();
+    assert_eq!(status.code(), Code::InvalidArgument);
+    assert_eq!(
+        status.metadata().get(ERROR_CODE_METADATA).unwrap(),
+        "net_income_not_reachable"
+    );
+}